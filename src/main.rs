@@ -1,6 +1,9 @@
 // --== MODULE IMPORTS ==-- //
     mod utils;
-    use utils::misc::colour_codes::ColourCode;
+    use utils::misc::{
+        colour_codes::ColourCode, currency::Currency, levelling::Levelling, multiclassing::Multiclassing,
+        schedule_time::reminder_offsets_from_config, wizard::{Wizard, DEFAULT_WIZARD}
+    };
 
     mod startup;
     use startup::{
@@ -14,7 +17,8 @@
     mod runtime;
     use runtime::{
         context_keys,
-        runtime_client
+        runtime_client,
+        scheduler
     };
 // ==--
 
@@ -34,13 +38,17 @@
 
     // SQLX
         use sqlx::{
-            sqlite::SqliteConnectOptions, SqlitePool, Row
+            sqlite::SqliteConnectOptions, SqlitePool
         };
 
     // TOML
         use toml::{Table, Value};
 
-    use crate::runtime::sql_scripts::characters::get_character_identifiers;
+    use crate::runtime::sql_scripts::{
+        characters::get_character_identifiers,
+        maintenance::{get_orphan_rows, run_migrations},
+        spells::{parse_spell_data, seed_spells, SPELL_DATA}
+    };
 // ==--
 
 
@@ -166,6 +174,36 @@ async fn main() -> ExitCode{
                 missing_fields.push("wakeup_channel_id");
             }
 
+            // Optional fields only need checking when present
+            if !matches!( config_data.get("auto_register_profiles"), None | Some(Value::Boolean(_)) ) {
+                missing_fields.push("auto_register_profiles");
+            }
+            if !matches!( config_data.get("review_channel_id"), None | Some(Value::Integer(_)) ) {
+                missing_fields.push("review_channel_id");
+            }
+            if !match config_data.get("bot_admins") {
+                None => true,
+                Some(Value::Array(user_ids)) => user_ids.iter().all(Value::is_integer),
+                Some(_) => false
+            } {
+                missing_fields.push("bot_admins");
+            }
+            if Currency::from_config(config_data.get("currency_denominations")).is_err() {
+                missing_fields.push("currency_denominations");
+            }
+            if Levelling::from_config(config_data.get("levelling")).is_err() {
+                missing_fields.push("levelling");
+            }
+            if Multiclassing::from_config(config_data.get("multiclassing")).is_err() {
+                missing_fields.push("multiclassing");
+            }
+            if reminder_offsets_from_config(config_data.get("session_reminders")).is_err() {
+                missing_fields.push("session_reminders");
+            }
+            if !matches!( config_data.get("wizard_file"), None | Some(Value::String(_)) ) {
+                missing_fields.push("wizard_file");
+            }
+
             missing_fields
         };
 
@@ -201,6 +239,62 @@ async fn main() -> ExitCode{
             match &config_data["bot_token"] { Value::String(token) => token, _ => panic!("Code failed to validate field `bot_token`") };
         let wakeup_channel_id =
             match &config_data["wakeup_channel_id"] { Value::Integer(id) => *id as u64, _ => panic!("Code failed to validate field `wakeup_channel_id`") };
+        let auto_register_profiles =
+            matches!( config_data.get("auto_register_profiles"), Some(Value::Boolean(true)) );
+        let review_channel_id =
+            match config_data.get("review_channel_id") { Some(Value::Integer(id)) => Some(*id as u64), _ => None };
+        let bot_admin_ids: Vec<u64> =
+            match config_data.get("bot_admins") { Some(Value::Array(user_ids)) => user_ids.iter().filter_map(Value::as_integer).map(|id| id as u64).collect(), _ => vec![] };
+        let currency =
+            Currency::from_config(config_data.get("currency_denominations")).expect("Code failed to validate field `currency_denominations`");
+        let levelling =
+            Levelling::from_config(config_data.get("levelling")).expect("Code failed to validate field `levelling`");
+        let multiclassing =
+            Multiclassing::from_config(config_data.get("multiclassing")).expect("Code failed to validate field `multiclassing`");
+        let session_reminder_offsets =
+            reminder_offsets_from_config(config_data.get("session_reminders")).expect("Code failed to validate field `session_reminders`");
+    // ==--
+
+    // --== READ CREATION WIZARD ==-- //
+
+        // Servers may ask their own questions when creating characters, those are checked now so
+        // that a mistake in them can't surface halfway through someone's character
+        print!("Reading creation wizard... ");
+        let wizard_data = match config_data.get("wizard_file") {
+            Some(Value::String(wizard_path)) => match fs::read_to_string(wizard_path) {
+                Ok(contents) => contents,
+                Err(why) => {
+                    println!(
+                        "{}Error{}: Failed to read wizard file `{}{}{}`: `{}{}{}`",
+                        ColourCode::Error,
+                        ColourCode::Reset,
+                        ColourCode::Field,
+                        wizard_path,
+                        ColourCode::Reset,
+                        ColourCode::Info,
+                        why,
+                        ColourCode::Reset
+                    );
+                    return ExitCode::from(1);
+                }
+            },
+            _ => DEFAULT_WIZARD.to_string()
+        };
+        let wizard = match Wizard::parse(&wizard_data) {
+            Ok(wizard) => wizard,
+            Err(why) => {
+                println!(
+                    "{}Error{}: The creation wizard is malformed: `{}{}{}`",
+                    ColourCode::Error,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    why,
+                    ColourCode::Reset
+                );
+                return ExitCode::from(1);
+            }
+        };
+        println!("{}Ok!{}", ColourCode::Success, ColourCode::Reset);
     // ==--
 
     // --== OPEN DATABASE ==-- //
@@ -208,7 +302,8 @@ async fn main() -> ExitCode{
         print!("Opening connection to database... ");
         let sqlite_connection_options = SqliteConnectOptions::new()
             .filename("realm.db")
-            .create_if_missing(true);
+            .create_if_missing(true)
+            .foreign_keys(true);
 
 
         let db_connection = match SqlitePool::connect_with(sqlite_connection_options).await {
@@ -235,7 +330,21 @@ async fn main() -> ExitCode{
     // --== RUN INIT SCRIPT ==-- // 
     
         print!("Running database migration script... ");
-        match sqlx::migrate!("./src/migrations").run(&db_connection).await {
+        let mut migration_connection = match db_connection.acquire().await {
+            Ok(conn) => conn,
+            Err(why) => {
+                println!(
+                    "{}Error{}: Unable to acquire a database connection: `{}{}{}`",
+                    ColourCode::Error,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    why,
+                    ColourCode::Reset
+                );
+                return ExitCode::from(20);
+            }
+        };
+        match run_migrations(&mut migration_connection).await {
             Ok(()) => println!("{}Ok!{}", ColourCode::Success, ColourCode::Reset),
             Err(why) => {
                 println!(
@@ -249,6 +358,84 @@ async fn main() -> ExitCode{
                 return ExitCode::from(1);
             }
         }
+        drop(migration_connection);
+    // ==--
+
+    // --== SEED SPELL CATALOGUE ==-- //
+
+        print!("Seeding spell catalogue... ");
+        let new_spells = match parse_spell_data(SPELL_DATA) {
+            Ok(new_spells) => new_spells,
+            Err(why) => {
+                println!(
+                    "{}Error{}: The spell catalogue is malformed: `{}{}{}`",
+                    ColourCode::Error,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    why,
+                    ColourCode::Reset
+                );
+                return ExitCode::from(1);
+            }
+        };
+        match seed_spells(&db_connection, &new_spells).await {
+            Ok(_) => println!("{}Ok!{}", ColourCode::Success, ColourCode::Reset),
+            Err(why) => {
+                println!(
+                    "{}Error{}: Unable to seed the spell catalogue: `{}{}{}`",
+                    ColourCode::Error,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    why,
+                    ColourCode::Reset
+                );
+                return ExitCode::from(20);
+            }
+        }
+    // ==--
+
+    // --== CHECK DATABASE INTEGRITY ==-- //
+
+        // Rows left dangling from before foreign keys were enforced are not fatal, but they are
+        // worth bringing to the attention of whoever hosts the bot
+        print!("Checking database integrity... ");
+        match get_orphan_rows(&db_connection).await {
+            Ok(orphan_rows) if orphan_rows.is_empty() => println!("{}Ok!{}", ColourCode::Success, ColourCode::Reset),
+            Ok(orphan_rows) => {
+                println!(
+                    "{}Warning{}: Found `{}{}{}` orphan row(s)",
+                    ColourCode::Warning,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    orphan_rows.len(),
+                    ColourCode::Reset
+                );
+                for orphan_row in orphan_rows {
+                    println!(
+                        "    `{}{}{}` row `{}{}{}` refers to a missing row in `{}{}{}`",
+                        ColourCode::Field,
+                        orphan_row.table,
+                        ColourCode::Reset,
+                        ColourCode::Info,
+                        orphan_row.rowid.map_or(String::from("?"), |rowid| rowid.to_string()),
+                        ColourCode::Reset,
+                        ColourCode::Field,
+                        orphan_row.parent,
+                        ColourCode::Reset
+                    );
+                }
+            },
+            Err(why) => {
+                println!(
+                    "{}Warning{}: Unable to check database integrity: `{}{}{}`",
+                    ColourCode::Warning,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    why,
+                    ColourCode::Reset
+                );
+            }
+        }
     // ==--
     
     // --== PREPARE CACHE ==-- //
@@ -269,29 +456,8 @@ async fn main() -> ExitCode{
             }
         };
         let mut user_characters: HashMap<u64, HashMap<u64, String>> = HashMap::new();
-        for row in character_identifiers {
-            let ( owner_id, character_id, character_name ): (u64, u64, String) = (
-                row.get(0),
-                row.get(1),
-                row.get(2)
-            );
-
-            match user_characters.get_mut(&owner_id) {
-                None => {
-                    let mut characters_map = HashMap::new();
-                    characters_map.insert(character_id, character_name);
-                    user_characters.insert(
-                        owner_id,
-                        characters_map
-                    );
-                },
-                Some(user_data) => {
-                    user_data.insert(
-                        character_id,
-                        character_name
-                    );
-                }
-            }
+        for identifier in character_identifiers {
+            context_keys::cache_user_character(&mut user_characters, identifier.owner.get(), identifier.id, identifier.name);
         }
     // ==-- 
     
@@ -302,9 +468,20 @@ async fn main() -> ExitCode{
             | GatewayIntents::MESSAGE_CONTENT
             | GatewayIntents::GUILD_MESSAGE_REACTIONS;
 
+        // The scheduler keeps its own handle on the database, next to the one the client holds
+        let scheduler_connection = db_connection.clone();
+
         let client = runtime_client::RuntimeClient {
             database_connection: db_connection,
-            wakeup_channel_id
+            wakeup_channel_id,
+            auto_register_profiles,
+            review_channel_id,
+            bot_admin_ids,
+            currency,
+            levelling,
+            multiclassing,
+            session_reminder_offsets,
+            wizard
         };
 
         println!(
@@ -356,6 +533,14 @@ async fn main() -> ExitCode{
         };
     // ==--
     
+    // --== START SCHEDULER ==-- //
+
+        // Reminders are kept in the database, so any that came due while offline go out now
+        print!("Starting scheduler... ");
+        scheduler::start(scheduler_connection, bot_client.http.clone());
+        println!("{}Ok!{}", ColourCode::Success, ColourCode::Reset);
+    // ==--
+
     // --== STARTING CLIENT ==-- //
         println!("\nStarting Client... {}Ok!{}", ColourCode::Success, ColourCode::Reset);
         println!("\n\nBegin Log:");
@@ -0,0 +1,191 @@
+-- Rebuilds every table that refers to a character or a user so that their foreign keys cascade,
+-- following SQLite's "make other kinds of table schema changes" procedure: create the new table,
+-- copy the rows over, drop the old table and rename the new one into its place. This is run with
+-- foreign keys switched off, see `sql_scripts::run_migrations`.
+--
+-- `LoresOfMagic` and `CharacterClasses` do not refer to anything, so they are left untouched
+
+
+
+-- PREPARE DATA --
+
+-- Every character's owner gets a profile, as `Characters.ownerDiscordID` now refers to one.
+-- Characters without an owner are kept as they are, their owner left `NULL`
+INSERT INTO DiscordUsers (pk_discordID, fk_selectedCharacter)
+  SELECT DISTINCT ownerDiscordID, NULL
+  FROM Characters
+  WHERE ownerDiscordID IS NOT NULL
+    AND ownerDiscordID NOT IN ( SELECT pk_discordID FROM DiscordUsers );
+
+-- Names must now be unique per owner. Keep the oldest character's name as is, and suffix the rest
+-- with their ID. Should that match a name the owner already has, a counter is added after the ID
+-- and counted up until it doesn't. As every suffix holds a different ID, the renamed characters
+-- can't clash with each other
+CREATE TABLE  CharacterRenames
+(
+    pk_characterID  INTEGER  PRIMARY KEY,
+    newName         TEXT     NOT NULL
+);
+
+WITH RECURSIVE CandidateNames (pk_characterID, ownerDiscordID, name, attempt, newName) AS (
+    SELECT pk_characterID, ownerDiscordID, name, 1, name || ' (' || pk_characterID || ')'
+    FROM Characters
+    WHERE ownerDiscordID IS NOT NULL
+      AND pk_characterID NOT IN (
+          SELECT MIN(pk_characterID)
+          FROM Characters
+          GROUP BY ownerDiscordID, name
+      )
+
+    UNION ALL
+
+    SELECT pk_characterID, ownerDiscordID, name, attempt + 1, name || ' (' || pk_characterID || '-' || (attempt + 1) || ')'
+    FROM CandidateNames
+    WHERE EXISTS (
+        SELECT 1
+        FROM Characters
+        WHERE Characters.ownerDiscordID = CandidateNames.ownerDiscordID
+          AND Characters.name = CandidateNames.newName
+    )
+)
+INSERT INTO CharacterRenames
+  SELECT pk_characterID, newName
+  FROM CandidateNames
+  WHERE NOT EXISTS (
+      SELECT 1
+      FROM Characters
+      WHERE Characters.ownerDiscordID = CandidateNames.ownerDiscordID
+        AND Characters.name = CandidateNames.newName
+  );
+
+UPDATE Characters
+  SET name = ( SELECT newName FROM CharacterRenames WHERE CharacterRenames.pk_characterID = Characters.pk_characterID )
+  WHERE pk_characterID IN ( SELECT pk_characterID FROM CharacterRenames );
+
+DROP TABLE CharacterRenames;
+
+
+
+-- CREATE NEW TABLES --
+CREATE TABLE  DiscordUsers_new
+(
+    pk_discordID          INTEGER  PRIMARY KEY,
+    fk_selectedCharacter  INTEGER,
+
+    FOREIGN KEY (fk_selectedCharacter)
+    REFERENCES Characters_new (pk_characterID)
+    ON DELETE SET NULL
+);
+
+
+CREATE TABLE  Characters_new
+(
+    pk_characterID  INTEGER  PRIMARY KEY,
+    ownerDiscordID  INTEGER,
+
+    name         TEXT  NOT NULL,
+    species      TEXT  NOT NULL,
+    alignment    TEXT  NOT NULL,
+    likes        TEXT  NOT NULL,
+    dislikes     TEXT  NOT NULL,
+    motivations  TEXT  NOT NULL,
+    companions   TEXT  NOT NULL,
+    backstory    TEXT  NOT NULL,
+    appearance   TEXT  NOT NULL,
+    extras       TEXT  NOT NULL,
+
+    UNIQUE (ownerDiscordID, name),
+
+    FOREIGN KEY (ownerDiscordID)
+    REFERENCES DiscordUsers_new (pk_discordID)
+    ON DELETE CASCADE
+);
+
+
+CREATE TABLE  CharacterStats_new
+(
+    pk_fk_characterID  INTEGER  PRIMARY KEY,
+
+    strength           INTEGER  NOT NULL,
+    dexterity          INTEGER  NOT NULL,
+    perception         INTEGER  NOT NULL,
+    knowledge          INTEGER  NOT NULL,
+    constitution       INTEGER  NOT NULL,
+    casting            INTEGER  NOT NULL,
+
+    FOREIGN KEY (pk_fk_characterID)
+    REFERENCES Characters_new (pk_characterID)
+    ON DELETE CASCADE
+);
+
+
+CREATE TABLE  CharacterUsedLores_new
+(
+    pk_fk_loreID       INTEGER  NOT NULL,
+    pk_fk_characterID  INTEGER  NOT NULL,
+    spentPoints        INTEGER  NOT NULL,
+
+    PRIMARY KEY (pk_fk_loreID, pk_fk_characterID),
+
+    FOREIGN KEY (pk_fk_loreID)      REFERENCES LoresOfMagic(pk_loreID)        ON DELETE RESTRICT,
+    FOREIGN KEY (pk_fk_characterID) REFERENCES Characters_new(pk_characterID) ON DELETE CASCADE
+);
+
+
+CREATE TABLE  SelectedCharacterClasses_new
+(
+    pk_fk_characterID  INTEGER  NOT NULL,
+    pk_fk_classID      INTEGER  NOT NULL,
+
+    PRIMARY KEY (pk_fk_characterID, pk_fk_classID),
+
+    FOREIGN KEY(pk_fk_characterID) REFERENCES Characters_new(pk_characterID) ON DELETE CASCADE,
+    FOREIGN KEY(pk_fk_classID)     REFERENCES CharacterClasses(pk_classID)   ON DELETE RESTRICT
+);
+
+
+
+-- COPY DATA --
+INSERT INTO DiscordUsers_new
+  SELECT pk_discordID, fk_selectedCharacter FROM DiscordUsers;
+
+INSERT INTO Characters_new
+  SELECT
+      pk_characterID, ownerDiscordID,
+      name, species, alignment, likes, dislike, motivations, companions, backstory, appearance, extras
+  FROM Characters;
+
+INSERT INTO CharacterStats_new
+  SELECT * FROM CharacterStats;
+
+INSERT INTO CharacterUsedLores_new
+  SELECT * FROM CharacterUsedLores;
+
+INSERT INTO SelectedCharacterClasses_new
+  SELECT * FROM SelectedCharacterClasses;
+
+
+
+-- SWAP TABLES --
+DROP TABLE SelectedCharacterClasses;
+DROP TABLE CharacterUsedLores;
+DROP TABLE CharacterStats;
+DROP TABLE Characters;
+DROP TABLE DiscordUsers;
+
+-- Renaming also rewrites the `*_new` references in the other tables' foreign keys
+ALTER TABLE DiscordUsers_new              RENAME TO DiscordUsers;
+ALTER TABLE Characters_new                RENAME TO Characters;
+ALTER TABLE CharacterStats_new            RENAME TO CharacterStats;
+ALTER TABLE CharacterUsedLores_new        RENAME TO CharacterUsedLores;
+ALTER TABLE SelectedCharacterClasses_new  RENAME TO SelectedCharacterClasses;
+
+
+
+-- CREATE INDEXES --
+-- Lookups of a user's characters are served by the `UNIQUE (ownerDiscordID, name)` index, the
+-- ones below cover the child columns that are not the leading column of a primary key, so that
+-- cascades do not have to scan the whole table
+CREATE INDEX  idx_DiscordUsers_selectedCharacter    ON DiscordUsers (fk_selectedCharacter);
+CREATE INDEX  idx_CharacterUsedLores_characterID    ON CharacterUsedLores (pk_fk_characterID);
+CREATE INDEX  idx_SelectedCharacterClasses_classID  ON SelectedCharacterClasses (pk_fk_classID);
@@ -0,0 +1,8 @@
+-- Registration date, stored as a unix timestamp so it can be handed straight to Discord's `<t:...>`
+-- formatting. Profiles registered before this migration have no known date and are left `NULL`
+ALTER TABLE  DiscordUsers  ADD COLUMN  registeredAt      INTEGER;
+
+-- Preferences
+ALTER TABLE  DiscordUsers  ADD COLUMN  utcOffsetMinutes  INTEGER  NOT NULL  DEFAULT 0;
+ALTER TABLE  DiscordUsers  ADD COLUMN  charactersPublic  BOOLEAN  NOT NULL  DEFAULT TRUE;
+ALTER TABLE  DiscordUsers  ADD COLUMN  ephemeralWizard   BOOLEAN  NOT NULL  DEFAULT FALSE;
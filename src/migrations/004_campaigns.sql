@@ -0,0 +1,40 @@
+-- CREATE TABLES --
+CREATE TABLE  IF NOT EXISTS  Campaigns
+(
+    pk_campaignID  INTEGER  PRIMARY KEY,
+    guildID        INTEGER  NOT NULL,
+    channelID      INTEGER  NOT NULL,
+    gmDiscordID    INTEGER  NOT NULL,
+
+    name           TEXT     NOT NULL,
+    isArchived     BOOLEAN  NOT NULL  DEFAULT FALSE,
+    createdAt      INTEGER  NOT NULL,
+
+    FOREIGN KEY (gmDiscordID)
+    REFERENCES DiscordUsers (pk_discordID)
+    ON DELETE CASCADE
+);
+
+
+CREATE TABLE  IF NOT EXISTS  CampaignCharacters
+(
+    pk_fk_campaignID   INTEGER  NOT NULL,
+    pk_fk_characterID  INTEGER  NOT NULL,
+    joinedAt           INTEGER  NOT NULL,
+
+    PRIMARY KEY (pk_fk_campaignID, pk_fk_characterID),
+
+    FOREIGN KEY (pk_fk_campaignID)  REFERENCES Campaigns (pk_campaignID)   ON DELETE CASCADE,
+    FOREIGN KEY (pk_fk_characterID) REFERENCES Characters (pk_characterID) ON DELETE CASCADE
+);
+
+
+
+-- CREATE INDEXES --
+-- A channel runs at most one campaign at a time, archived ones step aside for the next
+CREATE UNIQUE INDEX  IF NOT EXISTS  idx_Campaigns_activeChannel  ON Campaigns (channelID)  WHERE isArchived = FALSE;
+-- Likewise a name is only taken while its campaign runs, so a server can start a new chapter
+-- under the name of one it has archived
+CREATE UNIQUE INDEX  IF NOT EXISTS  idx_Campaigns_activeName     ON Campaigns (guildID, name)  WHERE isArchived = FALSE;
+CREATE INDEX  IF NOT EXISTS  idx_Campaigns_gmDiscordID                ON Campaigns (gmDiscordID);
+CREATE INDEX  IF NOT EXISTS  idx_CampaignCharacters_characterID       ON CampaignCharacters (pk_fk_characterID);
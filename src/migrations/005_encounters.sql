@@ -0,0 +1,50 @@
+-- CREATE TABLES --
+CREATE TABLE  IF NOT EXISTS  Encounters
+(
+    pk_encounterID    INTEGER  PRIMARY KEY,
+    channelID         INTEGER  NOT NULL  UNIQUE,
+    gmDiscordID       INTEGER  NOT NULL,
+    -- The pinned tracker embed, unknown until it has been sent
+    trackerMessageID  INTEGER,
+
+    round             INTEGER  NOT NULL  DEFAULT 1,
+    -- The entry whose turn it is. Stored by ID rather than position, so that entries joining
+    -- mid-combat do not move the turn onto someone else
+    fk_currentEntryID INTEGER,
+    startedAt         INTEGER  NOT NULL,
+
+    FOREIGN KEY (gmDiscordID)
+    REFERENCES DiscordUsers (pk_discordID)
+    ON DELETE CASCADE,
+
+    FOREIGN KEY (fk_currentEntryID)
+    REFERENCES EncounterEntries (pk_entryID)
+    ON DELETE SET NULL
+);
+
+
+-- An entry is either one of the players' characters, or an NPC added by the GM, in which case
+-- `fk_characterID` is NULL
+CREATE TABLE  IF NOT EXISTS  EncounterEntries
+(
+    pk_entryID      INTEGER  PRIMARY KEY,
+    fk_encounterID  INTEGER  NOT NULL,
+    fk_characterID  INTEGER,
+
+    name            TEXT     NOT NULL,
+    initiative      INTEGER  NOT NULL,
+    -- Breaks ties between equal initiatives
+    dexterity       INTEGER  NOT NULL,
+
+    UNIQUE (fk_encounterID, fk_characterID),
+
+    FOREIGN KEY (fk_encounterID) REFERENCES Encounters (pk_encounterID) ON DELETE CASCADE,
+    FOREIGN KEY (fk_characterID) REFERENCES Characters (pk_characterID) ON DELETE CASCADE
+);
+
+
+
+-- CREATE INDEXES --
+CREATE INDEX  IF NOT EXISTS  idx_Encounters_gmDiscordID          ON Encounters (gmDiscordID);
+CREATE INDEX  IF NOT EXISTS  idx_Encounters_currentEntryID       ON Encounters (fk_currentEntryID);
+CREATE INDEX  IF NOT EXISTS  idx_EncounterEntries_characterID    ON EncounterEntries (fk_characterID);
@@ -0,0 +1,43 @@
+-- CREATE TABLES --
+-- Maximum hit points are not stored, they follow from a character's constitution and class. A
+-- character without a row here is at full health
+CREATE TABLE  IF NOT EXISTS  CharacterHitPoints
+(
+    pk_fk_characterID  INTEGER  PRIMARY KEY,
+
+    currentHP          INTEGER  NOT NULL,
+    temporaryHP        INTEGER  NOT NULL  DEFAULT 0,
+
+    FOREIGN KEY (pk_fk_characterID)
+    REFERENCES Characters (pk_characterID)
+    ON DELETE CASCADE
+);
+
+
+-- Every change made through `/hp`, with the values from before and after it so that it can be
+-- undone. `amount` is NULL for changes that set the values outright, and for undos. Undos are
+-- recorded like any other change, but are never undone themselves
+CREATE TABLE  IF NOT EXISTS  HitPointHistory
+(
+    pk_changeID          INTEGER  PRIMARY KEY,
+    fk_characterID       INTEGER  NOT NULL,
+    changedByDiscordID   INTEGER  NOT NULL,
+
+    kind                 TEXT     NOT NULL  CHECK (kind IN ('damage', 'heal', 'set', 'undo')),
+    amount               INTEGER,
+    previousHP           INTEGER  NOT NULL,
+    previousTemporaryHP  INTEGER  NOT NULL,
+    newHP                INTEGER  NOT NULL,
+    newTemporaryHP       INTEGER  NOT NULL,
+    changedAt            INTEGER  NOT NULL,
+    isUndone             BOOLEAN  NOT NULL  DEFAULT FALSE,
+
+    FOREIGN KEY (fk_characterID)
+    REFERENCES Characters (pk_characterID)
+    ON DELETE CASCADE
+);
+
+
+
+-- CREATE INDEXES --
+CREATE INDEX  IF NOT EXISTS  idx_HitPointHistory_characterID  ON HitPointHistory (fk_characterID, pk_changeID);
@@ -0,0 +1,52 @@
+-- CREATE TABLES --
+-- The catalogue of every item that has been carried so far. Names are matched without regard to
+-- case, so that "Rope" and "rope" stack
+CREATE TABLE  IF NOT EXISTS  Items
+(
+    pk_itemID  INTEGER  PRIMARY KEY,
+
+    name       TEXT     NOT NULL  UNIQUE  COLLATE NOCASE,
+    -- Weight of a single item. Only bot admins may set it to 0, see `/inventory add`
+    weight     REAL     NOT NULL  CHECK (weight >= 0)
+);
+
+
+CREATE TABLE  IF NOT EXISTS  CharacterInventories
+(
+    pk_fk_characterID  INTEGER  NOT NULL,
+    pk_fk_itemID       INTEGER  NOT NULL,
+    quantity           INTEGER  NOT NULL  CHECK (quantity > 0),
+
+    PRIMARY KEY (pk_fk_characterID, pk_fk_itemID),
+
+    FOREIGN KEY (pk_fk_characterID) REFERENCES Characters (pk_characterID) ON DELETE CASCADE,
+    FOREIGN KEY (pk_fk_itemID)      REFERENCES Items (pk_itemID)           ON DELETE RESTRICT
+);
+
+
+-- Items being handed from one character to another. They only change hands once the owners of
+-- both characters have confirmed
+CREATE TABLE  IF NOT EXISTS  ItemTransfers
+(
+    pk_transferID       INTEGER  PRIMARY KEY,
+    fk_fromCharacterID  INTEGER  NOT NULL,
+    fk_toCharacterID    INTEGER  NOT NULL,
+    fk_itemID           INTEGER  NOT NULL,
+    quantity            INTEGER  NOT NULL  CHECK (quantity > 0),
+
+    senderConfirmed     BOOLEAN  NOT NULL  DEFAULT FALSE,
+    recipientConfirmed  BOOLEAN  NOT NULL  DEFAULT FALSE,
+    createdAt           INTEGER  NOT NULL,
+
+    FOREIGN KEY (fk_fromCharacterID) REFERENCES Characters (pk_characterID) ON DELETE CASCADE,
+    FOREIGN KEY (fk_toCharacterID)   REFERENCES Characters (pk_characterID) ON DELETE CASCADE,
+    FOREIGN KEY (fk_itemID)          REFERENCES Items (pk_itemID)           ON DELETE CASCADE
+);
+
+
+
+-- CREATE INDEXES --
+CREATE INDEX  IF NOT EXISTS  idx_CharacterInventories_itemID    ON CharacterInventories (pk_fk_itemID);
+CREATE INDEX  IF NOT EXISTS  idx_ItemTransfers_fromCharacterID  ON ItemTransfers (fk_fromCharacterID);
+CREATE INDEX  IF NOT EXISTS  idx_ItemTransfers_toCharacterID    ON ItemTransfers (fk_toCharacterID);
+CREATE INDEX  IF NOT EXISTS  idx_ItemTransfers_itemID           ON ItemTransfers (fk_itemID);
@@ -0,0 +1,49 @@
+-- CREATE TABLES --
+-- Balances are counted in the least valuable coin of the configured currency
+CREATE TABLE  IF NOT EXISTS  CharacterWallets
+(
+    pk_fk_characterID  INTEGER  PRIMARY KEY,
+    balance            INTEGER  NOT NULL  DEFAULT 0  CHECK (balance >= 0),
+
+    FOREIGN KEY (pk_fk_characterID)
+    REFERENCES Characters (pk_characterID)
+    ON DELETE CASCADE
+);
+
+
+-- Every change to a wallet, never updated nor deleted. The character columns deliberately have no
+-- foreign keys: cascading or nulling them would rewrite the ledger, so entries outlive the
+-- characters they are about
+CREATE TABLE  IF NOT EXISTS  WalletLedger
+(
+    pk_entryID                 INTEGER  PRIMARY KEY,
+    characterID                INTEGER  NOT NULL,
+    -- The other side of a payment, NULL for grants
+    counterpartyCharacterID    INTEGER,
+    actorDiscordID             INTEGER  NOT NULL,
+
+    kind                       TEXT     NOT NULL  CHECK (kind IN ('grant', 'payment')),
+    -- Signed change to the balance
+    amount                     INTEGER  NOT NULL,
+    balanceAfter               INTEGER  NOT NULL,
+    note                       TEXT,
+    createdAt                  INTEGER  NOT NULL
+);
+
+
+CREATE TRIGGER  IF NOT EXISTS  trg_WalletLedger_noUpdate
+BEFORE UPDATE ON WalletLedger
+BEGIN
+    SELECT RAISE(ABORT, 'WalletLedger is append-only');
+END;
+
+CREATE TRIGGER  IF NOT EXISTS  trg_WalletLedger_noDelete
+BEFORE DELETE ON WalletLedger
+BEGIN
+    SELECT RAISE(ABORT, 'WalletLedger is append-only');
+END;
+
+
+
+-- CREATE INDEXES --
+CREATE INDEX  IF NOT EXISTS  idx_WalletLedger_characterID  ON WalletLedger (characterID, pk_entryID);
@@ -0,0 +1,9 @@
+-- Progression. The level is stored rather than derived from the experience, so that changing the
+-- curve in the config never takes levels, or the points they granted, away from anyone
+ALTER TABLE  Characters  ADD COLUMN  experience         INTEGER  NOT NULL  DEFAULT 0  CHECK (experience >= 0);
+ALTER TABLE  Characters  ADD COLUMN  level              INTEGER  NOT NULL  DEFAULT 1  CHECK (level >= 1);
+
+-- Points granted by levelling up that the owner has not allocated yet. Stat points go to
+-- `CharacterStats`, lore points to `CharacterUsedLores`
+ALTER TABLE  Characters  ADD COLUMN  unspentStatPoints  INTEGER  NOT NULL  DEFAULT 0  CHECK (unspentStatPoints >= 0);
+ALTER TABLE  Characters  ADD COLUMN  unspentLorePoints  INTEGER  NOT NULL  DEFAULT 0  CHECK (unspentLorePoints >= 0);
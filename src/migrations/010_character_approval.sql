@@ -0,0 +1,7 @@
+-- Where a character stands in staff review. Characters from before the review existed count as
+-- approved, only approved characters may be selected or played
+ALTER TABLE  Characters  ADD COLUMN  status      TEXT  NOT NULL  DEFAULT 'approved'
+    CHECK (status IN ('pending', 'approved', 'rejected', 'changes_requested'));
+
+-- The reason given by staff when rejecting a character or asking for changes
+ALTER TABLE  Characters  ADD COLUMN  reviewNote  TEXT;
@@ -0,0 +1,53 @@
+-- CREATE TABLES --
+-- Game sessions announced in a channel. `startsAt` is a unix timestamp, the announcement message
+-- is posted after the row is made, so it is unknown at first
+CREATE TABLE  IF NOT EXISTS  Sessions
+(
+    pk_sessionID          INTEGER  PRIMARY KEY,
+    guildID               INTEGER  NOT NULL,
+    channelID             INTEGER  NOT NULL,
+    messageID             INTEGER,
+    organiserDiscordID    INTEGER  NOT NULL,
+
+    title                 TEXT     NOT NULL,
+    startsAt              INTEGER  NOT NULL,
+    isCancelled           BOOLEAN  NOT NULL  DEFAULT FALSE,
+    createdAt             INTEGER  NOT NULL
+);
+
+
+CREATE TABLE  IF NOT EXISTS  SessionRsvps
+(
+    pk_fk_sessionID  INTEGER  NOT NULL,
+    pk_discordID     INTEGER  NOT NULL,
+    response         TEXT     NOT NULL  CHECK (response IN ('attending', 'maybe', 'declining')),
+    respondedAt      INTEGER  NOT NULL,
+
+    PRIMARY KEY (pk_fk_sessionID, pk_discordID),
+
+    FOREIGN KEY (pk_fk_sessionID)
+    REFERENCES Sessions (pk_sessionID)
+    ON DELETE CASCADE
+);
+
+
+-- Reminders still to go out, kept in the database so none are lost to a restart
+CREATE TABLE  IF NOT EXISTS  SessionReminders
+(
+    pk_fk_sessionID   INTEGER  NOT NULL,
+    pk_offsetMinutes  INTEGER  NOT NULL  CHECK (pk_offsetMinutes > 0),
+    remindAt          INTEGER  NOT NULL,
+    isSent            BOOLEAN  NOT NULL  DEFAULT FALSE,
+
+    PRIMARY KEY (pk_fk_sessionID, pk_offsetMinutes),
+
+    FOREIGN KEY (pk_fk_sessionID)
+    REFERENCES Sessions (pk_sessionID)
+    ON DELETE CASCADE
+);
+
+
+
+-- CREATE INDEXES --
+CREATE INDEX  IF NOT EXISTS  idx_Sessions_guildStartsAt     ON Sessions (guildID, startsAt);
+CREATE INDEX  IF NOT EXISTS  idx_SessionReminders_pending   ON SessionReminders (remindAt)  WHERE isSent = FALSE;
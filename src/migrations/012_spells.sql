@@ -0,0 +1,24 @@
+-- CREATE TABLES --
+-- The spells of each lore, seeded at startup from `src/data/spells.toml`. A character knows every
+-- spell whose tier is no higher than the points they've spent in its lore
+CREATE TABLE  IF NOT EXISTS  Spells
+(
+    pk_spellID   INTEGER  PRIMARY KEY,
+    fk_loreID    INTEGER  NOT NULL,
+
+    name         TEXT     NOT NULL,
+    tier         INTEGER  NOT NULL  CHECK (tier >= 1),
+    cost         INTEGER  NOT NULL  CHECK (cost >= 0),
+    description  TEXT     NOT NULL,
+
+    UNIQUE (fk_loreID, name),
+
+    FOREIGN KEY (fk_loreID)
+    REFERENCES LoresOfMagic (pk_loreID)
+    ON DELETE CASCADE
+);
+
+
+
+-- CREATE INDEXES --
+CREATE INDEX  IF NOT EXISTS  idx_Spells_loreTier  ON Spells (fk_loreID, tier);
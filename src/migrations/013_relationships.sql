@@ -0,0 +1,29 @@
+-- CREATE TABLES --
+-- Links between characters. Mentorships read "character mentors other", the other kinds go both
+-- ways. A relationship only counts once the owners of both characters have confirmed it
+CREATE TABLE  IF NOT EXISTS  CharacterRelationships
+(
+    pk_relationshipID    INTEGER  PRIMARY KEY,
+    fk_characterID       INTEGER  NOT NULL,
+    fk_otherCharacterID  INTEGER  NOT NULL,
+
+    kind                 TEXT     NOT NULL  CHECK (kind IN ('ally', 'rival', 'family', 'mentor')),
+    note                 TEXT,
+
+    characterConfirmed   BOOLEAN  NOT NULL  DEFAULT FALSE,
+    otherConfirmed       BOOLEAN  NOT NULL  DEFAULT FALSE,
+    createdAt            INTEGER  NOT NULL,
+
+    CHECK (fk_characterID != fk_otherCharacterID),
+
+    FOREIGN KEY (fk_characterID)      REFERENCES Characters (pk_characterID) ON DELETE CASCADE,
+    FOREIGN KEY (fk_otherCharacterID) REFERENCES Characters (pk_characterID) ON DELETE CASCADE
+);
+
+
+
+-- CREATE INDEXES --
+-- Two characters share a kind of relationship at most once, whichever of them proposed it
+CREATE UNIQUE INDEX  IF NOT EXISTS  idx_CharacterRelationships_pair
+    ON CharacterRelationships (MIN(fk_characterID, fk_otherCharacterID), MAX(fk_characterID, fk_otherCharacterID), kind);
+CREATE INDEX  IF NOT EXISTS  idx_CharacterRelationships_otherCharacterID  ON CharacterRelationships (fk_otherCharacterID);
@@ -0,0 +1,45 @@
+-- Full-text index over the descriptive columns of `Characters`. It is an external content table,
+-- so the text itself stays in `Characters` and the triggers below only keep the index in step.
+--
+-- Note that dropping `Characters`, as a table rebuild would, drops these triggers with it
+
+
+
+-- CREATE INDEX --
+CREATE VIRTUAL TABLE  CharacterSearch  USING fts5
+(
+    name, species, appearance, backstory, extras,
+
+    content = 'Characters',
+    content_rowid = 'pk_characterID',
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+
+
+-- CREATE TRIGGERS --
+CREATE TRIGGER  trg_Characters_search_insert  AFTER INSERT ON Characters
+BEGIN
+    INSERT INTO CharacterSearch (rowid, name, species, appearance, backstory, extras)
+      VALUES (new.pk_characterID, new.name, new.species, new.appearance, new.backstory, new.extras);
+END;
+
+CREATE TRIGGER  trg_Characters_search_delete  AFTER DELETE ON Characters
+BEGIN
+    INSERT INTO CharacterSearch (CharacterSearch, rowid, name, species, appearance, backstory, extras)
+      VALUES ('delete', old.pk_characterID, old.name, old.species, old.appearance, old.backstory, old.extras);
+END;
+
+-- Status and ownership changes leave the indexed text alone, so only edits to it re-index the row
+CREATE TRIGGER  trg_Characters_search_update  AFTER UPDATE OF name, species, appearance, backstory, extras ON Characters
+BEGIN
+    INSERT INTO CharacterSearch (CharacterSearch, rowid, name, species, appearance, backstory, extras)
+      VALUES ('delete', old.pk_characterID, old.name, old.species, old.appearance, old.backstory, old.extras);
+    INSERT INTO CharacterSearch (rowid, name, species, appearance, backstory, extras)
+      VALUES (new.pk_characterID, new.name, new.species, new.appearance, new.backstory, new.extras);
+END;
+
+
+
+-- INDEX EXISTING CHARACTERS --
+INSERT INTO CharacterSearch (CharacterSearch) VALUES ('rebuild');
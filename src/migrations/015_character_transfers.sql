@@ -0,0 +1,29 @@
+-- Offers to hand a character over to another user. A character can only be offered to one user
+-- at a time, and an offer lapses at `expiresAt` if the recipient hasn't accepted it by then
+
+
+
+-- CREATE TABLES --
+CREATE TABLE  IF NOT EXISTS  CharacterTransfers
+(
+    pk_transferID   INTEGER  PRIMARY KEY,
+    fk_characterID  INTEGER  NOT NULL  UNIQUE,
+    fromDiscordID   INTEGER  NOT NULL,
+    toDiscordID     INTEGER  NOT NULL,
+
+    createdAt       INTEGER  NOT NULL,
+    expiresAt       INTEGER  NOT NULL,
+
+    CHECK (fromDiscordID <> toDiscordID),
+
+    FOREIGN KEY (fk_characterID)  REFERENCES Characters (pk_characterID)  ON DELETE CASCADE,
+    FOREIGN KEY (fromDiscordID)   REFERENCES DiscordUsers (pk_discordID)  ON DELETE CASCADE,
+    FOREIGN KEY (toDiscordID)     REFERENCES DiscordUsers (pk_discordID)  ON DELETE CASCADE
+);
+
+
+
+-- CREATE INDEXES --
+CREATE INDEX  IF NOT EXISTS  idx_CharacterTransfers_fromDiscordID  ON CharacterTransfers (fromDiscordID);
+CREATE INDEX  IF NOT EXISTS  idx_CharacterTransfers_toDiscordID    ON CharacterTransfers (toDiscordID);
+CREATE INDEX  IF NOT EXISTS  idx_CharacterTransfers_expiresAt      ON CharacterTransfers (expiresAt);
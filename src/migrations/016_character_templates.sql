@@ -0,0 +1,31 @@
+-- Archetypes players may start building a character from. Every detail is optional, those left
+-- `NULL` are filled in by the player as usual
+
+
+
+-- CREATE TABLES --
+CREATE TABLE  IF NOT EXISTS  CharacterTemplates
+(
+    pk_templateID  INTEGER  PRIMARY KEY,
+    name           TEXT     NOT NULL  UNIQUE  COLLATE NOCASE,
+    createdBy      INTEGER  NOT NULL,
+    createdAt      INTEGER  NOT NULL,
+
+    species        TEXT,
+    alignment      TEXT,
+    likes          TEXT,
+    dislikes       TEXT,
+    motivations    TEXT,
+    companions     TEXT,
+    backstory      TEXT,
+    appearance     TEXT,
+    extras         TEXT,
+    fk_classID     INTEGER,
+
+    FOREIGN KEY (fk_classID)  REFERENCES CharacterClasses (pk_classID)  ON DELETE SET NULL
+);
+
+
+
+-- CREATE INDEXES --
+CREATE INDEX  IF NOT EXISTS  idx_CharacterTemplates_classID  ON CharacterTemplates (fk_classID);
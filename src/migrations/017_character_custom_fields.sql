@@ -0,0 +1,13 @@
+-- CREATE TABLES --
+-- Answers to the creation wizard's questions that have no column of their own in `Characters`,
+-- keyed by the id the wizard definition gives them
+CREATE TABLE  IF NOT EXISTS  CharacterCustomFields
+(
+    fk_characterID  INTEGER  NOT NULL,
+    fieldKey        TEXT     NOT NULL,
+    value           TEXT     NOT NULL,
+
+    PRIMARY KEY (fk_characterID, fieldKey),
+
+    FOREIGN KEY (fk_characterID) REFERENCES Characters (pk_characterID) ON DELETE CASCADE
+);
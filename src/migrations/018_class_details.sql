@@ -0,0 +1,23 @@
+-- ALTER TABLES --
+-- What each class is about, and what it allows. The casting cap is the highest tier of spell the
+-- class can know, with 0 leaving it unable to cast at all. The stat budget is how many stat
+-- points characters of the class start out with, and the base hit points how many hit points
+-- they have before their constitution is added
+ALTER TABLE  CharacterClasses  ADD COLUMN  description  TEXT     NOT NULL  DEFAULT '';
+ALTER TABLE  CharacterClasses  ADD COLUMN  castingCap   INTEGER  NOT NULL  DEFAULT 0  CHECK (castingCap >= 0);
+ALTER TABLE  CharacterClasses  ADD COLUMN  statBudget   INTEGER  NOT NULL  DEFAULT 0  CHECK (statBudget >= 0);
+ALTER TABLE  CharacterClasses  ADD COLUMN  baseHitPoints  INTEGER  NOT NULL  DEFAULT 8  CHECK (baseHitPoints >= 1);
+
+
+
+-- UPDATE ROWS --
+-- The seeded classes keep how they behaved while their rules were written into the code, bar
+-- half-casters, who now stop short of the highest tier
+UPDATE CharacterClasses  SET description = 'They fight with weapons lacking magic',  castingCap = 0,  baseHitPoints = 12  WHERE pk_classID = 1;
+UPDATE CharacterClasses  SET description = 'They''re a mix of both',                 castingCap = 2,  baseHitPoints = 10  WHERE pk_classID = 2;
+UPDATE CharacterClasses  SET description = 'They prefer the magical arts',          castingCap = 3,  baseHitPoints = 8   WHERE pk_classID = 3;
+
+
+
+-- CREATE INDEXES --
+CREATE UNIQUE INDEX  IF NOT EXISTS  idx_CharacterClasses_className  ON CharacterClasses (className COLLATE NOCASE);
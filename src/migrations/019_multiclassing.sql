@@ -0,0 +1,29 @@
+-- ALTER TABLES --
+-- Characters may have several classes. The primary one is where the levels they gain go, and
+-- sets their hit points. The levels of a character's classes add up to their level
+ALTER TABLE  SelectedCharacterClasses  ADD COLUMN  isPrimary   INTEGER  NOT NULL  DEFAULT 0  CHECK (isPrimary IN (0, 1));
+ALTER TABLE  SelectedCharacterClasses  ADD COLUMN  classLevel  INTEGER  NOT NULL  DEFAULT 1  CHECK (classLevel >= 1);
+
+
+
+-- UPDATE ROWS --
+-- Hit points went by the lowest class ID so far, so that class becomes the primary one and takes
+-- every level the others don't
+UPDATE SelectedCharacterClasses
+   SET isPrimary = 1
+ WHERE pk_fk_classID = (
+       SELECT MIN(Others.pk_fk_classID) FROM SelectedCharacterClasses AS Others
+        WHERE Others.pk_fk_characterID = SelectedCharacterClasses.pk_fk_characterID
+ );
+
+UPDATE SelectedCharacterClasses
+   SET classLevel = MAX(1,
+       (SELECT level FROM Characters WHERE pk_characterID = SelectedCharacterClasses.pk_fk_characterID)
+       - (SELECT COUNT(*) - 1 FROM SelectedCharacterClasses AS Others WHERE Others.pk_fk_characterID = SelectedCharacterClasses.pk_fk_characterID)
+   )
+ WHERE isPrimary = 1;
+
+
+
+-- CREATE INDEXES --
+CREATE UNIQUE INDEX  IF NOT EXISTS  idx_SelectedCharacterClasses_primary  ON SelectedCharacterClasses (pk_fk_characterID)  WHERE isPrimary = 1;
@@ -0,0 +1,32 @@
+-- Species staff have set up. Their modifiers are added to the stats of characters of the species
+-- wherever those are shown or rolled with, and their traits are shown on the sheet. Characters may
+-- still have a species of their own making, which links to nothing
+
+
+
+-- CREATE TABLES --
+CREATE TABLE  IF NOT EXISTS  Species
+(
+    pk_speciesID  INTEGER  PRIMARY KEY,
+    name          TEXT     NOT NULL  UNIQUE  COLLATE NOCASE,
+    description   TEXT     NOT NULL  DEFAULT '',
+    traits        TEXT     NOT NULL  DEFAULT '',
+
+    strength      INTEGER  NOT NULL  DEFAULT 0,
+    dexterity     INTEGER  NOT NULL  DEFAULT 0,
+    perception    INTEGER  NOT NULL  DEFAULT 0,
+    knowledge     INTEGER  NOT NULL  DEFAULT 0,
+    constitution  INTEGER  NOT NULL  DEFAULT 0,
+    casting       INTEGER  NOT NULL  DEFAULT 0
+);
+
+
+
+-- ALTER TABLES --
+-- The species text stays as it was when the registered species is removed
+ALTER TABLE  Characters  ADD COLUMN  fk_speciesID  INTEGER  REFERENCES Species (pk_speciesID)  ON DELETE SET NULL;
+
+
+
+-- CREATE INDEXES --
+CREATE INDEX  IF NOT EXISTS  idx_Characters_speciesID  ON Characters (fk_speciesID);
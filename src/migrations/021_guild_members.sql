@@ -0,0 +1,18 @@
+-- The servers each user has been seen in, so that what is listed for a server can be kept to the
+-- characters of its own members. A user is added whenever they run a command in a server
+
+
+
+-- CREATE TABLES --
+CREATE TABLE  IF NOT EXISTS  GuildMembers
+(
+    pk_guildID    INTEGER  NOT NULL,
+    pk_discordID  INTEGER  NOT NULL,
+
+    PRIMARY KEY (pk_guildID, pk_discordID)
+);
+
+
+
+-- CREATE INDEXES --
+CREATE INDEX  IF NOT EXISTS  idx_GuildMembers_discordID  ON GuildMembers (pk_discordID);
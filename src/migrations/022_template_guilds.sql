@@ -0,0 +1,51 @@
+-- Templates are offered per server, with names unique within each. The table is rebuilt to swap
+-- the unique name for a unique server and name, see `002_constraints_and_indexes.sql` for the
+-- procedure. Templates made before now can't be traced back to the server they were made in, so
+-- they are kept without one, where they aren't offered to anyone
+
+
+
+-- CREATE NEW TABLES --
+CREATE TABLE  CharacterTemplates_new
+(
+    pk_templateID  INTEGER  PRIMARY KEY,
+    guildID        INTEGER,
+    name           TEXT     NOT NULL  COLLATE NOCASE,
+    createdBy      INTEGER  NOT NULL,
+    createdAt      INTEGER  NOT NULL,
+
+    species        TEXT,
+    alignment      TEXT,
+    likes          TEXT,
+    dislikes       TEXT,
+    motivations    TEXT,
+    companions     TEXT,
+    backstory      TEXT,
+    appearance     TEXT,
+    extras         TEXT,
+    fk_classID     INTEGER,
+
+    UNIQUE (guildID, name),
+
+    FOREIGN KEY (fk_classID)  REFERENCES CharacterClasses (pk_classID)  ON DELETE SET NULL
+);
+
+
+
+-- COPY DATA --
+INSERT INTO CharacterTemplates_new
+  SELECT
+      pk_templateID, NULL, name, createdBy, createdAt,
+      species, alignment, likes, dislikes, motivations, companions, backstory, appearance, extras, fk_classID
+  FROM CharacterTemplates;
+
+
+
+-- SWAP TABLES --
+DROP TABLE  CharacterTemplates;
+ALTER TABLE  CharacterTemplates_new  RENAME TO  CharacterTemplates;
+
+
+
+-- CREATE INDEXES --
+CREATE INDEX  IF NOT EXISTS  idx_CharacterTemplates_classID  ON CharacterTemplates (fk_classID);
@@ -0,0 +1,276 @@
+use crate::{
+    runtime::{
+        commands::{self, admin_commands},
+        runtime_client::RuntimeClient,
+        sql_scripts::{self, character_stats::Stat, models::{CharacterClass, Species}}
+    },
+    utils::misc::{
+        colour_codes::ColourCode, logging::create_log_message
+    }
+};
+
+use serenity::{
+    builder::{
+        CreateAutocompleteResponse,
+        CreateCommand,
+        CreateCommandOption,
+        CreateInteractionResponse
+    },
+    model::application::{
+        CommandInteraction,
+        CommandOptionType,
+        ResolvedOption
+    },
+    client::Context
+};
+
+/// Longest a class's description may be, it's listed alongside every class in the wizard
+pub const CLASS_DESCRIPTION_MAX_LENGTH: u16 = 100;
+
+/// The options describing a class, all optional so `edit` can leave what isn't given untouched
+fn class_detail_options( sub_command: CreateCommandOption ) -> CreateCommandOption {
+    sub_command
+        .add_sub_option(
+            CreateCommandOption::new(CommandOptionType::String, "description", "What the class is about, shown in the creation wizard")
+                .max_length(CLASS_DESCRIPTION_MAX_LENGTH)
+        )
+        .add_sub_option(
+            CreateCommandOption::new(CommandOptionType::Integer, "casting_cap", "The highest spell tier the class can learn, 0 for none")
+                .min_int_value(0)
+                .max_int_value(100)
+        )
+        .add_sub_option(
+            CreateCommandOption::new(CommandOptionType::Integer, "stat_budget", "The stat points new characters of the class start with")
+                .min_int_value(0)
+                .max_int_value(1000)
+        )
+        .add_sub_option(
+            CreateCommandOption::new(CommandOptionType::Integer, "base_hit_points", "Hit points characters of the class have before constitution")
+                .min_int_value(1)
+                .max_int_value(1000)
+        )
+}
+
+/// Longest a species' description may be, it's listed alongside every species in the wizard
+pub const SPECIES_DESCRIPTION_MAX_LENGTH: u16 = 100;
+
+/// The options describing a species, all optional so `edit` can leave what isn't given untouched
+fn species_detail_options( mut sub_command: CreateCommandOption ) -> CreateCommandOption {
+    sub_command = sub_command
+        .add_sub_option(
+            CreateCommandOption::new(CommandOptionType::String, "description", "What the species is like, shown in the creation wizard")
+                .max_length(SPECIES_DESCRIPTION_MAX_LENGTH)
+        )
+        .add_sub_option(
+            CreateCommandOption::new(CommandOptionType::String, "traits", "What sets the species apart, shown on character sheets")
+                .max_length(200)
+        );
+    for stat in Stat::ALL {
+        sub_command = sub_command.add_sub_option(
+            CreateCommandOption::new(CommandOptionType::Integer, stat.column(), format!( "Added to the {} of characters of the species", stat.column() ))
+                // Negative bounds can only be set through the number variant
+                .min_number_value(-10.0)
+                .max_int_value(10)
+        );
+    }
+    sub_command
+}
+
+pub fn build() -> CreateCommand {
+    CreateCommand::new("admin")
+        .description("Set up how the bot plays, bot admins only")
+        .dm_permission(false)
+        .set_options(vec![
+            CreateCommandOption::new(CommandOptionType::SubCommandGroup, "class", "The classes characters can be created with, bot admins only")
+                .add_sub_option(class_detail_options(
+                    CreateCommandOption::new(CommandOptionType::SubCommand, "add", "Offer a new class")
+                        .add_sub_option(
+                            CreateCommandOption::new(CommandOptionType::String, "name", "What the class is called")
+                                .required(true)
+                                .max_length(50)
+                        )
+                ))
+                .add_sub_option(class_detail_options(
+                    CreateCommandOption::new(CommandOptionType::SubCommand, "edit", "Change a class, leaving out what should stay the same")
+                        .add_sub_option(
+                            CreateCommandOption::new(CommandOptionType::String, "class", "The class to change")
+                                .required(true)
+                                .set_autocomplete(true)
+                        )
+                        .add_sub_option(
+                            CreateCommandOption::new(CommandOptionType::String, "name", "What the class is now called")
+                                .max_length(50)
+                        )
+                ))
+                .add_sub_option(
+                    CreateCommandOption::new(CommandOptionType::SubCommand, "remove", "Stop offering a class no character has")
+                        .add_sub_option(
+                            CreateCommandOption::new(CommandOptionType::String, "class", "The class to remove")
+                                .required(true)
+                                .set_autocomplete(true)
+                        )
+                ),
+            CreateCommandOption::new(CommandOptionType::SubCommandGroup, "species", "The species characters can be created as, bot admins only")
+                .add_sub_option(species_detail_options(
+                    CreateCommandOption::new(CommandOptionType::SubCommand, "add", "Offer a new species")
+                        .add_sub_option(
+                            CreateCommandOption::new(CommandOptionType::String, "name", "What the species is called")
+                                .required(true)
+                                .max_length(50)
+                        )
+                ))
+                .add_sub_option(species_detail_options(
+                    CreateCommandOption::new(CommandOptionType::SubCommand, "edit", "Change a species, leaving out what should stay the same")
+                        .add_sub_option(
+                            CreateCommandOption::new(CommandOptionType::String, "species", "The species to change")
+                                .required(true)
+                                .set_autocomplete(true)
+                        )
+                        .add_sub_option(
+                            CreateCommandOption::new(CommandOptionType::String, "name", "What the species is now called")
+                                .max_length(50)
+                        )
+                ))
+                .add_sub_option(
+                    CreateCommandOption::new(CommandOptionType::SubCommand, "remove", "Stop offering a species, its characters keep the name")
+                        .add_sub_option(
+                            CreateCommandOption::new(CommandOptionType::String, "species", "The species to remove")
+                                .required(true)
+                                .set_autocomplete(true)
+                        )
+                )
+        ])
+}
+
+
+pub async fn run( runtime_client: &RuntimeClient, ctx: Context, interaction_data: CommandInteraction ) {
+    let group_name = &interaction_data
+        .data
+        .options[0] // All of this command's sub commands sit in groups, so there exists a 0th
+                    // option, and it contains the name of the selected group
+        .name;
+    let sub_command_name = commands::grouped_sub_command_name(&interaction_data);
+
+
+    // Based on the group and sub command's names, delegate to the correct execution unit
+    match (group_name.as_str(), sub_command_name) {
+        ("class", Some("add"))    => admin_commands::class_add::run(runtime_client, ctx, interaction_data).await,
+        ("class", Some("edit"))   => admin_commands::class_edit::run(runtime_client, ctx, interaction_data).await,
+        ("class", Some("remove")) => admin_commands::class_remove::run(runtime_client, ctx, interaction_data).await,
+        ("species", Some("add"))    => admin_commands::species_add::run(runtime_client, ctx, interaction_data).await,
+        ("species", Some("edit"))   => admin_commands::species_edit::run(runtime_client, ctx, interaction_data).await,
+        ("species", Some("remove")) => admin_commands::species_remove::run(runtime_client, ctx, interaction_data).await,
+        // In the case that an unknown sub command is recived, inform the terminal of this
+        // occurance
+        (unimplemented_group, unimplemented_subcommand) => println!( "{}", create_log_message(
+                format!(
+                    "{}admin{}: Recieved unknown sub command: `{}{} {}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    unimplemented_group,
+                    unimplemented_subcommand.unwrap_or_default(),
+                    ColourCode::Reset
+                ),
+                ColourCode::Warning
+        ))
+    }
+}
+
+
+pub async fn autocomplete( runtime_client: &RuntimeClient, ctx: Context, interaction_data: CommandInteraction ) {
+    match interaction_data.data.autocomplete().map(|focused_option| focused_option.name) {
+        Some("species") => autocomplete_species(runtime_client, &ctx, &interaction_data).await,
+        _ => autocomplete_classes(runtime_client, &ctx, &interaction_data).await
+    }
+}
+
+/// Answers an autocomplete request for a class option with the classes whose names contain what's
+/// been typed so far. The choices carry the class's ID
+pub async fn autocomplete_classes( runtime_client: &RuntimeClient, ctx: &Context, interaction_data: &CommandInteraction ) {
+    let typed_so_far = commands::typed_so_far(interaction_data);
+    let classes = sql_scripts::character_classes::get_classes(&runtime_client.database_connection)
+        .await
+        .unwrap_or_default();
+
+    let mut autocomplete_response = CreateAutocompleteResponse::new();
+    for class in classes.into_iter().filter(|class| class.name.to_lowercase().contains(&typed_so_far)).take(25) {
+        autocomplete_response = autocomplete_response.add_string_choice(class.name, class.id.to_string());
+    }
+
+    let send_choices = interaction_data.create_response(&ctx.http, CreateInteractionResponse::Autocomplete(autocomplete_response));
+    if let Err(why) = send_choices.await {
+        println!( "{}", create_log_message(
+                format!(
+                    "Failed to send autocomplete choices: `{}{}{}`",
+                    ColourCode::Info,
+                    why,
+                    ColourCode::Reset
+                ),
+                ColourCode::Caution
+        ));
+    }
+}
+
+/// Finds the class a class option refers to. The choices carry the class's ID, but a typed in
+/// name works too
+pub fn find_class( classes: Vec<CharacterClass>, class_option: &str ) -> Option<CharacterClass> {
+    classes.into_iter().find(|class| {
+        class_option.parse::<u64>().is_ok_and(|class_id| class_id == class.id)
+            || class.name.eq_ignore_ascii_case(class_option.trim())
+    })
+}
+
+/// Answers an autocomplete request for a species option with the registered species whose names
+/// contain what's been typed so far. The choices carry the species' ID
+pub async fn autocomplete_species( runtime_client: &RuntimeClient, ctx: &Context, interaction_data: &CommandInteraction ) {
+    let typed_so_far = commands::typed_so_far(interaction_data);
+    let species_list = sql_scripts::species::get_species_list(&runtime_client.database_connection)
+        .await
+        .unwrap_or_default();
+
+    let mut autocomplete_response = CreateAutocompleteResponse::new();
+    for species in species_list.into_iter().filter(|species| species.name.to_lowercase().contains(&typed_so_far)).take(25) {
+        autocomplete_response = autocomplete_response.add_string_choice(species.name, species.id.to_string());
+    }
+
+    let send_choices = interaction_data.create_response(&ctx.http, CreateInteractionResponse::Autocomplete(autocomplete_response));
+    if let Err(why) = send_choices.await {
+        println!( "{}", create_log_message(
+                format!(
+                    "Failed to send autocomplete choices: `{}{}{}`",
+                    ColourCode::Info,
+                    why,
+                    ColourCode::Reset
+                ),
+                ColourCode::Caution
+        ));
+    }
+}
+
+/// Finds the species a species option refers to. The choices carry the species' ID, but a typed
+/// in name works too
+pub fn find_species( species_list: Vec<Species>, species_option: &str ) -> Option<Species> {
+    species_list.into_iter().find(|species| {
+        species_option.parse::<u64>().is_ok_and(|species_id| species_id == species.id)
+            || species.name.eq_ignore_ascii_case(species_option.trim())
+    })
+}
+
+/// Sets the modifiers of a species from the stat options that were given
+pub fn apply_modifier_options( species: &mut Species, sub_options: &[ResolvedOption<'_>] ) {
+    for stat in Stat::ALL {
+        let Some(modifier) = commands::integer_option(sub_options, stat.column()) else {
+            continue;
+        };
+        let species_modifier = match stat {
+            Stat::Strength     => &mut species.strength,
+            Stat::Dexterity    => &mut species.dexterity,
+            Stat::Perception   => &mut species.perception,
+            Stat::Knowledge    => &mut species.knowledge,
+            Stat::Constitution => &mut species.constitution,
+            Stat::Casting      => &mut species.casting
+        };
+        *species_modifier = modifier;
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{
+    runtime::{
+        commands::{
+            character_commands::create::MAX_CLASSES, integer_option, is_bot_admin, query_error_embed,
+            respond_with_embed, string_option, sub_command_options
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let database_conn_pool = &runtime_client.database_connection;
+
+    let sub_options = sub_command_options(&interaction_data);
+    let name = string_option(&sub_options, "name")
+        .expect("`name` is a required option")
+        .trim();
+    let description = string_option(&sub_options, "description").unwrap_or_default().trim();
+    let casting_cap = integer_option(&sub_options, "casting_cap").unwrap_or(0);
+    let stat_budget = integer_option(&sub_options, "stat_budget").unwrap_or(0);
+    let base_hit_points = integer_option(&sub_options, "base_hit_points").unwrap_or(8);
+
+    let response_embed = 'response_embed: {
+
+        if !is_bot_admin(runtime_client, interaction_data.user.id.get()) {
+            break 'response_embed CreateEmbed::new()
+                .title("Only bot admins can add classes")
+                .description("Every server the bot is in shares the same classes")
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        if name.is_empty() {
+            break 'response_embed CreateEmbed::new()
+                .title("Classes need a name")
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        // The creation wizard can only offer so many in a single select menu
+        match sql_scripts::character_classes::get_classes(database_conn_pool).await {
+            Ok(classes) if classes.len() >= MAX_CLASSES => break 'response_embed CreateEmbed::new()
+                .title(format!( "There can be at most {MAX_CLASSES} classes" ))
+                .description("Remove one with `/admin class remove` to make room")
+                .colour(ColourCode::Info.to_embed_colour()),
+            Ok(_) => {},
+            Err(query_err) => break 'response_embed query_error_embed(
+                "admin::class::add::get_classes", "fetch the classes", &query_err
+            )
+        }
+
+        match sql_scripts::character_classes::add_class(database_conn_pool, name, description, casting_cap, stat_budget, base_hit_points).await {
+            Ok(_) => {},
+            Err(query_err) if query_err.as_database_error().is_some_and(|db_err| db_err.is_unique_violation()) => {
+                break 'response_embed CreateEmbed::new()
+                    .title(format!( "There already is a class called `{name}`" ))
+                    .colour(ColourCode::Info.to_embed_colour());
+            },
+            Err(query_err) => break 'response_embed query_error_embed(
+                "admin::class::add::add_class", "save the class", &query_err
+            )
+        }
+
+        CreateEmbed::new()
+            .title(format!( "Characters can now be created as a {name}" ))
+            .field("Casting cap", casting_cap.to_string(), true)
+            .field("Stat budget", stat_budget.to_string(), true)
+            .field("Base hit points", base_hit_points.to_string(), true)
+            .colour(ColourCode::Success.to_embed_colour())
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, true, "admin::class::add").await;
+}
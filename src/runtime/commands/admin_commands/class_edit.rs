@@ -0,0 +1,92 @@
+use crate::{
+    runtime::{
+        commands::{
+            admin::find_class, integer_option, is_bot_admin, query_error_embed, respond_with_embed,
+            string_option, sub_command_options
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let database_conn_pool = &runtime_client.database_connection;
+
+    let sub_options = sub_command_options(&interaction_data);
+    let class_option = string_option(&sub_options, "class")
+        .expect("`class` is a required option");
+
+    let response_embed = 'response_embed: {
+
+        if !is_bot_admin(runtime_client, interaction_data.user.id.get()) {
+            break 'response_embed CreateEmbed::new()
+                .title("Only bot admins can change classes")
+                .description("Every server the bot is in shares the same classes")
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        let class = match sql_scripts::character_classes::get_classes(database_conn_pool).await {
+            Ok(classes) => find_class(classes, class_option),
+            Err(query_err) => break 'response_embed query_error_embed(
+                "admin::class::edit::get_classes", "fetch the classes", &query_err
+            )
+        };
+        let Some(mut class) = class else {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "There is no class called `{class_option}`" ))
+                .colour(ColourCode::Info.to_embed_colour());
+        };
+
+        // Whatever wasn't given stays as it was, a blank name included
+        if let Some(name) = string_option(&sub_options, "name").map(str::trim).filter(|name| !name.is_empty()) {
+            class.name = name.to_string();
+        }
+        if let Some(description) = string_option(&sub_options, "description") {
+            class.description = description.trim().to_string();
+        }
+        if let Some(casting_cap) = integer_option(&sub_options, "casting_cap") {
+            class.casting_cap = casting_cap;
+        }
+        if let Some(stat_budget) = integer_option(&sub_options, "stat_budget") {
+            class.stat_budget = stat_budget;
+        }
+        if let Some(base_hit_points) = integer_option(&sub_options, "base_hit_points") {
+            class.base_hit_points = base_hit_points;
+        }
+
+        // Existing characters keep the stat points they started with
+        match sql_scripts::character_classes::update_class(database_conn_pool, &class).await {
+            Ok(_) => {},
+            Err(query_err) if query_err.as_database_error().is_some_and(|db_err| db_err.is_unique_violation()) => {
+                break 'response_embed CreateEmbed::new()
+                    .title(format!( "There already is a class called `{}`", class.name ))
+                    .colour(ColourCode::Info.to_embed_colour());
+            },
+            Err(query_err) => break 'response_embed query_error_embed(
+                "admin::class::edit::update_class", "save the class", &query_err
+            )
+        }
+
+        CreateEmbed::new()
+            .title(format!( "Updated {}", class.name ))
+            .description(if class.description.is_empty() { "-" } else { &class.description })
+            .field("Casting cap", class.casting_cap.to_string(), true)
+            .field("Stat budget", class.stat_budget.to_string(), true)
+            .field("Base hit points", class.base_hit_points.to_string(), true)
+            .colour(ColourCode::Success.to_embed_colour())
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, true, "admin::class::edit").await;
+}
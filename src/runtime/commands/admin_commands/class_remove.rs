@@ -0,0 +1,84 @@
+use crate::{
+    runtime::{
+        commands::{
+            admin::find_class, is_bot_admin, query_error_embed, respond_with_embed, string_option,
+            sub_command_options
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let database_conn_pool = &runtime_client.database_connection;
+
+    let sub_options = sub_command_options(&interaction_data);
+    let class_option = string_option(&sub_options, "class")
+        .expect("`class` is a required option");
+
+    let response_embed = 'response_embed: {
+
+        if !is_bot_admin(runtime_client, interaction_data.user.id.get()) {
+            break 'response_embed CreateEmbed::new()
+                .title("Only bot admins can remove classes")
+                .description("Every server the bot is in shares the same classes")
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        let classes = match sql_scripts::character_classes::get_classes(database_conn_pool).await {
+            Ok(classes) => classes,
+            Err(query_err) => break 'response_embed query_error_embed(
+                "admin::class::remove::get_classes", "fetch the classes", &query_err
+            )
+        };
+
+        // The creation wizard can't be finished without a class to pick
+        if classes.len() <= 1 {
+            break 'response_embed CreateEmbed::new()
+                .title("The last class can't be removed")
+                .description("Add another with `/admin class add` first")
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+        let Some(class) = find_class(classes, class_option) else {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "There is no class called `{class_option}`" ))
+                .colour(ColourCode::Info.to_embed_colour());
+        };
+
+        match sql_scripts::character_classes::is_class_in_use(database_conn_pool, class.id).await {
+            Ok(false) => {},
+            Ok(true) => break 'response_embed CreateEmbed::new()
+                .title(format!( "Some characters are still a {}", class.name ))
+                .description("Classes can only be removed once no character has them")
+                .colour(ColourCode::Info.to_embed_colour()),
+            Err(query_err) => break 'response_embed query_error_embed(
+                "admin::class::remove::is_class_in_use", "check who has the class", &query_err
+            )
+        }
+
+        // Templates giving the class stop giving one
+        if let Err(query_err) = sql_scripts::character_classes::remove_class(database_conn_pool, class.id).await {
+            break 'response_embed query_error_embed(
+                "admin::class::remove::remove_class", "remove the class", &query_err
+            );
+        }
+
+        CreateEmbed::new()
+            .title(format!( "{} is no longer offered", class.name ))
+            .colour(ColourCode::Success.to_embed_colour())
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, true, "admin::class::remove").await;
+}
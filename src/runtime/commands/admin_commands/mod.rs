@@ -0,0 +1,6 @@
+pub mod class_add;
+pub mod class_edit;
+pub mod class_remove;
+pub mod species_add;
+pub mod species_edit;
+pub mod species_remove;
@@ -0,0 +1,88 @@
+use crate::{
+    runtime::{
+        commands::{
+            admin::apply_modifier_options, character_commands::create::MAX_SPECIES, is_bot_admin,
+            query_error_embed, respond_with_embed, string_option, sub_command_options
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts::{self, models::Species}
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let database_conn_pool = &runtime_client.database_connection;
+
+    let sub_options = sub_command_options(&interaction_data);
+    let name = string_option(&sub_options, "name")
+        .expect("`name` is a required option")
+        .trim();
+
+    let response_embed = 'response_embed: {
+
+        if !is_bot_admin(runtime_client, interaction_data.user.id.get()) {
+            break 'response_embed CreateEmbed::new()
+                .title("Only bot admins can add species")
+                .description("Every server the bot is in shares the same species")
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        if name.is_empty() {
+            break 'response_embed CreateEmbed::new()
+                .title("Species need a name")
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        // The creation wizard offers them in a single select menu, next to a custom one
+        match sql_scripts::species::get_species_list(database_conn_pool).await {
+            Ok(species_list) if species_list.len() >= MAX_SPECIES => break 'response_embed CreateEmbed::new()
+                .title(format!( "There can be at most {MAX_SPECIES} species" ))
+                .description("Remove one with `/admin species remove` to make room")
+                .colour(ColourCode::Info.to_embed_colour()),
+            Ok(_) => {},
+            Err(query_err) => break 'response_embed query_error_embed(
+                "admin::species::add::get_species_list", "fetch the species", &query_err
+            )
+        }
+
+        let mut species = Species {
+            name: name.to_string(),
+            description: string_option(&sub_options, "description").unwrap_or_default().trim().to_string(),
+            traits: string_option(&sub_options, "traits").unwrap_or_default().trim().to_string(),
+            ..Default::default()
+        };
+        apply_modifier_options(&mut species, &sub_options);
+
+        match sql_scripts::species::add_species(database_conn_pool, &species).await {
+            Ok(_) => {},
+            Err(query_err) if query_err.as_database_error().is_some_and(|db_err| db_err.is_unique_violation()) => {
+                break 'response_embed CreateEmbed::new()
+                    .title(format!( "There already is a species called `{name}`" ))
+                    .colour(ColourCode::Info.to_embed_colour());
+            },
+            Err(query_err) => break 'response_embed query_error_embed(
+                "admin::species::add::add_species", "save the species", &query_err
+            )
+        }
+
+        let modifiers = species.describe_modifiers();
+        CreateEmbed::new()
+            .title(format!( "Characters can now be created as {name}" ))
+            .description("Existing characters of the species get its modifiers too")
+            .field("Modifiers", if modifiers.is_empty() { "None" } else { &modifiers }, true)
+            .colour(ColourCode::Success.to_embed_colour())
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, true, "admin::species::add").await;
+}
@@ -0,0 +1,86 @@
+use crate::{
+    runtime::{
+        commands::{
+            admin::{apply_modifier_options, find_species}, is_bot_admin, query_error_embed,
+            respond_with_embed, string_option, sub_command_options
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let database_conn_pool = &runtime_client.database_connection;
+
+    let sub_options = sub_command_options(&interaction_data);
+    let species_option = string_option(&sub_options, "species")
+        .expect("`species` is a required option");
+
+    let response_embed = 'response_embed: {
+
+        if !is_bot_admin(runtime_client, interaction_data.user.id.get()) {
+            break 'response_embed CreateEmbed::new()
+                .title("Only bot admins can change species")
+                .description("Every server the bot is in shares the same species")
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        let species = match sql_scripts::species::get_species_list(database_conn_pool).await {
+            Ok(species_list) => find_species(species_list, species_option),
+            Err(query_err) => break 'response_embed query_error_embed(
+                "admin::species::edit::get_species_list", "fetch the species", &query_err
+            )
+        };
+        let Some(mut species) = species else {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "There is no species called `{species_option}`" ))
+                .colour(ColourCode::Info.to_embed_colour());
+        };
+
+        // Whatever wasn't given stays as it was, a blank name included
+        if let Some(name) = string_option(&sub_options, "name").map(str::trim).filter(|name| !name.is_empty()) {
+            species.name = name.to_string();
+        }
+        if let Some(description) = string_option(&sub_options, "description") {
+            species.description = description.trim().to_string();
+        }
+        if let Some(traits) = string_option(&sub_options, "traits") {
+            species.traits = traits.trim().to_string();
+        }
+        apply_modifier_options(&mut species, &sub_options);
+
+        match sql_scripts::species::update_species(database_conn_pool, &species).await {
+            Ok(_) => {},
+            Err(query_err) if query_err.as_database_error().is_some_and(|db_err| db_err.is_unique_violation()) => {
+                break 'response_embed CreateEmbed::new()
+                    .title(format!( "There already is a species called `{}`", species.name ))
+                    .colour(ColourCode::Info.to_embed_colour());
+            },
+            Err(query_err) => break 'response_embed query_error_embed(
+                "admin::species::edit::update_species", "save the species", &query_err
+            )
+        }
+
+        let modifiers = species.describe_modifiers();
+        CreateEmbed::new()
+            .title(format!( "Updated {}", species.name ))
+            .description(if species.description.is_empty() { "-" } else { &species.description })
+            .field("Modifiers", if modifiers.is_empty() { "None" } else { &modifiers }, true)
+            .field("Traits", if species.traits.is_empty() { "-" } else { &species.traits }, true)
+            .colour(ColourCode::Success.to_embed_colour())
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, true, "admin::species::edit").await;
+}
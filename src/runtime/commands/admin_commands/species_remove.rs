@@ -0,0 +1,65 @@
+use crate::{
+    runtime::{
+        commands::{
+            admin::find_species, is_bot_admin, query_error_embed, respond_with_embed, string_option,
+            sub_command_options
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let database_conn_pool = &runtime_client.database_connection;
+
+    let sub_options = sub_command_options(&interaction_data);
+    let species_option = string_option(&sub_options, "species")
+        .expect("`species` is a required option");
+
+    let response_embed = 'response_embed: {
+
+        if !is_bot_admin(runtime_client, interaction_data.user.id.get()) {
+            break 'response_embed CreateEmbed::new()
+                .title("Only bot admins can remove species")
+                .description("Every server the bot is in shares the same species")
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        let species = match sql_scripts::species::get_species_list(database_conn_pool).await {
+            Ok(species_list) => find_species(species_list, species_option),
+            Err(query_err) => break 'response_embed query_error_embed(
+                "admin::species::remove::get_species_list", "fetch the species", &query_err
+            )
+        };
+        let Some(species) = species else {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "There is no species called `{species_option}`" ))
+                .colour(ColourCode::Info.to_embed_colour());
+        };
+
+        if let Err(query_err) = sql_scripts::species::remove_species(database_conn_pool, species.id).await {
+            break 'response_embed query_error_embed(
+                "admin::species::remove::remove_species", "remove the species", &query_err
+            );
+        }
+
+        CreateEmbed::new()
+            .title(format!( "{} is no longer offered", species.name ))
+            .description("Characters of the species keep the name, but lose its modifiers and traits")
+            .colour(ColourCode::Success.to_embed_colour())
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, true, "admin::species::remove").await;
+}
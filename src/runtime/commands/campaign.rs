@@ -0,0 +1,143 @@
+use crate::{
+    runtime::{
+        commands::{self, campaign_commands},
+        runtime_client::RuntimeClient,
+        sql_scripts::{self, models::Campaign}
+    },
+    utils::misc::{
+        colour_codes::ColourCode, logging::create_log_message
+    }
+};
+
+use serenity::{
+    builder::{
+        CreateCommand,
+        CreateCommandOption,
+    },
+    model::application::{
+        CommandInteraction,
+        CommandOptionType
+    },
+    client::Context
+};
+
+pub fn build() -> CreateCommand {
+    CreateCommand::new("campaign")
+        .description("Run or take part in a campaign")
+        .dm_permission(false)
+        .set_options(vec![
+                CreateCommandOption::new(
+                    CommandOptionType::SubCommand,
+                    "create",
+                    "Start a campaign in this channel, with you as its GM"
+                )
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::String, "name", "The campaign's name")
+                            .required(true)
+                            .max_length(100)
+                    ),
+                CreateCommandOption::new(
+                    CommandOptionType::SubCommand,
+                    "join",
+                    "Enrol one of your characters in this channel's campaign"
+                )
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::String, "character", "The character to enrol")
+                            .required(true)
+                            .set_autocomplete(true)
+                    ),
+                CreateCommandOption::new(
+                    CommandOptionType::SubCommand,
+                    "leave",
+                    "Withdraw your character from this channel's campaign"
+                ),
+                CreateCommandOption::new(
+                    CommandOptionType::SubCommand,
+                    "roster",
+                    "List the characters taking part in this channel's campaign"
+                ),
+                CreateCommandOption::new(
+                    CommandOptionType::SubCommand,
+                    "relations",
+                    "Export the relationships between this campaign's characters as a graph"
+                )
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::String, "format", "The file format to export")
+                            .required(true)
+                            .add_string_choice("Graphviz DOT", "dot")
+                            .add_string_choice("JSON", "json")
+                    ),
+                CreateCommandOption::new(
+                    CommandOptionType::SubCommand,
+                    "archive",
+                    "End this channel's campaign, GM only"
+                )
+        ])
+}
+
+
+pub async fn run( runtime_client: &RuntimeClient, ctx: Context, interaction_data: CommandInteraction ) {
+    let sub_command_name = &interaction_data
+        .data
+        .options[0] // Because this command has sub commands, we know that there exists a 0th
+                    // option, and that it contains the name of the selected sub command
+        .name;
+
+
+    // Based on the sub command's name, delegate to the correct execution unit
+    match sub_command_name.as_str() {
+        "create"    => campaign_commands::create::run(runtime_client, ctx, interaction_data).await,
+        "join"      => campaign_commands::join::run(runtime_client, ctx, interaction_data).await,
+        "leave"     => campaign_commands::leave::run(runtime_client, ctx, interaction_data).await,
+        "roster"    => campaign_commands::roster::run(runtime_client, ctx, interaction_data).await,
+        "relations" => campaign_commands::relations::run(runtime_client, ctx, interaction_data).await,
+        "archive"   => campaign_commands::archive::run(runtime_client, ctx, interaction_data).await,
+        // In the case that an unknown sub command is recived, inform the terminal of this
+        // occurance
+        unimplemented_subcommand => println!( "{}", create_log_message(
+                format!(
+                    "{}campaign{}: Recieved unknown sub command: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    unimplemented_subcommand,
+                    ColourCode::Reset
+                ),
+                ColourCode::Warning
+        ))
+    }
+}
+
+
+pub async fn autocomplete( ctx: Context, interaction_data: CommandInteraction ) {
+    // `join` is the only sub command with an autocompleted option, and it takes a character
+    commands::autocomplete_user_characters(&ctx, &interaction_data).await;
+}
+
+
+/// Resolves the campaign running in a channel. Threads fall back to the campaign of their parent
+/// channel, so a campaign's scenes can be split into threads.
+///
+/// This is what any command scoped to "the campaign in this channel" should go through
+pub async fn channel_campaign( runtime_client: &RuntimeClient, channel_id: u64, parent_channel_id: Option<u64> ) -> Result<Option<Campaign>, sqlx::Error> {
+    let database_conn_pool = &runtime_client.database_connection;
+
+    if let Some(campaign) = sql_scripts::campaigns::get_active_by_channel(database_conn_pool, channel_id).await? {
+        return Ok(Some(campaign));
+    }
+
+    match parent_channel_id {
+        Some(parent_channel_id) => sql_scripts::campaigns::get_active_by_channel(database_conn_pool, parent_channel_id).await,
+        None => Ok(None)
+    }
+}
+
+/// `channel_campaign` for the channel a slash command was invoked in
+pub async fn interaction_campaign( runtime_client: &RuntimeClient, interaction_data: &CommandInteraction ) -> Result<Option<Campaign>, sqlx::Error> {
+    let parent_channel_id = interaction_data.channel
+        .as_ref()
+        .and_then(|channel| channel.parent_id)
+        .map(|parent_id| parent_id.get());
+
+    channel_campaign(runtime_client, interaction_data.channel_id.get(), parent_channel_id).await
+}
@@ -0,0 +1,74 @@
+use crate::{
+    runtime::{
+        commands::{campaign::interaction_campaign, is_server_manager, query_error_embed, respond_with_embed},
+        runtime_client::RuntimeClient,
+        sql_scripts
+    },
+    utils::misc::{colour_codes::ColourCode, logging::create_log_message},
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let invoker_id = interaction_data.user.id.get();
+    let invoker_tag = interaction_data.user.tag();
+
+    // Server managers may archive campaigns whose GM has gone missing
+    let is_server_manager = is_server_manager(interaction_data.member.as_deref());
+
+    let response_embed = 'response_embed: {
+
+        let campaign = match interaction_campaign(runtime_client, &interaction_data).await {
+            Ok(Some(campaign)) => campaign,
+            Ok(None) => {
+                break 'response_embed CreateEmbed::new()
+                    .title("No campaign runs in this channel")
+                    .colour(ColourCode::Info.to_embed_colour());
+            },
+            Err(query_err) => break 'response_embed query_error_embed(
+                "campaign::archive::interaction_campaign", "find this channel's campaign", &query_err
+            )
+        };
+
+        if campaign.gm.get() != invoker_id && !is_server_manager {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "Only the GM of {} can archive it", campaign.name ))
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        match sql_scripts::campaigns::archive_campaign(&runtime_client.database_connection, campaign.id).await {
+            Ok(_) => {
+                println!( "{}", create_log_message(
+                        format!(
+                            "`{}{}:#{}{}` archived campaign `{}{}{}`",
+                            ColourCode::Info,
+                            invoker_tag,
+                            invoker_id,
+                            ColourCode::Reset,
+                            ColourCode::Info,
+                            campaign.name,
+                            ColourCode::Reset
+                        ),
+                        ColourCode::Success
+                ));
+
+                CreateEmbed::new()
+                    .title(format!( "{} has been archived", campaign.name ))
+                    .description("Thank you for playing! A new campaign may now be started in this channel")
+                    .colour(ColourCode::Success.to_embed_colour())
+            },
+            Err(query_err) => query_error_embed("campaign::archive::archive_campaign", "archive the campaign", &query_err)
+        }
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, false, "campaign::archive").await;
+}
@@ -0,0 +1,124 @@
+use crate::{
+    runtime::{
+        commands::{query_error_embed, string_option, sub_command_options},
+        preconditions::{self, Precondition},
+        runtime_client::RuntimeClient,
+        sql_scripts
+    },
+    utils::misc::{colour_codes::ColourCode, logging::create_log_message},
+};
+
+use serenity::{
+    builder::{CreateEmbed, CreateInteractionResponse, CreateInteractionResponseMessage},
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+// The GM of a campaign is referenced through their profile
+pub const PRECONDITIONS: &[Precondition] = &[ Precondition::RegisteredProfile ];
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let Some(precondition_notices) = preconditions::enforce(runtime_client, &ctx, &interaction_data, PRECONDITIONS).await else {
+        return;
+    };
+
+    let invoker_id = interaction_data.user.id.get();
+    let invoker_tag = interaction_data.user.tag();
+    let channel_id = interaction_data.channel_id.get();
+
+    let sub_options = sub_command_options(&interaction_data);
+    let campaign_name = string_option(&sub_options, "name")
+        .expect("`name` is a required option")
+        .trim()
+        .to_string();
+
+    let response_embed = 'response_embed: {
+
+        let Some(guild_id) = interaction_data.guild_id else {
+            break 'response_embed CreateEmbed::new()
+                .title("Campaigns can only be run in servers")
+                .colour(ColourCode::Info.to_embed_colour());
+        };
+
+        // --== CHECK CHANNEL IS FREE ==-- //
+
+            match sql_scripts::campaigns::get_active_by_channel(&runtime_client.database_connection, channel_id).await {
+                Ok(None) => {},
+                Ok(Some(running_campaign)) => {
+                    break 'response_embed CreateEmbed::new()
+                        .title(format!( "This channel already runs `{}`", running_campaign.name ))
+                        .description("Its GM has to `/campaign archive` it before a new one can start here")
+                        .colour(ColourCode::Info.to_embed_colour());
+                },
+                Err(query_err) => break 'response_embed query_error_embed(
+                    "campaign::create::get_active_by_channel", "check this channel's campaign", &query_err
+                )
+            }
+        // ==--
+
+        // --== INSERT CAMPAIGN ==-- //
+
+            let add_campaign_query = sql_scripts::campaigns::add_campaign(
+                &runtime_client.database_connection,
+                guild_id.get(),
+                channel_id,
+                invoker_id,
+                &campaign_name
+            );
+
+            match add_campaign_query.await {
+                Ok(_) => {
+                    println!( "{}", create_log_message(
+                            format!(
+                                "`{}{}:#{}{}` started campaign `{}{}{}`",
+                                ColourCode::Info,
+                                invoker_tag,
+                                invoker_id,
+                                ColourCode::Reset,
+                                ColourCode::Info,
+                                campaign_name,
+                                ColourCode::Reset
+                            ),
+                            ColourCode::Success
+                    ));
+
+                    CreateEmbed::new()
+                        .title(format!( "{campaign_name} has begun!" ))
+                        .description(format!( "<@{invoker_id}> is its GM. Players, `/campaign join` with one of your characters" ))
+                        .colour(ColourCode::Success.to_embed_colour())
+                },
+                Err(query_err) if query_err.as_database_error().is_some_and(|db_err| db_err.is_unique_violation()) => {
+                    CreateEmbed::new()
+                        .title(format!( "This server already runs a campaign called `{campaign_name}`" ))
+                        .description("Campaign names have to be unique among a server's running campaigns. Archived ones free theirs up")
+                        .colour(ColourCode::Info.to_embed_colour())
+                },
+                Err(query_err) => query_error_embed("campaign::create::add_campaign", "create the campaign", &query_err)
+            }
+        // ==--
+
+    }; // let response_embed = {...}
+
+    // Any notices, such as the one for a freshly made profile, are shown above the campaign
+    let response = CreateInteractionResponseMessage::new()
+        .embeds(precondition_notices)
+        .add_embed(response_embed);
+
+    if let Err(why) = interaction_data.create_response(&ctx.http, CreateInteractionResponse::Message(response)).await {
+        println!( "{}", create_log_message(
+                format!(
+                    "{}campaign::create{}: Failed to send response: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    why,
+                    ColourCode::Reset
+                ),
+                ColourCode::Error
+        ));
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{
+    runtime::{
+        commands::{
+            campaign::interaction_campaign, query_error_embed, resolve_user_character,
+            respond_with_embed, string_option, sub_command_options, unapproved_character_embed
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts::{self, models::CharacterStatus}
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let invoker_id = interaction_data.user.id.get();
+
+    let sub_options = sub_command_options(&interaction_data);
+    let character_option = string_option(&sub_options, "character")
+        .expect("`character` is a required option");
+
+    let response_embed = 'response_embed: {
+
+        let campaign = match interaction_campaign(runtime_client, &interaction_data).await {
+            Ok(Some(campaign)) => campaign,
+            Ok(None) => {
+                break 'response_embed CreateEmbed::new()
+                    .title("No campaign runs in this channel")
+                    .colour(ColourCode::Info.to_embed_colour());
+            },
+            Err(query_err) => break 'response_embed query_error_embed(
+                "campaign::join::interaction_campaign", "find this channel's campaign", &query_err
+            )
+        };
+
+        let Some((character_id, character_name)) = resolve_user_character(&ctx, invoker_id, character_option).await else {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "You don't have a character called `{character_option}`" ))
+                .colour(ColourCode::Info.to_embed_colour());
+        };
+
+        // Characters still under review can't take part in anything yet
+        match sql_scripts::characters::get_character(&runtime_client.database_connection, character_id).await {
+            Ok(Some(character)) if character.status != CharacterStatus::Approved => {
+                break 'response_embed unapproved_character_embed(&character_name, character.status);
+            },
+            Ok(_) => {},
+            Err(query_err) => break 'response_embed query_error_embed(
+                "campaign::join::get_character", "check the character's review", &query_err
+            )
+        }
+
+        // --== CHECK ROSTER ==-- //
+
+            // Each player takes part with a single character
+            let roster = match sql_scripts::campaigns::get_roster(&runtime_client.database_connection, campaign.id).await {
+                Ok(roster) => roster,
+                Err(query_err) => break 'response_embed query_error_embed(
+                    "campaign::join::get_roster", "fetch the campaign's roster", &query_err
+                )
+            };
+
+            if let Some(enrolled_character) = roster.iter().find(|entry| entry.owner.get() == invoker_id) {
+                break 'response_embed CreateEmbed::new()
+                    .title(format!( "{} is already taking part in {}", enrolled_character.name, campaign.name ))
+                    .description("`/campaign leave` first to swap characters")
+                    .colour(ColourCode::Info.to_embed_colour());
+            }
+        // ==--
+
+        match sql_scripts::campaigns::add_character(&runtime_client.database_connection, campaign.id, character_id).await {
+            Ok(_) => CreateEmbed::new()
+                .title(format!( "{character_name} has joined {}!", campaign.name ))
+                .colour(ColourCode::Success.to_embed_colour()),
+            Err(query_err) => query_error_embed("campaign::join::add_character", "enrol your character", &query_err)
+        }
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, false, "campaign::join").await;
+}
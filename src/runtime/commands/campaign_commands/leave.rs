@@ -0,0 +1,60 @@
+use crate::{
+    runtime::{
+        commands::{campaign::interaction_campaign, query_error_embed, respond_with_embed},
+        runtime_client::RuntimeClient,
+        sql_scripts
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let invoker_id = interaction_data.user.id.get();
+
+    let response_embed = 'response_embed: {
+
+        let campaign = match interaction_campaign(runtime_client, &interaction_data).await {
+            Ok(Some(campaign)) => campaign,
+            Ok(None) => {
+                break 'response_embed CreateEmbed::new()
+                    .title("No campaign runs in this channel")
+                    .colour(ColourCode::Info.to_embed_colour());
+            },
+            Err(query_err) => break 'response_embed query_error_embed(
+                "campaign::leave::interaction_campaign", "find this channel's campaign", &query_err
+            )
+        };
+
+        let roster = match sql_scripts::campaigns::get_roster(&runtime_client.database_connection, campaign.id).await {
+            Ok(roster) => roster,
+            Err(query_err) => break 'response_embed query_error_embed(
+                "campaign::leave::get_roster", "fetch the campaign's roster", &query_err
+            )
+        };
+
+        let Some(enrolled_character) = roster.into_iter().find(|entry| entry.owner.get() == invoker_id) else {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "You have no character in {}", campaign.name ))
+                .colour(ColourCode::Info.to_embed_colour());
+        };
+
+        match sql_scripts::campaigns::remove_character(&runtime_client.database_connection, campaign.id, enrolled_character.character_id).await {
+            Ok(_) => CreateEmbed::new()
+                .title(format!( "{} has left {}", enrolled_character.name, campaign.name ))
+                .colour(ColourCode::Success.to_embed_colour()),
+            Err(query_err) => query_error_embed("campaign::leave::remove_character", "withdraw your character", &query_err)
+        }
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, false, "campaign::leave").await;
+}
@@ -0,0 +1,6 @@
+pub mod archive;
+pub mod create;
+pub mod join;
+pub mod leave;
+pub mod relations;
+pub mod roster;
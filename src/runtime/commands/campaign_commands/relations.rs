@@ -0,0 +1,277 @@
+use crate::{
+    runtime::{
+        commands::{campaign::interaction_campaign, query_error_embed, respond_with_embed, string_option, sub_command_options},
+        runtime_client::RuntimeClient,
+        sql_scripts::{
+            self,
+            models::{CampaignRosterEntry, Relationship, RelationshipKind, SelectedClass}
+        }
+    },
+    utils::misc::{colour_codes::ColourCode, logging::create_log_message},
+};
+
+use std::collections::HashMap;
+
+use serenity::{
+    builder::{CreateAttachment, CreateEmbed, CreateInteractionResponse, CreateInteractionResponseMessage},
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let database_conn_pool = &runtime_client.database_connection;
+
+    let sub_options = sub_command_options(&interaction_data);
+    let export_format = string_option(&sub_options, "format").unwrap_or("dot");
+
+    let refusal_embed = 'refusal_embed: {
+
+        let campaign = match interaction_campaign(runtime_client, &interaction_data).await {
+            Ok(Some(campaign)) => campaign,
+            Ok(None) => break 'refusal_embed CreateEmbed::new()
+                .title("No campaign runs in this channel")
+                .colour(ColourCode::Info.to_embed_colour()),
+            Err(query_err) => break 'refusal_embed query_error_embed(
+                "campaign::relations::interaction_campaign", "find this channel's campaign", &query_err
+            )
+        };
+
+        let (roster, relationships) = match (
+            sql_scripts::campaigns::get_roster(database_conn_pool, campaign.id).await,
+            sql_scripts::relationships::get_campaign_relationships(database_conn_pool, campaign.id).await
+        ) {
+            (Ok(roster), Ok(relationships)) => (roster, relationships),
+            (Err(query_err), _) | (_, Err(query_err)) => break 'refusal_embed query_error_embed(
+                "campaign::relations::get_campaign_relationships", "fetch the campaign's relationships", &query_err
+            )
+        };
+
+        let mut classes = HashMap::new();
+        for entry in &roster {
+            match sql_scripts::character_classes::get_class_split(database_conn_pool, entry.character_id).await {
+                Ok(class_split) => classes.insert(entry.character_id, class_split),
+                Err(query_err) => break 'refusal_embed query_error_embed(
+                    "campaign::relations::get_class_split", "fetch the characters' classes", &query_err
+                )
+            };
+        }
+
+        let (graph, extension) = match export_format {
+            "json" => (graph_json(&campaign.name, &roster, &classes, &relationships), "json"),
+            _ => (graph_dot(&campaign.name, &roster, &classes, &relationships), "dot")
+        };
+
+        let export_message = CreateInteractionResponseMessage::new()
+            .embed(
+                CreateEmbed::new()
+                    .title(format!( "{} relationship graph", campaign.name ))
+                    .description(format!( "{} character(s) and {} confirmed relationship(s)", roster.len(), relationships.len() ))
+                    .colour(ColourCode::Info.to_embed_colour())
+            )
+            .add_file(CreateAttachment::bytes(graph.into_bytes(), format!( "relationships.{extension}" )));
+
+        if let Err(why) = interaction_data.create_response(&ctx.http, CreateInteractionResponse::Message(export_message)).await {
+            println!( "{}", create_log_message(
+                    format!(
+                        "{}campaign::relations{}: Failed to send response: `{}{}{}`",
+                        ColourCode::Location,
+                        ColourCode::Reset,
+                        ColourCode::Info,
+                        why,
+                        ColourCode::Reset
+                    ),
+                    ColourCode::Error
+            ));
+        }
+
+        return;
+
+    }; // let refusal_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, refusal_embed, true, "campaign::relations").await;
+}
+
+
+
+// --== EXPORT FORMATS ==-- //
+
+    /// Escapes text for a double quoted string, which DOT and JSON escape alike for our purposes
+    fn quoted( text: &str ) -> String {
+        let mut quoted_text = String::from("\"");
+        for character in text.chars() {
+            match character {
+                '"'  => quoted_text.push_str("\\\""),
+                '\\' => quoted_text.push_str("\\\\"),
+                '\n' => quoted_text.push_str("\\n"),
+                character if character.is_control() => quoted_text.push_str(&format!( "\\u{:04x}", character as u32 )),
+                character => quoted_text.push(character)
+            }
+        }
+        quoted_text.push('"');
+        quoted_text
+    }
+
+    /// A character's classes and their levels in each, such as `Fighter 3, Mage 1`
+    fn class_levels( classes: &[SelectedClass] ) -> String {
+        classes.iter()
+            .map(|selected| format!( "{} {}", selected.class.name, selected.level ))
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    /// The campaign's relationships as a Graphviz graph. Characters are labelled with their
+    /// classes below their name. Mentorships are drawn as arrows from the mentor, the other kinds
+    /// without arrowheads
+    pub fn graph_dot( campaign_name: &str, roster: &[CampaignRosterEntry], classes: &HashMap<u64, Vec<SelectedClass>>, relationships: &[Relationship] ) -> String {
+        let mut graph = format!( "digraph {} {{\n", quoted(campaign_name) );
+
+        for entry in roster {
+            let label = match classes.get(&entry.character_id) {
+                Some(class_split) if !class_split.is_empty() => format!( "{}\n{}", entry.name, class_levels(class_split) ),
+                _ => entry.name.clone()
+            };
+            graph.push_str(&format!( "    c{} [label={}];\n", entry.character_id, quoted(&label) ));
+        }
+        for relationship in relationships {
+            let mut label = relationship.kind.key().to_string();
+            if let Some(note) = &relationship.note {
+                label.push_str(&format!( ": {note}" ));
+            }
+            let direction = match relationship.kind {
+                RelationshipKind::Mentor => "forward",
+                _ => "none"
+            };
+
+            graph.push_str(&format!(
+                "    c{} -> c{} [label={}, dir={direction}];\n",
+                relationship.character_id,
+                relationship.other_character_id,
+                quoted(&label)
+            ));
+        }
+
+        graph.push_str("}\n");
+        graph
+    }
+
+    /// The campaign's relationships as JSON, with a `nodes` list of characters and their classes,
+    /// and an `edges` list of relationships between them
+    pub fn graph_json( campaign_name: &str, roster: &[CampaignRosterEntry], classes: &HashMap<u64, Vec<SelectedClass>>, relationships: &[Relationship] ) -> String {
+        let nodes: Vec<String> = roster.iter()
+            .map(|entry| {
+                let class_list: Vec<String> = classes.get(&entry.character_id)
+                    .map(Vec::as_slice)
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|selected| format!(
+                        "{{ \"name\": {}, \"level\": {}, \"primary\": {} }}",
+                        quoted(&selected.class.name),
+                        selected.level,
+                        selected.is_primary
+                    ))
+                    .collect();
+
+                format!(
+                    "    {{ \"id\": {}, \"name\": {}, \"owner\": \"{}\", \"classes\": [{}] }}",
+                    entry.character_id,
+                    quoted(&entry.name),
+                    entry.owner.get(),
+                    class_list.join(", ")
+                )
+            })
+            .collect();
+
+        let edges: Vec<String> = relationships.iter()
+            .map(|relationship| format!(
+                "    {{ \"from\": {}, \"to\": {}, \"kind\": \"{}\", \"note\": {} }}",
+                relationship.character_id,
+                relationship.other_character_id,
+                relationship.kind.key(),
+                relationship.note.as_deref().map_or(String::from("null"), quoted)
+            ))
+            .collect();
+
+        format!(
+            "{{\n  \"campaign\": {},\n  \"nodes\": [\n{}\n  ],\n  \"edges\": [\n{}\n  ]\n}}\n",
+            quoted(campaign_name),
+            nodes.join(",\n"),
+            edges.join(",\n")
+        )
+    }
+// ==--
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::sql_scripts::models::{CharacterClass, DiscordId};
+
+    fn selected_class( name: &str, is_primary: bool, level: i64 ) -> SelectedClass {
+        SelectedClass {
+            class: CharacterClass {
+                id: 0,
+                name: String::from(name),
+                description: String::new(),
+                casting_cap: 0,
+                stat_budget: 0,
+                base_hit_points: 8
+            },
+            is_primary,
+            level
+        }
+    }
+
+    fn example_graph() -> (Vec<CampaignRosterEntry>, HashMap<u64, Vec<SelectedClass>>, Vec<Relationship>) {
+        let roster = vec![
+            CampaignRosterEntry { character_id: 1, owner: DiscordId(10), name: String::from("Hale"), joined_at: 0 },
+            CampaignRosterEntry { character_id: 2, owner: DiscordId(20), name: String::from("Mira \"the Red\""), joined_at: 0 }
+        ];
+        let relationships = vec![
+            Relationship {
+                id: 1,
+                character_id: 1,
+                character_name: String::from("Hale"),
+                other_character_id: 2,
+                other_name: String::from("Mira \"the Red\""),
+                kind: RelationshipKind::Mentor,
+                note: Some(String::from("Taught her the blade")),
+                character_confirmed: true,
+                other_confirmed: true,
+                created_at: 0
+            }
+        ];
+        // Mira has yet to take up a class
+        let classes = HashMap::from([
+            (1, vec![ selected_class("Fighter", true, 3), selected_class("Mage", false, 1) ])
+        ]);
+        (roster, classes, relationships)
+    }
+
+    #[test]
+    fn graph_exports() {
+        let (roster, classes, relationships) = example_graph();
+
+        assert_eq!(
+            graph_dot("The Long Road", &roster, &classes, &relationships),
+            "digraph \"The Long Road\" {\n\
+            \x20   c1 [label=\"Hale\\nFighter 3, Mage 1\"];\n\
+            \x20   c2 [label=\"Mira \\\"the Red\\\"\"];\n\
+            \x20   c1 -> c2 [label=\"mentor: Taught her the blade\", dir=forward];\n\
+            }\n"
+        );
+
+        assert_eq!(
+            graph_json("The Long Road", &roster, &classes, &relationships),
+            "{\n  \"campaign\": \"The Long Road\",\n  \"nodes\": [\n\
+            \x20   { \"id\": 1, \"name\": \"Hale\", \"owner\": \"10\", \"classes\": [{ \"name\": \"Fighter\", \"level\": 3, \"primary\": true }, \
+            { \"name\": \"Mage\", \"level\": 1, \"primary\": false }] },\n\
+            \x20   { \"id\": 2, \"name\": \"Mira \\\"the Red\\\"\", \"owner\": \"20\", \"classes\": [] }\n  ],\n  \"edges\": [\n\
+            \x20   { \"from\": 1, \"to\": 2, \"kind\": \"mentor\", \"note\": \"Taught her the blade\" }\n  ]\n}\n"
+        );
+    }
+}
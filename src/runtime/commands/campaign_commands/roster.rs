@@ -0,0 +1,69 @@
+use crate::{
+    runtime::{
+        commands::{campaign::interaction_campaign, query_error_embed, respond_with_embed},
+        runtime_client::RuntimeClient,
+        sql_scripts
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+// Embed descriptions are capped at 4096 characters, leave some room for the overflow line
+const ROSTER_DESCRIPTION_LIMIT: usize = 4000;
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let response_embed = 'response_embed: {
+
+        let campaign = match interaction_campaign(runtime_client, &interaction_data).await {
+            Ok(Some(campaign)) => campaign,
+            Ok(None) => {
+                break 'response_embed CreateEmbed::new()
+                    .title("No campaign runs in this channel")
+                    .colour(ColourCode::Info.to_embed_colour());
+            },
+            Err(query_err) => break 'response_embed query_error_embed(
+                "campaign::roster::interaction_campaign", "find this channel's campaign", &query_err
+            )
+        };
+
+        let roster = match sql_scripts::campaigns::get_roster(&runtime_client.database_connection, campaign.id).await {
+            Ok(roster) => roster,
+            Err(query_err) => break 'response_embed query_error_embed(
+                "campaign::roster::get_roster", "fetch the campaign's roster", &query_err
+            )
+        };
+
+        let mut roster_description = String::new();
+        for (idx, entry) in roster.iter().enumerate() {
+            let roster_line = format!( "- **{}**, played by <@{}>\n", entry.name, entry.owner.get() );
+
+            if roster_description.len() + roster_line.len() > ROSTER_DESCRIPTION_LIMIT {
+                roster_description.push_str(&format!( "*...and {} more*", roster.len() - idx ));
+                break;
+            }
+            roster_description.push_str(&roster_line);
+        }
+        if roster.is_empty() {
+            roster_description.push_str("Nobody has joined yet");
+        }
+
+        CreateEmbed::new()
+            .title(format!( "{} roster", campaign.name ))
+            .description(roster_description)
+            .field("GM", format!( "<@{}>", campaign.gm.get() ), true)
+            .field("Running since", format!( "<t:{}:D>", campaign.created_at ), true)
+            .colour(ColourCode::Info.to_embed_colour())
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, false, "campaign::roster").await;
+}
@@ -4,7 +4,8 @@ use core::panic;
 
 use crate::{
     runtime::{
-        commands::character_commands, runtime_client::RuntimeClient
+        commands::{self, character_commands}, runtime_client::RuntimeClient,
+        sql_scripts::models::RelationshipKind
     },
     utils::misc::{
         colour_codes::ColourCode, logging::create_log_message
@@ -27,6 +28,25 @@ use serenity::{
 };
 
 pub fn build() -> CreateCommand {
+    let character_option = || CreateCommandOption::new(CommandOptionType::String, "character", "One of your characters")
+        .required(true)
+        .set_autocomplete(true);
+    let player_option = || CreateCommandOption::new(CommandOptionType::User, "player", "Who the other character belongs to")
+        .required(true);
+    let other_option = || CreateCommandOption::new(CommandOptionType::String, "other", "The other character")
+        .required(true)
+        .set_autocomplete(true);
+    let class_option = || CreateCommandOption::new(CommandOptionType::String, "class", "The class")
+        .required(true)
+        .set_autocomplete(true);
+    let kind_option = || {
+        let mut kind_option = CreateCommandOption::new(CommandOptionType::String, "kind", "How they are related");
+        for kind in RelationshipKind::ALL {
+            kind_option = kind_option.add_string_choice(kind.key(), kind.key());
+        }
+        kind_option
+    };
+
     CreateCommand::new("character")
         .description("Manage your characters")
         .set_options(vec![
@@ -35,6 +55,76 @@ pub fn build() -> CreateCommand {
                     "create",
                     "Construct your new character"
                 )
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::String, "template", "An archetype to start from, see `/template list`")
+                            .set_autocomplete(true)
+                    ),
+                CreateCommandOption::new(CommandOptionType::SubCommand, "select", "Pick the character you play as")
+                    .add_sub_option(character_option()),
+                CreateCommandOption::new(CommandOptionType::SubCommand, "transfer", "Hand one of your characters over to another user")
+                    .add_sub_option(character_option())
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::User, "user", "Who to offer the character to")
+                            .required(true)
+                    ),
+                CreateCommandOption::new(CommandOptionType::SubCommand, "resubmit", "Send a character back to staff for review")
+                    .add_sub_option(character_option()),
+                CreateCommandOption::new(CommandOptionType::SubCommand, "relate", "Tie one of your characters to another, once both owners confirm")
+                    .add_sub_option(player_option())
+                    .add_sub_option(other_option())
+                    .add_sub_option(kind_option().required(true))
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::String, "character", "Your character, defaults to your selected one")
+                            .set_autocomplete(true)
+                    )
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::String, "note", "How they know each other")
+                            .max_length(200)
+                    ),
+                CreateCommandOption::new(CommandOptionType::SubCommand, "unrelate", "End a relationship between one of your characters and another")
+                    .add_sub_option(character_option())
+                    .add_sub_option(player_option())
+                    .add_sub_option(other_option())
+                    .add_sub_option(kind_option()),
+                CreateCommandOption::new(CommandOptionType::SubCommand, "relations", "Show who a character is tied to")
+                    .add_sub_option(
+                        CreateCommandOption::new(
+                            CommandOptionType::String,
+                            "character",
+                            "Defaults to your selected character, GMs may pick any character in their campaign"
+                        )
+                            .set_autocomplete(true)
+                    ),
+                CreateCommandOption::new(CommandOptionType::SubCommand, "search", "Find characters by their name, species, appearance, backstory or extras")
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::String, "query", "The words to look for")
+                            .required(true)
+                            .max_length(character_commands::search::SEARCH_TEXT_MAX_BYTES as u16)
+                    ),
+                CreateCommandOption::new(CommandOptionType::SubCommand, "directory", "Browse the characters of this server's members")
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::String, "class", "Only characters of this class")
+                            .set_autocomplete(true)
+                    )
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::String, "species", "Only characters of this species")
+                            .set_autocomplete(true)
+                            .max_length(40)
+                    )
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::User, "owner", "Only this user's characters")
+                    ),
+                CreateCommandOption::new(CommandOptionType::SubCommandGroup, "class", "The classes your characters have")
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::SubCommand, "add", "Have one of your characters take up another class")
+                            .add_sub_option(character_option())
+                            .add_sub_option(class_option())
+                    )
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::SubCommand, "primary", "Pick the class a character's new levels go to")
+                            .add_sub_option(character_option())
+                            .add_sub_option(class_option())
+                    )
         ])
 }
 
@@ -49,7 +139,30 @@ pub async fn run( runtime_client: &RuntimeClient, ctx: Context, interaction_data
 
     // Based on the sub command's name, delegate to the correct execution unit
     match sub_command_name.as_str() {
-        "create" => character_commands::create::run(runtime_client, ctx, interaction_data).await,
+        "create"    => character_commands::create::run(runtime_client, ctx, interaction_data).await,
+        "select"    => character_commands::select::run(runtime_client, ctx, interaction_data).await,
+        "transfer"  => character_commands::transfer::run(runtime_client, ctx, interaction_data).await,
+        "resubmit"  => character_commands::resubmit::run(runtime_client, ctx, interaction_data).await,
+        "relate"    => character_commands::relate::run(runtime_client, ctx, interaction_data).await,
+        "unrelate"  => character_commands::unrelate::run(runtime_client, ctx, interaction_data).await,
+        "relations" => character_commands::relations::run(runtime_client, ctx, interaction_data).await,
+        "search"    => character_commands::search::run(runtime_client, ctx, interaction_data).await,
+        "directory" => character_commands::directory::run(runtime_client, ctx, interaction_data).await,
+        "class"     => match commands::grouped_sub_command_name(&interaction_data) {
+            Some("add")     => character_commands::class_add::run(runtime_client, ctx, interaction_data).await,
+            Some("primary") => character_commands::class_primary::run(runtime_client, ctx, interaction_data).await,
+            unimplemented_subcommand => println!( "{}", create_log_message(
+                    format!(
+                        "{}character{}: Recieved unknown sub command: `{}class {}{}`",
+                        ColourCode::Location,
+                        ColourCode::Reset,
+                        ColourCode::Info,
+                        unimplemented_subcommand.unwrap_or_default(),
+                        ColourCode::Reset
+                    ),
+                    ColourCode::Warning
+            ))
+        },
         // In the case that an unknown sub command is recived, inform the terminal of this
         // occurance
         unimplemented_subcommand => println!( "{}", create_log_message(
@@ -67,17 +180,40 @@ pub async fn run( runtime_client: &RuntimeClient, ctx: Context, interaction_data
 }
 
 
+pub async fn autocomplete( runtime_client: &RuntimeClient, ctx: Context, interaction_data: CommandInteraction ) {
+    match interaction_data.data.autocomplete().map(|focused_option| focused_option.name) {
+        // The other character belongs to whoever was picked as `player`
+        Some("other") => commands::autocomplete_player_characters(&ctx, &interaction_data, "player").await,
+        Some("template") => commands::template::autocomplete_templates(runtime_client, &ctx, &interaction_data).await,
+        Some(focused_option @ ("class" | "species")) => {
+            character_commands::directory::autocomplete(runtime_client, &ctx, &interaction_data, focused_option).await
+        },
+        // GMs may look at the relations of any character in their campaign
+        _ if commands::sub_command_options(&interaction_data).first().is_some_and(|option| option.name == "relations") => {
+            commands::autocomplete_campaign_characters(runtime_client, &ctx, &interaction_data).await
+        },
+        _ => commands::autocomplete_user_characters(&ctx, &interaction_data).await
+    }
+}
+
+
 pub async fn handle_component_interaction( runtime_client: &RuntimeClient, interaction_data: ComponentInteraction, ctx: Context, split_custom_id: Vec<&str> ) {
     match split_custom_id[1] {
-        "create" => character_commands::create::handle_component(runtime_client, interaction_data, ctx, split_custom_id).await,
+        "create"    => character_commands::create::handle_component(runtime_client, interaction_data, ctx, split_custom_id).await,
+        "review"    => character_commands::review::handle_component(runtime_client, interaction_data, ctx, split_custom_id).await,
+        "relation"  => character_commands::relate::handle_component(runtime_client, interaction_data, ctx, split_custom_id).await,
+        "transfer"  => character_commands::transfer::handle_component(runtime_client, interaction_data, ctx, split_custom_id).await,
+        "search"    => character_commands::search::handle_component(runtime_client, interaction_data, ctx, split_custom_id).await,
+        "directory" => character_commands::directory::handle_component(runtime_client, interaction_data, ctx, split_custom_id).await,
         uknown_component => panic!("character uknown_component: {uknown_component}")
     }
 }
 
 
-pub async fn handle_modal( modal_interaction: ModalInteraction, ctx: Context, split_custom_id: Vec<&str> ) {
+pub async fn handle_modal( runtime_client: &RuntimeClient, modal_interaction: ModalInteraction, ctx: Context, split_custom_id: Vec<&str> ) {
     match split_custom_id[1] {
-        "create" => character_commands::create::handle_modal(modal_interaction, ctx, split_custom_id).await,
+        "create" => character_commands::create::handle_modal(runtime_client, modal_interaction, ctx, split_custom_id).await,
+        "review" => character_commands::review::handle_modal(runtime_client, modal_interaction, ctx, split_custom_id).await,
         unknown_modal => panic!("Unknown modal: `{unknown_modal}`")
     }
 }
@@ -0,0 +1,78 @@
+use crate::{
+    runtime::{
+        commands::{
+            admin::find_class, query_error_embed, resolve_user_character, respond_with_embed, string_option,
+            sub_command_options
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts::{self, character_classes::MulticlassOutcome}
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let database_conn_pool = &runtime_client.database_connection;
+    let invoker_id = interaction_data.user.id.get();
+
+    let sub_options = sub_command_options(&interaction_data);
+    let character_option = string_option(&sub_options, "character")
+        .expect("`character` is a required option");
+    let class_option = string_option(&sub_options, "class")
+        .expect("`class` is a required option");
+
+    let response_embed = 'response_embed: {
+
+        let Some((character_id, character_name)) = resolve_user_character(&ctx, invoker_id, character_option).await else {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "You don't have a character called `{character_option}`" ))
+                .colour(ColourCode::Info.to_embed_colour());
+        };
+
+        let class = match sql_scripts::character_classes::get_classes(database_conn_pool).await {
+            Ok(classes) => find_class(classes, class_option),
+            Err(query_err) => break 'response_embed query_error_embed(
+                "character::class::add::get_classes", "fetch the classes", &query_err
+            )
+        };
+        let Some(class) = class else {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "There is no class called `{class_option}`" ))
+                .colour(ColourCode::Info.to_embed_colour());
+        };
+
+        match sql_scripts::character_classes::multiclass(database_conn_pool, &runtime_client.multiclassing, character_id, class.id).await {
+            Ok(MulticlassOutcome::Added(stat_points_gained)) => {
+                let mut added_embed = CreateEmbed::new()
+                    .title(format!( "{character_name} is now also a {}", class.name ))
+                    .description("The class starts at level 1, taken from their primary class")
+                    .colour(ColourCode::Success.to_embed_colour());
+                if stat_points_gained > 0 {
+                    added_embed = added_embed.field("Stat points gained", stat_points_gained.to_string(), true);
+                }
+                added_embed
+            },
+            Ok(MulticlassOutcome::NoLevelToSpare) => CreateEmbed::new()
+                .title(format!( "{character_name}'s primary class has no level to spare" ))
+                .description("Pick a primary class above level 1 with `/character class primary`")
+                .colour(ColourCode::Info.to_embed_colour()),
+            Ok(MulticlassOutcome::NotAllowed(reason)) => CreateEmbed::new()
+                .title(format!( "{character_name} can't become a {}", class.name ))
+                .description(reason)
+                .colour(ColourCode::Info.to_embed_colour()),
+            Err(query_err) => query_error_embed("character::class::add::multiclass", "add the class", &query_err)
+        }
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, true, "character::class::add").await;
+}
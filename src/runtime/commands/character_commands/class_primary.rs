@@ -0,0 +1,68 @@
+use crate::{
+    runtime::{
+        commands::{
+            admin::find_class, query_error_embed, resolve_user_character, respond_with_embed, string_option,
+            sub_command_options
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let database_conn_pool = &runtime_client.database_connection;
+    let invoker_id = interaction_data.user.id.get();
+
+    let sub_options = sub_command_options(&interaction_data);
+    let character_option = string_option(&sub_options, "character")
+        .expect("`character` is a required option");
+    let class_option = string_option(&sub_options, "class")
+        .expect("`class` is a required option");
+
+    let response_embed = 'response_embed: {
+
+        let Some((character_id, character_name)) = resolve_user_character(&ctx, invoker_id, character_option).await else {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "You don't have a character called `{character_option}`" ))
+                .colour(ColourCode::Info.to_embed_colour());
+        };
+
+        let class = match sql_scripts::character_classes::get_character_classes(database_conn_pool, character_id).await {
+            Ok(classes) => find_class(classes, class_option),
+            Err(query_err) => break 'response_embed query_error_embed(
+                "character::class::primary::get_character_classes", "check the character's classes", &query_err
+            )
+        };
+        let Some(class) = class else {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "{character_name} isn't a `{class_option}`" ))
+                .description("Take up the class with `/character class add` first")
+                .colour(ColourCode::Info.to_embed_colour());
+        };
+
+        match sql_scripts::character_classes::set_primary_class(database_conn_pool, character_id, class.id).await {
+            Ok(true) => CreateEmbed::new()
+                .title(format!( "{character_name} is now foremost a {}", class.name ))
+                .description("The levels they gain go to this class, which also sets their hit points")
+                .colour(ColourCode::Success.to_embed_colour()),
+            Ok(false) => CreateEmbed::new()
+                .title(format!( "{character_name} stopped being a {} in the meantime", class.name ))
+                .colour(ColourCode::Info.to_embed_colour()),
+            Err(query_err) => query_error_embed("character::class::primary::set_primary_class", "change the primary class", &query_err)
+        }
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, true, "character::class::primary").await;
+}
@@ -4,11 +4,17 @@
 // --== MODULE IMPORTS ==-- //
 use crate::{
     runtime::{
-        context_keys, runtime_client::RuntimeClient, sql_scripts
+        commands::{
+            character_commands::review, query_error_embed, respond_with_embed, string_option, sub_command_options,
+            template::find_template, truncated
+        },
+        context_keys, preconditions::{self, Precondition},
+        runtime_client::RuntimeClient, sql_scripts::{self, models::Species}
     },
     utils::misc::{
         colour_codes::ColourCode, 
-        logging::create_log_message
+        logging::create_log_message,
+        wizard::{StageKind, WizardField}
     },
 };
 // ==--
@@ -17,7 +23,6 @@ use crate::{
 // --== CRATE IMPORTS ==-- //
 
     // STD & core
-        use core::panic;
         use std::collections::HashMap;
 
     // SERENITY
@@ -25,9 +30,13 @@ use crate::{
             builder::{
                 CreateActionRow, CreateButton, CreateEmbed, CreateEmbedFooter,
                 CreateInputText, CreateInteractionResponse, CreateInteractionResponseMessage,
-                CreateModal, EditMessage
-            }, client::Context, model::application::{
-                ActionRowComponent, ButtonStyle, CommandInteraction, ComponentInteraction, InputTextStyle, ModalInteraction
+                CreateModal, CreateSelectMenu, CreateSelectMenuKind, CreateSelectMenuOption
+            }, client::Context, model::{
+                application::{
+                    ActionRowComponent, ButtonStyle, CommandInteraction, ComponentInteraction, ComponentInteractionDataKind,
+                    InputTextStyle, ModalInteraction
+                },
+                channel::MessageFlags
             }
         };
 // ==--
@@ -41,16 +50,21 @@ use crate::{
         sub_command: &'a str,
         label: &'a str,
         user_id: u64,
-        stage: u8
+        stage: u8,
+        /// The template ID on `start`, the option's position on `choose`, the class's ID on `class`,
+        /// the stage to change on `edit`.
+        /// Select menus carry theirs in the interaction instead
+        argument: Option<&'a str>
     }
     impl ParsedComponentCustomId<'_> {
-        pub fn from_vec(split_custom_id: Vec<&str>) -> Result<ParsedComponentCustomId, &'static str> {
+        pub fn from_vec(split_custom_id: Vec<&str>) -> Result<ParsedComponentCustomId<'_>, &'static str> {
             Ok(ParsedComponentCustomId {
                 command:     split_custom_id.first().ok_or("`command` field missing")?,
                 sub_command: split_custom_id.get(1).ok_or("`sub_command` field missing")?,
                 label:       split_custom_id.get(2).ok_or("`label` field missing")?,
                 user_id:     split_custom_id.get(3).ok_or("`user_id` field missing")?.parse().or(Err("`couldn't parse `user_id`"))?,
-                stage:       split_custom_id.get(4).ok_or("`stage` field missing")?.parse().or(Err("couldn't parse `stage`"))?
+                stage:       split_custom_id.get(4).ok_or("`stage` field missing")?.parse().or(Err("couldn't parse `stage`"))?,
+                argument:    split_custom_id.get(5).copied()
             })
         }
     }
@@ -63,7 +77,7 @@ use crate::{
         stage: u8
     }
     impl ParsedModalCustomId<'_> {
-        pub fn from_vec(split_custom_id: Vec<&str>) -> Result<ParsedModalCustomId, &'static str> {
+        pub fn from_vec(split_custom_id: Vec<&str>) -> Result<ParsedModalCustomId<'_>, &'static str> {
             Ok(ParsedModalCustomId { 
                 command:     split_custom_id.first().ok_or("`command` field missing")?,
                 sub_command: split_custom_id.get(1).ok_or("`sub_command` field missing")?,
@@ -73,67 +87,473 @@ use crate::{
     }
 // ==--
 
-// --== EMBED AND MODAL STAGE RELATED INFO ==-- //
+// Characters belong to a profile, so one has to exist before we start building
+pub const PRECONDITIONS: &[Precondition] = &[ Precondition::RegisteredProfile ];
 
-    // Here we will store the titles and descriptions for embeds depending on their stage. Along
-    // with a function generatiing modal field name and IDs
+// --== STAGE MESSAGES ==-- //
 
-    const EMBED_STAGE_TITLES: [&str; 6] = [
-        "Welcome to character createion!",
-        "First of all, the basics",
-        "Next up, what does your character have a regard for, anything they abhore?",
-        "Do they wander with others? Any other extra information?",
-        "What drives them? Do they stick with a team? What's their past?",
-        "Lastly, what's their class?"
-    ];
-    const EMBED_STAGE_DESCRIPTIONS: [&str; 6] = [
+    // The welcome message is the same everywhere, the stages after it come from the wizard
+    // definition the bot was started with
+
+    const WELCOME_TITLE: &str = "Welcome to character createion!";
+    const WELCOME_DESCRIPTION: &str =
         "To build your character press  `Start`  when ready, or  `Cancel`  at any time to stop.\n \
         Character creation will occur in stages, with forms popping up whenever you click `Continue` \
-        to prompt for your input. Feel free to go at your own pace.",
-
-        "What's your character's name, species, and appearance?",
-
-        "DESC: Large or small, whether it's a quiet moment with a cup of tea that they like, or their \
-        archnemesis, which they dispise",
-
-        "If your character is a loner, feel free to type in 'N/A'",
-
-        "What are their Motivations? Do they align themselves to anybody/anything? What's their backstory",
-
-        "\
-        - Martial: They fight with weapons lacking magic\
-        - Caster: They prefer the magical arts\
-        - Half-Caster: They're a mix of both
-        "
-    ];
-
-    /// Returns the field (Name, ID) for modals of a given stage, and if it should be a paragraph
-    ///
-    /// # Panics:
-    /// This function will panic if you attempt to get field name and IDs from invalid stages
-    fn get_modal_fields(stage: &u8) -> Vec<(&'static str, &'static str, bool)> {
-
-        match stage {
-            1 => vec![
-                ("Name", "name", false),
-                ("Species", "species", false),
-                ("Appearance", "appearance", true)
-            ],
-            2 => vec![
-                ("Likes", "likes", false),
-                ("Dislikes", "dislikes", false)
-            ],
-            3 => vec![
-                ("Companions", "companions", true),
-                ("Extra", "extra", true)
-            ],
-            4 => vec![
-                ("Motivations", "motivations", false),
-                ("Alignment", "alignment", false),
-                ("Backstory", "backstory", true)
-            ],
-            invalid_stage => panic!("Function recieved invalid stage: `{invalid_stage}`")
+        to prompt for your input. Feel free to go at your own pace.";
+
+    // Buttons are laid out five to a row, and with a row kept for `Cancel` up to twenty fit. More
+    // classes than that are offered through a select menu, which holds up to twenty five
+    const BUTTONS_PER_ROW: usize = 5;
+    const MAX_CLASS_BUTTONS: usize = 20;
+    pub const MAX_CLASSES: usize = 25;
+    // Leaves room in the embed's description for the stage's own
+    const CLASS_LIST_MAX_BYTES: usize = 3000;
+
+    // Registered species share a select menu with the custom option
+    pub const MAX_SPECIES: usize = 24;
+    const CUSTOM_SPECIES_VALUE: &str = "other";
+    const SPECIES_LIST_MAX_BYTES: usize = 3000;
+    // Discord's limit on select menu option descriptions
+    const OPTION_DESCRIPTION_MAX_CHARS: usize = 100;
+
+    // Discord's limits on embeds, with room kept for the review's title and description
+    const EMBED_FIELD_VALUE_MAX_CHARS: usize = 1024;
+    const REVIEW_FIELDS_MAX_CHARS: usize = 5000;
+    // Long answers are cut short in the review, the full answer is in the form when editing
+    const REVIEW_ANSWER_MAX_CHARS: usize = 300;
+    // Discord's limit on button labels
+    const BUTTON_LABEL_MAX_CHARS: usize = 80;
+
+    /// Kept in the building cache once the user has left the review to change an answer, sending
+    /// them back to it after the stage rather than on to the next. The `|` keeps it apart from
+    /// answer ids
+    const REVIEWING_KEY: &str = "|reviewing";
+
+    /// The stage to show once the user is done with this one
+    fn stage_after( runtime_client: &RuntimeClient, stage_number: u8, answers: &HashMap<String, String> ) -> u8 {
+        if answers.contains_key(REVIEWING_KEY) {
+            runtime_client.wizard.stages.len() as u8 + 1
+        } else {
+            stage_number + 1
+        }
+    }
+
+    /// Changes the user's answers, handing back all of them. Gives nothing when the user isn't
+    /// building a character, as happens when the bot restarted since the wizard was sent
+    async fn update_answers( ctx: &Context, invoker_id: u64, update: impl FnOnce(&mut HashMap<String, String>) ) -> Option<HashMap<String, String>> {
+        let mut context_data_writer = ctx.data.write().await;
+        let user_character_building_cache = context_data_writer
+            .get_mut::<context_keys::CharacterBuildingDataKey>()
+            .expect("Key inserted at main.rs")
+            .get_mut(&invoker_id)?;
+
+        update(user_character_building_cache);
+        Some(user_character_building_cache.clone())
+    }
+
+    /// The user's answers so far, see `update_answers`
+    async fn cached_answers( ctx: &Context, invoker_id: u64 ) -> Option<HashMap<String, String>> {
+        let context_data_reader = ctx.data.read().await;
+        context_data_reader.get::<context_keys::CharacterBuildingDataKey>()
+            .expect("Key inserted in main.rs")
+            .get(&invoker_id)
+            .cloned()
+    }
+
+    /// Notes the user has left the review, handing back their answers
+    async fn mark_reviewing( ctx: &Context, invoker_id: u64 ) -> Option<HashMap<String, String>> {
+        update_answers(ctx, invoker_id, |answers| {
+            answers.insert(REVIEWING_KEY.to_string(), String::new());
+        }).await
+    }
+
+    /// Sent in place of a stage when a button or form no longer matches the wizard, as its
+    /// definition changed or the bot restarted since the message was sent. Whatever was answered
+    /// is dropped, so the user can start again
+    async fn out_of_date_message( ctx: &Context, invoker_id: u64 ) -> CreateInteractionResponseMessage {
+        {
+            let mut context_data_writer = ctx.data.write().await;
+            context_data_writer.get_mut::<context_keys::CharacterBuildingDataKey>()
+                .expect("Key inserted in main.rs")
+                .remove(&invoker_id);
+        }
+
+        let dismiss_buttons = CreateActionRow::Buttons(vec![
+            CreateButton::new(format!( "character|create|dismiss|{invoker_id}|0" ))
+                .style(ButtonStyle::Danger)
+                .label("Dismiss")
+        ]);
+        let out_of_date_embed = CreateEmbed::new()
+            .title("This wizard is out of date")
+            .description("Character creation has changed since this message was sent. Use `/character create` to start again")
+            .colour(ColourCode::Info.to_embed_colour());
+
+        CreateInteractionResponseMessage::new()
+            .components(vec![ dismiss_buttons ])
+            .embed(out_of_date_embed)
+    }
+
+    /// Whether an answer names one of the registered species, which are picked from the menu
+    /// rather than typed into the form
+    fn is_registered_species( species_list: &[Species], answer: Option<&String> ) -> bool {
+        answer.is_some_and(|answer| species_list.iter().any(|species| species.name.eq_ignore_ascii_case(answer)))
+    }
+
+    /// What's wrong with the answers to a stage, going by the wizard's rules and the names of the
+    /// user's other characters
+    async fn stage_problems( runtime_client: &RuntimeClient, invoker_id: u64, stage_number: u8, answers: &HashMap<String, String> ) -> Result<Vec<String>, sqlx::Error> {
+        let wizard = &runtime_client.wizard;
+        let mut problems = wizard.check_stage(stage_number, answers);
+
+        if wizard.stage_of("name") == Some(stage_number)
+            && let Some(name) = answers.get("name").filter(|name| !name.is_empty())
+            && sql_scripts::characters::is_name_taken(&runtime_client.database_connection, invoker_id, name).await?
+        {
+            problems.push(format!( "**{}** is already taken by one of your characters", wizard.label_of("name").unwrap_or("Name") ));
+        }
+
+        Ok(problems)
+    }
+
+    /// The first stage whose answers need correcting, with an embed saying why
+    async fn first_stage_with_problems( runtime_client: &RuntimeClient, invoker_id: u64, answers: &HashMap<String, String> ) -> Option<(u8, CreateEmbed)> {
+        for stage_number in 1..=runtime_client.wizard.stages.len() as u8 {
+            match stage_problems(runtime_client, invoker_id, stage_number, answers).await {
+                Ok(problems) if problems.is_empty() => continue,
+                Ok(problems) => return Some(( stage_number, problems_embed(&problems) )),
+                Err(query_err) => return Some((
+                    stage_number,
+                    query_error_embed("character::create::stage_problems", "check your answers", &query_err)
+                ))
+            }
         }
+        None
+    }
+
+    /// Shown under a stage sent back to the user, listing what to correct
+    fn problems_embed( problems: &[String] ) -> CreateEmbed {
+        CreateEmbed::new()
+            .title("Some answers need another look")
+            .description(problems.join("\n"))
+            .colour(ColourCode::Error.to_embed_colour())
+    }
+
+    /// Builds the message for a stage of the wizard, with the buttons that move it along. The
+    /// stage after the last one asks the user to finish. `answers` marks which choice, if any, was
+    /// already made, such as by a template
+    async fn stage_message( runtime_client: &RuntimeClient, invoker_id: u64, stage_number: u8, answers: &HashMap<String, String> ) -> CreateInteractionResponseMessage {
+        let wizard = &runtime_client.wizard;
+
+        let cancel_button = CreateButton::new(format!( "character|create|cancel|{invoker_id}|{stage_number}" ))
+            .style(ButtonStyle::Secondary)
+            .label("Cancel");
+
+        let Some(stage) = wizard.stage(stage_number) else {
+            return review_message(runtime_client, invoker_id, stage_number, answers, cancel_button).await;
+        };
+
+        let mut embed = CreateEmbed::new()
+            .title(&stage.title)
+            .footer(CreateEmbedFooter::new(format!( "{stage_number}/{}", wizard.stages.len() )))
+            .colour(ColourCode::Location.to_embed_colour());
+        if !stage.description.is_empty() {
+            embed = embed.description(&stage.description);
+        }
+
+        let components = match &stage.kind {
+            StageKind::Form(fields) => {
+                let mut rows = vec![];
+
+                // Registered species are picked from a menu, listed with what they change. The
+                // form only asks for the species should the player make up their own
+                if fields.iter().any(|field| field.id == "species") {
+                    let species_list = match sql_scripts::species::get_species_list(&runtime_client.database_connection).await {
+                        Ok(species_list) => species_list,
+                        Err(query_err) => return CreateInteractionResponseMessage::new()
+                            .components(vec![ CreateActionRow::Buttons(vec![ cancel_button ]) ])
+                            .embed(query_error_embed("character::create::get_species_list", "fetch the species", &query_err))
+                    };
+
+                    if !species_list.is_empty() {
+                        let mut species_lines = String::new();
+                        for species in &species_list {
+                            let modifiers = species.describe_modifiers();
+                            let mut species_line = format!( "- **{}**", species.name );
+                            if !modifiers.is_empty() {
+                                species_line.push_str(&format!( " ({modifiers})" ));
+                            }
+                            if !species.description.is_empty() {
+                                species_line.push_str(&format!( ": {}", species.description ));
+                            }
+                            species_line.push('\n');
+                            if species_lines.len() + species_line.len() > SPECIES_LIST_MAX_BYTES {
+                                break;
+                            }
+                            species_lines.push_str(&species_line);
+                        }
+                        embed = embed.description(format!( "{}\n{species_lines}", stage.description ).trim().to_string());
+
+                        let chosen_species = answers.get("species");
+                        let is_custom = chosen_species.is_some_and(|species| !species.is_empty())
+                            && !is_registered_species(&species_list, chosen_species);
+                        let mut species_options: Vec<CreateSelectMenuOption> = species_list.iter()
+                            .take(MAX_SPECIES)
+                            .map(|species| {
+                                let mut species_option = CreateSelectMenuOption::new(&species.name, species.id.to_string())
+                                    .default_selection(chosen_species.is_some_and(|chosen| chosen.eq_ignore_ascii_case(&species.name)));
+                                let modifiers = species.describe_modifiers();
+                                if !modifiers.is_empty() {
+                                    species_option = species_option.description(modifiers.chars().take(OPTION_DESCRIPTION_MAX_CHARS).collect::<String>());
+                                }
+                                species_option
+                            })
+                            .collect();
+                        species_options.push(
+                            CreateSelectMenuOption::new("Other (custom)", CUSTOM_SPECIES_VALUE)
+                                .description("Type in a species of your own in the form")
+                                .default_selection(is_custom)
+                        );
+
+                        rows.push(CreateActionRow::SelectMenu(
+                            CreateSelectMenu::new(
+                                format!( "character|create|species|{invoker_id}|{stage_number}" ),
+                                CreateSelectMenuKind::String { options: species_options }
+                            )
+                                .placeholder("Pick a species")
+                        ));
+                    }
+                }
+
+                rows.push(CreateActionRow::Buttons(vec![
+                    CreateButton::new(format!( "character|create|continue|{invoker_id}|{stage_number}" ))
+                        .style(ButtonStyle::Primary)
+                        .label("Continue"),
+                    cancel_button
+                ]));
+                rows
+            },
+
+            // Options are pressed by their position, keeping the custom IDs short whatever their
+            // values are. The option already chosen stands out from the rest
+            StageKind::Choice { id, options } => {
+                let option_buttons: Vec<CreateButton> = options.iter()
+                    .enumerate()
+                    .map(|(option_idx, option)| {
+                        let style = if answers.get(id) == Some(&option.value) { ButtonStyle::Success } else { ButtonStyle::Primary };
+                        CreateButton::new(format!( "character|create|choose|{invoker_id}|{stage_number}|{option_idx}" ))
+                            .style(style)
+                            .label(&option.label)
+                    })
+                    .collect();
+
+                let mut rows: Vec<CreateActionRow> = option_buttons.chunks(BUTTONS_PER_ROW)
+                    .map(|row_buttons| CreateActionRow::Buttons(row_buttons.to_vec()))
+                    .collect();
+                rows.push(CreateActionRow::Buttons(vec![ cancel_button ]));
+                rows
+            },
+
+            // The classes are whichever staff have set up, each listed with its description
+            StageKind::Class => {
+                let classes = match sql_scripts::character_classes::get_classes(&runtime_client.database_connection).await {
+                    Ok(classes) => classes,
+                    Err(query_err) => return CreateInteractionResponseMessage::new()
+                        .components(vec![ CreateActionRow::Buttons(vec![ cancel_button ]) ])
+                        .embed(query_error_embed("character::create::get_classes", "fetch the classes", &query_err))
+                };
+
+                let mut class_list = String::new();
+                for class in &classes {
+                    let class_line = if class.description.is_empty() {
+                        format!( "- **{}**\n", class.name )
+                    } else {
+                        format!( "- **{}**: {}\n", class.name, class.description )
+                    };
+                    if class_list.len() + class_line.len() > CLASS_LIST_MAX_BYTES {
+                        break;
+                    }
+                    class_list.push_str(&class_line);
+                }
+                embed = embed.description(format!( "{}\n{class_list}", stage.description ).trim().to_string());
+
+                let chosen_class = answers.get("class");
+                let mut rows: Vec<CreateActionRow> = if classes.len() <= MAX_CLASS_BUTTONS {
+                    let class_buttons: Vec<CreateButton> = classes.iter()
+                        .map(|class| {
+                            let style = if chosen_class == Some(&class.id.to_string()) { ButtonStyle::Success } else { ButtonStyle::Primary };
+                            CreateButton::new(format!( "character|create|class|{invoker_id}|{stage_number}|{}", class.id ))
+                                .style(style)
+                                .label(&class.name)
+                        })
+                        .collect();
+
+                    class_buttons.chunks(BUTTONS_PER_ROW)
+                        .map(|row_buttons| CreateActionRow::Buttons(row_buttons.to_vec()))
+                        .collect()
+                } else {
+                    let class_options = classes.iter()
+                        .take(MAX_CLASSES)
+                        .map(|class| {
+                            CreateSelectMenuOption::new(&class.name, class.id.to_string())
+                                .default_selection(chosen_class == Some(&class.id.to_string()))
+                        })
+                        .collect();
+
+                    vec![
+                        CreateActionRow::SelectMenu(
+                            CreateSelectMenu::new(
+                                format!( "character|create|class|{invoker_id}|{stage_number}" ),
+                                CreateSelectMenuKind::String { options: class_options }
+                            )
+                                .placeholder("Pick a class")
+                        )
+                    ]
+                };
+                rows.push(CreateActionRow::Buttons(vec![ cancel_button ]));
+                rows
+            }
+        };
+
+        CreateInteractionResponseMessage::new()
+            .components(components)
+            .embed(embed)
+    }
+
+    /// Builds the review shown after the last stage, summing up every answer with a button to
+    /// change each stage's. Nothing is saved until the user confirms
+    async fn review_message( runtime_client: &RuntimeClient, invoker_id: u64, review_stage: u8, answers: &HashMap<String, String>, cancel_button: CreateButton ) -> CreateInteractionResponseMessage {
+        let wizard = &runtime_client.wizard;
+
+        let class_name = match answers.get("class").and_then(|class_id| class_id.parse::<u64>().ok()) {
+            Some(class_id) => match sql_scripts::character_classes::get_class(&runtime_client.database_connection, class_id).await {
+                Ok(class) => class.map(|class| class.name),
+                Err(query_err) => return CreateInteractionResponseMessage::new()
+                    .components(vec![ CreateActionRow::Buttons(vec![ cancel_button ]) ])
+                    .embed(query_error_embed("character::create::get_class", "fetch your class", &query_err))
+            },
+            None => None
+        };
+
+        let mut embed = CreateEmbed::new()
+            .title(format!( "Review {}", answers.get("name").map_or("your character", String::as_str) ))
+            .footer(CreateEmbedFooter::new("Review"))
+            .colour(ColourCode::Location.to_embed_colour());
+        let mut description = String::from(
+            "Check your answers below. Press a stage's button to change its answers, `Back` to return to the last stage, \
+            or `Confirm` once you're happy with your character."
+        );
+
+        let mut fields_chars = 0;
+        for stage in &wizard.stages {
+            let answer_lines: Vec<String> = match &stage.kind {
+                StageKind::Form(fields) => fields.iter()
+                    .map(|field| {
+                        let answer = answers.get(&field.id)
+                            .filter(|answer| !answer.is_empty())
+                            .map_or(String::from("*Left blank*"), |answer| truncated(answer, REVIEW_ANSWER_MAX_CHARS));
+                        format!( "**{}**: {answer}", field.label )
+                    })
+                    .collect(),
+                StageKind::Choice { id, options } => {
+                    let chosen = answers.get(id).map(|value| {
+                        options.iter()
+                            .find(|option| &option.value == value)
+                            .map_or(value.as_str(), |option| option.label.as_str())
+                    });
+                    vec![ chosen.unwrap_or("*Not chosen*").to_string() ]
+                },
+                StageKind::Class => vec![ class_name.clone().unwrap_or(String::from("*Not chosen*")) ]
+            };
+
+            let field_value = truncated(&answer_lines.join("\n"), EMBED_FIELD_VALUE_MAX_CHARS);
+            fields_chars += stage.title.chars().count() + field_value.chars().count();
+            if fields_chars > REVIEW_FIELDS_MAX_CHARS {
+                description.push_str("\n\n*Not every answer fits here, press a stage's button to see the rest.*");
+                break;
+            }
+            embed = embed.field(&stage.title, field_value, false);
+        }
+        embed = embed.description(description);
+
+        // Up to twenty stages fit, five to a row, with a row kept for the buttons moving on
+        let edit_buttons: Vec<CreateButton> = wizard.stages.iter()
+            .enumerate()
+            .map(|(stage_idx, stage)| {
+                CreateButton::new(format!( "character|create|edit|{invoker_id}|{review_stage}|{}", stage_idx + 1 ))
+                    .style(ButtonStyle::Secondary)
+                    .label(truncated(&stage.title, BUTTON_LABEL_MAX_CHARS))
+            })
+            .collect();
+        let mut rows: Vec<CreateActionRow> = edit_buttons.chunks(BUTTONS_PER_ROW)
+            .map(|row_buttons| CreateActionRow::Buttons(row_buttons.to_vec()))
+            .collect();
+        rows.push(CreateActionRow::Buttons(vec![
+            CreateButton::new(format!( "character|create|finish|{invoker_id}|{review_stage}" ))
+                .style(ButtonStyle::Success)
+                .label("Confirm"),
+            CreateButton::new(format!( "character|create|back|{invoker_id}|{review_stage}" ))
+                .style(ButtonStyle::Primary)
+                .label("Back"),
+            cancel_button
+        ]));
+
+        CreateInteractionResponseMessage::new()
+            .components(rows)
+            .embed(embed)
+    }
+
+    /// Builds the form of a stage, its inputs filled in with the answers given so far, such as
+    /// those from a template. Gives nothing when the species was the form's only input and was
+    /// picked from the menu
+    async fn stage_modal( runtime_client: &RuntimeClient, stage_number: u8, fields: &[WizardField], answers: &HashMap<String, String> ) -> Option<CreateModal> {
+        // A species picked from the menu isn't asked for again
+        let species_list = if fields.iter().any(|field| field.id == "species") {
+            sql_scripts::species::get_species_list(&runtime_client.database_connection)
+                .await
+                .unwrap_or_default()
+        } else {
+            vec![]
+        };
+        let skips_species = is_registered_species(&species_list, answers.get("species"));
+
+        let mut text_fields = vec![];
+        for field in fields {
+
+            if field.id == "species" && skips_species {
+                continue;
+            }
+
+            let style = if field.is_paragraph {
+                InputTextStyle::Paragraph
+            } else {
+                InputTextStyle::Short
+            };
+
+            // Discord enforces the lengths itself, the answers are still checked once sent as
+            // they get trimmed
+            let mut input_text = CreateInputText::new(style, &field.label, &field.id)
+                .required(field.required)
+                .max_length(field.length_limit());
+            if let Some(min_length) = field.min_length {
+                input_text = input_text.min_length(min_length);
+            }
+            // Discord refuses defaults longer than the input allows, which a template's
+            // answers can be
+            if let Some(existing_answer) = answers.get(&field.id) {
+                let max_chars = usize::from(field.length_limit());
+                input_text = input_text.value(existing_answer.chars().take(max_chars).collect::<String>());
+            }
+
+            text_fields.push( CreateActionRow::InputText(input_text) )
+        }
+
+        if text_fields.is_empty() {
+            return None;
+        }
+        Some(
+            CreateModal::new(format!( "character|create|{stage_number}" ), "Character Building")
+                .components(text_fields)
+        )
     }
 // ==--
 
@@ -152,20 +572,26 @@ use crate::{
     a 'starting' message which will quickly explain character creation with 'Start' and 'Cancel' buttons. The 'Cancel'
     button when clicked at any stage will clear the cache. 'Start' will move to the next 'stage'.
     
-    In stages 1..=4, the message that the user sees, later simply 'message', will contain two buttons 'Continue'
-    and 'Cancel'. The latter will act the same, but 'Continue' will spawn a new modal. That modal will inherit the
-    stage from the message it is attached to. That modal when recieved will send the next stage
+    The stages that follow are laid out by the wizard definition, see `src/data/wizard.toml`. In form stages the
+    message that the user sees, later simply 'message', will contain two buttons 'Continue' and 'Cancel'. The
+    latter will act the same, but 'Continue' will spawn a new modal. That modal will inherit the stage from the
+    message it is attached to. That modal when recieved will send the next stage. Choice stages instead show a
+    button for each option, and pressing one sends the next stage. Forms asking for the species also offer the
+    registered species in a select menu, the form then only asking for one of the player's own making
+
+    The review after the last stage lists every answer. Each stage has a button there to change its answers, a form
+    opening filled in with them, after which the user is brought back to the review rather than the next stage.
+    `Back` returns to the last stage the same way. The character is only inserted once `Confirm` is pressed
+
+    Buttons and forms outlive the wizard they were sent with, the definition may change or the bot restart while a
+    message is open. Those that no longer match it, or whose user has no answers cached, tell the user to start again
 
     
 
     Stages:
-    [0] Start
-    [1] Name, Species, Appearance
-    [2] Likes, Dislikes
-    [3] Companions, Extra
-    [4] Motivations, Alignment, Backstoty
-    [5] Class
-    [6] Confirmation
+    [0]          Start
+    [1..=N]      The wizard's stages
+    [N+1]        Review, summing up the answers before anything is saved
 
 
 */
@@ -183,17 +609,61 @@ use crate::{
 //                                      //
 // --== HANDLE COMMAND INTERACTION ==-- //
 //                                      //
-pub async fn run( _runtime_client: &RuntimeClient, ctx: Context, interaction_data: CommandInteraction ) {
+pub async fn run( runtime_client: &RuntimeClient, ctx: Context, interaction_data: CommandInteraction ) {
     let invoker_id = interaction_data.user.id.get();
 
+    let Some(precondition_notices) = preconditions::enforce(runtime_client, &ctx, &interaction_data, PRECONDITIONS).await else {
+        return;
+    };
+
+    // Users may prefer to build their characters out of sight of everyone else in the channel.
+    // Should their profile not be readable, fall back to a public wizard
+    let is_wizard_ephemeral = sql_scripts::discord_users::get_by_user_id(&runtime_client.database_connection, invoker_id)
+        .await
+        .ok()
+        .flatten()
+        .is_some_and(|discord_user| discord_user.preferences.ephemeral_wizard);
+
+    // Should a template have been picked, it is carried along on the start button so that its
+    // answers can be filled in once building starts
+    let sub_options = sub_command_options(&interaction_data);
+    let template = match string_option(&sub_options, "template") {
+        Some(template_option) => {
+            let templates = match interaction_data.guild_id {
+                Some(guild_id) => sql_scripts::templates::get_templates(&runtime_client.database_connection, guild_id.get()).await,
+                None => Ok(vec![])
+            };
+            let found_template = match templates {
+                Ok(templates) => match find_template(templates, template_option) {
+                    Some(template) => Ok(template),
+                    None => Err(CreateEmbed::new()
+                        .title(format!( "There is no template called `{template_option}`" ))
+                        .description("See which are on offer with `/template list`")
+                        .colour(ColourCode::Info.to_embed_colour()))
+                },
+                Err(query_err) => Err(query_error_embed("character::create::get_templates", "fetch the templates", &query_err))
+            };
+            match found_template {
+                Ok(template) => Some(template),
+                Err(refusal_embed) => {
+                    respond_with_embed(&ctx, &interaction_data, refusal_embed, true, "character::create").await;
+                    return;
+                }
+            }
+        },
+        None => None
+    };
+    let template_segment = template.as_ref().map_or(String::new(), |template| format!( "|{}", template.id ));
+
 
 
     // --== MESGAE BUILDING ==-- //
     
         // First stage is creating the buttons we will add, each will have a specific format:
                /*   command|sub_command|button_label|invoker_id|stage    */
+        // The start button may also carry a template ID as a sixth field
         let start_buttons = CreateActionRow::Buttons(vec![
-            CreateButton::new(format!( "character|create|start|{invoker_id}|0" ))
+            CreateButton::new(format!( "character|create|start|{invoker_id}|0{template_segment}" ))
                 .style(ButtonStyle::Primary)
                 .label("Start!"),
 
@@ -204,16 +674,24 @@ pub async fn run( _runtime_client: &RuntimeClient, ctx: Context, interaction_dat
         ]);
 
         // Next the embed
-        let start_embed = CreateEmbed::new()
-            .title( EMBED_STAGE_TITLES[0] )
-            .description( EMBED_STAGE_DESCRIPTIONS[0] )
+        let mut start_embed = CreateEmbed::new()
+            .title( WELCOME_TITLE )
+            .description( WELCOME_DESCRIPTION )
             .colour(ColourCode::Location.to_embed_colour());  // For a nice purple colour
+        if let Some(template) = &template {
+            start_embed = start_embed.field(
+                "Template",
+                format!( "Starting out as **{}**, its answers will be filled in for you to change", template.name ),
+                false
+            );
+        }
         
         // And connect the two into a new `ResponseMessage` struct
         let start_message = CreateInteractionResponseMessage::new()
             .components(vec![ start_buttons ])  // It `component` is it's own row of items, as we
-            .embed(start_embed);                // only need the one row, a single element vec will
-                                                // do
+            .embeds(precondition_notices)       // only need the one row, a single element vec will
+            .add_embed(start_embed)             // do
+            .ephemeral(is_wizard_ephemeral);
     // ==--
 
 
@@ -245,144 +723,72 @@ pub async fn run( _runtime_client: &RuntimeClient, ctx: Context, interaction_dat
 //                                    //
 // --== HANDLE MODAL INTERACTION ==-- //
 //                                    //
-pub async fn handle_modal( modal_interaction: ModalInteraction, ctx: Context, split_custom_id: Vec<&str> ) {
+pub async fn handle_modal( runtime_client: &RuntimeClient, modal_interaction: ModalInteraction, ctx: Context, split_custom_id: Vec<&str> ) {
     let invoker_id = modal_interaction.user.id.get();
     let modal_id = ParsedModalCustomId::from_vec(split_custom_id)
         .expect("Modal should have a correctly formed ID");
 
 
 
-    // First part is loading the recieved fields' data into the cache
-    {
-        let mut context_data_writer = ctx.data.write().await;
-        let character_building_cache = context_data_writer.get_mut::<context_keys::CharacterBuildingDataKey>()
-            .expect("CharacterBuildingDataKey should be inserted in main.rs");
-
-        let user_character_building_cache = character_building_cache.get_mut(&invoker_id)
-            .expect(
-                "We can only read user data from a modal if they've pressed the button that gets them a modal \
-                and this can only be done if they've started building the character, which they have to to get here"
-            );
-
-        // Get inputed data from the modal in the form of components
-        //
-        // Keep in mind that each text field takes up one entire component, so when iterating over
-        // them we just have to index the 0th element
-        let form_fields = &modal_interaction.data.components;
-        for field_action_row in form_fields {
-            
-            // We need to unpack the component
-            let ActionRowComponent::InputText(ref attribute) = field_action_row.components[0] else {
-                // This should always be a input text, but to keep the compiler happy:
-                continue  // TODO: Log this
-            };
-
-            // If for some unexpected reason the user wasn't forced to input text for this field,
-            // skip it
-            if attribute.value.is_none() {
-                continue;  // TODO: Log this
-            }
-
-            user_character_building_cache.0.insert(
-                attribute.custom_id.clone(),
-                attribute.value.clone().unwrap()
-            );
-        }
-
-    }  // context_data_writer lock
-
-    let next_message = match &modal_id.stage {
-        1..=3 => {
-
-            let next_embed: usize = (modal_id.stage+1).into();
-
-            let buttons = CreateActionRow::Buttons(vec![
-                CreateButton::new(format!( "character|create|continue|{invoker_id}|{next_embed}" ))
-                    .style(ButtonStyle::Primary)
-                    .label("Continue"),
-
-                CreateButton::new(format!( "character|create|cancel|{invoker_id}|{next_embed}" ))
-                    .style(ButtonStyle::Secondary)
-                    .label("Cancel")
-            ]);
-
-            let embed = CreateEmbed::new()
-                .title( EMBED_STAGE_TITLES[next_embed] )
-                .description( EMBED_STAGE_DESCRIPTIONS[next_embed] )
-                .footer(CreateEmbedFooter::new(format!( "{next_embed}/5" )))
-                .colour(ColourCode::Location.to_embed_colour());
-
-            EditMessage::new()
-                .components(vec![ buttons ])
-                .embed(embed)
-
-        },
-
-        4 => {  // Next message is the one that asks the user for their character's class
-
-            let buttons = CreateActionRow::Buttons(vec![
-                CreateButton::new(format!( "character|create|martial|{invoker_id}|5" ))
-                    .style(ButtonStyle::Primary)
-                    .label("Martial"),
+    // Get inputed data from the modal in the form of components
+    //
+    // Keep in mind that each text field takes up one entire component, so when iterating over
+    // them we just have to index the 0th element
+    let mut submitted_answers = vec![];
+    for field_action_row in &modal_interaction.data.components {
+
+        // We need to unpack the component
+        let Some(ActionRowComponent::InputText(attribute)) = field_action_row.components.first() else {
+            // This should always be a input text, but to keep the compiler happy:
+            continue  // TODO: Log this
+        };
 
-                CreateButton::new(format!( "character|create|half-caster|{invoker_id}|5" ))
-                    .style(ButtonStyle::Primary)
-                    .label("Half-Caster"),
+        // If for some unexpected reason the user wasn't forced to input text for this field,
+        // skip it
+        let Some(value) = &attribute.value else {
+            continue;  // TODO: Log this
+        };
 
-                CreateButton::new(format!( "character|create|caster|{invoker_id}|5" ))
-                    .style(ButtonStyle::Primary)
-                    .label("Caster"),
+        submitted_answers.push(( attribute.custom_id.clone(), value.trim().to_string() ));
+    }
 
-                CreateButton::new(format!( "character|create|cancel|{invoker_id}|5" ))
-                    .style(ButtonStyle::Secondary)
-                    .label("Cancel")
-            ]);
+    // A form opened before the wizard changed may ask for answers its stage no longer does
+    let matches_stage = match runtime_client.wizard.stage(modal_id.stage).map(|stage| &stage.kind) {
+        Some(StageKind::Form(fields)) => submitted_answers.iter()
+            .all(|(field_id, _)| fields.iter().any(|field| &field.id == field_id)),
+        _ => false
+    };
 
-            let embed = CreateEmbed::new()
-                .title( EMBED_STAGE_TITLES[5] )
-                .description( EMBED_STAGE_DESCRIPTIONS[5] )
-                .footer(CreateEmbedFooter::new("5/5"))
-                .colour(ColourCode::Location.to_embed_colour());
+    // Next is loading the answers into the cache, keeping a copy of all the answers so far for the
+    // next stage. They are kept even when they break the wizard's rules, so the form can be
+    // filled in with them again to be corrected
+    let answers = if matches_stage {
+        update_answers(&ctx, invoker_id, |answers| answers.extend(submitted_answers)).await
+    } else {
+        None
+    };
 
-            EditMessage::new()
-                .components(vec![ buttons ])
-                .embed(embed)
+    // Answers that break the wizard's rules keep the user on the stage, told what to correct
+    let next_message = match answers {
+        None => out_of_date_message(&ctx, invoker_id).await,
+        Some(answers) => match stage_problems(runtime_client, invoker_id, modal_id.stage, &answers).await {
+            Ok(problems) if problems.is_empty() => {
+                stage_message(runtime_client, invoker_id, stage_after(runtime_client, modal_id.stage, &answers), &answers).await
+            },
+            Ok(problems) => stage_message(runtime_client, invoker_id, modal_id.stage, &answers).await
+                .add_embed(problems_embed(&problems)),
+            Err(query_err) => stage_message(runtime_client, invoker_id, modal_id.stage, &answers).await
+                .add_embed(query_error_embed("character::create::stage_problems", "check your answers", &query_err))
         }
-
-        unknown_stage => panic!("Recieved modal from a stage that shouldn't give a modal, `{unknown_stage}`")  // TODO: Proper log
     };
-    // Next up edit the original message, using the cached `Message` struct
-    {
-        let context_data_read = ctx.data.write().await;
-        let character_building_cache = context_data_read
-            .get::<context_keys::CharacterBuildingDataKey>()
-            .expect("Key should be inserted in main.rs");
-
-        // Get a reference to the `Message`
-        let mut building_message = character_building_cache.get(&invoker_id)
-            .expect(
-                "This code should only be available when the user is building a character, \
-                     therefore in this hashmap"
-            )
-            .1
-            .clone(); // Get the message
-
-        let edit_message = building_message.edit(&ctx.http, next_message);
-        if let Err(why) = edit_message.await  { println!( "{}", create_log_message(
-                format!(
-                    "Failed to edit message: `{}{}{}`",
-                    ColourCode::Info,
-                    why,
-                    ColourCode::Reset
-                ),
-                ColourCode::Caution
-        ) )}
-    }
 
-    let send_acknowledgement = modal_interaction.create_response(&ctx.http, CreateInteractionResponse::Acknowledge);
-    if let Err(why) = send_acknowledgement.await  { println!( "{}", create_log_message(
+    // Next up move the wizard's message to the next stage. As every modal is opened from one of
+    // its buttons, the modal's response can update that message directly, which also works for
+    // ephemeral messages that cannot be edited through the channel
+    let update_message = modal_interaction.create_response(&ctx.http, CreateInteractionResponse::UpdateMessage(next_message));
+    if let Err(why) = update_message.await  { println!( "{}", create_log_message(
             format!(
-                "Failed to send acknowledge: `{}{}{}`",
+                "Failed to edit message: `{}{}{}`",
                 ColourCode::Info,
                 why,
                 ColourCode::Reset
@@ -468,9 +874,22 @@ pub async fn handle_component( runtime_client: &RuntimeClient, component_interac
 
         "dismiss" => {
             // This isn't a direct response as we are deleting the base message. In this case we
-            // don't have a specific response to give so we will early return
-            let delete_message = component_interaction.message.delete(&ctx.http);
-            if let Err(why) = delete_message.await {  println!( "{}", 
+            // don't have a specific response to give so we will early return.
+            //
+            // Ephemeral messages cannot be deleted through the channel, instead we acknowledge the
+            // interaction and delete the message it came from through it
+            let is_message_ephemeral = component_interaction.message.flags
+                .is_some_and(|flags| flags.contains(MessageFlags::EPHEMERAL));
+
+            let delete_message = if is_message_ephemeral {
+                match component_interaction.create_response(&ctx.http, CreateInteractionResponse::Acknowledge).await {
+                    Ok(()) => component_interaction.delete_response(&ctx.http).await,
+                    Err(why) => Err(why)
+                }
+            } else {
+                component_interaction.message.delete(&ctx.http).await
+            };
+            if let Err(why) = delete_message {  println!( "{}", 
                 create_log_message(
                     format!(
                         "Failed to delete message: `{}{}{}`",
@@ -493,10 +912,22 @@ pub async fn handle_component( runtime_client: &RuntimeClient, component_interac
             // check if they're already in the process of building one, and if so, break with an
             // error message
 
+            // Answers from the template the user picked, if any. A template removed since the
+            // command was used simply leaves the wizard blank
+            let template_id = component_id.argument.and_then(|template_id| template_id.parse::<u64>().ok());
+            let template_answers = match (component_interaction.guild_id, template_id) {
+                (Some(guild_id), Some(template_id)) => sql_scripts::templates::get_template(&runtime_client.database_connection, guild_id.get(), template_id)
+                    .await
+                    .ok()
+                    .flatten()
+                    .map_or(vec![], |template| template.details.wizard_fields()),
+                _ => vec![]
+            };
+
             // We need to access data locked behind a mutex here. Just to be safe, it's best
             // practice to do so in a scope in order to drop the lock as soon as possible.
             // We'll do that here
-            {
+            let answers = {
                 let mut context_data_writer = ctx.data.write().await;  // As if we don't break early
                                                                        // we'll be inserting some data
                 let character_building_cache =
@@ -541,140 +972,232 @@ pub async fn handle_component( runtime_client: &RuntimeClient, component_interac
 
                 // Ok, if we've reached this point it means we are good to start building the
                 // user's character. To start lets initialise a cache where we will hold all of
-                // their character's information, along with a clone of the message the start
-                // button is attached to. This is to allow modal interactions to edit the message
-                // to the next stage upon submission
-                character_building_cache.insert(invoker_id,
-                    ( HashMap::new(), *component_interaction.message.clone() )
-                );
+                // their character's information, starting from the template's answers
+                let user_character_building_cache: HashMap<String, String> = template_answers.into_iter()
+                    .map(|(field_id, answer)| (field_id.to_string(), answer))
+                    .collect();
+                character_building_cache.insert(invoker_id, user_character_building_cache.clone());
+                user_character_building_cache
             
-            }  // context_data_writer lock
+            };  // context_data_writer lock
 
 
 
             // Now we must move towards the next stage, in this instance 'Stage 1'
-            let stage_1_buttons = CreateActionRow::Buttons(vec![
-                CreateButton::new(format!( "character|create|continue|{invoker_id}|1" ))
-                    .style(ButtonStyle::Primary)
-                    .label("Continue"),
+            CreateInteractionResponse::UpdateMessage(stage_message(runtime_client, invoker_id, 1, &answers).await)
 
-                CreateButton::new(format!( "character|create|cancel|{invoker_id}|1" ))
-                    .style(ButtonStyle::Secondary)
-                    .label("Cancel")
+        }, // "start"
 
-            ]);
 
-            let stage_1_embed = CreateEmbed::new()
-                .title( EMBED_STAGE_TITLES[1] )
-                .description( EMBED_STAGE_DESCRIPTIONS[1] )
-                .footer(CreateEmbedFooter::new("1/5"))
-                .colour(ColourCode::Location.to_embed_colour());
 
-            CreateInteractionResponse::UpdateMessage(
-                CreateInteractionResponseMessage::new()
-                    .components(vec![ stage_1_buttons ])
-                    .embed(stage_1_embed)
-            )
+        "continue" => 'continue_response: {
 
-        }, // "start"
+            // We need to send a modal, with any answers already in the cache (such as those from a
+            // template) as the inputs' default values
+            let stage_kind = runtime_client.wizard.stage(component_id.stage).map(|stage| &stage.kind);
+            let (Some(existing_answers), Some(StageKind::Form(fields))) = (cached_answers(&ctx, invoker_id).await, stage_kind) else {
+                break 'continue_response CreateInteractionResponse::UpdateMessage(out_of_date_message(&ctx, invoker_id).await);
+            };
 
+            // Nothing is left to ask when the species was the form's only input
+            match stage_modal(runtime_client, component_id.stage, fields, &existing_answers).await {
+                Some(modal) => CreateInteractionResponse::Modal(modal),
+                None => CreateInteractionResponse::UpdateMessage(
+                    stage_message(runtime_client, invoker_id, stage_after(runtime_client, component_id.stage, &existing_answers), &existing_answers).await
+                )
+            }
 
+        }, // "continue"
 
-        "continue" => {
 
-            // We need to send a modal
 
+        "choose" => 'choose_response: {
 
-            let mut text_fields = vec![];
+            // Options are pressed by their position, which may now belong to another option or
+            // none at all should the wizard have changed
+            let chosen = match runtime_client.wizard.stage(component_id.stage).map(|stage| &stage.kind) {
+                Some(StageKind::Choice { id, options }) => component_id.argument
+                    .and_then(|option_idx| option_idx.parse::<usize>().ok())
+                    .and_then(|option_idx| options.get(option_idx))
+                    .map(|option| (id.clone(), option.value.clone())),
+                _ => None
+            };
+            let Some((choice_id, chosen_value)) = chosen else {
+                break 'choose_response CreateInteractionResponse::UpdateMessage(out_of_date_message(&ctx, invoker_id).await);
+            };
 
-            // Vec<(field_name, field_id, is_paragraph)>
-            let field_data_vec = get_modal_fields(&component_id.stage);
+            let Some(answers) = update_answers(&ctx, invoker_id, |answers| { answers.insert(choice_id, chosen_value); }).await else {
+                break 'choose_response CreateInteractionResponse::UpdateMessage(out_of_date_message(&ctx, invoker_id).await);
+            };
 
-            for (field_name, field_id, is_paragraph) in field_data_vec {
+            CreateInteractionResponse::UpdateMessage(
+                stage_message(runtime_client, invoker_id, stage_after(runtime_client, component_id.stage, &answers), &answers).await
+            )
+        }, // "choose"
 
-                let style = if is_paragraph {
-                    InputTextStyle::Paragraph
-                } else {
-                    InputTextStyle::Short
-                };
 
-                text_fields.push( CreateActionRow::InputText(
-                    CreateInputText::new(style, field_name, field_id)
-                        .required(true)
-                ))
+
+        "class" => 'class_response: {
+
+            if !matches!(runtime_client.wizard.stage(component_id.stage).map(|stage| &stage.kind), Some(StageKind::Class)) {
+                break 'class_response CreateInteractionResponse::UpdateMessage(out_of_date_message(&ctx, invoker_id).await);
             }
 
+            // Buttons carry the class's ID, the select menu offered for many classes its value
+            let class_id = match &component_interaction.data.kind {
+                ComponentInteractionDataKind::StringSelect { values } => values.first().map(String::as_str),
+                _ => component_id.argument
+            }
+                .and_then(|class_id| class_id.parse::<u64>().ok());
+
+            // A class removed since the stage was shown can't be picked, instead the stage is
+            // shown again with the classes there are now
+            let class_exists = match class_id {
+                Some(class_id) => matches!(
+                    sql_scripts::character_classes::get_class(&runtime_client.database_connection, class_id).await,
+                    Ok(Some(_))
+                ),
+                None => false
+            };
 
-            let modal = CreateModal::new(format!( "character|create|{}", component_id.stage ), "Character Building")
-                .components(text_fields);
+            let answers = update_answers(&ctx, invoker_id, |answers| {
+                if let (true, Some(class_id)) = (class_exists, class_id) {
+                    answers.insert("class".to_string(), class_id.to_string());
+                }
+            }).await;
+            let Some(answers) = answers else {
+                break 'class_response CreateInteractionResponse::UpdateMessage(out_of_date_message(&ctx, invoker_id).await);
+            };
 
+            let next_stage = if class_exists { stage_after(runtime_client, component_id.stage, &answers) } else { component_id.stage };
+            CreateInteractionResponse::UpdateMessage(
+                stage_message(runtime_client, invoker_id, next_stage, &answers).await
+            )
+        }, // "class"
 
 
-            CreateInteractionResponse::Modal(modal)
 
-        }, // "continue"
+        "species" => 'species_response: {
 
+            let asks_species = matches!(
+                runtime_client.wizard.stage(component_id.stage).map(|stage| &stage.kind),
+                Some(StageKind::Form(fields)) if fields.iter().any(|field| field.id == "species")
+            );
+            if !asks_species {
+                break 'species_response CreateInteractionResponse::UpdateMessage(out_of_date_message(&ctx, invoker_id).await);
+            }
 
+            let picked_value = match &component_interaction.data.kind {
+                ComponentInteractionDataKind::StringSelect { values } => values.first().cloned(),
+                _ => None
+            };
+            let species_list = sql_scripts::species::get_species_list(&runtime_client.database_connection)
+                .await
+                .unwrap_or_default();
+            let picked_species = picked_value.as_deref()
+                .and_then(|species_id| species_id.parse::<u64>().ok())
+                .and_then(|species_id| species_list.iter().find(|species| species.id == species_id));
+
+            // Picking a custom species clears a registered one, so the form asks for it. One
+            // removed since the stage was shown is treated the same
+            let answers = update_answers(&ctx, invoker_id, |answers| {
+                match picked_species {
+                    Some(species) => {
+                        answers.insert("species".to_string(), species.name.clone());
+                    },
+                    None if is_registered_species(&species_list, answers.get("species")) => {
+                        answers.remove("species");
+                    },
+                    None => {}
+                }
+            }).await;
+            let Some(answers) = answers else {
+                break 'species_response CreateInteractionResponse::UpdateMessage(out_of_date_message(&ctx, invoker_id).await);
+            };
 
-        class if ["martial", "half-caster", "caster"].contains(&class) => {
+            CreateInteractionResponse::UpdateMessage(
+                stage_message(runtime_client, invoker_id, component_id.stage, &answers).await
+            )
+        }, // "species"
 
-            {
-                let mut context_data_writer = ctx.data.write().await;
-                let character_building_cache = context_data_writer
-                    .get_mut::<context_keys::CharacterBuildingDataKey>()
-                    .expect("Key inserted at main.rs");
 
-                character_building_cache.get_mut(&invoker_id)
-                    .expect("User is in the process of building their character")
-                    .0
-                    .insert("class".to_string(), class.to_string());
+
+        "edit" => 'edit_response: {
+
+            let edited_stage = component_id.argument
+                .and_then(|stage_number| stage_number.parse::<u8>().ok())
+                .and_then(|stage_number| Some(( stage_number, runtime_client.wizard.stage(stage_number)? )));
+            let (Some((edited_stage, stage)), Some(answers)) = (edited_stage, mark_reviewing(&ctx, invoker_id).await) else {
+                break 'edit_response CreateInteractionResponse::UpdateMessage(out_of_date_message(&ctx, invoker_id).await);
+            };
+
+            // Forms open straight away, unless they come with the species menu which is on the
+            // stage's message. Choices are shown again, the one made standing out
+            let form_fields = match &stage.kind {
+                StageKind::Form(fields) if !fields.iter().any(|field| field.id == "species") => Some(fields),
+                _ => None
+            };
+            let modal = match form_fields {
+                Some(fields) => stage_modal(runtime_client, edited_stage, fields, &answers).await,
+                None => None
+            };
+            match modal {
+                Some(modal) => CreateInteractionResponse::Modal(modal),
+                None => CreateInteractionResponse::UpdateMessage(
+                    stage_message(runtime_client, invoker_id, edited_stage, &answers).await
+                )
             }
+        }, // "edit"
 
-            let buttons = CreateActionRow::Buttons(vec![
-                CreateButton::new(format!( "character|create|finish|{invoker_id}|6" ))
-                    .style(ButtonStyle::Success)
-                    .label("Finish"),
 
-                CreateButton::new(format!( "character|create|cancel|{invoker_id}|6" ))
-                    .style(ButtonStyle::Secondary)
-                    .label("Cancel")
-            ]);
 
-            let embed = CreateEmbed::new()
-                .title("Finish")
-                .description("Finish")
-                .colour(ColourCode::Location.to_embed_colour());
+        "back" => {
+
+            // The stage before the review, which is the last one unless the wizard has changed
+            let last_stage = runtime_client.wizard.stages.len() as u8;
+            let answers = if component_id.stage == last_stage + 1 {
+                mark_reviewing(&ctx, invoker_id).await
+            } else {
+                None
+            };
+            let new_message = match answers {
+                Some(answers) => stage_message(runtime_client, invoker_id, last_stage, &answers).await,
+                None => out_of_date_message(&ctx, invoker_id).await
+            };
+            CreateInteractionResponse::UpdateMessage(new_message)
+        }, // "back"
 
-            CreateInteractionResponse::UpdateMessage( CreateInteractionResponseMessage::new()
-                .components(vec![ buttons ])
-                .embed(embed)
-            )
-        }, // class
 
 
+        "finish" => 'finish: {
 
-        "finish" => {
+            // Answers are checked once more, as they may have been skipped past, or another of the
+            // user's characters may have taken the name since
+            let Some(answers) = cached_answers(&ctx, invoker_id).await else {
+                break 'finish CreateInteractionResponse::UpdateMessage(out_of_date_message(&ctx, invoker_id).await);
+            };
+            if let Some((stage_number, problems)) = first_stage_with_problems(runtime_client, invoker_id, &answers).await {
+                let answers = mark_reviewing(&ctx, invoker_id).await.unwrap_or(answers);
+                break 'finish CreateInteractionResponse::UpdateMessage(
+                    stage_message(runtime_client, invoker_id, stage_number, &answers).await.add_embed(problems)
+                );
+            }
 
             // First we need to cast the cache to a `Character` struct, then use it to insert a new
             // character into the database on the user's behalf
 
             let new_message = {
 
-                let built_character = {
-                    let mut context_data_writer = ctx.data.write().await;
-                    let character_building_cache = context_data_writer
-                        .get_mut::<context_keys::CharacterBuildingDataKey>()
-                        .expect("Key inserted in main.rs");
-
-                    let character_hashmap = &character_building_cache.get_mut(&invoker_id)
-                        .expect("User will be in cache when finalising character creation")
-                        .0;
-
-                    sql_scripts::characters::Character::from_hashmap_cache(character_hashmap)
-                };
+                let built_character = sql_scripts::characters::NewCharacter::from_hashmap_cache(&answers, &runtime_client.wizard.custom_field_ids());
 
                 match built_character {
-                    Ok(character) => {
+                    Ok(mut character) => {
+
+                        // With a review channel configured, staff have to approve the character
+                        // before it can be used
+                        if runtime_client.review_channel_id.is_some() {
+                            character.status = sql_scripts::models::CharacterStatus::Pending;
+                        }
 
                         let insert_character_query = sql_scripts::characters::insert_character(
                             &runtime_client.database_connection,
@@ -682,7 +1205,7 @@ pub async fn handle_component( runtime_client: &RuntimeClient, component_interac
                             character.clone()
                         );
                         match insert_character_query.await {
-                            Ok(()) => {
+                            Ok(new_character_id) => {
 
                                 // Log the success
                                 println!("{}", create_log_message(
@@ -693,7 +1216,7 @@ pub async fn handle_component( runtime_client: &RuntimeClient, component_interac
                                             invoker_id,
                                             ColourCode::Reset,
                                             ColourCode::Info,
-                                            character.name,
+                                            character.details.name,
                                             ColourCode::Reset
                                         ),
                                         ColourCode::Success
@@ -711,44 +1234,36 @@ pub async fn handle_component( runtime_client: &RuntimeClient, component_interac
 
 
 
-                                // Insert into character identifiers cache
+                                // Insert into character identifiers cache, using the ID the insert
+                                // itself handed back so concurrent creations cannot cross over
                                 {
-                                    let new_character_id = sql_scripts::characters::get_largest_character_id(&runtime_client.database_connection)
-                                        .await
-                                        .expect("Getting the ID of the just inserted character. A simple operation that should not fail");
-
                                     let mut data_writer = ctx.data.write().await;
                                     let user_characters = data_writer.get_mut::<context_keys::UserCharactersCache>()
                                         .expect("Key inserted in main.rs");
 
-                                    match user_characters.get_mut(&invoker_id) {
-                                        None => {
-                                            let mut new_characters_identifier_hashmap = HashMap::new();
-                                            new_characters_identifier_hashmap.insert(
-                                                new_character_id,
-                                                character.name.clone()
-                                            );
-
-                                            user_characters.insert(
-                                                invoker_id,
-                                                new_characters_identifier_hashmap
-                                            );
-                                        },
-                                        Some(character_id_name_hashmap) => {
-                                            character_id_name_hashmap.insert(
-                                                new_character_id,
-                                                character.name.clone()
-                                            );
-                                        }
-                                    };
+                                    context_keys::cache_user_character(
+                                        user_characters,
+                                        invoker_id,
+                                        new_character_id,
+                                        character.details.name.clone()
+                                    );
                                 }
 
 
 
-                                let finish_embed = CreateEmbed::new()
-                                    .title(format!( "{} Has been successfully added to your characters!", character.name))
-                                    .description("You may now use them!")
-                                    .colour(ColourCode::Success.to_embed_colour());
+                                let finish_embed = if runtime_client.review_channel_id.is_some() {
+                                    review::post_review_request(runtime_client, &ctx, new_character_id).await;
+
+                                    CreateEmbed::new()
+                                        .title(format!( "{} Has been sent to staff for review!", character.details.name))
+                                        .description("You'll get a DM once they've been approved, and may use them from then on")
+                                        .colour(ColourCode::Success.to_embed_colour())
+                                } else {
+                                    CreateEmbed::new()
+                                        .title(format!( "{} Has been successfully added to your characters!", character.details.name))
+                                        .description("You may now use them!")
+                                        .colour(ColourCode::Success.to_embed_colour())
+                                };
 
                                 let finish_buttons = CreateActionRow::Buttons(vec![
                                     CreateButton::new(format!( "character|create|dismiss|{invoker_id}|7" ))
@@ -805,11 +1320,18 @@ pub async fn handle_component( runtime_client: &RuntimeClient, component_interac
 
 
 
-        unknown_label => panic!(
-            "{}character_create::handle_component{}: Recieved unknown buttom label: {}{unknown_label}{}",
-            ColourCode::Location, ColourCode::Reset,
-            ColourCode::Info,     ColourCode::Reset
-        )
+        // Left by a version of the wizard that had buttons this one doesn't
+        unknown_label => {
+            println!("{}", create_log_message(
+                    format!(
+                        "{}character_create::handle_component{}: Recieved unknown button label: {}{unknown_label}{}",
+                        ColourCode::Location, ColourCode::Reset,
+                        ColourCode::Info,     ColourCode::Reset
+                    ),
+                    ColourCode::Warning
+            ));
+            CreateInteractionResponse::UpdateMessage(out_of_date_message(&ctx, invoker_id).await)
+        }
     }; // let edited_message = match component_id.label {...};
 
 
@@ -0,0 +1,333 @@
+use crate::{
+    runtime::{
+        commands::{
+            self, character_commands::sheet, query_error_embed, string_option, sub_command_options, user_option
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts::{self, character_search::DirectoryFilters}
+    },
+    utils::misc::{colour_codes::ColourCode, logging::create_log_message},
+};
+
+use serenity::{
+    builder::{
+        CreateActionRow, CreateAutocompleteResponse, CreateButton, CreateEmbed, CreateEmbedFooter,
+        CreateInteractionResponse, CreateInteractionResponseMessage, CreateSelectMenu, CreateSelectMenuKind,
+        CreateSelectMenuOption
+    },
+    client::Context,
+    model::application::{ButtonStyle, CommandInteraction, ComponentInteraction, ComponentInteractionDataKind},
+};
+
+// Directory components follow the format:
+//     character|directory|page|page|class_id|owner_id|species    character|directory|open
+// Filters left unset are empty, and the species goes last as it may itself contain `|`. The
+// select menu's values are the IDs of the characters on the page. Whoever presses gets the
+// directory worked out again for themselves, so nobody sees more than their own browsing would.
+// Only the characters of the server's own members are listed
+
+const PAGE_LENGTH: u32 = 10;
+
+// Select menu option labels and descriptions are capped at 100 characters
+const OPTION_TEXT_LIMIT: usize = 100;
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let sub_options = sub_command_options(&interaction_data);
+    let filters = DirectoryFilters {
+        class_id: string_option(&sub_options, "class").and_then(|class_id| class_id.parse::<u64>().ok()),
+        species: string_option(&sub_options, "species")
+            .map(str::trim)
+            .filter(|species| !species.is_empty())
+            .map(String::from),
+        owner_id: user_option(&sub_options, "owner").map(|owner| owner.id.get())
+    };
+
+    let response_message = match interaction_data.guild_id {
+        None => CreateInteractionResponseMessage::new().embed(outside_guild_embed()),
+
+        // The filters ride along in the page buttons' IDs, which are capped at 100 bytes
+        Some(_) if page_custom_id(u32::MAX, &filters).len() > 100 => CreateInteractionResponseMessage::new().embed(
+            CreateEmbed::new()
+                .title("That species is too long to filter by")
+                .colour(ColourCode::Info.to_embed_colour())
+        ),
+        Some(_) if string_option(&sub_options, "class").is_some() && filters.class_id.is_none() => CreateInteractionResponseMessage::new().embed(
+            CreateEmbed::new()
+                .title("Pick a class from the suggestions")
+                .colour(ColourCode::Info.to_embed_colour())
+        ),
+
+        Some(guild_id) => directory_page(runtime_client, interaction_data.user.id.get(), guild_id.get(), &filters, 0).await
+    };
+
+    let response = CreateInteractionResponse::Message(response_message.ephemeral(true));
+    if let Err(response_send_err) = interaction_data.create_response(&ctx.http, response).await {
+        println!( "{}", create_log_message(
+                format!(
+                    "{}character::directory{}: Failed to send response: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    response_send_err,
+                    ColourCode::Reset
+                ),
+                ColourCode::Error
+        ));
+    }
+}
+
+pub async fn handle_component(
+    runtime_client: &RuntimeClient,
+    component_interaction: ComponentInteraction,
+    ctx: Context,
+    split_custom_id: Vec<&str>,
+) {
+    let viewer_id = component_interaction.user.id.get();
+    let guild_id = component_interaction.guild_id.map(|guild_id| guild_id.get());
+
+    let response = match split_custom_id.get(2).copied() {
+        Some("open") => {
+            let character_id = match &component_interaction.data.kind {
+                ComponentInteractionDataKind::StringSelect { values } => values.first().and_then(|value| value.parse::<u64>().ok()),
+                _ => None
+            };
+            let response_embed = match (guild_id, character_id) {
+                (None, _) => outside_guild_embed(),
+                (Some(guild_id), Some(character_id)) => open_sheet(runtime_client, viewer_id, guild_id, character_id).await,
+                (Some(_), None) => CreateEmbed::new()
+                    .title("No character was picked")
+                    .colour(ColourCode::Info.to_embed_colour())
+            };
+
+            CreateInteractionResponse::Message(
+                CreateInteractionResponseMessage::new()
+                    .embed(response_embed)
+                    .ephemeral(true)
+            )
+        },
+
+        _ => {
+            let page = split_custom_id.get(3).and_then(|page| page.parse::<u32>().ok());
+            let filters = match (split_custom_id.get(4), split_custom_id.get(5), split_custom_id.get(6..)) {
+                (Some(class_id), Some(owner_id), Some(species)) => Some(DirectoryFilters {
+                    class_id: class_id.parse::<u64>().ok(),
+                    owner_id: owner_id.parse::<u64>().ok(),
+                    species: Some(species.join("|")).filter(|species| !species.is_empty())
+                }),
+                _ => None
+            };
+
+            let response_message = match (guild_id, page, filters) {
+                (Some(guild_id), Some(page), Some(filters)) => directory_page(runtime_client, viewer_id, guild_id, &filters, page).await,
+                _ => CreateInteractionResponseMessage::new()
+                    .embed(
+                        CreateEmbed::new()
+                            .title("This directory can't be paged through anymore")
+                            .colour(ColourCode::Info.to_embed_colour())
+                    )
+                    .components(vec![])
+            };
+
+            CreateInteractionResponse::UpdateMessage(response_message)
+        }
+    };
+
+    if let Err(response_send_err) = component_interaction.create_response(&ctx.http, response).await {
+        println!( "{}", create_log_message(
+                format!(
+                    "{}character::directory::handle_component{}: Failed to send response: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    response_send_err,
+                    ColourCode::Reset
+                ),
+                ColourCode::Error
+        ));
+    }
+}
+
+pub async fn autocomplete( runtime_client: &RuntimeClient, ctx: &Context, interaction_data: &CommandInteraction, focused_option: &str ) {
+    if focused_option == "class" {
+        return commands::admin::autocomplete_classes(runtime_client, ctx, interaction_data).await;
+    }
+
+    let database_conn_pool = &runtime_client.database_connection;
+    let typed_so_far = commands::typed_so_far(interaction_data);
+
+    let mut autocomplete_response = CreateAutocompleteResponse::new();
+    let species = match interaction_data.guild_id {
+        Some(guild_id) => sql_scripts::character_search::get_visible_species(database_conn_pool, interaction_data.user.id.get(), guild_id.get(), &typed_so_far, 25)
+            .await
+            .unwrap_or_default(),
+        None => vec![]
+    };
+    for species in species {
+        autocomplete_response = autocomplete_response.add_string_choice(species.clone(), species);
+    }
+
+    let send_choices = interaction_data.create_response(&ctx.http, CreateInteractionResponse::Autocomplete(autocomplete_response));
+    if let Err(why) = send_choices.await {
+        println!( "{}", create_log_message(
+                format!(
+                    "Failed to send autocomplete choices: `{}{}{}`",
+                    ColourCode::Info,
+                    why,
+                    ColourCode::Reset
+                ),
+                ColourCode::Caution
+        ));
+    }
+}
+
+
+
+// --== DIRECTORY PAGE ==-- //
+
+    fn page_custom_id( page: u32, filters: &DirectoryFilters ) -> String {
+        format!(
+            "character|directory|page|{page}|{}|{}|{}",
+            filters.class_id.map(|class_id| class_id.to_string()).unwrap_or_default(),
+            filters.owner_id.map(|owner_id| owner_id.to_string()).unwrap_or_default(),
+            filters.species.as_deref().unwrap_or_default()
+        )
+    }
+
+    fn outside_guild_embed() -> CreateEmbed {
+        CreateEmbed::new()
+            .title("The directory can only be browsed in servers")
+            .description("It lists the characters of the server's members")
+            .colour(ColourCode::Info.to_embed_colour())
+    }
+
+    /// Builds one page of the directory, with a menu to open any character on it and the buttons
+    /// to its neighbours
+    async fn directory_page( runtime_client: &RuntimeClient, viewer_id: u64, guild_id: u64, filters: &DirectoryFilters, page: u32 ) -> CreateInteractionResponseMessage {
+        let database_conn_pool = &runtime_client.database_connection;
+
+        let page_embed = 'page_embed: {
+            let character_count = match sql_scripts::character_search::count_directory(database_conn_pool, viewer_id, guild_id, filters).await {
+                Ok(character_count) => character_count,
+                Err(query_err) => break 'page_embed Err(query_error_embed(
+                    "character::directory::count_directory", "count the characters", &query_err
+                ))
+            };
+            let page_count = character_count.div_ceil(PAGE_LENGTH).max(1);
+            let page = page.min(page_count - 1);
+
+            let characters = match sql_scripts::character_search::get_directory_page(database_conn_pool, viewer_id, guild_id, filters, PAGE_LENGTH, page * PAGE_LENGTH).await {
+                Ok(characters) => characters,
+                Err(query_err) => break 'page_embed Err(query_error_embed(
+                    "character::directory::get_directory_page", "fetch the characters", &query_err
+                ))
+            };
+
+            let mut directory_description = String::new();
+            for character in &characters {
+                directory_description.push_str(&format!(
+                    "- **{}**, {}, {}\n",
+                    character.name,
+                    commands::truncated(&character.species, OPTION_TEXT_LIMIT),
+                    character.owner_mention()
+                ));
+            }
+            if characters.is_empty() {
+                directory_description.push_str("No characters you can see fit those filters");
+            }
+
+            // --== FILTERS ==-- //
+
+                let mut applied_filters = vec![];
+                if let Some(class_id) = filters.class_id {
+                    let class_name = match sql_scripts::character_classes::get_class(database_conn_pool, class_id).await {
+                        Ok(class) => class.map_or(String::from("unknown class"), |class| class.name),
+                        Err(query_err) => break 'page_embed Err(query_error_embed(
+                            "character::directory::get_class", "find the class", &query_err
+                        ))
+                    };
+                    applied_filters.push(format!( "class {class_name}" ));
+                }
+                if let Some(species) = &filters.species {
+                    applied_filters.push(format!( "species {species}" ));
+                }
+                if let Some(owner_id) = filters.owner_id {
+                    applied_filters.push(format!( "owned by <@{owner_id}>" ));
+                }
+                if !applied_filters.is_empty() {
+                    directory_description.insert_str(0, &format!( "*Only {}*\n\n", applied_filters.join(", ") ));
+                }
+            // ==--
+
+            Ok((
+                CreateEmbed::new()
+                    .title("Character directory")
+                    .description(directory_description)
+                    .footer(CreateEmbedFooter::new(format!( "Page {} of {page_count}, {character_count} character(s)", page + 1 )))
+                    .colour(ColourCode::Info.to_embed_colour()),
+                characters,
+                page,
+                page_count
+            ))
+        };
+
+        let (embed, characters, page, page_count) = match page_embed {
+            Ok(page_embed) => page_embed,
+            Err(error_embed) => return CreateInteractionResponseMessage::new()
+                .embed(error_embed)
+                .components(vec![])
+        };
+
+        let mut components = vec![];
+        if !characters.is_empty() {
+            let character_options = characters.iter()
+                .map(|character| {
+                    let character_option = CreateSelectMenuOption::new(commands::truncated(&character.name, OPTION_TEXT_LIMIT), character.id.to_string());
+                    // Species is left blank when the creation wizard doesn't ask it
+                    if character.species.trim().is_empty() {
+                        character_option
+                    } else {
+                        character_option.description(commands::truncated(&character.species, OPTION_TEXT_LIMIT))
+                    }
+                })
+                .collect();
+
+            components.push(CreateActionRow::SelectMenu(
+                CreateSelectMenu::new("character|directory|open", CreateSelectMenuKind::String { options: character_options })
+                    .placeholder("Open a character's sheet")
+            ));
+        }
+        components.push(CreateActionRow::Buttons(vec![
+            CreateButton::new(page_custom_id(page.saturating_sub(1), filters))
+                .style(ButtonStyle::Secondary)
+                .label("Previous")
+                .disabled(page == 0),
+            CreateButton::new(page_custom_id(page + 1, filters))
+                .style(ButtonStyle::Secondary)
+                .label("Next")
+                .disabled(page + 1 >= page_count),
+        ]));
+
+        CreateInteractionResponseMessage::new()
+            .embed(embed)
+            .components(components)
+    }
+
+    /// The sheet of the picked character, as long as the viewer may still see them
+    async fn open_sheet( runtime_client: &RuntimeClient, viewer_id: u64, guild_id: u64, character_id: u64 ) -> CreateEmbed {
+        match sql_scripts::character_search::get_visible_character(&runtime_client.database_connection, viewer_id, guild_id, character_id).await {
+            Ok(Some(character)) => match sheet::sheet_embed(runtime_client, &character).await {
+                Ok(sheet_embed) => sheet_embed,
+                Err(query_err) => query_error_embed("character::directory::sheet_embed", "put together the character's sheet", &query_err)
+            },
+            Ok(None) => CreateEmbed::new()
+                .title("That character can't be opened anymore")
+                .description("They may have been deleted, or their owner hid their characters")
+                .colour(ColourCode::Info.to_embed_colour()),
+            Err(query_err) => query_error_embed("character::directory::get_visible_character", "find the character", &query_err)
+        }
+    }
+// ==--
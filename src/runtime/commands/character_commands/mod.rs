@@ -1 +1,13 @@
+pub mod class_add;
+pub mod class_primary;
 pub mod create;
+pub mod directory;
+pub mod relate;
+pub mod relations;
+pub mod resubmit;
+pub mod review;
+pub mod search;
+pub mod select;
+pub mod sheet;
+pub mod transfer;
+pub mod unrelate;
@@ -0,0 +1,308 @@
+use crate::{
+    runtime::{
+        commands::{
+            query_error_embed, refusal, resolve_character_target, resolve_user_character,
+            respond_with_embed, string_option, sub_command_options, user_option
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts::{
+            self,
+            models::{Character, Relationship, RelationshipKind},
+            relationships::ConfirmOutcome
+        }
+    },
+    utils::misc::{colour_codes::ColourCode, logging::create_log_message},
+};
+
+use serenity::{
+    builder::{
+        CreateActionRow, CreateButton, CreateEmbed, CreateInteractionResponse,
+        CreateInteractionResponseMessage
+    },
+    client::Context,
+    model::application::{ButtonStyle, CommandInteraction, ComponentInteraction},
+};
+
+// Relationship buttons follow the format:
+//     character|relation|confirm|relationship_id    character|relation|decline|relationship_id
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let database_conn_pool = &runtime_client.database_connection;
+    let invoker_id = interaction_data.user.id.get();
+
+    let sub_options = sub_command_options(&interaction_data);
+    let player = user_option(&sub_options, "player")
+        .expect("`player` is a required option");
+    let other_option = string_option(&sub_options, "other")
+        .expect("`other` is a required option");
+    let kind = string_option(&sub_options, "kind")
+        .and_then(RelationshipKind::from_key)
+        .expect("`kind` is a required option with fixed choices");
+    let note = string_option(&sub_options, "note")
+        .map(str::trim)
+        .filter(|note| !note.is_empty());
+
+    let refusal_embed = 'refusal_embed: {
+
+        let character = match resolve_character_target(runtime_client, &ctx, &interaction_data, "character").await {
+            Ok(character) if character.owner_id == invoker_id => character,
+            Ok(character) => break 'refusal_embed CreateEmbed::new()
+                .title(format!( "Only {}'s owner can tie them to other characters", character.name ))
+                .colour(ColourCode::Info.to_embed_colour()),
+            Err(refusal_embed) => break 'refusal_embed refusal_embed
+        };
+
+        let Some((other_id, _)) = resolve_user_character(&ctx, player.id.get(), other_option).await else {
+            break 'refusal_embed CreateEmbed::new()
+                .title(format!( "{} doesn't have a character called `{other_option}`", player.name ))
+                .colour(ColourCode::Info.to_embed_colour());
+        };
+
+        if other_id == character.character_id {
+            break 'refusal_embed CreateEmbed::new()
+                .title(format!( "{} can't be related to themselves", character.name ))
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        // Whoever proposes confirms for their own side, so linking two of your own characters
+        // needs nobody else
+        let other_owner = player.id.get();
+        let add_relationship_query = sql_scripts::relationships::add_relationship(
+            database_conn_pool,
+            character.character_id,
+            other_id,
+            kind,
+            note,
+            true,
+            other_owner == invoker_id
+        );
+
+        let relationship = match add_relationship_query.await {
+            Ok(relationship_id) => match sql_scripts::relationships::get_relationship(database_conn_pool, relationship_id).await {
+                Ok(Some(relationship)) => relationship,
+                Ok(None) => break 'refusal_embed CreateEmbed::new()
+                    .title("The relationship vanished as it was made")
+                    .colour(ColourCode::Error.to_embed_colour()),
+                Err(query_err) => break 'refusal_embed query_error_embed(
+                    "character::relate::get_relationship", "fetch the relationship", &query_err
+                )
+            },
+            Err(query_err) if query_err.as_database_error().is_some_and(|db_err| db_err.is_unique_violation()) => {
+                break 'refusal_embed CreateEmbed::new()
+                    .title(format!( "{} and that character are already linked that way", character.name ))
+                    .description("A proposal may still be waiting on confirmation, `/character relations` shows it")
+                    .colour(ColourCode::Info.to_embed_colour());
+            },
+            Err(query_err) => break 'refusal_embed query_error_embed(
+                "character::relate::add_relationship", "propose the relationship", &query_err
+            )
+        };
+
+        let mut proposal_message = CreateInteractionResponseMessage::new()
+            .embed(relationship_embed(&relationship, invoker_id, other_owner));
+        if !relationship.is_confirmed() {
+            proposal_message = proposal_message
+                .content(format!( "<@{other_owner}>" ))
+                .components(relationship_buttons(relationship.id));
+        }
+
+        if let Err(why) = interaction_data.create_response(&ctx.http, CreateInteractionResponse::Message(proposal_message)).await {
+            println!( "{}", create_log_message(
+                    format!(
+                        "{}character::relate{}: Failed to send response: `{}{}{}`",
+                        ColourCode::Location,
+                        ColourCode::Reset,
+                        ColourCode::Info,
+                        why,
+                        ColourCode::Reset
+                    ),
+                    ColourCode::Error
+            ));
+
+            // Nobody can confirm a proposal they can't see
+            if !relationship.is_confirmed() {
+                let _ = sql_scripts::relationships::remove_relationship(database_conn_pool, relationship.id).await;
+            }
+        }
+
+        return;
+
+    }; // let refusal_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, refusal_embed, true, "character::relate").await;
+}
+
+
+pub async fn handle_component(
+    runtime_client: &RuntimeClient,
+    component_interaction: ComponentInteraction,
+    ctx: Context,
+    split_custom_id: Vec<&str>,
+) {
+    let database_conn_pool = &runtime_client.database_connection;
+    let presser_id = component_interaction.user.id.get();
+
+    let relationship_id = split_custom_id.get(3)
+        .and_then(|relationship_id| relationship_id.parse::<u64>().ok());
+
+    let response = 'response: {
+
+        let relationship = match relationship_id {
+            Some(relationship_id) => sql_scripts::relationships::get_relationship(database_conn_pool, relationship_id).await,
+            None => Ok(None)
+        };
+        let relationship = match relationship {
+            Ok(Some(relationship)) => relationship,
+            Ok(None) => break 'response closed_proposal(
+                CreateEmbed::new()
+                    .title("This proposal is no longer open")
+                    .colour(ColourCode::Info.to_embed_colour())
+            ),
+            Err(query_err) => break 'response refusal(
+                query_error_embed("character::relate::get_relationship", "find the relationship", &query_err)
+            )
+        };
+
+        // --== FETCH OWNERS ==-- //
+
+            let owners = (
+                sql_scripts::characters::get_character(database_conn_pool, relationship.character_id).await,
+                sql_scripts::characters::get_character(database_conn_pool, relationship.other_character_id).await
+            );
+            let (character_owner, other_owner) = match owners {
+                (Ok(Some(Character { owner: Some(character_owner), .. })), Ok(Some(Character { owner: Some(other_owner), .. }))) => (
+                    character_owner.get(),
+                    other_owner.get()
+                ),
+                (Err(query_err), _) | (_, Err(query_err)) => break 'response refusal(
+                    query_error_embed("character::relate::get_character", "find the relationship's characters", &query_err)
+                ),
+                _ => break 'response closed_proposal(
+                    CreateEmbed::new()
+                        .title("This proposal is no longer open")
+                        .colour(ColourCode::Info.to_embed_colour())
+                )
+            };
+        // ==--
+
+        let (is_character_owner, is_other_owner) = (character_owner == presser_id, other_owner == presser_id);
+        if !is_character_owner && !is_other_owner {
+            break 'response refusal(
+                CreateEmbed::new()
+                    .title("This proposal isn't yours to answer")
+                    .colour(ColourCode::Info.to_embed_colour())
+            );
+        }
+
+        if split_custom_id[2] == "decline" {
+            if let Err(query_err) = sql_scripts::relationships::remove_relationship(database_conn_pool, relationship.id).await {
+                break 'response refusal(
+                    query_error_embed("character::relate::remove_relationship", "decline the relationship", &query_err)
+                );
+            }
+
+            break 'response closed_proposal(
+                CreateEmbed::new()
+                    .title(format!( "{} and {} remain unrelated", relationship.character_name, relationship.other_name ))
+                    .description(format!( "<@{presser_id}> declined" ))
+                    .colour(ColourCode::Info.to_embed_colour())
+            );
+        }
+
+        match sql_scripts::relationships::confirm_relationship(database_conn_pool, relationship.id, is_character_owner, is_other_owner).await {
+            Ok(ConfirmOutcome::AwaitingConfirmation) => {
+                let relationship = Relationship {
+                    character_confirmed: relationship.character_confirmed || is_character_owner,
+                    other_confirmed: relationship.other_confirmed || is_other_owner,
+                    ..relationship
+                };
+
+                CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new()
+                        .embed(relationship_embed(&relationship, character_owner, other_owner))
+                        .components(relationship_buttons(relationship.id))
+                )
+            },
+            Ok(ConfirmOutcome::Confirmed) => {
+                let relationship = Relationship { character_confirmed: true, other_confirmed: true, ..relationship };
+                closed_proposal(relationship_embed(&relationship, character_owner, other_owner))
+            },
+            Ok(ConfirmOutcome::Missing) => closed_proposal(
+                CreateEmbed::new()
+                    .title("This proposal is no longer open")
+                    .colour(ColourCode::Info.to_embed_colour())
+            ),
+            Err(query_err) => refusal(
+                query_error_embed("character::relate::confirm_relationship", "confirm the relationship", &query_err)
+            )
+        }
+
+    }; // let response = {...}
+
+    if let Err(response_send_err) = component_interaction.create_response(&ctx.http, response).await {
+        println!( "{}", create_log_message(
+                format!(
+                    "{}character::relate::handle_component{}: Failed to send response: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    response_send_err,
+                    ColourCode::Reset
+                ),
+                ColourCode::Error
+        ));
+    }
+}
+
+
+
+// --== PROPOSAL MESSAGE ==-- //
+
+    fn relationship_embed( relationship: &Relationship, character_owner: u64, other_owner: u64 ) -> CreateEmbed {
+        let confirmation = |is_confirmed: bool| if is_confirmed { "Confirmed" } else { "Waiting" };
+        let title = format!( "{} {} {}", relationship.character_name, relationship.kind.describe(), relationship.other_name );
+
+        let relationship_embed = if relationship.is_confirmed() {
+            CreateEmbed::new()
+                .title(title)
+                .colour(ColourCode::Success.to_embed_colour())
+        } else {
+            CreateEmbed::new()
+                .title(format!( "Proposed: {title}" ))
+                .field(relationship.character_name.clone(), format!( "<@{character_owner}>: {}", confirmation(relationship.character_confirmed) ), true)
+                .field(relationship.other_name.clone(), format!( "<@{other_owner}>: {}", confirmation(relationship.other_confirmed) ), true)
+                .colour(ColourCode::Location.to_embed_colour())
+        };
+
+        match &relationship.note {
+            Some(note) => relationship_embed.description(format!( "*{note}*" )),
+            None => relationship_embed
+        }
+    }
+
+    fn relationship_buttons( relationship_id: u64 ) -> Vec<CreateActionRow> {
+        vec![
+            CreateActionRow::Buttons(vec![
+                CreateButton::new(format!( "character|relation|confirm|{relationship_id}" ))
+                    .style(ButtonStyle::Success)
+                    .label("Confirm"),
+                CreateButton::new(format!( "character|relation|decline|{relationship_id}" ))
+                    .style(ButtonStyle::Secondary)
+                    .label("Decline"),
+            ])
+        ]
+    }
+
+    /// Replaces the proposal with its outcome, removing the buttons
+    fn closed_proposal( embed: CreateEmbed ) -> CreateInteractionResponse {
+        CreateInteractionResponse::UpdateMessage(
+            CreateInteractionResponseMessage::new()
+                .embed(embed)
+                .components(vec![])
+        )
+    }
+// ==--
@@ -0,0 +1,74 @@
+use crate::{
+    runtime::{
+        commands::{query_error_embed, resolve_character_target, respond_with_embed},
+        runtime_client::RuntimeClient,
+        sql_scripts
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+// Embed descriptions are capped at 4096 characters, leave some room for the overflow line
+const RELATIONS_DESCRIPTION_LIMIT: usize = 4000;
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let response_embed = 'response_embed: {
+
+        let target = match resolve_character_target(runtime_client, &ctx, &interaction_data, "character").await {
+            Ok(target) => target,
+            Err(refusal_embed) => break 'response_embed refusal_embed
+        };
+
+        let relationships = match sql_scripts::relationships::get_character_relationships(&runtime_client.database_connection, target.character_id).await {
+            Ok(relationships) => relationships,
+            Err(query_err) => break 'response_embed query_error_embed(
+                "character::relations::get_character_relationships", "fetch the character's relationships", &query_err
+            )
+        };
+
+        let mut relations_description = String::new();
+        for (idx, relationship) in relationships.iter().enumerate() {
+            // Written from the point of view of the character being shown
+            let (description, other_name) = if relationship.character_id == target.character_id {
+                (relationship.kind.describe(), &relationship.other_name)
+            } else {
+                (relationship.kind.describe_reversed(), &relationship.character_name)
+            };
+
+            let mut relation_line = format!( "- {description} **{other_name}**" );
+            if !relationship.is_confirmed() {
+                relation_line.push_str(" *(awaiting confirmation)*");
+            }
+            if let Some(note) = &relationship.note {
+                relation_line.push_str(&format!( "\n  *{note}*" ));
+            }
+            relation_line.push('\n');
+
+            if relations_description.len() + relation_line.len() > RELATIONS_DESCRIPTION_LIMIT {
+                relations_description.push_str(&format!( "*...and {} more*", relationships.len() - idx ));
+                break;
+            }
+            relations_description.push_str(&relation_line);
+        }
+        if relationships.is_empty() {
+            relations_description.push_str("No ties to other characters yet, `/character relate` proposes one");
+        }
+
+        CreateEmbed::new()
+            .title(format!( "{}'s relationships", target.name ))
+            .description(relations_description)
+            .colour(ColourCode::Info.to_embed_colour())
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, false, "character::relations").await;
+}
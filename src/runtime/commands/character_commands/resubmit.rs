@@ -0,0 +1,80 @@
+use crate::{
+    runtime::{
+        commands::{
+            character_commands::review::post_review_request, query_error_embed, resolve_user_character,
+            respond_with_embed, string_option, sub_command_options
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts::{self, models::CharacterStatus}
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let database_conn_pool = &runtime_client.database_connection;
+    let invoker_id = interaction_data.user.id.get();
+
+    let sub_options = sub_command_options(&interaction_data);
+    let character_option = string_option(&sub_options, "character")
+        .expect("`character` is a required option");
+
+    let response_embed = 'response_embed: {
+
+        if runtime_client.review_channel_id.is_none() {
+            break 'response_embed CreateEmbed::new()
+                .title("Characters aren't reviewed on this server")
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        let Some((character_id, character_name)) = resolve_user_character(&ctx, invoker_id, character_option).await else {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "You don't have a character called `{character_option}`" ))
+                .colour(ColourCode::Info.to_embed_colour());
+        };
+
+        let status = match sql_scripts::characters::get_character(database_conn_pool, character_id).await {
+            Ok(Some(character)) => character.status,
+            Ok(None) => break 'response_embed CreateEmbed::new()
+                .title(format!( "{character_name} no longer exists" ))
+                .colour(ColourCode::Info.to_embed_colour()),
+            Err(query_err) => break 'response_embed query_error_embed(
+                "character::resubmit::get_character", "check the character's review", &query_err
+            )
+        };
+
+        // Pending characters may be posted again, in case their review went missing
+        if !matches!(status, CharacterStatus::Pending | CharacterStatus::ChangesRequested) {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "{character_name} has been {} already", status.describe() ))
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        match sql_scripts::characters::transition_status(database_conn_pool, character_id, status, CharacterStatus::Pending, None).await {
+            Ok(true) => {
+                post_review_request(runtime_client, &ctx, character_id).await;
+
+                CreateEmbed::new()
+                    .title(format!( "{character_name} was sent to staff for review" ))
+                    .description("You'll get a DM once they've decided")
+                    .colour(ColourCode::Success.to_embed_colour())
+            },
+            Ok(false) => CreateEmbed::new()
+                .title(format!( "{character_name} was reviewed in the meantime" ))
+                .colour(ColourCode::Info.to_embed_colour()),
+            Err(query_err) => query_error_embed("character::resubmit::transition_status", "resubmit the character", &query_err)
+        }
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, true, "character::resubmit").await;
+}
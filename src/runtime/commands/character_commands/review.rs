@@ -0,0 +1,366 @@
+use crate::{
+    runtime::{
+        commands::{character_commands::sheet, is_server_manager, query_error_embed, refusal, truncated},
+        runtime_client::RuntimeClient,
+        sql_scripts::{
+            self,
+            models::{Character, CharacterStatus}
+        }
+    },
+    utils::misc::{colour_codes::ColourCode, logging::create_log_message},
+};
+
+use serenity::{
+    builder::{
+        CreateActionRow, CreateButton, CreateEmbed, CreateInputText, CreateInteractionResponse,
+        CreateInteractionResponseMessage, CreateMessage, CreateModal
+    },
+    client::Context,
+    model::{
+        application::{ActionRowComponent, ButtonStyle, ComponentInteraction, InputTextStyle, ModalInteraction},
+        id::{ChannelId, UserId}
+    },
+};
+
+// Review buttons follow the format:
+//     character|review|approve|character_id    character|review|reject|character_id    character|review|changes|character_id
+// Rejecting and asking for changes first open a modal for the reason, which carries the same ID
+
+// Embed field values are capped at 1024 characters
+const FIELD_VALUE_LIMIT: usize = 1000;
+// An embed holds at most 25 fields, 11 of which go to the answers every character has
+const CUSTOM_FIELDS_SHOWN: usize = 14;
+
+
+
+// --== REVIEW MESSAGE ==-- //
+
+    /// Posts a pending character to the review channel for staff to decide on. Failing to do so is
+    /// only logged, the owner can post it again through `/character resubmit`
+    pub async fn post_review_request( runtime_client: &RuntimeClient, ctx: &Context, character_id: u64 ) {
+        let Some(review_channel_id) = runtime_client.review_channel_id else {
+            return;
+        };
+
+        let review_embed = match review_embed(runtime_client, character_id).await {
+            Ok(Some(review_embed)) => review_embed,
+            Ok(None) => return,
+            Err(query_err) => {
+                println!( "{}", create_log_message(
+                        format!(
+                            "{}character::review::post_review_request{}: Failed to build review: `{}{}{}`",
+                            ColourCode::Location,
+                            ColourCode::Reset,
+                            ColourCode::Info,
+                            query_err,
+                            ColourCode::Reset
+                        ),
+                        ColourCode::Error
+                ));
+                return;
+            }
+        };
+
+        let review_message = CreateMessage::new()
+            .embed(review_embed)
+            .components(vec![
+                CreateActionRow::Buttons(vec![
+                    CreateButton::new(format!( "character|review|approve|{character_id}" ))
+                        .style(ButtonStyle::Success)
+                        .label("Approve"),
+                    CreateButton::new(format!( "character|review|changes|{character_id}" ))
+                        .style(ButtonStyle::Primary)
+                        .label("Request Changes"),
+                    CreateButton::new(format!( "character|review|reject|{character_id}" ))
+                        .style(ButtonStyle::Danger)
+                        .label("Reject"),
+                ])
+            ]);
+
+        if let Err(why) = ChannelId::new(review_channel_id).send_message(&ctx.http, review_message).await {
+            println!( "{}", create_log_message(
+                    format!(
+                        "{}character::review::post_review_request{}: Failed to post review: `{}{}{}`",
+                        ColourCode::Location,
+                        ColourCode::Reset,
+                        ColourCode::Info,
+                        why,
+                        ColourCode::Reset
+                    ),
+                    ColourCode::Error
+            ));
+        }
+    }
+
+    /// Everything staff need to know about a character to review it. `None` if it no longer exists
+    async fn review_embed( runtime_client: &RuntimeClient, character_id: u64 ) -> Result<Option<CreateEmbed>, sqlx::Error> {
+        let database_conn_pool = &runtime_client.database_connection;
+
+        let Some(character) = sql_scripts::characters::get_character(database_conn_pool, character_id).await? else {
+            return Ok(None);
+        };
+        let class_names: Vec<String> = sql_scripts::character_classes::get_character_classes(database_conn_pool, character_id)
+            .await?
+            .into_iter()
+            .map(|class| class.name)
+            .collect();
+        let custom_fields = sql_scripts::characters::get_custom_fields(database_conn_pool, character_id).await?;
+
+        let mut review_embed = CreateEmbed::new()
+            .title(format!( "{} awaits review", character.name ))
+            .field("Owner", character.owner_mention(), true)
+            .field("Species", truncated(&character.species, FIELD_VALUE_LIMIT), true)
+            .field("Class", class_names.join(", "), true)
+            .field("Alignment", truncated(&character.alignment, FIELD_VALUE_LIMIT), true)
+            .field("Likes", truncated(&character.likes, FIELD_VALUE_LIMIT), true)
+            .field("Dislikes", truncated(&character.dislikes, FIELD_VALUE_LIMIT), true)
+            .field("Appearance", truncated(&character.appearance, FIELD_VALUE_LIMIT), false)
+            .field("Motivations", truncated(&character.motivations, FIELD_VALUE_LIMIT), false)
+            .field("Companions", truncated(&character.companions, FIELD_VALUE_LIMIT), false)
+            .field("Backstory", truncated(&character.backstory, FIELD_VALUE_LIMIT), false)
+            .field("Extras", truncated(&character.extras, FIELD_VALUE_LIMIT), false)
+            .colour(ColourCode::Location.to_embed_colour());
+
+        // The answers to the server's own wizard questions are up for review as well
+        for (label, value) in sheet::labelled_custom_fields(&runtime_client.wizard, custom_fields).iter().take(CUSTOM_FIELDS_SHOWN) {
+            review_embed = review_embed.field(label, truncated(value, FIELD_VALUE_LIMIT), false);
+        }
+
+        Ok(Some(review_embed))
+    }
+
+    /// Tells the owner what staff decided, logging should their DMs be closed
+    async fn notify_owner( ctx: &Context, character: &Character ) {
+        let decision_embed = match character.status {
+            CharacterStatus::Approved => CreateEmbed::new()
+                .title(format!( "{} was approved!", character.name ))
+                .description("You can now select and play them")
+                .colour(ColourCode::Success.to_embed_colour()),
+            CharacterStatus::ChangesRequested => CreateEmbed::new()
+                .title(format!( "Staff asked for changes to {}", character.name ))
+                .description(format!(
+                    "{}\n\nOnce you've made them, send the character back with `/character resubmit`",
+                    character.review_note.as_deref().unwrap_or_default()
+                ))
+                .colour(ColourCode::Warning.to_embed_colour()),
+            _ => CreateEmbed::new()
+                .title(format!( "{} was rejected", character.name ))
+                .description(character.review_note.clone().unwrap_or_default())
+                .colour(ColourCode::Error.to_embed_colour())
+        };
+
+        let Some(owner) = character.owner else {
+            return;
+        };
+
+        if let Err(why) = UserId::new(owner.get()).direct_message(&ctx.http, CreateMessage::new().embed(decision_embed)).await {
+            println!( "{}", create_log_message(
+                    format!(
+                        "{}character::review::notify_owner{}: Failed to DM the owner of character `{}{}{}`: `{}{}{}`",
+                        ColourCode::Location,
+                        ColourCode::Reset,
+                        ColourCode::Info,
+                        character.id,
+                        ColourCode::Reset,
+                        ColourCode::Info,
+                        why,
+                        ColourCode::Reset
+                    ),
+                    ColourCode::Caution
+            ));
+        }
+    }
+// ==--
+
+
+
+// --== DECISIONS ==-- //
+
+    /// Records a decision and replaces the review message's buttons with its outcome
+    async fn decide( runtime_client: &RuntimeClient, ctx: &Context, character_id: u64, reviewer_id: u64, decision: CharacterStatus, review_note: Option<&str> ) -> CreateInteractionResponse {
+        let database_conn_pool = &runtime_client.database_connection;
+
+        let is_decided = match sql_scripts::characters::transition_status(database_conn_pool, character_id, CharacterStatus::Pending, decision, review_note).await {
+            Ok(is_decided) => is_decided,
+            Err(query_err) => return refusal(query_error_embed("character::review::transition_status", "record the decision", &query_err))
+        };
+
+        let character = match sql_scripts::characters::get_character(database_conn_pool, character_id).await {
+            Ok(Some(character)) => character,
+            Ok(None) => return closed_review(
+                CreateEmbed::new()
+                    .title("This character no longer exists")
+                    .colour(ColourCode::Info.to_embed_colour())
+            ),
+            Err(query_err) => return refusal(query_error_embed("character::review::get_character", "find the character", &query_err))
+        };
+
+        if !is_decided {
+            return refusal(
+                CreateEmbed::new()
+                    .title(format!( "{} is {} already", character.name, character.status.describe() ))
+                    .colour(ColourCode::Info.to_embed_colour())
+            );
+        }
+
+        println!( "{}", create_log_message(
+                format!(
+                    "`{}{}{}` marked character `{}{}{}` as {}",
+                    ColourCode::Info,
+                    reviewer_id,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    character_id,
+                    ColourCode::Reset,
+                    decision.describe()
+                ),
+                ColourCode::Success
+        ));
+        notify_owner(ctx, &character).await;
+
+        let (title, colour) = match decision {
+            CharacterStatus::Approved => (format!( "{} was approved", character.name ), ColourCode::Success),
+            CharacterStatus::ChangesRequested => (format!( "Changes were requested to {}", character.name ), ColourCode::Warning),
+            _ => (format!( "{} was rejected", character.name ), ColourCode::Error)
+        };
+
+        let mut decision_embed = CreateEmbed::new()
+            .title(title)
+            .field("Owner", character.owner_mention(), true)
+            .field("Reviewer", format!( "<@{reviewer_id}>" ), true)
+            .colour(colour.to_embed_colour());
+        if let Some(review_note) = review_note {
+            decision_embed = decision_embed.field("Reason", review_note, false);
+        }
+
+        closed_review(decision_embed)
+    }
+
+    pub async fn handle_component(
+        runtime_client: &RuntimeClient,
+        component_interaction: ComponentInteraction,
+        ctx: Context,
+        split_custom_id: Vec<&str>,
+    ) {
+        let character_id = split_custom_id.get(3).and_then(|character_id| character_id.parse::<u64>().ok());
+
+        let response = 'response: {
+            if !is_server_manager(component_interaction.member.as_ref()) {
+                break 'response refusal(
+                    CreateEmbed::new()
+                        .title("Only staff can review characters")
+                        .colour(ColourCode::Info.to_embed_colour())
+                );
+            }
+
+            let Some(character_id) = character_id else {
+                break 'response closed_review(
+                    CreateEmbed::new()
+                        .title("This review can no longer be acted on")
+                        .colour(ColourCode::Info.to_embed_colour())
+                );
+            };
+
+            match split_custom_id[2] {
+                "approve" => decide(
+                    runtime_client, &ctx, character_id, component_interaction.user.id.get(), CharacterStatus::Approved, None
+                ).await,
+
+                // The other decisions need a reason, which is asked for first
+                label => {
+                    let reason_label = if label == "reject" { "Why is it rejected?" } else { "What should be changed?" };
+
+                    CreateInteractionResponse::Modal(
+                        CreateModal::new(format!( "character|review|{label}|{character_id}" ), "Character Review")
+                            .components(vec![
+                                CreateActionRow::InputText(
+                                    CreateInputText::new(InputTextStyle::Paragraph, reason_label, "reason")
+                                        .required(true)
+                                        .max_length(1000)
+                                )
+                            ])
+                    )
+                }
+            }
+        };
+
+        if let Err(response_send_err) = component_interaction.create_response(&ctx.http, response).await {
+            println!( "{}", create_log_message(
+                    format!(
+                        "{}character::review::handle_component{}: Failed to send response: `{}{}{}`",
+                        ColourCode::Location,
+                        ColourCode::Reset,
+                        ColourCode::Info,
+                        response_send_err,
+                        ColourCode::Reset
+                    ),
+                    ColourCode::Error
+            ));
+        }
+    }
+
+    pub async fn handle_modal(
+        runtime_client: &RuntimeClient,
+        modal_interaction: ModalInteraction,
+        ctx: Context,
+        split_custom_id: Vec<&str>,
+    ) {
+        let character_id = split_custom_id.get(3).and_then(|character_id| character_id.parse::<u64>().ok());
+        let decision = match split_custom_id.get(2) {
+            Some(&"reject") => CharacterStatus::Rejected,
+            _ => CharacterStatus::ChangesRequested
+        };
+
+        let reason = modal_interaction.data.components.iter()
+            .flat_map(|action_row| action_row.components.iter())
+            .find_map(|component| match component {
+                ActionRowComponent::InputText(input_text) if input_text.custom_id == "reason" => input_text.value.clone(),
+                _ => None
+            })
+            .unwrap_or_default();
+
+        let response = 'response: {
+            // Permissions could have changed while the modal was open
+            if !is_server_manager(modal_interaction.member.as_ref()) {
+                break 'response refusal(
+                    CreateEmbed::new()
+                        .title("Only staff can review characters")
+                        .colour(ColourCode::Info.to_embed_colour())
+                );
+            }
+
+            let Some(character_id) = character_id else {
+                break 'response closed_review(
+                    CreateEmbed::new()
+                        .title("This review can no longer be acted on")
+                        .colour(ColourCode::Info.to_embed_colour())
+                );
+            };
+
+            decide(runtime_client, &ctx, character_id, modal_interaction.user.id.get(), decision, Some(reason.trim())).await
+        };
+
+        if let Err(response_send_err) = modal_interaction.create_response(&ctx.http, response).await {
+            println!( "{}", create_log_message(
+                    format!(
+                        "{}character::review::handle_modal{}: Failed to send response: `{}{}{}`",
+                        ColourCode::Location,
+                        ColourCode::Reset,
+                        ColourCode::Info,
+                        response_send_err,
+                        ColourCode::Reset
+                    ),
+                    ColourCode::Error
+            ));
+        }
+    }
+
+    /// Replaces the review message with the decision, removing the buttons
+    fn closed_review( embed: CreateEmbed ) -> CreateInteractionResponse {
+        CreateInteractionResponse::UpdateMessage(
+            CreateInteractionResponseMessage::new()
+                .embed(embed)
+                .components(vec![])
+        )
+    }
+// ==--
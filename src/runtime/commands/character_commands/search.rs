@@ -0,0 +1,183 @@
+use crate::{
+    runtime::{
+        commands::{query_error_embed, string_option, sub_command_options},
+        runtime_client::RuntimeClient,
+        sql_scripts::{self, character_search::match_expression}
+    },
+    utils::misc::{colour_codes::ColourCode, logging::create_log_message},
+};
+
+use serenity::{
+    builder::{
+        CreateActionRow, CreateButton, CreateEmbed, CreateEmbedFooter, CreateInteractionResponse,
+        CreateInteractionResponseMessage
+    },
+    client::Context,
+    model::application::{ButtonStyle, CommandInteraction, ComponentInteraction},
+};
+
+// Page buttons follow the format:
+//     character|search|page|search_text
+// The search text goes last as it may itself contain `|`. Results are worked out again for whoever
+// presses, so a button can't show anyone more than their own search would
+
+const PAGE_LENGTH: u32 = 5;
+
+/// Custom IDs are capped at 100 bytes, the search text has to fit alongside the prefix and page
+pub const SEARCH_TEXT_MAX_BYTES: usize = 72;
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let sub_options = sub_command_options(&interaction_data);
+    let search_text = string_option(&sub_options, "query")
+        .expect("`query` is a required option")
+        .trim();
+
+    let response_message = if search_text.len() > SEARCH_TEXT_MAX_BYTES {
+        CreateInteractionResponseMessage::new().embed(
+            CreateEmbed::new()
+                .title("That search is too long")
+                .description("Try a few distinctive words instead")
+                .colour(ColourCode::Info.to_embed_colour())
+        )
+    } else {
+        search_page(runtime_client, interaction_data.user.id.get(), search_text, 0).await
+    };
+
+    let response = CreateInteractionResponse::Message(response_message.ephemeral(true));
+    if let Err(response_send_err) = interaction_data.create_response(&ctx.http, response).await {
+        println!( "{}", create_log_message(
+                format!(
+                    "{}character::search{}: Failed to send response: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    response_send_err,
+                    ColourCode::Reset
+                ),
+                ColourCode::Error
+        ));
+    }
+}
+
+pub async fn handle_component(
+    runtime_client: &RuntimeClient,
+    component_interaction: ComponentInteraction,
+    ctx: Context,
+    split_custom_id: Vec<&str>,
+) {
+    let page = split_custom_id.get(2).and_then(|page| page.parse::<u32>().ok());
+    let search_text = split_custom_id.get(3..).map(|search_text| search_text.join("|"));
+
+    let response_message = match (page, search_text) {
+        (Some(page), Some(search_text)) => search_page(runtime_client, component_interaction.user.id.get(), &search_text, page).await,
+        _ => CreateInteractionResponseMessage::new()
+            .embed(
+                CreateEmbed::new()
+                    .title("These results can't be paged through anymore")
+                    .colour(ColourCode::Info.to_embed_colour())
+            )
+            .components(vec![])
+    };
+
+    let response = CreateInteractionResponse::UpdateMessage(response_message);
+    if let Err(response_send_err) = component_interaction.create_response(&ctx.http, response).await {
+        println!( "{}", create_log_message(
+                format!(
+                    "{}character::search::handle_component{}: Failed to send response: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    response_send_err,
+                    ColourCode::Reset
+                ),
+                ColourCode::Error
+        ));
+    }
+}
+
+
+
+// --== SEARCH PAGE ==-- //
+
+    /// Builds one page of search results, along with the buttons to its neighbours
+    async fn search_page( runtime_client: &RuntimeClient, searcher_id: u64, search_text: &str, page: u32 ) -> CreateInteractionResponseMessage {
+        let database_conn_pool = &runtime_client.database_connection;
+
+        let page_embed = 'page_embed: {
+            let Some(expression) = match_expression(search_text) else {
+                break 'page_embed Err(
+                    CreateEmbed::new()
+                        .title("There's nothing to search for in that")
+                        .description("Searches look for words, punctuation on its own won't match anything")
+                        .colour(ColourCode::Info.to_embed_colour())
+                );
+            };
+
+            let result_count = match sql_scripts::character_search::count_search_results(database_conn_pool, &expression, searcher_id).await {
+                Ok(result_count) => result_count,
+                Err(query_err) => break 'page_embed Err(query_error_embed(
+                    "character::search::count_search_results", "count the matching characters", &query_err
+                ))
+            };
+            let page_count = result_count.div_ceil(PAGE_LENGTH).max(1);
+            let page = page.min(page_count - 1);
+
+            let results = match sql_scripts::character_search::search_characters(database_conn_pool, &expression, searcher_id, PAGE_LENGTH, page * PAGE_LENGTH).await {
+                Ok(results) => results,
+                Err(query_err) => break 'page_embed Err(query_error_embed(
+                    "character::search::search_characters", "search the characters", &query_err
+                ))
+            };
+
+            let mut results_description = String::new();
+            for (idx, result) in results.iter().enumerate() {
+                // Snippets may span several lines of a backstory, keep each to its own quote
+                let snippet = result.snippet.split_whitespace().collect::<Vec<_>>().join(" ");
+
+                results_description.push_str(&format!(
+                    "{}. **{}**, <@{}>\n> {snippet}\n",
+                    page * PAGE_LENGTH + idx as u32 + 1,
+                    result.name,
+                    result.owner.get()
+                ));
+            }
+            if results.is_empty() {
+                results_description.push_str("No characters you can see match that");
+            }
+
+            Ok((
+                CreateEmbed::new()
+                    .title(format!( "Characters matching `{search_text}`" ))
+                    .description(results_description)
+                    .footer(CreateEmbedFooter::new(format!( "Page {} of {page_count}, {result_count} match(es)", page + 1 )))
+                    .colour(ColourCode::Info.to_embed_colour()),
+                page,
+                page_count
+            ))
+        };
+
+        match page_embed {
+            Ok((embed, page, page_count)) => CreateInteractionResponseMessage::new()
+                .embed(embed)
+                .components(vec![
+                    CreateActionRow::Buttons(vec![
+                        CreateButton::new(format!( "character|search|{}|{search_text}", page.saturating_sub(1) ))
+                            .style(ButtonStyle::Secondary)
+                            .label("Previous")
+                            .disabled(page == 0),
+                        CreateButton::new(format!( "character|search|{}|{search_text}", page + 1 ))
+                            .style(ButtonStyle::Secondary)
+                            .label("Next")
+                            .disabled(page + 1 >= page_count),
+                    ])
+                ]),
+            Err(error_embed) => CreateInteractionResponseMessage::new()
+                .embed(error_embed)
+                .components(vec![])
+        }
+    }
+// ==--
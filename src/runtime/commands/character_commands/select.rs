@@ -0,0 +1,65 @@
+use crate::{
+    runtime::{
+        commands::{
+            query_error_embed, resolve_user_character, respond_with_embed, string_option, sub_command_options,
+            unapproved_character_embed
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts::{self, models::CharacterStatus}
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let database_conn_pool = &runtime_client.database_connection;
+    let invoker_id = interaction_data.user.id.get();
+
+    let sub_options = sub_command_options(&interaction_data);
+    let character_option = string_option(&sub_options, "character")
+        .expect("`character` is a required option");
+
+    let response_embed = 'response_embed: {
+
+        let Some((character_id, character_name)) = resolve_user_character(&ctx, invoker_id, character_option).await else {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "You don't have a character called `{character_option}`" ))
+                .colour(ColourCode::Info.to_embed_colour());
+        };
+
+        let select_result = match sql_scripts::discord_users::set_selected_character(database_conn_pool, invoker_id, Some(character_id)).await {
+            Ok(select_result) => select_result,
+            Err(query_err) => break 'response_embed query_error_embed(
+                "character::select::set_selected_character", "select the character", &query_err
+            )
+        };
+
+        if select_result.rows_affected() == 1 {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "{character_name} is now your selected character" ))
+                .colour(ColourCode::Success.to_embed_colour());
+        }
+
+        // Only characters that haven't been approved are turned away
+        let status = match sql_scripts::characters::get_character(database_conn_pool, character_id).await {
+            Ok(character) => character.map_or(CharacterStatus::Pending, |character| character.status),
+            Err(query_err) => break 'response_embed query_error_embed(
+                "character::select::get_character", "check the character's review", &query_err
+            )
+        };
+
+        unapproved_character_embed(&character_name, status)
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, true, "character::select").await;
+}
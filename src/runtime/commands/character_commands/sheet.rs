@@ -0,0 +1,130 @@
+use crate::{
+    runtime::{
+        commands::truncated,
+        runtime_client::RuntimeClient,
+        sql_scripts::{self, character_classes::CombinedCaps, models::{Character, SelectedClass}}
+    },
+    utils::misc::{colour_codes::ColourCode, wizard::Wizard},
+};
+
+use serenity::builder::CreateEmbed;
+
+// A whole message is capped at 6000 characters across its embeds, so the long fields are cut
+// short enough for all of them to fit at once
+const SHORT_FIELD_LIMIT: usize = 200;
+const LONG_FIELD_LIMIT: usize = 600;
+const BACKSTORY_LIMIT: usize = 1000;
+// Answers to a server's own wizard questions share what's left
+const CUSTOM_FIELD_LIMIT: usize = 150;
+const CUSTOM_FIELDS_SHOWN: usize = 5;
+
+/// A character's classes, with the levels they have in each once there's more than one
+fn class_split( classes: &[SelectedClass] ) -> String {
+    match classes {
+        [] => String::from("-"),
+        [selected] => selected.class.name.clone(),
+        classes => classes.iter()
+            .map(|selected| match selected.is_primary {
+                true  => format!( "{} {} (primary)", selected.class.name, selected.level ),
+                false => format!( "{} {}", selected.class.name, selected.level )
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+/// What a character's classes allow between them
+fn describe_caps( caps: CombinedCaps ) -> String {
+    let casting = match caps.casting_cap {
+        None => String::from("Spells of any tier"),
+        Some(0) => String::from("No spells"),
+        Some(casting_cap) => format!( "Spells up to tier {casting_cap}" )
+    };
+    format!( "{casting}\nStarted with {} stat points", caps.stat_budget )
+}
+
+/// Labels a character's custom fields with the wizard questions they answer. They follow the order
+/// the wizard asks them in, those it no longer asks go last under their key
+pub fn labelled_custom_fields( wizard: &Wizard, mut custom_fields: Vec<(String, String)> ) -> Vec<(String, String)> {
+    let field_order = wizard.custom_field_ids();
+    custom_fields.sort_by_key(|(field_key, _)| field_order.iter().position(|id| id == field_key).unwrap_or(usize::MAX));
+
+    custom_fields.into_iter()
+        .map(|(field_key, value)| (wizard.label_of(&field_key).unwrap_or(&field_key).to_string(), value))
+        .collect()
+}
+
+/// Everything there is to know about a character, for anyone allowed to see them
+pub async fn sheet_embed( runtime_client: &RuntimeClient, character: &Character ) -> Result<CreateEmbed, sqlx::Error> {
+    let database_conn_pool = &runtime_client.database_connection;
+
+    // --== FETCH DETAILS ==-- //
+
+        let classes = sql_scripts::character_classes::get_class_split(database_conn_pool, character.id).await?;
+
+        let progression = sql_scripts::experience::get_progression(database_conn_pool, character.id).await?;
+        let stats = sql_scripts::character_stats::get_effective_stats(database_conn_pool, character.id).await?;
+        let species = sql_scripts::species::get_character_species(database_conn_pool, character.id).await?;
+
+        let lores = sql_scripts::lores::get_lores(database_conn_pool).await?;
+        let lore_lines: Vec<String> = sql_scripts::lores::get_used_lores(database_conn_pool, character.id)
+            .await?
+            .into_iter()
+            .filter(|used_lore| used_lore.spent_points > 0)
+            .filter_map(|used_lore| {
+                lores.iter()
+                    .find(|lore| lore.id == used_lore.lore_id)
+                    .map(|lore| format!( "{}: {}", lore.name, used_lore.spent_points ))
+            })
+            .collect();
+
+        let custom_fields = sql_scripts::characters::get_custom_fields(database_conn_pool, character.id).await?;
+    // ==--
+
+    let level = match progression {
+        Some(progression) => format!( "{} ({} XP)", progression.level, progression.experience ),
+        None => String::from("Unknown")
+    };
+    let stats = match stats {
+        Some(stats) => format!(
+            "STR {} | DEX {} | PER {}\nKNO {} | CON {} | CAS {}",
+            stats.strength, stats.dexterity, stats.perception, stats.knowledge, stats.constitution, stats.casting
+        ),
+        None => String::from("Not allocated yet")
+    };
+    // Registered species show the modifiers already added to the stats
+    let species_description = match species.as_ref().map(|species| species.describe_modifiers()) {
+        Some(modifiers) if !modifiers.is_empty() => format!( "{}\n{modifiers}", truncated(&character.species, SHORT_FIELD_LIMIT) ),
+        _ => truncated(&character.species, SHORT_FIELD_LIMIT)
+    };
+    let lores = if lore_lines.is_empty() { String::from("None") } else { lore_lines.join("\n") };
+
+    let mut sheet_embed = CreateEmbed::new()
+        .title(character.name.clone())
+        .field("Owner", character.owner_mention(), true)
+        .field("Species", species_description, true)
+        .field("Class", class_split(&classes), true)
+        .field("Level", level, true)
+        .field("Class caps", describe_caps(CombinedCaps::of(&classes)), true)
+        .field("Alignment", truncated(&character.alignment, SHORT_FIELD_LIMIT), true)
+        .field("Status", character.status.describe(), true)
+        .field("Stats", stats, true)
+        .field("Lores", lores, true)
+        .field("Likes", truncated(&character.likes, SHORT_FIELD_LIMIT), true)
+        .field("Dislikes", truncated(&character.dislikes, SHORT_FIELD_LIMIT), true)
+        .field("Appearance", truncated(&character.appearance, LONG_FIELD_LIMIT), false)
+        .field("Motivations", truncated(&character.motivations, LONG_FIELD_LIMIT), false)
+        .field("Companions", truncated(&character.companions, LONG_FIELD_LIMIT), false)
+        .field("Backstory", truncated(&character.backstory, BACKSTORY_LIMIT), false)
+        .field("Extras", truncated(&character.extras, LONG_FIELD_LIMIT), false)
+        .colour(ColourCode::Info.to_embed_colour());
+    if let Some(species) = species.filter(|species| !species.traits.is_empty()) {
+        sheet_embed = sheet_embed.field("Traits", truncated(&species.traits, SHORT_FIELD_LIMIT), false);
+    }
+
+    for (label, value) in labelled_custom_fields(&runtime_client.wizard, custom_fields).iter().take(CUSTOM_FIELDS_SHOWN) {
+        sheet_embed = sheet_embed.field(label, truncated(value, CUSTOM_FIELD_LIMIT), true);
+    }
+
+    Ok(sheet_embed)
+}
@@ -0,0 +1,311 @@
+use crate::{
+    runtime::{
+        commands::{
+            query_error_embed, refusal, resolve_user_character, respond_with_embed, string_option, sub_command_options,
+            user_option
+        },
+        context_keys,
+        runtime_client::RuntimeClient,
+        sql_scripts::{
+            self,
+            character_transfers::AcceptOutcome,
+            models::CharacterTransfer
+        }
+    },
+    utils::misc::{colour_codes::ColourCode, logging::create_log_message},
+};
+
+use serenity::{
+    builder::{
+        CreateActionRow, CreateAllowedMentions, CreateButton, CreateEmbed, CreateInteractionResponse,
+        CreateInteractionResponseMessage
+    },
+    client::Context,
+    model::application::{ButtonStyle, CommandInteraction, ComponentInteraction},
+};
+
+// Transfer buttons follow the format:
+//     character|transfer|accept|transfer_id    character|transfer|decline|transfer_id
+
+/// How long the recipient has to accept an offer
+const TRANSFER_EXPIRY_SECONDS: i64 = 24 * 60 * 60;
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let database_conn_pool = &runtime_client.database_connection;
+    let invoker_id = interaction_data.user.id.get();
+
+    let sub_options = sub_command_options(&interaction_data);
+    let character_option = string_option(&sub_options, "character")
+        .expect("`character` is a required option");
+    let recipient = user_option(&sub_options, "user")
+        .expect("`user` is a required option");
+
+    let refusal_embed = 'refusal_embed: {
+
+        let Some((character_id, character_name)) = resolve_user_character(&ctx, invoker_id, character_option).await else {
+            break 'refusal_embed CreateEmbed::new()
+                .title(format!( "You don't have a character called `{character_option}`" ))
+                .colour(ColourCode::Info.to_embed_colour());
+        };
+
+        if recipient.id.get() == invoker_id {
+            break 'refusal_embed CreateEmbed::new()
+                .title(format!( "{character_name} is already yours" ))
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+        if recipient.bot {
+            break 'refusal_embed CreateEmbed::new()
+                .title("Bots can't take on characters")
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        // Characters belong to a profile, so the recipient needs one to take them on
+        match sql_scripts::discord_users::get_by_user_id(database_conn_pool, recipient.id.get()).await {
+            Ok(Some(_)) => {},
+            Ok(None) => break 'refusal_embed CreateEmbed::new()
+                .title(format!( "{} doesn't have a profile yet", recipient.name ))
+                .description("They can make one with `/profile register`")
+                .colour(ColourCode::Info.to_embed_colour()),
+            Err(query_err) => break 'refusal_embed query_error_embed(
+                "character::transfer::get_by_user_id", "check the recipient's profile", &query_err
+            )
+        }
+
+        // --== OPEN TRANSFER ==-- //
+
+            let expires_at = chrono::Utc::now().timestamp() + TRANSFER_EXPIRY_SECONDS;
+            let add_transfer_query = sql_scripts::character_transfers::add_transfer(
+                database_conn_pool,
+                character_id,
+                invoker_id,
+                recipient.id.get(),
+                expires_at
+            );
+
+            let transfer_id = match add_transfer_query.await {
+                Ok(transfer_id) => transfer_id,
+                Err(query_err) if query_err.as_database_error().is_some_and(|db_err| db_err.is_unique_violation()) => {
+                    break 'refusal_embed CreateEmbed::new()
+                        .title(format!( "{character_name} has already been offered to someone" ))
+                        .description("That offer has to be accepted, declined or run out first")
+                        .colour(ColourCode::Info.to_embed_colour());
+                },
+                Err(query_err) => break 'refusal_embed query_error_embed(
+                    "character::transfer::add_transfer", "offer the character", &query_err
+                )
+            };
+
+            let transfer_message = CreateInteractionResponseMessage::new()
+                .content(format!( "<@{}>", recipient.id ))
+                .embed(
+                    CreateEmbed::new()
+                        .title(format!( "{} offers {character_name} to {}", interaction_data.user.name, recipient.name ))
+                        .description(format!(
+                            "<@{}>, accept to take {character_name} on as your own. The offer runs out <t:{expires_at}:R>",
+                            recipient.id
+                        ))
+                        .colour(ColourCode::Location.to_embed_colour())
+                )
+                .components(transfer_buttons(transfer_id))
+                .allowed_mentions(CreateAllowedMentions::new().users(vec![recipient.id]));
+
+            if let Err(why) = interaction_data.create_response(&ctx.http, CreateInteractionResponse::Message(transfer_message)).await {
+                println!( "{}", create_log_message(
+                        format!(
+                            "{}character::transfer{}: Failed to send response: `{}{}{}`",
+                            ColourCode::Location,
+                            ColourCode::Reset,
+                            ColourCode::Info,
+                            why,
+                            ColourCode::Reset
+                        ),
+                        ColourCode::Error
+                ));
+
+                // Nobody can accept an offer they can't see
+                let _ = sql_scripts::character_transfers::remove_transfer(database_conn_pool, transfer_id).await;
+            }
+        // ==--
+
+        return;
+
+    }; // let refusal_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, refusal_embed, true, "character::transfer").await;
+}
+
+
+pub async fn handle_component(
+    runtime_client: &RuntimeClient,
+    component_interaction: ComponentInteraction,
+    ctx: Context,
+    split_custom_id: Vec<&str>,
+) {
+    let database_conn_pool = &runtime_client.database_connection;
+    let presser_id = component_interaction.user.id.get();
+
+    let transfer_id = split_custom_id.get(3)
+        .and_then(|transfer_id| transfer_id.parse::<u64>().ok());
+
+    let response = 'response: {
+
+        // --== FETCH TRANSFER ==-- //
+
+            let transfer = match transfer_id {
+                Some(transfer_id) => sql_scripts::character_transfers::get_transfer(database_conn_pool, transfer_id).await,
+                None => Ok(None)
+            };
+            let transfer = match transfer {
+                Ok(Some(transfer)) => transfer,
+                Ok(None) => break 'response closed_transfer(no_longer_open()),
+                Err(query_err) => break 'response refusal(
+                    query_error_embed("character::transfer::get_transfer", "find the offer", &query_err)
+                )
+            };
+
+            let character_name = match sql_scripts::characters::get_character(database_conn_pool, transfer.character_id).await {
+                Ok(Some(character)) => character.name,
+                Ok(None) => break 'response closed_transfer(no_longer_open()),
+                Err(query_err) => break 'response refusal(
+                    query_error_embed("character::transfer::get_character", "find the character", &query_err)
+                )
+            };
+        // ==--
+
+        let (is_sender, is_recipient) = (transfer.from_user.get() == presser_id, transfer.to_user.get() == presser_id);
+
+        match split_custom_id[2] {
+            // Either side may call the offer off
+            "decline" if is_sender || is_recipient => {
+                if let Err(query_err) = sql_scripts::character_transfers::remove_transfer(database_conn_pool, transfer.id).await {
+                    break 'response refusal(
+                        query_error_embed("character::transfer::remove_transfer", "withdraw the offer", &query_err)
+                    );
+                }
+
+                let verb = if is_recipient { "declined" } else { "withdrew" };
+                closed_transfer(
+                    CreateEmbed::new()
+                        .title(format!( "{character_name} stays with their owner" ))
+                        .description(format!( "<@{presser_id}> {verb} the offer" ))
+                        .colour(ColourCode::Info.to_embed_colour())
+                )
+            },
+
+            "accept" if is_recipient => accept(&ctx, runtime_client, &transfer, character_name).await,
+
+            _ => refusal(
+                CreateEmbed::new()
+                    .title("This offer isn't yours to answer")
+                    .colour(ColourCode::Info.to_embed_colour())
+            )
+        }
+
+    }; // let response = {...}
+
+    if let Err(response_send_err) = component_interaction.create_response(&ctx.http, response).await {
+        println!( "{}", create_log_message(
+                format!(
+                    "{}character::transfer::handle_component{}: Failed to send response: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    response_send_err,
+                    ColourCode::Reset
+                ),
+                ColourCode::Error
+        ));
+    }
+}
+
+
+
+// --== TRANSFER MESSAGE ==-- //
+
+    /// Hands the character over, moving it between its owners in the `UserCharactersCache` too
+    async fn accept( ctx: &Context, runtime_client: &RuntimeClient, transfer: &CharacterTransfer, character_name: String ) -> CreateInteractionResponse {
+        let recipient_id = transfer.to_user.get();
+
+        match sql_scripts::character_transfers::accept_transfer(&runtime_client.database_connection, transfer.id).await {
+            Ok(AcceptOutcome::Transferred(previous_owner)) => {
+                {
+                    let mut data_writer = ctx.data.write().await;
+                    let user_characters = data_writer.get_mut::<context_keys::UserCharactersCache>()
+                        .expect("Key inserted in main.rs");
+
+                    context_keys::move_user_character(user_characters, previous_owner, recipient_id, transfer.character_id, character_name.clone());
+                }
+
+                println!( "{}", create_log_message(
+                        format!(
+                            "Character `{}{}{}` was handed from `{}{}{}` to `{}{}{}`",
+                            ColourCode::Info,
+                            transfer.character_id,
+                            ColourCode::Reset,
+                            ColourCode::Info,
+                            previous_owner,
+                            ColourCode::Reset,
+                            ColourCode::Info,
+                            recipient_id,
+                            ColourCode::Reset
+                        ),
+                        ColourCode::Success
+                ));
+
+                closed_transfer(
+                    CreateEmbed::new()
+                        .title(format!( "{character_name} has a new owner" ))
+                        .description(format!( "<@{previous_owner}> handed them over to <@{recipient_id}>" ))
+                        .colour(ColourCode::Success.to_embed_colour())
+                )
+            },
+            Ok(AcceptOutcome::NameTaken) => refusal(
+                CreateEmbed::new()
+                    .title(format!( "You already have a character called {character_name}" ))
+                    .description("Rename yours and accept again, the offer stays open until it runs out")
+                    .colour(ColourCode::Info.to_embed_colour())
+            ),
+            Ok(AcceptOutcome::Expired) => closed_transfer(
+                CreateEmbed::new()
+                    .title(format!( "The offer of {character_name} ran out" ))
+                    .colour(ColourCode::Info.to_embed_colour())
+            ),
+            Ok(AcceptOutcome::Missing) => closed_transfer(no_longer_open()),
+            Err(query_err) => refusal(
+                query_error_embed("character::transfer::accept_transfer", "take the character on", &query_err)
+            )
+        }
+    }
+
+    fn transfer_buttons( transfer_id: u64 ) -> Vec<CreateActionRow> {
+        vec![
+            CreateActionRow::Buttons(vec![
+                CreateButton::new(format!( "character|transfer|accept|{transfer_id}" ))
+                    .style(ButtonStyle::Success)
+                    .label("Accept"),
+                CreateButton::new(format!( "character|transfer|decline|{transfer_id}" ))
+                    .style(ButtonStyle::Secondary)
+                    .label("Decline"),
+            ])
+        ]
+    }
+
+    fn no_longer_open() -> CreateEmbed {
+        CreateEmbed::new()
+            .title("This offer is no longer open")
+            .colour(ColourCode::Info.to_embed_colour())
+    }
+
+    /// Replaces the offer with its outcome, removing the buttons
+    fn closed_transfer( embed: CreateEmbed ) -> CreateInteractionResponse {
+        CreateInteractionResponse::UpdateMessage(
+            CreateInteractionResponseMessage::new()
+                .embed(embed)
+                .components(vec![])
+        )
+    }
+// ==--
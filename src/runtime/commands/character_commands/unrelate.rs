@@ -0,0 +1,63 @@
+use crate::{
+    runtime::{
+        commands::{
+            query_error_embed, resolve_user_character, respond_with_embed, string_option,
+            sub_command_options, user_option
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts::{self, models::RelationshipKind}
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let invoker_id = interaction_data.user.id.get();
+
+    let sub_options = sub_command_options(&interaction_data);
+    let character_option = string_option(&sub_options, "character")
+        .expect("`character` is a required option");
+    let player = user_option(&sub_options, "player")
+        .expect("`player` is a required option");
+    let other_option = string_option(&sub_options, "other")
+        .expect("`other` is a required option");
+    let kind = string_option(&sub_options, "kind").and_then(RelationshipKind::from_key);
+
+    let response_embed = 'response_embed: {
+
+        // Either side may end a relationship on their own
+        let Some((character_id, character_name)) = resolve_user_character(&ctx, invoker_id, character_option).await else {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "You don't have a character called `{character_option}`" ))
+                .colour(ColourCode::Info.to_embed_colour());
+        };
+        let Some((other_id, other_name)) = resolve_user_character(&ctx, player.id.get(), other_option).await else {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "{} doesn't have a character called `{other_option}`", player.name ))
+                .colour(ColourCode::Info.to_embed_colour());
+        };
+
+        match sql_scripts::relationships::remove_between(&runtime_client.database_connection, character_id, other_id, kind).await {
+            Ok(0) => CreateEmbed::new()
+                .title(format!( "{character_name} and {other_name} weren't related that way" ))
+                .colour(ColourCode::Info.to_embed_colour()),
+            Ok(removed_count) => CreateEmbed::new()
+                .title(format!( "{character_name} and {other_name} parted ways" ))
+                .description(format!( "{removed_count} relationship(s) removed" ))
+                .colour(ColourCode::Success.to_embed_colour()),
+            Err(query_err) => query_error_embed("character::unrelate::remove_between", "remove the relationship", &query_err)
+        }
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, false, "character::unrelate").await;
+}
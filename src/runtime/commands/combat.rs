@@ -0,0 +1,292 @@
+use crate::{
+    runtime::{
+        commands::{self, combat_commands},
+        runtime_client::RuntimeClient,
+        sql_scripts::models::{Encounter, EncounterEntry}
+    },
+    utils::misc::{
+        colour_codes::ColourCode, logging::create_log_message
+    }
+};
+
+use serenity::{
+    builder::{
+        CreateActionRow,
+        CreateButton,
+        CreateCommand,
+        CreateCommandOption,
+        CreateEmbed,
+        EditMessage
+    },
+    model::{
+        application::{
+            ButtonStyle,
+            CommandInteraction,
+            CommandOptionType,
+            ComponentInteraction
+        },
+        id::{ChannelId, MessageId}
+    },
+    client::Context
+};
+
+/// How far an NPC's dexterity or fixed initiative may go either way, keeping rolls well clear of
+/// overflowing
+pub const MAX_INITIATIVE_MODIFIER: i64 = 100;
+
+/// Whether the user may run the encounter, which its GM does and server managers may step in for
+pub fn runs_encounter( encounter: &Encounter, user_id: u64, is_server_manager: bool ) -> bool {
+    encounter.gm.get() == user_id || is_server_manager
+}
+
+pub fn build() -> CreateCommand {
+    CreateCommand::new("combat")
+        .description("Keep track of turn order during a fight")
+        .dm_permission(false)
+        .set_options(vec![
+                CreateCommandOption::new(
+                    CommandOptionType::SubCommand,
+                    "start",
+                    "Start a combat encounter in this channel, with you as its GM"
+                ),
+                CreateCommandOption::new(
+                    CommandOptionType::SubCommand,
+                    "join",
+                    "Roll initiative for one of your characters"
+                )
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::String, "character", "The character joining the fight")
+                            .required(true)
+                            .set_autocomplete(true)
+                    ),
+                CreateCommandOption::new(
+                    CommandOptionType::SubCommand,
+                    "npc",
+                    "Add a non-player combatant, GM only"
+                )
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::String, "name", "What the combatant is called")
+                            .required(true)
+                            .max_length(100)
+                    )
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::Integer, "dexterity", "Added to the initiative roll, and breaks ties")
+                            // Negative bounds can only be set through the number variant
+                            .min_number_value(-MAX_INITIATIVE_MODIFIER as f64)
+                            .max_int_value(MAX_INITIATIVE_MODIFIER as u64)
+                    )
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::Integer, "initiative", "Use this initiative instead of rolling")
+                            .min_number_value(-MAX_INITIATIVE_MODIFIER as f64)
+                            .max_int_value(MAX_INITIATIVE_MODIFIER as u64)
+                    ),
+                CreateCommandOption::new(
+                    CommandOptionType::SubCommand,
+                    "next",
+                    "Move on to the next turn"
+                ),
+                CreateCommandOption::new(
+                    CommandOptionType::SubCommand,
+                    "end",
+                    "End this channel's combat encounter, GM only"
+                )
+        ])
+}
+
+
+pub async fn run( runtime_client: &RuntimeClient, ctx: Context, interaction_data: CommandInteraction ) {
+    let sub_command_name = &interaction_data
+        .data
+        .options[0] // Because this command has sub commands, we know that there exists a 0th
+                    // option, and that it contains the name of the selected sub command
+        .name;
+
+
+    // Based on the sub command's name, delegate to the correct execution unit
+    match sub_command_name.as_str() {
+        "start" => combat_commands::start::run(runtime_client, ctx, interaction_data).await,
+        "join"  => combat_commands::join::run(runtime_client, ctx, interaction_data).await,
+        "npc"   => combat_commands::npc::run(runtime_client, ctx, interaction_data).await,
+        "next"  => combat_commands::next::run(runtime_client, ctx, interaction_data).await,
+        "end"   => combat_commands::end::run(runtime_client, ctx, interaction_data).await,
+        // In the case that an unknown sub command is recived, inform the terminal of this
+        // occurance
+        unimplemented_subcommand => println!( "{}", create_log_message(
+                format!(
+                    "{}combat{}: Recieved unknown sub command: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    unimplemented_subcommand,
+                    ColourCode::Reset
+                ),
+                ColourCode::Warning
+        ))
+    }
+}
+
+
+pub async fn autocomplete( ctx: Context, interaction_data: CommandInteraction ) {
+    // `join` is the only sub command with an autocompleted option, and it takes a character
+    commands::autocomplete_user_characters(&ctx, &interaction_data).await;
+}
+
+
+pub async fn handle_component_interaction( runtime_client: &RuntimeClient, interaction_data: ComponentInteraction, ctx: Context, split_custom_id: Vec<&str> ) {
+    match split_custom_id[1] {
+        "next" | "previous" => combat_commands::next::handle_component(runtime_client, interaction_data, ctx, split_custom_id).await,
+        unknown_component => println!( "{}", create_log_message(
+                format!(
+                    "{}combat{}: Recieved unknown component: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    unknown_component,
+                    ColourCode::Reset
+                ),
+                ColourCode::Warning
+        ))
+    }
+}
+
+
+
+// --== TURN ORDER ==-- //
+
+    /// Works out the round and entry after moving one turn forwards or backwards through `entries`,
+    /// which have to be in turn order.
+    ///
+    /// Moving on from the last entry starts the next round. Should the current entry be unknown,
+    /// as it is before the first turn or after the entry left, the first entry of the round is up
+    pub fn step_turn( entries: &[EncounterEntry], current_entry: Option<u64>, round: i64, forwards: bool ) -> (i64, Option<u64>) {
+        let (Some(first_entry), Some(last_entry)) = (entries.first(), entries.last()) else {
+            return (round, None);
+        };
+
+        let Some(current_position) = entries.iter().position(|entry| Some(entry.id) == current_entry) else {
+            return (round, Some(first_entry.id));
+        };
+
+        match (forwards, entries.get(current_position + 1)) {
+            (true, Some(next_entry)) => (round, Some(next_entry.id)),
+            (true, None) => (round + 1, Some(first_entry.id)),
+
+            (false, _) if current_position > 0 => (round, Some(entries[current_position - 1].id)),
+            (false, _) if round > 1 => (round - 1, Some(last_entry.id)),
+            // There is nothing before the first turn of the first round
+            (false, _) => (round, current_entry)
+        }
+    }
+// ==--
+
+// --== TRACKER ==-- //
+
+    // The tracker is the pinned message showing the turn order. It's sent by `/combat start` and
+    // edited whenever the order or the turn changes. Its buttons follow the format:
+    //     combat|next|encounter_id    combat|previous|encounter_id
+
+    /// Builds the embed and buttons of an encounter's tracker
+    pub fn tracker_message( encounter: &Encounter, entries: &[EncounterEntry] ) -> (CreateEmbed, Vec<CreateActionRow>) {
+        let mut turn_order = String::new();
+        for entry in entries {
+            let player = entry.owner.map_or(String::new(), |owner| format!( " <@{}>", owner.get() ));
+
+            if Some(entry.id) == encounter.current_entry {
+                turn_order.push_str(&format!( "**▶ {}** `{}`{player}\n", entry.name, entry.initiative ));
+            } else {
+                turn_order.push_str(&format!( "- {} `{}`{player}\n", entry.name, entry.initiative ));
+            }
+        }
+        if entries.is_empty() {
+            turn_order.push_str("Nobody has joined yet, `/combat join` with one of your characters");
+        } else if encounter.current_entry.is_none() {
+            turn_order.push_str("\nPress `Next` to begin");
+        }
+
+        let tracker_embed = CreateEmbed::new()
+            .title(format!( "Combat, round {}", encounter.round ))
+            .description(turn_order)
+            .field("GM", format!( "<@{}>", encounter.gm.get() ), true)
+            .colour(ColourCode::Location.to_embed_colour());
+
+        let tracker_buttons = CreateActionRow::Buttons(vec![
+            CreateButton::new(format!( "combat|previous|{}", encounter.id ))
+                .style(ButtonStyle::Secondary)
+                .label("Previous"),
+            CreateButton::new(format!( "combat|next|{}", encounter.id ))
+                .style(ButtonStyle::Primary)
+                .label("Next"),
+        ]);
+
+        (tracker_embed, vec![ tracker_buttons ])
+    }
+
+    /// Edits the pinned tracker of an encounter to show its current state. Failing to do so is
+    /// only logged, the encounter itself is already up to date in the database
+    pub async fn refresh_tracker( ctx: &Context, encounter: &Encounter, entries: &[EncounterEntry] ) {
+        let Some(tracker_message_id) = encounter.tracker_message_id else {
+            return;
+        };
+
+        let (tracker_embed, tracker_buttons) = tracker_message(encounter, entries);
+        let edit_tracker = ChannelId::new(encounter.channel_id.get()).edit_message(
+            &ctx.http,
+            MessageId::new(tracker_message_id.get()),
+            EditMessage::new()
+                .embed(tracker_embed)
+                .components(tracker_buttons)
+        );
+
+        if let Err(why) = edit_tracker.await {
+            println!( "{}", create_log_message(
+                    format!(
+                        "{}combat::refresh_tracker{}: Failed to edit tracker: `{}{}{}`",
+                        ColourCode::Location,
+                        ColourCode::Reset,
+                        ColourCode::Info,
+                        why,
+                        ColourCode::Reset
+                    ),
+                    ColourCode::Caution
+            ));
+        }
+    }
+// ==--
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::sql_scripts::models::DiscordId;
+
+    fn entry( id: u64 ) -> EncounterEntry {
+        EncounterEntry { id, character_id: None, owner: None, name: format!( "NPC {id}" ), initiative: 10, dexterity: 0 }
+    }
+
+    #[test]
+    fn turns_wrap_into_new_rounds() {
+        let entries = [ entry(3), entry(1), entry(2) ];
+
+        assert_eq!(step_turn(&[], None, 1, true), (1, None));
+        assert_eq!(step_turn(&entries, None, 1, true), (1, Some(3)));
+        assert_eq!(step_turn(&entries, Some(1), 1, true), (1, Some(2)));
+        assert_eq!(step_turn(&entries, Some(2), 1, true), (2, Some(3)));
+
+        assert_eq!(step_turn(&entries, Some(3), 2, false), (1, Some(2)));
+        assert_eq!(step_turn(&entries, Some(2), 1, false), (1, Some(1)));
+        assert_eq!(step_turn(&entries, Some(3), 1, false), (1, Some(3)));
+    }
+
+    #[test]
+    fn gms_and_server_managers_run_encounters() {
+        let encounter = Encounter {
+            id: 1, channel_id: DiscordId(10), gm: DiscordId(20), tracker_message_id: None,
+            round: 1, current_entry: None, started_at: 0
+        };
+
+        assert!(runs_encounter(&encounter, 20, false));
+        assert!(runs_encounter(&encounter, 30, true));
+        assert!(!runs_encounter(&encounter, 30, false));
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{
+    runtime::{
+        commands::{combat::runs_encounter, is_server_manager, query_error_embed, respond_with_embed},
+        runtime_client::RuntimeClient,
+        sql_scripts
+    },
+    utils::misc::{colour_codes::ColourCode, logging::create_log_message},
+};
+
+use serenity::{
+    builder::{CreateEmbed, EditMessage},
+    client::Context,
+    model::{
+        application::CommandInteraction,
+        id::{ChannelId, MessageId}
+    },
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let invoker_id = interaction_data.user.id.get();
+    let is_server_manager = is_server_manager(interaction_data.member.as_deref());
+
+    let response_embed = 'response_embed: {
+
+        let encounter = match sql_scripts::encounters::get_by_channel(&runtime_client.database_connection, interaction_data.channel_id.get()).await {
+            Ok(Some(encounter)) => encounter,
+            Ok(None) => {
+                break 'response_embed CreateEmbed::new()
+                    .title("There's no fight in this channel")
+                    .colour(ColourCode::Info.to_embed_colour());
+            },
+            Err(query_err) => break 'response_embed query_error_embed(
+                "combat::end::get_by_channel", "find this channel's fight", &query_err
+            )
+        };
+
+        if !runs_encounter(&encounter, invoker_id, is_server_manager) {
+            break 'response_embed CreateEmbed::new()
+                .title("Only the GM of this fight can end it")
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        if let Err(query_err) = sql_scripts::encounters::remove_encounter(&runtime_client.database_connection, encounter.id).await {
+            break 'response_embed query_error_embed("combat::end::remove_encounter", "end the fight", &query_err);
+        }
+
+        // --== RETIRE TRACKER ==-- //
+
+            if let Some(tracker_message_id) = encounter.tracker_message_id {
+                let (channel_id, message_id) = (
+                    ChannelId::new(encounter.channel_id.get()),
+                    MessageId::new(tracker_message_id.get())
+                );
+
+                let retired_tracker = EditMessage::new()
+                    .embed(
+                        CreateEmbed::new()
+                            .title("Combat has ended")
+                            .description(format!( "The fight lasted {} round(s)", encounter.round ))
+                            .colour(ColourCode::Info.to_embed_colour())
+                    )
+                    .components(vec![]);
+
+                // Both of these only tidy up, so failing them is no reason to bother the GM
+                let retire_results = [
+                    channel_id.edit_message(&ctx.http, message_id, retired_tracker).await.map(|_| ()),
+                    channel_id.unpin(&ctx.http, message_id).await
+                ];
+                for why in retire_results.into_iter().filter_map(Result::err) {
+                    println!( "{}", create_log_message(
+                            format!(
+                                "{}combat::end{}: Failed to retire tracker: `{}{}{}`",
+                                ColourCode::Location,
+                                ColourCode::Reset,
+                                ColourCode::Info,
+                                why,
+                                ColourCode::Reset
+                            ),
+                            ColourCode::Caution
+                    ));
+                }
+            }
+        // ==--
+
+        CreateEmbed::new()
+            .title("The fight is over")
+            .description(format!( "It lasted {} round(s)", encounter.round ))
+            .colour(ColourCode::Success.to_embed_colour())
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, false, "combat::end").await;
+}
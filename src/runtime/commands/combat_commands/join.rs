@@ -0,0 +1,113 @@
+use crate::{
+    runtime::{
+        commands::{
+            combat::refresh_tracker, query_error_embed, resolve_user_character,
+            respond_with_embed, string_option, sub_command_options, unapproved_character_embed
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts::{self, models::CharacterStatus}
+    },
+    utils::misc::{colour_codes::ColourCode, dice::roll_die},
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let invoker_id = interaction_data.user.id.get();
+    let database_conn_pool = &runtime_client.database_connection;
+
+    let sub_options = sub_command_options(&interaction_data);
+    let character_option = string_option(&sub_options, "character")
+        .expect("`character` is a required option");
+
+    let response_embed = 'response_embed: {
+
+        let encounter = match sql_scripts::encounters::get_by_channel(database_conn_pool, interaction_data.channel_id.get()).await {
+            Ok(Some(encounter)) => encounter,
+            Ok(None) => {
+                break 'response_embed CreateEmbed::new()
+                    .title("There's no fight in this channel")
+                    .description("A GM can start one with `/combat start`")
+                    .colour(ColourCode::Info.to_embed_colour());
+            },
+            Err(query_err) => break 'response_embed query_error_embed(
+                "combat::join::get_by_channel", "find this channel's fight", &query_err
+            )
+        };
+
+        let Some((character_id, character_name)) = resolve_user_character(&ctx, invoker_id, character_option).await else {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "You don't have a character called `{character_option}`" ))
+                .colour(ColourCode::Info.to_embed_colour());
+        };
+
+        // Characters still under review can't take part in anything yet
+        match sql_scripts::characters::get_character(database_conn_pool, character_id).await {
+            Ok(Some(character)) if character.status != CharacterStatus::Approved => {
+                break 'response_embed unapproved_character_embed(&character_name, character.status);
+            },
+            Ok(_) => {},
+            Err(query_err) => break 'response_embed query_error_embed(
+                "combat::join::get_character", "check the character's review", &query_err
+            )
+        }
+
+        // --== ROLL INITIATIVE ==-- //
+
+            // Characters without stats roll a flat d20
+            let dexterity = match sql_scripts::character_stats::get_effective_stats(database_conn_pool, character_id).await {
+                Ok(stats) => stats.map_or(0, |stats| stats.dexterity),
+                Err(query_err) => break 'response_embed query_error_embed(
+                    "combat::join::get_stats", "fetch your character's dexterity", &query_err
+                )
+            };
+
+            let die_roll = roll_die(20);
+            let initiative = die_roll + dexterity;
+        // ==--
+
+        let add_entry_query = sql_scripts::encounters::add_entry(
+            database_conn_pool,
+            encounter.id,
+            Some(character_id),
+            &character_name,
+            initiative,
+            dexterity
+        );
+
+        match add_entry_query.await {
+            Ok(_) => {},
+            Err(query_err) if query_err.as_database_error().is_some_and(|db_err| db_err.is_unique_violation()) => {
+                break 'response_embed CreateEmbed::new()
+                    .title(format!( "{character_name} is already in this fight" ))
+                    .colour(ColourCode::Info.to_embed_colour());
+            },
+            Err(query_err) => break 'response_embed query_error_embed(
+                "combat::join::add_entry", "add your character to the fight", &query_err
+            )
+        }
+
+        match sql_scripts::encounters::get_entries(database_conn_pool, encounter.id).await {
+            Ok(entries) => refresh_tracker(&ctx, &encounter, &entries).await,
+            Err(query_err) => break 'response_embed query_error_embed(
+                "combat::join::get_entries", "update the turn order", &query_err
+            )
+        }
+
+        CreateEmbed::new()
+            .title(format!( "{character_name} joins the fight!" ))
+            .description(format!( "Initiative: `{die_roll}` + `{dexterity}` dexterity = **{initiative}**" ))
+            .colour(ColourCode::Success.to_embed_colour())
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, false, "combat::join").await;
+}
@@ -0,0 +1,5 @@
+pub mod end;
+pub mod join;
+pub mod next;
+pub mod npc;
+pub mod start;
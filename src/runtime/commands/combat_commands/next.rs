@@ -0,0 +1,166 @@
+use crate::{
+    runtime::{
+        commands::{
+            combat::{refresh_tracker, runs_encounter, step_turn, tracker_message},
+            is_server_manager, query_error_embed, respond_with_embed
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts::{self, models::{Encounter, EncounterEntry}}
+    },
+    utils::misc::{colour_codes::ColourCode, logging::create_log_message},
+};
+
+use serenity::{
+    builder::{CreateEmbed, CreateInteractionResponse, CreateInteractionResponseMessage},
+    client::Context,
+    model::application::{CommandInteraction, ComponentInteraction},
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let is_server_manager = is_server_manager(interaction_data.member.as_deref());
+
+    let response_embed = 'response_embed: {
+
+        let encounter = match sql_scripts::encounters::get_by_channel(&runtime_client.database_connection, interaction_data.channel_id.get()).await {
+            Ok(Some(encounter)) => encounter,
+            Ok(None) => {
+                break 'response_embed CreateEmbed::new()
+                    .title("There's no fight in this channel")
+                    .colour(ColourCode::Info.to_embed_colour());
+            },
+            Err(query_err) => break 'response_embed query_error_embed(
+                "combat::next::get_by_channel", "find this channel's fight", &query_err
+            )
+        };
+
+        let (encounter, entries) = match advance_turn(runtime_client, encounter, interaction_data.user.id.get(), is_server_manager, true).await {
+            Ok(advanced_encounter) => advanced_encounter,
+            Err(refusal_embed) => break 'response_embed refusal_embed
+        };
+        refresh_tracker(&ctx, &encounter, &entries).await;
+
+        let Some(current_entry) = entries.iter().find(|entry| Some(entry.id) == encounter.current_entry) else {
+            break 'response_embed CreateEmbed::new()
+                .title("Nobody has joined this fight yet")
+                .colour(ColourCode::Info.to_embed_colour());
+        };
+
+        CreateEmbed::new()
+            .title(format!( "Round {}: {}'s turn", encounter.round, current_entry.name ))
+            .description(current_entry.owner.map_or(String::new(), |owner| format!( "<@{}>, you're up", owner.get() )))
+            .colour(ColourCode::Location.to_embed_colour())
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, false, "combat::next").await;
+}
+
+
+pub async fn handle_component(
+    runtime_client: &RuntimeClient,
+    component_interaction: ComponentInteraction,
+    ctx: Context,
+    split_custom_id: Vec<&str>,
+) {
+    let is_forwards = split_custom_id[1] == "next";
+    let is_server_manager = is_server_manager(component_interaction.member.as_ref());
+
+    let encounter_id = split_custom_id.get(2)
+        .and_then(|encounter_id| encounter_id.parse::<u64>().ok());
+
+    let encounter = match encounter_id {
+        Some(encounter_id) => sql_scripts::encounters::get_encounter(&runtime_client.database_connection, encounter_id).await,
+        None => Ok(None)
+    };
+
+    let response = match encounter {
+        // The tracker outlived its fight, so retire its buttons
+        Ok(None) => CreateInteractionResponse::UpdateMessage(
+            CreateInteractionResponseMessage::new()
+                .embed(
+                    CreateEmbed::new()
+                        .title("This fight has ended")
+                        .colour(ColourCode::Info.to_embed_colour())
+                )
+                .components(vec![])
+        ),
+
+        Ok(Some(encounter)) => match advance_turn(runtime_client, encounter, component_interaction.user.id.get(), is_server_manager, is_forwards).await {
+            Ok((encounter, entries)) => {
+                let (tracker_embed, tracker_buttons) = tracker_message(&encounter, &entries);
+
+                CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new()
+                        .embed(tracker_embed)
+                        .components(tracker_buttons)
+                )
+            },
+            Err(refusal_embed) => CreateInteractionResponse::Message(
+                CreateInteractionResponseMessage::new()
+                    .embed(refusal_embed)
+                    .ephemeral(true)
+            )
+        },
+
+        Err(query_err) => CreateInteractionResponse::Message(
+            CreateInteractionResponseMessage::new()
+                .embed(query_error_embed("combat::next::handle_component", "find this fight", &query_err))
+                .ephemeral(true)
+        )
+    };
+
+    if let Err(response_send_err) = component_interaction.create_response(&ctx.http, response).await {
+        println!( "{}", create_log_message(
+                format!(
+                    "{}combat::next::handle_component{}: Failed to send response: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    response_send_err,
+                    ColourCode::Reset
+                ),
+                ColourCode::Error
+        ));
+    }
+}
+
+
+/// Moves an encounter one turn forwards or backwards, on behalf of `user_id`.
+///
+/// The GM may move the turn either way, whoever's turn it is may only end it. On success returns
+/// the updated encounter along with its entries in turn order, otherwise the embed to refuse with
+async fn advance_turn( runtime_client: &RuntimeClient, mut encounter: Encounter, user_id: u64, is_server_manager: bool, forwards: bool ) -> Result<(Encounter, Vec<EncounterEntry>), CreateEmbed> {
+    let database_conn_pool = &runtime_client.database_connection;
+
+    let entries = sql_scripts::encounters::get_entries(database_conn_pool, encounter.id)
+        .await
+        .map_err(|query_err| query_error_embed("combat::next::get_entries", "fetch the turn order", &query_err))?;
+
+    let is_gm = runs_encounter(&encounter, user_id, is_server_manager);
+    let is_current_player = entries.iter()
+        .find(|entry| Some(entry.id) == encounter.current_entry)
+        .and_then(|entry| entry.owner)
+        .is_some_and(|owner| owner.get() == user_id);
+
+    let may_advance = is_gm || (forwards && is_current_player);
+    if !may_advance {
+        return Err(
+            CreateEmbed::new()
+                .title(if forwards { "It's not your turn" } else { "Only the GM can go back a turn" })
+                .colour(ColourCode::Info.to_embed_colour())
+        );
+    }
+
+    let (round, current_entry) = step_turn(&entries, encounter.current_entry, encounter.round, forwards);
+    sql_scripts::encounters::set_turn(database_conn_pool, encounter.id, round, current_entry)
+        .await
+        .map_err(|query_err| query_error_embed("combat::next::set_turn", "move on to the next turn", &query_err))?;
+
+    encounter.round = round;
+    encounter.current_entry = current_entry;
+    Ok((encounter, entries))
+}
@@ -0,0 +1,84 @@
+use crate::{
+    runtime::{
+        commands::{
+            combat::{refresh_tracker, runs_encounter}, integer_option, is_server_manager,
+            query_error_embed, respond_with_embed, string_option, sub_command_options
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts
+    },
+    utils::misc::{colour_codes::ColourCode, dice::roll_die},
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let invoker_id = interaction_data.user.id.get();
+    let is_server_manager = is_server_manager(interaction_data.member.as_deref());
+    let database_conn_pool = &runtime_client.database_connection;
+
+    let sub_options = sub_command_options(&interaction_data);
+    let npc_name = string_option(&sub_options, "name")
+        .expect("`name` is a required option")
+        .trim()
+        .to_string();
+    let dexterity = integer_option(&sub_options, "dexterity").unwrap_or(0);
+    let fixed_initiative = integer_option(&sub_options, "initiative");
+
+    let response_embed = 'response_embed: {
+
+        let encounter = match sql_scripts::encounters::get_by_channel(database_conn_pool, interaction_data.channel_id.get()).await {
+            Ok(Some(encounter)) => encounter,
+            Ok(None) => {
+                break 'response_embed CreateEmbed::new()
+                    .title("There's no fight in this channel")
+                    .colour(ColourCode::Info.to_embed_colour());
+            },
+            Err(query_err) => break 'response_embed query_error_embed(
+                "combat::npc::get_by_channel", "find this channel's fight", &query_err
+            )
+        };
+
+        if !runs_encounter(&encounter, invoker_id, is_server_manager) {
+            break 'response_embed CreateEmbed::new()
+                .title("Only the GM of this fight can add NPCs")
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        let (initiative, roll_description) = match fixed_initiative {
+            Some(initiative) => (initiative, format!( "Initiative: **{initiative}**" )),
+            None => {
+                let die_roll = roll_die(20);
+                (die_roll + dexterity, format!( "Initiative: `{die_roll}` + `{dexterity}` dexterity = **{}**", die_roll + dexterity ))
+            }
+        };
+
+        if let Err(query_err) = sql_scripts::encounters::add_entry(database_conn_pool, encounter.id, None, &npc_name, initiative, dexterity).await {
+            break 'response_embed query_error_embed("combat::npc::add_entry", "add the NPC to the fight", &query_err);
+        }
+
+        match sql_scripts::encounters::get_entries(database_conn_pool, encounter.id).await {
+            Ok(entries) => refresh_tracker(&ctx, &encounter, &entries).await,
+            Err(query_err) => break 'response_embed query_error_embed(
+                "combat::npc::get_entries", "update the turn order", &query_err
+            )
+        }
+
+        CreateEmbed::new()
+            .title(format!( "{npc_name} joins the fight!" ))
+            .description(roll_description)
+            .colour(ColourCode::Success.to_embed_colour())
+
+    }; // let response_embed = {...}
+
+    // The GM's rolls stay behind the screen, the tracker shows where the NPC landed
+    respond_with_embed(&ctx, &interaction_data, response_embed, true, "combat::npc").await;
+}
@@ -0,0 +1,147 @@
+use crate::{
+    runtime::{
+        commands::{campaign::interaction_campaign, combat::tracker_message, query_error_embed, respond_with_embed},
+        preconditions::{self, Precondition},
+        runtime_client::RuntimeClient,
+        sql_scripts
+    },
+    utils::misc::{colour_codes::ColourCode, logging::create_log_message},
+};
+
+use serenity::{
+    builder::{CreateEmbed, CreateInteractionResponse, CreateInteractionResponseMessage},
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+// The GM of an encounter is referenced through their profile
+pub const PRECONDITIONS: &[Precondition] = &[ Precondition::RegisteredProfile ];
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let Some(precondition_notices) = preconditions::enforce(runtime_client, &ctx, &interaction_data, PRECONDITIONS).await else {
+        return;
+    };
+
+    let invoker_id = interaction_data.user.id.get();
+    let channel_id = interaction_data.channel_id.get();
+    let database_conn_pool = &runtime_client.database_connection;
+
+    let refusal_embed = 'refusal_embed: {
+
+        match sql_scripts::encounters::get_by_channel(database_conn_pool, channel_id).await {
+            Ok(None) => {},
+            Ok(Some(_)) => {
+                break 'refusal_embed CreateEmbed::new()
+                    .title("A fight is already underway in this channel")
+                    .description("Its GM has to `/combat end` it before another can start")
+                    .colour(ColourCode::Info.to_embed_colour());
+            },
+            Err(query_err) => break 'refusal_embed query_error_embed(
+                "combat::start::get_by_channel", "check for a fight in this channel", &query_err
+            )
+        }
+
+        // In a campaign's channel, running the fights is up to its GM
+        match interaction_campaign(runtime_client, &interaction_data).await {
+            Ok(Some(campaign)) if campaign.gm.get() != invoker_id => {
+                break 'refusal_embed CreateEmbed::new()
+                    .title(format!( "Only the GM of {} can start a fight here", campaign.name ))
+                    .colour(ColourCode::Info.to_embed_colour());
+            },
+            Ok(_) => {},
+            Err(query_err) => break 'refusal_embed query_error_embed(
+                "combat::start::interaction_campaign", "find this channel's campaign", &query_err
+            )
+        }
+
+        // --== INSERT ENCOUNTER ==-- //
+
+            let encounter = match sql_scripts::encounters::add_encounter(database_conn_pool, channel_id, invoker_id).await {
+                Ok(encounter_id) => sql_scripts::encounters::get_encounter(database_conn_pool, encounter_id).await,
+                Err(query_err) => Err(query_err)
+            };
+
+            let encounter = match encounter {
+                Ok(Some(encounter)) => encounter,
+                Ok(None) => unreachable!("The encounter was just inserted"),
+                Err(query_err) => break 'refusal_embed query_error_embed(
+                    "combat::start::add_encounter", "start the fight", &query_err
+                )
+            };
+        // ==--
+
+        // --== SEND & PIN TRACKER ==-- //
+
+            let (tracker_embed, tracker_buttons) = tracker_message(&encounter, &[]);
+            let tracker = CreateInteractionResponseMessage::new()
+                .embeds(precondition_notices)
+                .add_embed(tracker_embed)
+                .components(tracker_buttons);
+
+            if let Err(why) = interaction_data.create_response(&ctx.http, CreateInteractionResponse::Message(tracker)).await {
+                println!( "{}", create_log_message(
+                        format!(
+                            "{}combat::start{}: Failed to send tracker: `{}{}{}`",
+                            ColourCode::Location,
+                            ColourCode::Reset,
+                            ColourCode::Info,
+                            why,
+                            ColourCode::Reset
+                        ),
+                        ColourCode::Error
+                ));
+
+                // Without a tracker nobody can see the fight, so don't leave the channel blocked
+                let _ = sql_scripts::encounters::remove_encounter(database_conn_pool, encounter.id).await;
+                return;
+            }
+
+            let tracker_message = match interaction_data.get_response(&ctx.http).await {
+                Ok(tracker_message) => tracker_message,
+                Err(why) => {
+                    println!( "{}", create_log_message(
+                            format!(
+                                "{}combat::start{}: Failed to fetch tracker: `{}{}{}`",
+                                ColourCode::Location,
+                                ColourCode::Reset,
+                                ColourCode::Info,
+                                why,
+                                ColourCode::Reset
+                            ),
+                            ColourCode::Caution
+                    ));
+                    return;
+                }
+            };
+
+            if let Err(query_err) = sql_scripts::encounters::set_tracker_message(database_conn_pool, encounter.id, tracker_message.id.get()).await {
+                // The buttons still work, the tracker just won't follow `/combat` commands
+                let _ = query_error_embed("combat::start::set_tracker_message", "remember the tracker", &query_err);
+            }
+
+            // Pinning needs `Manage Messages`, which the bot may well not have been given
+            if let Err(why) = tracker_message.pin(&ctx.http).await {
+                println!( "{}", create_log_message(
+                        format!(
+                            "{}combat::start{}: Failed to pin tracker: `{}{}{}`",
+                            ColourCode::Location,
+                            ColourCode::Reset,
+                            ColourCode::Info,
+                            why,
+                            ColourCode::Reset
+                        ),
+                        ColourCode::Caution
+                ));
+            }
+        // ==--
+
+        return;
+
+    }; // let refusal_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, refusal_embed, true, "combat::start").await;
+}
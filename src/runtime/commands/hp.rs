@@ -0,0 +1,160 @@
+use crate::{
+    runtime::{
+        commands::{self, hp_commands},
+        runtime_client::RuntimeClient,
+        sql_scripts::models::CharacterHitPoints
+    },
+    utils::misc::{
+        colour_codes::ColourCode, logging::create_log_message
+    }
+};
+
+use serenity::{
+    builder::{
+        CreateCommand,
+        CreateCommandOption
+    },
+    model::application::{
+        CommandInteraction,
+        CommandOptionType
+    },
+    client::Context
+};
+
+pub fn build() -> CreateCommand {
+    // Every sub command acts on the invoker's selected character, unless they name another
+    let character_option = || CreateCommandOption::new(
+        CommandOptionType::String,
+        "character",
+        "Defaults to your selected character, GMs may pick any character in their campaign"
+    )
+        .set_autocomplete(true);
+
+    CreateCommand::new("hp")
+        .description("Keep track of your characters' health")
+        .set_options(vec![
+                CreateCommandOption::new(CommandOptionType::SubCommand, "damage", "Deal damage, temporary hit points are used up first")
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::Integer, "amount", "How much damage is dealt")
+                            .required(true)
+                            .min_int_value(1)
+                    )
+                    .add_sub_option(character_option()),
+                CreateCommandOption::new(CommandOptionType::SubCommand, "heal", "Restore hit points, up to the maximum")
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::Integer, "amount", "How much is healed")
+                            .required(true)
+                            .min_int_value(1)
+                    )
+                    .add_sub_option(character_option()),
+                CreateCommandOption::new(CommandOptionType::SubCommand, "set", "Set current or temporary hit points outright")
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::Integer, "current", "Current hit points")
+                            .min_int_value(0)
+                    )
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::Integer, "temporary", "Temporary hit points")
+                            .min_int_value(0)
+                    )
+                    .add_sub_option(character_option()),
+                CreateCommandOption::new(CommandOptionType::SubCommand, "status", "Show a character's hit points")
+                    .add_sub_option(character_option()),
+                CreateCommandOption::new(CommandOptionType::SubCommand, "history", "List the latest changes to a character's hit points")
+                    .add_sub_option(character_option()),
+                CreateCommandOption::new(CommandOptionType::SubCommand, "undo", "Revert the latest change to a character's hit points")
+                    .add_sub_option(character_option())
+        ])
+}
+
+
+pub async fn run( runtime_client: &RuntimeClient, ctx: Context, interaction_data: CommandInteraction ) {
+    let sub_command_name = &interaction_data
+        .data
+        .options[0] // Because this command has sub commands, we know that there exists a 0th
+                    // option, and that it contains the name of the selected sub command
+        .name;
+
+
+    // Based on the sub command's name, delegate to the correct execution unit
+    match sub_command_name.as_str() {
+        "damage" | "heal" | "set" => hp_commands::adjust::run(runtime_client, ctx, interaction_data).await,
+        "status"  => hp_commands::status::run(runtime_client, ctx, interaction_data).await,
+        "history" => hp_commands::history::run(runtime_client, ctx, interaction_data).await,
+        "undo"    => hp_commands::undo::run(runtime_client, ctx, interaction_data).await,
+        // In the case that an unknown sub command is recived, inform the terminal of this
+        // occurance
+        unimplemented_subcommand => println!( "{}", create_log_message(
+                format!(
+                    "{}hp{}: Recieved unknown sub command: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    unimplemented_subcommand,
+                    ColourCode::Reset
+                ),
+                ColourCode::Warning
+        ))
+    }
+}
+
+
+pub async fn autocomplete( runtime_client: &RuntimeClient, ctx: Context, interaction_data: CommandInteraction ) {
+    // Every autocompleted option of this command takes a character
+    commands::autocomplete_campaign_characters(runtime_client, &ctx, &interaction_data).await;
+}
+
+
+
+// --== STATUS DISPLAY ==-- //
+
+    const HEALTH_BAR_LENGTH: i64 = 10;
+
+    /// A compact line such as `▰▰▰▰▰▰▱▱▱▱ 12/20 HP +3 temporary`
+    pub fn status_line( hit_points: &CharacterHitPoints, max_hp: i64 ) -> String {
+        // Anyone still standing gets at least one segment
+        let mut filled_segments = (hit_points.current * HEALTH_BAR_LENGTH / max_hp).clamp(0, HEALTH_BAR_LENGTH);
+        if hit_points.current > 0 {
+            filled_segments = filled_segments.max(1);
+        }
+
+        let mut status_line = format!(
+            "{}{} **{}/{max_hp}** HP",
+            "▰".repeat(filled_segments as usize),
+            "▱".repeat((HEALTH_BAR_LENGTH - filled_segments) as usize),
+            hit_points.current
+        );
+        if hit_points.temporary > 0 {
+            status_line.push_str(&format!( " +{} temporary", hit_points.temporary ));
+        }
+        if hit_points.current == 0 {
+            status_line.push_str(", down!");
+        }
+
+        status_line
+    }
+
+    /// Picks an embed colour from how hurt the character is
+    pub fn status_colour( hit_points: &CharacterHitPoints, max_hp: i64 ) -> ColourCode {
+        match hit_points.current {
+            0 => ColourCode::Error,
+            current if current * 2 <= max_hp => ColourCode::Caution,
+            _ => ColourCode::Success
+        }
+    }
+// ==--
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_line_shows_health() {
+        let hit_points = |current, temporary| CharacterHitPoints { character_id: 1, current, temporary };
+
+        assert_eq!(status_line(&hit_points(20, 0), 20), "▰▰▰▰▰▰▰▰▰▰ **20/20** HP");
+        assert_eq!(status_line(&hit_points(1, 3), 20), "▰▱▱▱▱▱▱▱▱▱ **1/20** HP +3 temporary");
+        assert_eq!(status_line(&hit_points(0, 0), 20), "▱▱▱▱▱▱▱▱▱▱ **0/20** HP, down!");
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{
+    runtime::{
+        commands::{
+            hp::{status_colour, status_line},
+            integer_option, query_error_embed, resolve_character_target, respond_with_embed,
+            sub_command_options
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts::{self, hit_points::HitPointAdjustment}
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+/// Handles `/hp damage`, `/hp heal` and `/hp set`, which differ only in the adjustment they make
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let invoker_id = interaction_data.user.id.get();
+
+    let sub_command_name = interaction_data.data.options[0].name.as_str();
+    let sub_options = sub_command_options(&interaction_data);
+    let amount = integer_option(&sub_options, "amount");
+
+    let response_embed = 'response_embed: {
+
+        let adjustment = match (sub_command_name, amount) {
+            ("damage", Some(amount)) => HitPointAdjustment::Damage(amount),
+            ("heal", Some(amount)) => HitPointAdjustment::Heal(amount),
+            _ => HitPointAdjustment::Set {
+                current: integer_option(&sub_options, "current"),
+                temporary: integer_option(&sub_options, "temporary")
+            }
+        };
+
+        if adjustment == (HitPointAdjustment::Set { current: None, temporary: None }) {
+            break 'response_embed CreateEmbed::new()
+                .title("Nothing to set")
+                .description("Fill in `current`, `temporary`, or both")
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        let target = match resolve_character_target(runtime_client, &ctx, &interaction_data, "character").await {
+            Ok(target) => target,
+            Err(refusal_embed) => break 'response_embed refusal_embed
+        };
+
+        let adjust_query = sql_scripts::hit_points::adjust_hit_points(
+            &runtime_client.database_connection,
+            target.character_id,
+            invoker_id,
+            adjustment
+        );
+
+        let (previous, adjusted, max_hp) = match adjust_query.await {
+            Ok(adjusted_hit_points) => adjusted_hit_points,
+            Err(query_err) => break 'response_embed query_error_embed(
+                "hp::adjust::adjust_hit_points", "change the hit points", &query_err
+            )
+        };
+
+        let title = match adjustment {
+            HitPointAdjustment::Damage(amount) => format!( "{} takes {amount} damage", target.name ),
+            HitPointAdjustment::Heal(_) => format!( "{} heals {}", target.name, adjusted.current - previous.current ),
+            HitPointAdjustment::Set { .. } => format!( "{}'s hit points have been set", target.name )
+        };
+
+        // Let players know when their GM changed their character's hit points
+        let mut description = status_line(&adjusted, max_hp);
+        if target.owner_id != invoker_id {
+            description.push_str(&format!( "\n<@{}>", target.owner_id ));
+        }
+
+        CreateEmbed::new()
+            .title(title)
+            .description(description)
+            .colour(status_colour(&adjusted, max_hp).to_embed_colour())
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, false, "hp::adjust").await;
+}
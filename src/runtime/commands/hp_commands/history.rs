@@ -0,0 +1,73 @@
+use crate::{
+    runtime::{
+        commands::{query_error_embed, resolve_character_target, respond_with_embed},
+        runtime_client::RuntimeClient,
+        sql_scripts
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+const HISTORY_LENGTH: u32 = 15;
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let response_embed = 'response_embed: {
+
+        let target = match resolve_character_target(runtime_client, &ctx, &interaction_data, "character").await {
+            Ok(target) => target,
+            Err(refusal_embed) => break 'response_embed refusal_embed
+        };
+
+        let history = match sql_scripts::hit_points::get_history(&runtime_client.database_connection, target.character_id, HISTORY_LENGTH).await {
+            Ok(history) => history,
+            Err(query_err) => break 'response_embed query_error_embed(
+                "hp::history::get_history", "fetch the hit point history", &query_err
+            )
+        };
+
+        let mut history_description = String::new();
+        for change in &history {
+            let action = match change.amount {
+                Some(amount) => format!( "{} {amount}", change.kind ),
+                None => change.kind.clone()
+            };
+
+            let history_line = format!(
+                "<t:{}:R> **{action}** by <@{}>: {} → {} HP, {} → {} temporary",
+                change.changed_at,
+                change.changed_by.get(),
+                change.previous_hp,
+                change.new_hp,
+                change.previous_temporary_hp,
+                change.new_temporary_hp
+            );
+
+            // Undone changes stay on record, struck through
+            if change.is_undone {
+                history_description.push_str(&format!( "- ~~{history_line}~~\n" ));
+            } else {
+                history_description.push_str(&format!( "- {history_line}\n" ));
+            }
+        }
+        if history.is_empty() {
+            history_description.push_str("No changes have been made yet");
+        }
+
+        CreateEmbed::new()
+            .title(format!( "{}'s hit point history", target.name ))
+            .description(history_description)
+            .colour(ColourCode::Info.to_embed_colour())
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, true, "hp::history").await;
+}
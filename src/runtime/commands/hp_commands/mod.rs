@@ -0,0 +1,4 @@
+pub mod adjust;
+pub mod history;
+pub mod status;
+pub mod undo;
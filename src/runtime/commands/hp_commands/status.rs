@@ -0,0 +1,41 @@
+use crate::{
+    runtime::{
+        commands::{
+            hp::{status_colour, status_line},
+            query_error_embed, resolve_character_target, respond_with_embed
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts
+    },
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let response_embed = 'response_embed: {
+
+        let target = match resolve_character_target(runtime_client, &ctx, &interaction_data, "character").await {
+            Ok(target) => target,
+            Err(refusal_embed) => break 'response_embed refusal_embed
+        };
+
+        match sql_scripts::hit_points::get_hit_points(&runtime_client.database_connection, target.character_id).await {
+            Ok((hit_points, max_hp)) => CreateEmbed::new()
+                .title(target.name)
+                .description(status_line(&hit_points, max_hp))
+                .colour(status_colour(&hit_points, max_hp).to_embed_colour()),
+            Err(query_err) => query_error_embed("hp::status::get_hit_points", "fetch the hit points", &query_err)
+        }
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, false, "hp::status").await;
+}
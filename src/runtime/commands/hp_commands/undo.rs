@@ -0,0 +1,61 @@
+use crate::{
+    runtime::{
+        commands::{
+            hp::{status_colour, status_line},
+            query_error_embed, resolve_character_target, respond_with_embed
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let response_embed = 'response_embed: {
+
+        let target = match resolve_character_target(runtime_client, &ctx, &interaction_data, "character").await {
+            Ok(target) => target,
+            Err(refusal_embed) => break 'response_embed refusal_embed
+        };
+
+        // Once a character plays in a GM's campaign, correcting the record is up to the GM
+        if target.in_campaign && !target.is_gm {
+            break 'response_embed CreateEmbed::new()
+                .title("Only the GM of this campaign can undo changes")
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        let undone_change = match sql_scripts::hit_points::undo_last_change(&runtime_client.database_connection, target.character_id, interaction_data.user.id.get()).await {
+            Ok(Some(undone_change)) => undone_change,
+            Ok(None) => {
+                break 'response_embed CreateEmbed::new()
+                    .title(format!( "There's nothing left to undo for {}", target.name ))
+                    .colour(ColourCode::Info.to_embed_colour());
+            },
+            Err(query_err) => break 'response_embed query_error_embed(
+                "hp::undo::undo_last_change", "undo the change", &query_err
+            )
+        };
+
+        match sql_scripts::hit_points::get_hit_points(&runtime_client.database_connection, target.character_id).await {
+            Ok((hit_points, max_hp)) => CreateEmbed::new()
+                .title(format!( "Undid the {} to {}'s hit points", undone_change.kind, target.name ))
+                .description(status_line(&hit_points, max_hp))
+                .colour(status_colour(&hit_points, max_hp).to_embed_colour()),
+            Err(query_err) => query_error_embed("hp::undo::get_hit_points", "fetch the hit points", &query_err)
+        }
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, false, "hp::undo").await;
+}
@@ -0,0 +1,165 @@
+use crate::{
+    runtime::{
+        commands::{self, inventory_commands},
+        runtime_client::RuntimeClient,
+        sql_scripts
+    },
+    utils::misc::{
+        colour_codes::ColourCode, logging::create_log_message
+    }
+};
+
+use serenity::{
+    builder::{
+        CreateAutocompleteResponse,
+        CreateCommand,
+        CreateCommandOption,
+        CreateInteractionResponse
+    },
+    model::application::{
+        CommandInteraction,
+        CommandOptionType,
+        ComponentInteraction
+    },
+    client::Context
+};
+
+pub fn build() -> CreateCommand {
+    let item_option = || CreateCommandOption::new(CommandOptionType::String, "item", "The item's name")
+        .required(true)
+        .max_length(100)
+        .set_autocomplete(true);
+    let quantity_option = || CreateCommandOption::new(CommandOptionType::Integer, "quantity", "How many, one by default")
+        .min_int_value(1);
+    let character_option = |option_name: &str| CreateCommandOption::new(
+        CommandOptionType::String,
+        option_name,
+        "Defaults to your selected character, GMs may pick any character in their campaign"
+    )
+        .set_autocomplete(true);
+
+    CreateCommand::new("inventory")
+        .description("Manage what your characters carry")
+        .set_options(vec![
+                CreateCommandOption::new(CommandOptionType::SubCommand, "add", "Put items into a character's inventory")
+                    .add_sub_option(item_option())
+                    .add_sub_option(quantity_option())
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::Number, "weight", "Weight of a single item, sets it for everyone, GMs and bot admins only")
+                            .min_number_value(0.0)
+                    )
+                    .add_sub_option(character_option("character")),
+                CreateCommandOption::new(CommandOptionType::SubCommand, "remove", "Take items out of a character's inventory")
+                    .add_sub_option(item_option())
+                    .add_sub_option(quantity_option())
+                    .add_sub_option(character_option("character")),
+                CreateCommandOption::new(CommandOptionType::SubCommand, "list", "Show what a character carries")
+                    .add_sub_option(character_option("character")),
+                CreateCommandOption::new(CommandOptionType::SubCommand, "give", "Hand items to another character, once both owners confirm")
+                    .add_sub_option(item_option())
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::User, "player", "Who the receiving character belongs to")
+                            .required(true)
+                    )
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::String, "recipient", "The character receiving the items")
+                            .required(true)
+                            .set_autocomplete(true)
+                    )
+                    .add_sub_option(quantity_option())
+                    .add_sub_option(character_option("from"))
+        ])
+}
+
+
+pub async fn run( runtime_client: &RuntimeClient, ctx: Context, interaction_data: CommandInteraction ) {
+    let sub_command_name = &interaction_data
+        .data
+        .options[0] // Because this command has sub commands, we know that there exists a 0th
+                    // option, and that it contains the name of the selected sub command
+        .name;
+
+
+    // Based on the sub command's name, delegate to the correct execution unit
+    match sub_command_name.as_str() {
+        "add"    => inventory_commands::add::run(runtime_client, ctx, interaction_data).await,
+        "remove" => inventory_commands::remove::run(runtime_client, ctx, interaction_data).await,
+        "list"   => inventory_commands::list::run(runtime_client, ctx, interaction_data).await,
+        "give"   => inventory_commands::give::run(runtime_client, ctx, interaction_data).await,
+        // In the case that an unknown sub command is recived, inform the terminal of this
+        // occurance
+        unimplemented_subcommand => println!( "{}", create_log_message(
+                format!(
+                    "{}inventory{}: Recieved unknown sub command: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    unimplemented_subcommand,
+                    ColourCode::Reset
+                ),
+                ColourCode::Warning
+        ))
+    }
+}
+
+
+pub async fn autocomplete( runtime_client: &RuntimeClient, ctx: Context, interaction_data: CommandInteraction ) {
+    let Some(focused_option) = interaction_data.data.autocomplete() else {
+        return;
+    };
+
+    match focused_option.name {
+        "item" => autocomplete_items(runtime_client, &ctx, &interaction_data).await,
+
+        // The receiving character belongs to whoever was picked as `player`
+        "recipient" => commands::autocomplete_player_characters(&ctx, &interaction_data, "player").await,
+
+        _ => commands::autocomplete_campaign_characters(runtime_client, &ctx, &interaction_data).await
+    }
+}
+
+/// Offers the items of the catalogue whose names contain what's been typed so far
+async fn autocomplete_items( runtime_client: &RuntimeClient, ctx: &Context, interaction_data: &CommandInteraction ) {
+    let typed_so_far = commands::typed_so_far(interaction_data);
+
+    // Discord caps autocomplete responses at 25 choices
+    let matching_items = sql_scripts::items::search_items(&runtime_client.database_connection, &typed_so_far, 25)
+        .await
+        .unwrap_or_default();
+
+    let mut autocomplete_response = CreateAutocompleteResponse::new();
+    for item in matching_items {
+        autocomplete_response = autocomplete_response.add_string_choice(item.name.clone(), item.name);
+    }
+
+    let send_choices = interaction_data.create_response(&ctx.http, CreateInteractionResponse::Autocomplete(autocomplete_response));
+    if let Err(why) = send_choices.await {
+        println!( "{}", create_log_message(
+                format!(
+                    "Failed to send autocomplete choices: `{}{}{}`",
+                    ColourCode::Info,
+                    why,
+                    ColourCode::Reset
+                ),
+                ColourCode::Caution
+        ));
+    }
+}
+
+
+pub async fn handle_component_interaction( runtime_client: &RuntimeClient, interaction_data: ComponentInteraction, ctx: Context, split_custom_id: Vec<&str> ) {
+    match split_custom_id[1] {
+        "transfer" => inventory_commands::give::handle_component(runtime_client, interaction_data, ctx, split_custom_id).await,
+        unknown_component => println!( "{}", create_log_message(
+                format!(
+                    "{}inventory{}: Recieved unknown component: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    unknown_component,
+                    ColourCode::Reset
+                ),
+                ColourCode::Warning
+        ))
+    }
+}
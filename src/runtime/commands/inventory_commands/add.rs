@@ -0,0 +1,121 @@
+use crate::{
+    runtime::{
+        commands::{
+            integer_option, is_bot_admin, number_option, query_error_embed, resolve_character_target,
+            respond_with_embed, string_option, sub_command_options
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts::{self, inventories::AddOutcome}
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let database_conn_pool = &runtime_client.database_connection;
+
+    let sub_options = sub_command_options(&interaction_data);
+    let item_name = string_option(&sub_options, "item")
+        .expect("`item` is a required option")
+        .trim()
+        .to_string();
+    let quantity = integer_option(&sub_options, "quantity").unwrap_or(1);
+    let weight = number_option(&sub_options, "weight");
+
+    let response_embed = 'response_embed: {
+
+        if item_name.is_empty() {
+            break 'response_embed CreateEmbed::new()
+                .title("Items need a name")
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        let target = match resolve_character_target(runtime_client, &ctx, &interaction_data, "character").await {
+            Ok(target) => target,
+            Err(refusal_embed) => break 'response_embed refusal_embed
+        };
+
+        // --== FIND ITEM ==-- //
+
+            // Every server shares the catalogue, and the carry limit goes by its weights. So only
+            // GMs and bot admins may set them, and only bot admins may make items weightless
+            let invoker_is_bot_admin = is_bot_admin(runtime_client, interaction_data.user.id.get());
+            match weight {
+                Some(_) if !target.is_gm && !invoker_is_bot_admin => break 'response_embed CreateEmbed::new()
+                    .title("Only GMs and bot admins can set what items weigh")
+                    .description("Leave out the weight to use the one the item already has")
+                    .colour(ColourCode::Info.to_embed_colour()),
+                Some(weight) if weight <= 0.0 && !invoker_is_bot_admin => break 'response_embed CreateEmbed::new()
+                    .title("Only bot admins can make items weightless")
+                    .colour(ColourCode::Info.to_embed_colour()),
+                _ => {}
+            }
+
+            let existing_item = match sql_scripts::items::get_item_by_name(database_conn_pool, &item_name).await {
+                Ok(existing_item) => existing_item,
+                Err(query_err) => break 'response_embed query_error_embed(
+                    "inventory::add::get_item_by_name", "find the item", &query_err
+                )
+            };
+
+            let item = match (existing_item, weight) {
+                (Some(mut item), Some(weight)) if weight != item.weight => {
+                    if let Err(query_err) = sql_scripts::items::set_weight(database_conn_pool, item.id, weight).await {
+                        break 'response_embed query_error_embed(
+                            "inventory::add::set_weight", "change the item's weight", &query_err
+                        );
+                    }
+                    item.weight = weight;
+                    item
+                },
+                (Some(item), _) => item,
+
+                (None, Some(weight)) => match sql_scripts::items::get_or_add_item(database_conn_pool, &item_name, weight).await {
+                    Ok(item) => item,
+                    Err(query_err) => break 'response_embed query_error_embed(
+                        "inventory::add::get_or_add_item", "add the item", &query_err
+                    )
+                },
+                (None, None) => break 'response_embed CreateEmbed::new()
+                    .title(format!( "There is no item called `{item_name}` yet" ))
+                    .description(if target.is_gm || invoker_is_bot_admin {
+                        "Give it a weight to add it"
+                    } else {
+                        "Ask your GM to add it along with its weight"
+                    })
+                    .colour(ColourCode::Info.to_embed_colour())
+            };
+        // ==--
+
+        match sql_scripts::inventories::add_items(database_conn_pool, target.character_id, item.id, quantity).await {
+            Ok(AddOutcome::Added(stacked_quantity)) => {
+                let mut description = format!( "{} now carries {stacked_quantity}", target.name );
+                if weight.is_some() {
+                    description.push_str(&format!( "\n{} weighs {} each", item.name, item.weight ));
+                }
+
+                CreateEmbed::new()
+                    .title(format!( "Added {quantity} × {}", item.name ))
+                    .description(description)
+                    .colour(ColourCode::Success.to_embed_colour())
+            },
+            Ok(AddOutcome::TooHeavy { carried, limit }) => CreateEmbed::new()
+                .title(format!( "{} can't carry that much", target.name ))
+                .description(format!( "They carry {carried} of the {limit} their strength allows, and that would add {}", item.weight * quantity as f64 ))
+                .colour(ColourCode::Info.to_embed_colour()),
+            Err(query_err) => query_error_embed("inventory::add::add_items", "add the items", &query_err)
+        }
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, false, "inventory::add").await;
+}
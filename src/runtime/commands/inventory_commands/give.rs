@@ -0,0 +1,343 @@
+use crate::{
+    runtime::{
+        commands::{
+            integer_option, query_error_embed, refusal, resolve_character_target, resolve_user_character,
+            respond_with_embed, string_option, sub_command_options, user_option
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts::{
+            self,
+            inventories::TransferOutcome,
+            models::{Character, Item, ItemTransfer}
+        }
+    },
+    utils::misc::{colour_codes::ColourCode, logging::create_log_message},
+};
+
+use serenity::{
+    builder::{
+        CreateActionRow, CreateButton, CreateEmbed, CreateInteractionResponse,
+        CreateInteractionResponseMessage
+    },
+    client::Context,
+    model::application::{ButtonStyle, CommandInteraction, ComponentInteraction},
+};
+
+// Transfer buttons follow the format:
+//     inventory|transfer|confirm|transfer_id    inventory|transfer|cancel|transfer_id
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let database_conn_pool = &runtime_client.database_connection;
+
+    let sub_options = sub_command_options(&interaction_data);
+    let item_name = string_option(&sub_options, "item")
+        .expect("`item` is a required option")
+        .trim();
+    let recipient_option = string_option(&sub_options, "recipient")
+        .expect("`recipient` is a required option");
+    let player = user_option(&sub_options, "player")
+        .expect("`player` is a required option");
+    let quantity = integer_option(&sub_options, "quantity").unwrap_or(1);
+
+    let refusal_embed = 'refusal_embed: {
+
+        let sender = match resolve_character_target(runtime_client, &ctx, &interaction_data, "from").await {
+            Ok(sender) => sender,
+            Err(refusal_embed) => break 'refusal_embed refusal_embed
+        };
+
+        let Some((recipient_id, recipient_name)) = resolve_user_character(&ctx, player.id.get(), recipient_option).await else {
+            break 'refusal_embed CreateEmbed::new()
+                .title(format!( "{} doesn't have a character called `{recipient_option}`", player.name ))
+                .colour(ColourCode::Info.to_embed_colour());
+        };
+
+        if recipient_id == sender.character_id {
+            break 'refusal_embed CreateEmbed::new()
+                .title(format!( "{} already has those", sender.name ))
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        // --== CHECK SENDER'S ITEMS ==-- //
+
+            let item = match sql_scripts::items::get_item_by_name(database_conn_pool, item_name).await {
+                Ok(item) => item,
+                Err(query_err) => break 'refusal_embed query_error_embed(
+                    "inventory::give::get_item_by_name", "find the item", &query_err
+                )
+            };
+
+            let held_quantity = match &item {
+                Some(item) => match sql_scripts::inventories::get_inventory(database_conn_pool, sender.character_id).await {
+                    Ok(inventory) => inventory.iter()
+                        .find(|entry| entry.item_id == item.id)
+                        .map_or(0, |entry| entry.quantity),
+                    Err(query_err) => break 'refusal_embed query_error_embed(
+                        "inventory::give::get_inventory", "fetch the inventory", &query_err
+                    )
+                },
+                None => 0
+            };
+
+            let Some(item) = item.filter(|_| held_quantity >= quantity) else {
+                break 'refusal_embed CreateEmbed::new()
+                    .title(format!( "{} only carries {held_quantity} × {item_name}", sender.name ))
+                    .colour(ColourCode::Info.to_embed_colour());
+            };
+        // ==--
+
+        // --== OPEN TRANSFER ==-- //
+
+            let transfer_id = match sql_scripts::inventories::add_transfer(database_conn_pool, sender.character_id, recipient_id, item.id, quantity).await {
+                Ok(transfer_id) => transfer_id,
+                Err(query_err) => break 'refusal_embed query_error_embed(
+                    "inventory::give::add_transfer", "open the transfer", &query_err
+                )
+            };
+
+            let transfer = ItemTransfer {
+                id: transfer_id,
+                from_character: sender.character_id,
+                to_character: recipient_id,
+                item_id: item.id,
+                quantity,
+                sender_confirmed: false,
+                recipient_confirmed: false,
+                created_at: 0
+            };
+            let parties = TransferParties {
+                item_name: item.name,
+                sender_name: sender.name,
+                sender_owner: sender.owner_id,
+                recipient_name,
+                recipient_owner: player.id.get()
+            };
+
+            let transfer_message = CreateInteractionResponseMessage::new()
+                .content(format!( "<@{}> <@{}>", parties.sender_owner, parties.recipient_owner ))
+                .embed(transfer_embed(&transfer, &parties))
+                .components(transfer_buttons(transfer_id));
+
+            if let Err(why) = interaction_data.create_response(&ctx.http, CreateInteractionResponse::Message(transfer_message)).await {
+                println!( "{}", create_log_message(
+                        format!(
+                            "{}inventory::give{}: Failed to send response: `{}{}{}`",
+                            ColourCode::Location,
+                            ColourCode::Reset,
+                            ColourCode::Info,
+                            why,
+                            ColourCode::Reset
+                        ),
+                        ColourCode::Error
+                ));
+
+                // Nobody can confirm a transfer they can't see
+                let _ = sql_scripts::inventories::remove_transfer(database_conn_pool, transfer_id).await;
+            }
+        // ==--
+
+        return;
+
+    }; // let refusal_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, refusal_embed, true, "inventory::give").await;
+}
+
+
+pub async fn handle_component(
+    runtime_client: &RuntimeClient,
+    component_interaction: ComponentInteraction,
+    ctx: Context,
+    split_custom_id: Vec<&str>,
+) {
+    let database_conn_pool = &runtime_client.database_connection;
+    let presser_id = component_interaction.user.id.get();
+
+    let transfer_id = split_custom_id.get(3)
+        .and_then(|transfer_id| transfer_id.parse::<u64>().ok());
+
+    let response = 'response: {
+
+        // --== FETCH TRANSFER ==-- //
+
+            let transfer = match transfer_id {
+                Some(transfer_id) => sql_scripts::inventories::get_transfer(database_conn_pool, transfer_id).await,
+                None => Ok(None)
+            };
+
+            let transfer = match transfer {
+                Ok(Some(transfer)) => transfer,
+                Ok(None) => break 'response closed_transfer(
+                    CreateEmbed::new()
+                        .title("This transfer is no longer open")
+                        .colour(ColourCode::Info.to_embed_colour())
+                ),
+                Err(query_err) => break 'response refusal(
+                    query_error_embed("inventory::give::get_transfer", "find the transfer", &query_err)
+                )
+            };
+
+            let parties = match fetch_parties(runtime_client, &transfer).await {
+                Ok(parties) => parties,
+                Err(query_err) => break 'response refusal(
+                    query_error_embed("inventory::give::fetch_parties", "find the transfer's characters", &query_err)
+                )
+            };
+        // ==--
+
+        let (is_sender, is_recipient) = (parties.sender_owner == presser_id, parties.recipient_owner == presser_id);
+        if !is_sender && !is_recipient {
+            break 'response refusal(
+                CreateEmbed::new()
+                    .title("This transfer isn't yours to settle")
+                    .colour(ColourCode::Info.to_embed_colour())
+            );
+        }
+
+        if split_custom_id[2] == "cancel" {
+            if let Err(query_err) = sql_scripts::inventories::remove_transfer(database_conn_pool, transfer.id).await {
+                break 'response refusal(
+                    query_error_embed("inventory::give::remove_transfer", "cancel the transfer", &query_err)
+                );
+            }
+
+            break 'response closed_transfer(
+                CreateEmbed::new()
+                    .title(format!( "{} × {} stays with {}", transfer.quantity, parties.item_name, parties.sender_name ))
+                    .description(format!( "<@{presser_id}> cancelled the transfer" ))
+                    .colour(ColourCode::Info.to_embed_colour())
+            );
+        }
+
+        match sql_scripts::inventories::confirm_transfer(database_conn_pool, transfer.id, is_sender, is_recipient).await {
+            Ok(TransferOutcome::AwaitingConfirmation) => {
+                let transfer = ItemTransfer {
+                    sender_confirmed: transfer.sender_confirmed || is_sender,
+                    recipient_confirmed: transfer.recipient_confirmed || is_recipient,
+                    ..transfer
+                };
+
+                CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new()
+                        .embed(transfer_embed(&transfer, &parties))
+                        .components(transfer_buttons(transfer.id))
+                )
+            },
+            Ok(TransferOutcome::Completed) => closed_transfer(
+                CreateEmbed::new()
+                    .title(format!( "{} handed {} × {} to {}", parties.sender_name, transfer.quantity, parties.item_name, parties.recipient_name ))
+                    .colour(ColourCode::Success.to_embed_colour())
+            ),
+            Ok(TransferOutcome::NotEnough(held_quantity)) => closed_transfer(
+                CreateEmbed::new()
+                    .title("The transfer fell through")
+                    .description(format!( "{} only carries {held_quantity} × {} by now", parties.sender_name, parties.item_name ))
+                    .colour(ColourCode::Info.to_embed_colour())
+            ),
+            Ok(TransferOutcome::TooHeavy { carried, limit }) => closed_transfer(
+                CreateEmbed::new()
+                    .title("The transfer fell through")
+                    .description(format!( "{} carries {carried} of the {limit} their strength allows, and can't take any more", parties.recipient_name ))
+                    .colour(ColourCode::Info.to_embed_colour())
+            ),
+            Ok(TransferOutcome::Missing) => closed_transfer(
+                CreateEmbed::new()
+                    .title("This transfer is no longer open")
+                    .colour(ColourCode::Info.to_embed_colour())
+            ),
+            Err(query_err) => refusal(
+                query_error_embed("inventory::give::confirm_transfer", "confirm the transfer", &query_err)
+            )
+        }
+
+    }; // let response = {...}
+
+    if let Err(response_send_err) = component_interaction.create_response(&ctx.http, response).await {
+        println!( "{}", create_log_message(
+                format!(
+                    "{}inventory::give::handle_component{}: Failed to send response: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    response_send_err,
+                    ColourCode::Reset
+                ),
+                ColourCode::Error
+        ));
+    }
+}
+
+
+
+// --== TRANSFER MESSAGE ==-- //
+
+    /// Who and what a transfer involves, as shown on its message
+    struct TransferParties {
+        item_name: String,
+        sender_name: String,
+        sender_owner: u64,
+        recipient_name: String,
+        recipient_owner: u64
+    }
+
+    async fn fetch_parties( runtime_client: &RuntimeClient, transfer: &ItemTransfer ) -> Result<TransferParties, sqlx::Error> {
+        let database_conn_pool = &runtime_client.database_connection;
+
+        // The transfer's foreign keys cascade, so while it exists all three do too
+        let item: Option<Item> = sql_scripts::items::get_item(database_conn_pool, transfer.item_id).await?;
+        let sender: Option<Character> = sql_scripts::characters::get_character(database_conn_pool, transfer.from_character).await?;
+        let recipient: Option<Character> = sql_scripts::characters::get_character(database_conn_pool, transfer.to_character).await?;
+
+        let (Some(item), Some(sender), Some(recipient)) = (item, sender, recipient) else {
+            return Err(sqlx::Error::RowNotFound);
+        };
+        let (Some(sender_owner), Some(recipient_owner)) = (sender.owner, recipient.owner) else {
+            return Err(sqlx::Error::RowNotFound);
+        };
+
+        Ok(TransferParties {
+            item_name: item.name,
+            sender_name: sender.name,
+            sender_owner: sender_owner.get(),
+            recipient_name: recipient.name,
+            recipient_owner: recipient_owner.get()
+        })
+    }
+
+    fn transfer_embed( transfer: &ItemTransfer, parties: &TransferParties ) -> CreateEmbed {
+        let confirmation = |is_confirmed: bool| if is_confirmed { "Confirmed" } else { "Waiting" };
+
+        CreateEmbed::new()
+            .title(format!( "{} offers {} × {} to {}", parties.sender_name, transfer.quantity, parties.item_name, parties.recipient_name ))
+            .description("The items change hands once both players confirm")
+            .field(parties.sender_name.clone(), format!( "<@{}>: {}", parties.sender_owner, confirmation(transfer.sender_confirmed) ), true)
+            .field(parties.recipient_name.clone(), format!( "<@{}>: {}", parties.recipient_owner, confirmation(transfer.recipient_confirmed) ), true)
+            .colour(ColourCode::Location.to_embed_colour())
+    }
+
+    fn transfer_buttons( transfer_id: u64 ) -> Vec<CreateActionRow> {
+        vec![
+            CreateActionRow::Buttons(vec![
+                CreateButton::new(format!( "inventory|transfer|confirm|{transfer_id}" ))
+                    .style(ButtonStyle::Success)
+                    .label("Confirm"),
+                CreateButton::new(format!( "inventory|transfer|cancel|{transfer_id}" ))
+                    .style(ButtonStyle::Secondary)
+                    .label("Cancel"),
+            ])
+        ]
+    }
+
+    /// Replaces the transfer message with its outcome, removing the buttons
+    fn closed_transfer( embed: CreateEmbed ) -> CreateInteractionResponse {
+        CreateInteractionResponse::UpdateMessage(
+            CreateInteractionResponseMessage::new()
+                .embed(embed)
+                .components(vec![])
+        )
+    }
+// ==--
@@ -0,0 +1,80 @@
+use crate::{
+    runtime::{
+        commands::{query_error_embed, resolve_character_target, respond_with_embed},
+        runtime_client::RuntimeClient,
+        sql_scripts
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+// Embed descriptions are capped at 4096 characters, leave some room for the overflow line
+const INVENTORY_DESCRIPTION_LIMIT: usize = 4000;
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let database_conn_pool = &runtime_client.database_connection;
+
+    let response_embed = 'response_embed: {
+
+        let target = match resolve_character_target(runtime_client, &ctx, &interaction_data, "character").await {
+            Ok(target) => target,
+            Err(refusal_embed) => break 'response_embed refusal_embed
+        };
+
+        let inventory = match sql_scripts::inventories::get_inventory(database_conn_pool, target.character_id).await {
+            Ok(inventory) => inventory,
+            Err(query_err) => break 'response_embed query_error_embed(
+                "inventory::list::get_inventory", "fetch the inventory", &query_err
+            )
+        };
+
+        let (carried, limit) = match sql_scripts::inventories::get_encumbrance(database_conn_pool, target.character_id).await {
+            Ok(encumbrance) => encumbrance,
+            Err(query_err) => break 'response_embed query_error_embed(
+                "inventory::list::get_encumbrance", "weigh the inventory", &query_err
+            )
+        };
+
+        let mut inventory_description = String::new();
+        for (idx, entry) in inventory.iter().enumerate() {
+            let inventory_line = if entry.weight > 0.0 {
+                format!( "- **{}** × {}, weighing {}\n", entry.name, entry.quantity, entry.weight * entry.quantity as f64 )
+            } else {
+                format!( "- **{}** × {}\n", entry.name, entry.quantity )
+            };
+
+            if inventory_description.len() + inventory_line.len() > INVENTORY_DESCRIPTION_LIMIT {
+                inventory_description.push_str(&format!( "*...and {} more*", inventory.len() - idx ));
+                break;
+            }
+            inventory_description.push_str(&inventory_line);
+        }
+        if inventory.is_empty() {
+            inventory_description.push_str("Nothing but lint");
+        }
+
+        let encumbrance = if carried > limit {
+            format!( "{carried} / {limit}, encumbered!" )
+        } else {
+            format!( "{carried} / {limit}" )
+        };
+
+        CreateEmbed::new()
+            .title(format!( "{}'s inventory", target.name ))
+            .description(inventory_description)
+            .field("Carrying", encumbrance, true)
+            .colour(ColourCode::Info.to_embed_colour())
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, false, "inventory::list").await;
+}
@@ -0,0 +1,4 @@
+pub mod add;
+pub mod give;
+pub mod list;
+pub mod remove;
@@ -0,0 +1,65 @@
+use crate::{
+    runtime::{
+        commands::{
+            integer_option, query_error_embed, resolve_character_target, respond_with_embed,
+            string_option, sub_command_options
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts::{self, inventories::RemoveOutcome}
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let database_conn_pool = &runtime_client.database_connection;
+
+    let sub_options = sub_command_options(&interaction_data);
+    let item_name = string_option(&sub_options, "item")
+        .expect("`item` is a required option")
+        .trim();
+    let quantity = integer_option(&sub_options, "quantity").unwrap_or(1);
+
+    let response_embed = 'response_embed: {
+
+        let target = match resolve_character_target(runtime_client, &ctx, &interaction_data, "character").await {
+            Ok(target) => target,
+            Err(refusal_embed) => break 'response_embed refusal_embed
+        };
+
+        let item = match sql_scripts::items::get_item_by_name(database_conn_pool, item_name).await {
+            Ok(Some(item)) => item,
+            Ok(None) => {
+                break 'response_embed CreateEmbed::new()
+                    .title(format!( "{} doesn't carry any `{item_name}`", target.name ))
+                    .colour(ColourCode::Info.to_embed_colour());
+            },
+            Err(query_err) => break 'response_embed query_error_embed(
+                "inventory::remove::get_item_by_name", "find the item", &query_err
+            )
+        };
+
+        match sql_scripts::inventories::remove_items(database_conn_pool, target.character_id, item.id, quantity).await {
+            Ok(RemoveOutcome::Removed(remaining_quantity)) => CreateEmbed::new()
+                .title(format!( "Removed {quantity} × {}", item.name ))
+                .description(format!( "{} has {remaining_quantity} left", target.name ))
+                .colour(ColourCode::Success.to_embed_colour()),
+            Ok(RemoveOutcome::NotEnough(held_quantity)) => CreateEmbed::new()
+                .title(format!( "{} only carries {held_quantity} × {}", target.name, item.name ))
+                .colour(ColourCode::Info.to_embed_colour()),
+            Err(query_err) => query_error_embed("inventory::remove::remove_items", "remove the items", &query_err)
+        }
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, false, "inventory::remove").await;
+}
@@ -2,4 +2,444 @@ pub mod character_commands;
 pub mod character;
 pub mod profile_commands;
 pub mod profile;
+pub mod campaign_commands;
+pub mod campaign;
+pub mod combat_commands;
+pub mod combat;
+pub mod hp_commands;
+pub mod hp;
+pub mod inventory_commands;
+pub mod inventory;
+pub mod wallet_commands;
+pub mod wallet;
+pub mod xp_commands;
+pub mod xp;
+pub mod session_commands;
+pub mod session;
+pub mod spell_commands;
+pub mod spell;
+pub mod template_commands;
+pub mod template;
+pub mod admin_commands;
+pub mod admin;
 
+use crate::{
+    runtime::{
+        commands::campaign::interaction_campaign, context_keys, runtime_client::RuntimeClient,
+        sql_scripts::{self, models::CharacterStatus}
+    },
+    utils::misc::{colour_codes::ColourCode, logging::create_log_message}
+};
+
+use serenity::{
+    builder::{
+        CreateAutocompleteResponse, CreateEmbed, CreateInteractionResponse,
+        CreateInteractionResponseMessage
+    },
+    client::Context,
+    model::{
+        application::{CommandInteraction, ResolvedOption, ResolvedValue, Unresolved},
+        guild::Member,
+        id::ChannelId,
+        user::User
+    }
+};
+
+/// Returns the options the user filled in for the invoked sub command, looking inside its group
+/// should it have one, or an empty `Vec` should the command have been invoked without one
+pub fn sub_command_options( interaction_data: &CommandInteraction ) -> Vec<ResolvedOption<'_>> {
+    match interaction_data.data.options().into_iter().next() {
+        Some(ResolvedOption { value: ResolvedValue::SubCommand(sub_options), .. }) => sub_options,
+        Some(ResolvedOption { value: ResolvedValue::SubCommandGroup(group_options), .. }) => {
+            match group_options.into_iter().next() {
+                Some(ResolvedOption { value: ResolvedValue::SubCommand(sub_options), .. }) => sub_options,
+                _ => vec![]
+            }
+        },
+        _ => vec![]
+    }
+}
+
+/// Returns the name of the sub command invoked within a group, or `None` should the command have
+/// been invoked without a group
+pub fn grouped_sub_command_name( interaction_data: &CommandInteraction ) -> Option<&str> {
+    match interaction_data.data.options().into_iter().next() {
+        Some(ResolvedOption { value: ResolvedValue::SubCommandGroup(group_options), .. }) => {
+            group_options.first().map(|sub_command| sub_command.name)
+        },
+        _ => None
+    }
+}
+
+/// Returns the value of a sub command's string option
+pub fn string_option<'a>( sub_options: &[ResolvedOption<'a>], option_name: &str ) -> Option<&'a str> {
+    sub_options.iter().find_map(|option| match option.value {
+        ResolvedValue::String(value) if option.name == option_name => Some(value),
+        _ => None
+    })
+}
+
+/// Returns the value of a sub command's integer option
+pub fn integer_option( sub_options: &[ResolvedOption<'_>], option_name: &str ) -> Option<i64> {
+    sub_options.iter().find_map(|option| match option.value {
+        ResolvedValue::Integer(value) if option.name == option_name => Some(value),
+        _ => None
+    })
+}
+
+/// Returns the value of a sub command's user option
+pub fn user_option( sub_options: &[ResolvedOption<'_>], option_name: &str ) -> Option<User> {
+    sub_options.iter().find_map(|option| match option.value {
+        ResolvedValue::User(user, _) if option.name == option_name => Some(user.clone()),
+        _ => None
+    })
+}
+
+/// Returns the value of a sub command's number option
+pub fn number_option( sub_options: &[ResolvedOption<'_>], option_name: &str ) -> Option<f64> {
+    sub_options.iter().find_map(|option| match option.value {
+        ResolvedValue::Number(value) if option.name == option_name => Some(value),
+        _ => None
+    })
+}
+
+/// Returns the value of a sub command's channel option
+pub fn channel_option( sub_options: &[ResolvedOption<'_>], option_name: &str ) -> Option<ChannelId> {
+    sub_options.iter().find_map(|option| match option.value {
+        ResolvedValue::Channel(channel) if option.name == option_name => Some(channel.id),
+        _ => None
+    })
+}
+
+/// Whether a member may manage the server, which lets them step in for a GM who has gone missing
+pub fn is_server_manager( member: Option<&Member> ) -> bool {
+    member
+        .and_then(|member| member.permissions)
+        .is_some_and(|permissions| permissions.manage_guild())
+}
+
+/// Whether a user may change what every server the bot is in shares. Managing a single server isn't
+/// enough for those, as it would let one server decide for all the others
+pub fn is_bot_admin( runtime_client: &RuntimeClient, user_id: u64 ) -> bool {
+    runtime_client.bot_admin_ids.contains(&user_id)
+}
+
+/// Responds to a slash command with a single embed, logging should that fail. `location` names
+/// the command in the log, such as `campaign::create`
+pub async fn respond_with_embed( ctx: &Context, interaction_data: &CommandInteraction, embed: CreateEmbed, ephemeral: bool, location: &str ) {
+    let response = CreateInteractionResponse::Message(
+        CreateInteractionResponseMessage::new()
+            .embed(embed)
+            .ephemeral(ephemeral)
+    );
+
+    if let Err(response_send_err) = interaction_data.create_response(&ctx.http, response).await {
+        println!( "{}", create_log_message(
+                format!(
+                    "{}{location}{}: Failed to send response: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    response_send_err,
+                    ColourCode::Reset
+                ),
+                ColourCode::Error
+        ));
+    }
+}
+
+/// Tells only the presser why their press did nothing
+pub fn refusal( embed: CreateEmbed ) -> CreateInteractionResponse {
+    CreateInteractionResponse::Message(
+        CreateInteractionResponseMessage::new()
+            .embed(embed)
+            .ephemeral(true)
+    )
+}
+
+/// Cuts text down to at most `max_chars` characters, marking that it was cut. Blank text is shown
+/// as a dash, as neither embed fields, button labels nor menu options can be empty
+pub fn truncated( text: &str, max_chars: usize ) -> String {
+    if text.trim().is_empty() {
+        return String::from("-");
+    }
+
+    match text.char_indices().nth(max_chars) {
+        Some(_) => {
+            let cut_off = text.char_indices().nth(max_chars.saturating_sub(3)).map_or(0, |(cut_off, _)| cut_off);
+            format!( "{}...", &text[..cut_off] )
+        },
+        None => text.to_string()
+    }
+}
+
+/// Notes that whoever ran a command in a server is one of its members, logging should that fail
+pub async fn note_guild_member( runtime_client: &RuntimeClient, interaction_data: &CommandInteraction ) {
+    let Some(guild_id) = interaction_data.guild_id else {
+        return;
+    };
+
+    if let Err(query_err) = sql_scripts::discord_users::add_guild_member(&runtime_client.database_connection, guild_id.get(), interaction_data.user.id.get()).await {
+        println!( "{}", create_log_message(
+                format!(
+                    "{}note_guild_member{}: Failed to note guild member: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    query_err,
+                    ColourCode::Reset
+                ),
+                ColourCode::Error
+        ));
+    }
+}
+
+/// Logs a failed query, and builds the embed telling the user about it. `action` completes the
+/// sentence "We were unable to ..."
+pub fn query_error_embed( location: &str, action: &str, query_err: &sqlx::Error ) -> CreateEmbed {
+    println!( "{}", create_log_message(
+            format!(
+                "{}{location}{}: Failed to {action}: `{}{}{}`",
+                ColourCode::Location,
+                ColourCode::Reset,
+                ColourCode::Info,
+                query_err,
+                ColourCode::Reset
+            ),
+            ColourCode::Error
+    ));
+
+    CreateEmbed::new()
+        .title("An unexpected error occured :(")
+        .description(format!("We were unable to {action}\n{query_err}"))
+        .colour(ColourCode::Error.to_embed_colour())
+}
+
+
+
+// --== CHARACTER OPTIONS ==-- //
+
+    // Options that take one of the invoker's characters are string options with autocomplete.
+    // The choices carry the character's ID as their value, but a user may just as well type in
+    // the name, so both are accepted when resolving
+
+    /// Answers an autocomplete request for a character option with the invoker's characters
+    /// whose names contain what they've typed so far
+    pub async fn autocomplete_user_characters( ctx: &Context, interaction_data: &CommandInteraction ) {
+        let matching_characters = user_character_choices(ctx, interaction_data.user.id.get(), &typed_so_far(interaction_data)).await;
+        send_character_choices(ctx, interaction_data, matching_characters).await;
+    }
+
+    /// What the user has typed into the option being autocompleted, lowercased for matching
+    pub fn typed_so_far( interaction_data: &CommandInteraction ) -> String {
+        interaction_data.data.autocomplete()
+            .map_or(String::new(), |focused_option| focused_option.value.to_lowercase())
+    }
+
+    /// Returns the IDs and names of a user's characters whose names contain `typed_so_far`
+    pub async fn user_character_choices( ctx: &Context, user_id: u64, typed_so_far: &str ) -> Vec<(u64, String)> {
+        let data_read = ctx.data.read().await;
+        data_read.get::<context_keys::UserCharactersCache>()
+            .expect("Key inserted in main.rs")
+            .get(&user_id)
+            .map_or(vec![], |user_characters| {
+                user_characters.iter()
+                    .filter(|(_, name)| name.to_lowercase().contains(typed_so_far))
+                    .map(|(id, name)| (*id, name.clone()))
+                    .collect()
+            })
+    }
+
+    /// Answers an autocomplete request with the given characters, sorted by name
+    pub async fn send_character_choices( ctx: &Context, interaction_data: &CommandInteraction, mut matching_characters: Vec<(u64, String)> ) {
+        matching_characters.sort_by(|(id_a, name_a), (id_b, name_b)| name_a.cmp(name_b).then(id_a.cmp(id_b)));
+        matching_characters.dedup_by_key(|(character_id, _)| *character_id);
+
+        // Discord caps autocomplete responses at 25 choices
+        let mut autocomplete_response = CreateAutocompleteResponse::new();
+        for (character_id, character_name) in matching_characters.into_iter().take(25) {
+            autocomplete_response = autocomplete_response.add_string_choice(character_name, character_id.to_string());
+        }
+
+        let send_choices = interaction_data.create_response(&ctx.http, CreateInteractionResponse::Autocomplete(autocomplete_response));
+        if let Err(why) = send_choices.await {
+            println!( "{}", create_log_message(
+                    format!(
+                        "Failed to send autocomplete choices: `{}{}{}`",
+                        ColourCode::Info,
+                        why,
+                        ColourCode::Reset
+                    ),
+                    ColourCode::Caution
+            ));
+        }
+    }
+
+    /// Answers an autocomplete request for a character option with the characters of whoever
+    /// was picked in the user option `player_option`
+    pub async fn autocomplete_player_characters( ctx: &Context, interaction_data: &CommandInteraction, player_option: &str ) {
+        // Users picked in other options arrive unresolved while autocompleting
+        let player_id = sub_command_options(interaction_data).iter().find_map(|option| match option.value {
+            ResolvedValue::User(user, _) if option.name == player_option => Some(user.id.get()),
+            ResolvedValue::Unresolved(Unresolved::User(user_id)) if option.name == player_option => Some(user_id.get()),
+            _ => None
+        });
+
+        let matching_characters = match player_id {
+            Some(player_id) => user_character_choices(ctx, player_id, &typed_so_far(interaction_data)).await,
+            None => vec![]
+        };
+        send_character_choices(ctx, interaction_data, matching_characters).await;
+    }
+
+    /// Resolves a character option to one of the user's characters, returning its ID and name
+    pub async fn resolve_user_character( ctx: &Context, user_id: u64, option_value: &str ) -> Option<(u64, String)> {
+        let data_read = ctx.data.read().await;
+        let user_characters = data_read.get::<context_keys::UserCharactersCache>()
+            .expect("Key inserted in main.rs")
+            .get(&user_id)?;
+
+        if let Ok(character_id) = option_value.parse::<u64>()
+            && let Some(character_name) = user_characters.get(&character_id)
+        {
+            return Some((character_id, character_name.clone()));
+        }
+
+        user_characters.iter()
+            .find(|(_, name)| name.eq_ignore_ascii_case(option_value.trim()))
+            .map(|(id, name)| (*id, name.clone()))
+    }
+
+    /// Answers an autocomplete request for a character option with the invoker's characters, and
+    /// for the GM of this channel's campaign also with the characters taking part in it
+    pub async fn autocomplete_campaign_characters( runtime_client: &RuntimeClient, ctx: &Context, interaction_data: &CommandInteraction ) {
+        let invoker_id = interaction_data.user.id.get();
+        let typed_so_far = typed_so_far(interaction_data);
+
+        let mut matching_characters = user_character_choices(ctx, invoker_id, &typed_so_far).await;
+
+        if let Ok(Some(campaign)) = interaction_campaign(runtime_client, interaction_data).await
+            && campaign.gm.get() == invoker_id
+            && let Ok(roster) = sql_scripts::campaigns::get_roster(&runtime_client.database_connection, campaign.id).await
+        {
+            matching_characters.extend(
+                roster.into_iter()
+                    .filter(|entry| entry.name.to_lowercase().contains(&typed_so_far))
+                    .map(|entry| (entry.character_id, entry.name))
+            );
+        }
+
+        send_character_choices(ctx, interaction_data, matching_characters).await;
+    }
+
+    /// A character picked through a character option, or the invoker's selected character when
+    /// the option was left empty
+    pub struct CharacterTarget {
+        pub character_id: u64,
+        pub name: String,
+        pub owner_id: u64,
+        /// Whether the character takes part in the campaign of this channel
+        pub in_campaign: bool,
+        /// Whether the invoker is the GM of that campaign
+        pub is_gm: bool
+    }
+
+    /// Works out which character the invoker means by the option `option_name`. They may pick
+    /// their own characters, and the GM of this channel's campaign any character taking part in
+    /// it. Returns the embed to refuse with otherwise
+    pub async fn resolve_character_target( runtime_client: &RuntimeClient, ctx: &Context, interaction_data: &CommandInteraction, option_name: &str ) -> Result<CharacterTarget, CreateEmbed> {
+        let invoker_id = interaction_data.user.id.get();
+        let database_conn_pool = &runtime_client.database_connection;
+
+        let sub_options = sub_command_options(interaction_data);
+        let character_option = string_option(&sub_options, option_name);
+
+        let campaign = interaction_campaign(runtime_client, interaction_data)
+            .await
+            .map_err(|query_err| query_error_embed("resolve_character_target::interaction_campaign", "find this channel's campaign", &query_err))?;
+
+        let roster = match &campaign {
+            Some(campaign) => sql_scripts::campaigns::get_roster(database_conn_pool, campaign.id)
+                .await
+                .map_err(|query_err| query_error_embed("resolve_character_target::get_roster", "fetch the campaign's roster", &query_err))?,
+            None => vec![]
+        };
+        let is_campaign_gm = campaign.is_some_and(|campaign| campaign.gm.get() == invoker_id);
+
+        // --== FIND CHARACTER ==-- //
+
+            let (character_id, name, owner_id) = match character_option {
+                Some(character_option) => match resolve_user_character(ctx, invoker_id, character_option).await {
+                    Some((character_id, name)) => (character_id, name, invoker_id),
+
+                    // GMs may reach for the characters of their players
+                    None if is_campaign_gm => roster.iter()
+                        .find(|entry| {
+                            character_option.parse::<u64>().is_ok_and(|character_id| character_id == entry.character_id)
+                                || entry.name.eq_ignore_ascii_case(character_option.trim())
+                        })
+                        .map(|entry| (entry.character_id, entry.name.clone(), entry.owner.get()))
+                        .ok_or_else(|| CreateEmbed::new()
+                            .title(format!( "No character called `{character_option}` takes part in this campaign" ))
+                            .colour(ColourCode::Info.to_embed_colour())
+                        )?,
+
+                    None => return Err(
+                        CreateEmbed::new()
+                            .title(format!( "You don't have a character called `{character_option}`" ))
+                            .colour(ColourCode::Info.to_embed_colour())
+                    )
+                },
+
+                None => {
+                    let selected_character = sql_scripts::discord_users::get_by_user_id(database_conn_pool, invoker_id)
+                        .await
+                        .map_err(|query_err| query_error_embed("resolve_character_target::get_by_user_id", "check your profile", &query_err))?
+                        .and_then(|discord_user| discord_user.selected_character);
+
+                    let selected_character = match selected_character {
+                        Some(character_id) => resolve_user_character(ctx, invoker_id, &character_id.to_string()).await,
+                        None => None
+                    };
+
+                    let Some((character_id, name)) = selected_character else {
+                        return Err(
+                            CreateEmbed::new()
+                                .title("You haven't selected a character")
+                                .description(format!( "Name one with the `{option_name}` option instead" ))
+                                .colour(ColourCode::Info.to_embed_colour())
+                        );
+                    };
+                    (character_id, name, invoker_id)
+                }
+            };
+        // ==--
+
+        // Characters still under review can't be played
+        let status = sql_scripts::characters::get_character(database_conn_pool, character_id)
+            .await
+            .map_err(|query_err| query_error_embed("resolve_character_target::get_character", "check the character's review", &query_err))?
+            .map(|character| character.status);
+        if let Some(status) = status && status != CharacterStatus::Approved {
+            return Err(unapproved_character_embed(&name, status));
+        }
+
+        let in_campaign = roster.iter().any(|entry| entry.character_id == character_id);
+
+        Ok(CharacterTarget {
+            character_id,
+            name,
+            owner_id,
+            in_campaign,
+            is_gm: is_campaign_gm && in_campaign
+        })
+    }
+
+    /// Explains why a character that hasn't been approved yet can't be used
+    pub fn unapproved_character_embed( character_name: &str, status: CharacterStatus ) -> CreateEmbed {
+        CreateEmbed::new()
+            .title(format!( "{character_name} can't be played while {}", status.describe() ))
+            .description("Characters have to be approved by staff before they can be used")
+            .colour(ColourCode::Info.to_embed_colour())
+    }
+// ==--
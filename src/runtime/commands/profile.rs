@@ -17,6 +17,7 @@ use serenity::{
     },
     model::application::{
         CommandInteraction,
+        ComponentInteraction,
         CommandOptionType
     },
     client::Context
@@ -24,7 +25,7 @@ use serenity::{
 
 pub fn build() -> CreateCommand {
     CreateCommand::new("profile")
-        .description("Add or remove your discord profile from the database")
+        .description("Manage your discord profile")
         .set_options(vec![
                 CreateCommandOption::new(
                     CommandOptionType::SubCommand,
@@ -35,7 +36,37 @@ pub fn build() -> CreateCommand {
                     CommandOptionType::SubCommand,
                     "deregister",
                     "Remove your discord profile from the database"
+                ),
+                CreateCommandOption::new(
+                    CommandOptionType::SubCommand,
+                    "view",
+                    "Show your, or another user's, profile"
                 )
+                    .add_sub_option(CreateCommandOption::new(
+                        CommandOptionType::User,
+                        "user",
+                        "Whose profile to show, yours if left empty"
+                    )),
+                CreateCommandOption::new(
+                    CommandOptionType::SubCommand,
+                    "settings",
+                    "Show or change your preferences"
+                )
+                    .add_sub_option(CreateCommandOption::new(
+                        CommandOptionType::String,
+                        "time_zone",
+                        "Your offset from UTC, such as `UTC+2` or `-03:30`"
+                    ))
+                    .add_sub_option(CreateCommandOption::new(
+                        CommandOptionType::Boolean,
+                        "public_characters",
+                        "Whether other users may look at your characters"
+                    ))
+                    .add_sub_option(CreateCommandOption::new(
+                        CommandOptionType::Boolean,
+                        "private_wizard",
+                        "Whether character creation should only be visible to you"
+                    ))
         ])
 }
 
@@ -50,8 +81,10 @@ pub async fn run( runtime_client: &RuntimeClient, ctx: Context, interaction_data
 
     // Based on the sub command's name, delegate to the correct execution unit
     match sub_command_name.as_str() {
-        "register" => profile_commands::register::run( &runtime_client, ctx, interaction_data ).await,    
-        "deregister" => profile_commands::deregister::run( &runtime_client, ctx, interaction_data ).await,
+        "register" => profile_commands::register::run( runtime_client, ctx, interaction_data ).await,    
+        "deregister" => profile_commands::deregister::run( runtime_client, ctx, interaction_data ).await,
+        "view" => profile_commands::view::run( runtime_client, ctx, interaction_data ).await,
+        "settings" => profile_commands::settings::run( runtime_client, ctx, interaction_data ).await,
         // In the case that an unknown sub command is recived, inform the terminal of this
         // occurance
         unimplemented_subcommand => println!( "{}", create_log_message(
@@ -68,3 +101,20 @@ pub async fn run( runtime_client: &RuntimeClient, ctx: Context, interaction_data
     }
 }
 
+
+pub async fn handle_component_interaction( runtime_client: &RuntimeClient, interaction_data: ComponentInteraction, ctx: Context, split_custom_id: Vec<&str> ) {
+    match split_custom_id[1] {
+        "register" => profile_commands::register::handle_component(runtime_client, interaction_data, ctx, split_custom_id).await,
+        unknown_component => println!( "{}", create_log_message(
+                format!(
+                    "Recieved unknown `{}profile{}` component: `{}{}{}`",
+                    ColourCode::Info,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    unknown_component,
+                    ColourCode::Reset
+                ),
+                ColourCode::Warning
+        ))
+    }
+}
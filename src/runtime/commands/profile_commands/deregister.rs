@@ -1,5 +1,5 @@
 use crate::{
-    runtime::{runtime_client::RuntimeClient, sql_scripts},
+    runtime::{context_keys, runtime_client::RuntimeClient, sql_scripts},
     utils::misc::{colour_codes::ColourCode, logging::create_log_message},
 };
 
@@ -71,6 +71,15 @@ pub async fn run(
 
             match remove_user_query.await {
                 Ok(_) => {
+                    // The user's characters were removed along with their profile, so they have
+                    // to leave the cache too
+                    {
+                        let mut data_writer = ctx.data.write().await;
+                        data_writer.get_mut::<context_keys::UserCharactersCache>()
+                            .expect("Key inserted in main.rs")
+                            .remove(&invoker_id);
+                    }
+
                     println!( "{}", create_log_message(
                             format!(
                                 "Sccessfully removed user `{}{}:#{}{}` from the database",
@@ -84,6 +93,7 @@ pub async fn run(
 
                     break 'response_embed CreateEmbed::new()
                         .title("Successfully removed you from the database")
+                        .description("Along with all of your characters")
                         .colour(ColourCode::Success.to_embed_colour())
                 },
                 Err(query_err) => {
@@ -1,3 +1,4 @@
 pub mod deregister;
 pub mod register;
-
+pub mod settings;
+pub mod view;
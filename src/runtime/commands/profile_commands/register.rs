@@ -6,7 +6,10 @@ use crate::{
 use serenity::{
     builder::{CreateEmbed, CreateInteractionResponse, CreateInteractionResponseMessage},
     client::Context,
-    model::application::CommandInteraction,
+    model::{
+        application::{CommandInteraction, ComponentInteraction},
+        user::User
+    },
 };
 
 pub async fn run(
@@ -14,10 +17,77 @@ pub async fn run(
     ctx: Context,
     interaction_data: CommandInteraction,
 ) {
-    let invoker_id = interaction_data.user.id.get();
-    let invoker_tag = interaction_data.user.tag();
+    let response_embed = register_user(runtime_client, &interaction_data.user).await;
+
+    let response = CreateInteractionResponse::Message(
+        CreateInteractionResponseMessage::new().embed(response_embed)
+    );
+    let response_payload = interaction_data.create_response(&ctx.http, response);
+
+    if let Err(response_send_err) = response_payload.await {
+        println!( "{}", create_log_message(
+                format!(
+                    "{}profile::register{}: Failed to send response: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    response_send_err,
+                    ColourCode::Reset
+                ),
+                ColourCode::Error
+        ));
+    }
+
+}
+
+
+/// Handles the `Register` button that refusals for a missing profile carry, see
+/// `preconditions::Precondition::RegisteredProfile`
+pub async fn handle_component(
+    runtime_client: &RuntimeClient,
+    component_interaction: ComponentInteraction,
+    ctx: Context,
+    split_custom_id: Vec<&str>,
+) {
+    // Only the user who was refused gets to press the button
+    let is_intended_user = split_custom_id.get(2)
+        .and_then(|user_id| user_id.parse::<u64>().ok())
+        .is_some_and(|user_id| user_id == component_interaction.user.id.get());
+
+    let response = if is_intended_user {
+        let response_embed = register_user(runtime_client, &component_interaction.user).await;
+
+        CreateInteractionResponse::UpdateMessage(
+            CreateInteractionResponseMessage::new()
+                .embed(response_embed)
+                .components(vec![])
+        )
+    } else {
+        CreateInteractionResponse::Acknowledge
+    };
+
+    if let Err(response_send_err) = component_interaction.create_response(&ctx.http, response).await {
+        println!( "{}", create_log_message(
+                format!(
+                    "{}profile::register::handle_component{}: Failed to send response: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    response_send_err,
+                    ColourCode::Reset
+                ),
+                ColourCode::Error
+        ));
+    }
+}
 
-    let response_embed = 'response_embed: {
+
+/// Adds the user to the database, returning the embed describing the outcome
+async fn register_user( runtime_client: &RuntimeClient, user: &User ) -> CreateEmbed {
+    let invoker_id = user.id.get();
+    let invoker_tag = user.tag();
+
+    'response_embed: {
 
         // --== CHECK IF USER IN DB ==-- //
 
@@ -101,25 +171,5 @@ pub async fn run(
             }
         // ==--
 
-    }; // let response_embed = {...}
-
-    let response = CreateInteractionResponse::Message(
-        CreateInteractionResponseMessage::new().embed(response_embed)
-    );
-    let response_payload = interaction_data.create_response(&ctx.http, response);
-
-    if let Err(response_send_err) = response_payload.await {
-        println!( "{}", create_log_message(
-                format!(
-                    "{}profile::register{}: Failed to send response: `{}{}{}`",
-                    ColourCode::Location,
-                    ColourCode::Reset,
-                    ColourCode::Info,
-                    response_send_err,
-                    ColourCode::Reset
-                ),
-                ColourCode::Error
-        ));
-    }
-
+    } // 'response_embed: {...}
 }
@@ -0,0 +1,137 @@
+use crate::{
+    runtime::{commands::sub_command_options, runtime_client::RuntimeClient, sql_scripts},
+    utils::misc::{
+        colour_codes::ColourCode,
+        logging::create_log_message,
+        utc_offset::{format_utc_offset, parse_utc_offset}
+    },
+};
+
+use serenity::{
+    builder::{CreateEmbed, CreateInteractionResponse, CreateInteractionResponseMessage},
+    client::Context,
+    model::application::{CommandInteraction, ResolvedValue},
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let invoker_id = interaction_data.user.id.get();
+    let invoker_tag = interaction_data.user.tag();
+
+    let response_embed = 'response_embed: {
+
+        // --== FETCH CURRENT SETTINGS ==-- //
+
+            let mut preferences = match sql_scripts::discord_users::get_by_user_id(&runtime_client.database_connection, invoker_id).await {
+                Ok(Some(discord_user)) => discord_user.preferences,
+                Ok(None) => {
+                    break 'response_embed CreateEmbed::new()
+                        .title("You're not in the database")
+                        .description("Use `/profile register` to add yourself")
+                        .colour(ColourCode::Info.to_embed_colour());
+                },
+                Err(query_err) => {
+                    println!( "{}", create_log_message(
+                            format!(
+                                "{}profile::settings::get_by_user_id{}: Failed to fetch profile: `{}{}{}`",
+                                ColourCode::Location,
+                                ColourCode::Reset,
+                                ColourCode::Info,
+                                query_err,
+                                ColourCode::Reset
+                            ),
+                            ColourCode::Error
+                    ));
+
+                    break 'response_embed CreateEmbed::new()
+                        .title("An unexpected error occured :(")
+                        .description(format!("We were unable to fetch your settings\n{query_err}"))
+                        .colour(ColourCode::Error.to_embed_colour());
+                }
+            };
+        // ==--
+
+        // --== APPLY CHANGES ==-- //
+
+            // Every option is optional, so invoking the command without any simply shows the
+            // current settings
+            let sub_options = sub_command_options(&interaction_data);
+            let has_changes = !sub_options.is_empty();
+
+            for option in sub_options {
+                match (option.name, option.value) {
+                    ("time_zone", ResolvedValue::String(time_zone)) => match parse_utc_offset(time_zone) {
+                        Some(offset_minutes) => preferences.utc_offset_minutes = offset_minutes,
+                        None => {
+                            break 'response_embed CreateEmbed::new()
+                                .title("Unrecognised time zone")
+                                .description(format!(
+                                    "`{time_zone}` isn't a UTC offset. Try something like `UTC+2` or `-03:30`"
+                                ))
+                                .colour(ColourCode::Error.to_embed_colour());
+                        }
+                    },
+                    ("public_characters", ResolvedValue::Boolean(is_public)) => preferences.characters_public = is_public,
+                    ("private_wizard", ResolvedValue::Boolean(is_private)) => preferences.ephemeral_wizard = is_private,
+                    _ => {}
+                }
+            }
+
+            if has_changes
+                && let Err(query_err) = sql_scripts::discord_users::set_preferences(&runtime_client.database_connection, invoker_id, &preferences).await
+            {
+                println!( "{}", create_log_message(
+                        format!(
+                            "Failed to update settings of user `{}{}:#{}{}`: `{}{}{}`",
+                            ColourCode::Info,
+                            invoker_tag,
+                            invoker_id,
+                            ColourCode::Reset,
+                            ColourCode::Info,
+                            query_err,
+                            ColourCode::Reset
+                        ),
+                        ColourCode::Error
+                ));
+
+                break 'response_embed CreateEmbed::new()
+                    .title("Failed to update your settings :(")
+                    .description(format!("`{query_err}`"))
+                    .colour(ColourCode::Error.to_embed_colour());
+            }
+        // ==--
+
+        CreateEmbed::new()
+            .title(if has_changes { "Your settings have been updated" } else { "Your settings" })
+            .field("Time zone", format_utc_offset(preferences.utc_offset_minutes), true)
+            .field("Characters public", if preferences.characters_public { "Yes" } else { "No" }, true)
+            .field("Private wizard", if preferences.ephemeral_wizard { "Yes" } else { "No" }, true)
+            .colour(if has_changes { ColourCode::Success } else { ColourCode::Info }.to_embed_colour())
+
+    }; // let response_embed = {...}
+
+    let response = CreateInteractionResponse::Message(
+        CreateInteractionResponseMessage::new()
+            .embed(response_embed)
+            .ephemeral(true)
+    );
+    let response_payload = interaction_data.create_response(&ctx.http, response);
+
+    if let Err(response_send_err) = response_payload.await {
+        println!( "{}", create_log_message(
+                format!(
+                    "{}profile::settings{}: Failed to send response: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    response_send_err,
+                    ColourCode::Reset
+                ),
+                ColourCode::Error
+        ));
+    }
+
+}
@@ -0,0 +1,152 @@
+use crate::{
+    runtime::{commands::sub_command_options, runtime_client::RuntimeClient, sql_scripts},
+    utils::misc::{colour_codes::ColourCode, logging::create_log_message, utc_offset::format_utc_offset},
+};
+
+use serenity::{
+    builder::{CreateEmbed, CreateInteractionResponse, CreateInteractionResponseMessage},
+    client::Context,
+    model::application::{CommandInteraction, ResolvedValue},
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let invoker_id = interaction_data.user.id.get();
+
+    // Whose profile to show, the invoker's unless they picked someone else
+    let target_user = sub_command_options(&interaction_data)
+        .into_iter()
+        .find_map(|option| match option.value {
+            ResolvedValue::User(user, _) if option.name == "user" => Some(user.clone()),
+            _ => None
+        })
+        .unwrap_or_else(|| interaction_data.user.clone());
+    let target_id = target_user.id.get();
+    let is_own_profile = target_id == invoker_id;
+
+    let response_embed = 'response_embed: {
+
+        // --== FETCH PROFILE ==-- //
+
+            let discord_user = match sql_scripts::discord_users::get_by_user_id(&runtime_client.database_connection, target_id).await {
+                Ok(Some(discord_user)) => discord_user,
+                Ok(None) if is_own_profile => {
+                    break 'response_embed CreateEmbed::new()
+                        .title("You're not in the database")
+                        .description("Use `/profile register` to add yourself")
+                        .colour(ColourCode::Info.to_embed_colour());
+                },
+                Ok(None) => {
+                    break 'response_embed CreateEmbed::new()
+                        .title(format!( "{} isn't in the database", target_user.name ))
+                        .colour(ColourCode::Info.to_embed_colour());
+                },
+                Err(query_err) => {
+                    println!( "{}", create_log_message(
+                            format!(
+                                "{}profile::view::get_by_user_id{}: Failed to fetch profile: `{}{}{}`",
+                                ColourCode::Location,
+                                ColourCode::Reset,
+                                ColourCode::Info,
+                                query_err,
+                                ColourCode::Reset
+                            ),
+                            ColourCode::Error
+                    ));
+
+                    break 'response_embed CreateEmbed::new()
+                        .title("An unexpected error occured :(")
+                        .description(format!("We were unable to fetch the profile\n{query_err}"))
+                        .colour(ColourCode::Error.to_embed_colour());
+                }
+            };
+        // ==--
+
+        // --== FETCH CHARACTERS ==-- //
+
+            let characters = match sql_scripts::characters::get_characters_by_owner(&runtime_client.database_connection, target_id).await {
+                Ok(characters) => characters,
+                Err(query_err) => {
+                    println!( "{}", create_log_message(
+                            format!(
+                                "{}profile::view::get_characters_by_owner{}: Failed to fetch characters: `{}{}{}`",
+                                ColourCode::Location,
+                                ColourCode::Reset,
+                                ColourCode::Info,
+                                query_err,
+                                ColourCode::Reset
+                            ),
+                            ColourCode::Error
+                    ));
+
+                    break 'response_embed CreateEmbed::new()
+                        .title("An unexpected error occured :(")
+                        .description(format!("We were unable to fetch the profile's characters\n{query_err}"))
+                        .colour(ColourCode::Error.to_embed_colour());
+                }
+            };
+        // ==--
+
+        // --== BUILD EMBED ==-- //
+
+            let registered_at = match discord_user.registered_at {
+                Some(timestamp) => format!("<t:{timestamp}:D>"),
+                None => String::from("Unknown")
+            };
+
+            // Others only get to see the characters if the owner allows it
+            let can_see_characters = is_own_profile || discord_user.preferences.characters_public;
+
+            let (character_count, selected_character) = if can_see_characters {
+                let selected_character = discord_user.selected_character
+                    .and_then(|selected_id| characters.iter().find(|character| character.id == selected_id))
+                    .map_or(String::from("None"), |character| character.name.clone());
+
+                (characters.len().to_string(), selected_character)
+            } else {
+                (String::from("Hidden"), String::from("Hidden"))
+            };
+
+            let mut profile_embed = CreateEmbed::new()
+                .title(format!( "{}'s profile", target_user.name ))
+                .thumbnail(target_user.face())
+                .field("Registered", registered_at, true)
+                .field("Characters", character_count, true)
+                .field("Selected character", selected_character, true)
+                .colour(ColourCode::Info.to_embed_colour());
+
+            if is_own_profile {
+                profile_embed = profile_embed
+                    .field("Time zone", format_utc_offset(discord_user.preferences.utc_offset_minutes), true)
+                    .field("Characters public", if discord_user.preferences.characters_public { "Yes" } else { "No" }, true)
+                    .field("Private wizard", if discord_user.preferences.ephemeral_wizard { "Yes" } else { "No" }, true);
+            }
+
+            profile_embed
+        // ==--
+
+    }; // let response_embed = {...}
+
+    let response = CreateInteractionResponse::Message(
+        CreateInteractionResponseMessage::new().embed(response_embed)
+    );
+    let response_payload = interaction_data.create_response(&ctx.http, response);
+
+    if let Err(response_send_err) = response_payload.await {
+        println!( "{}", create_log_message(
+                format!(
+                    "{}profile::view{}: Failed to send response: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    response_send_err,
+                    ColourCode::Reset
+                ),
+                ColourCode::Error
+        ));
+    }
+
+}
@@ -0,0 +1,223 @@
+use crate::{
+    runtime::{
+        commands::{self, session_commands},
+        runtime_client::RuntimeClient,
+        sql_scripts::{
+            self,
+            models::{RsvpResponse, Session, SessionRsvp}
+        }
+    },
+    utils::misc::{
+        colour_codes::ColourCode, logging::create_log_message
+    }
+};
+
+use serenity::{
+    builder::{
+        CreateActionRow,
+        CreateAutocompleteResponse,
+        CreateButton,
+        CreateCommand,
+        CreateCommandOption,
+        CreateEmbed,
+        CreateEmbedFooter,
+        CreateInteractionResponse
+    },
+    model::{
+        application::{
+            ButtonStyle,
+            CommandInteraction,
+            CommandOptionType,
+            ComponentInteraction
+        },
+        channel::ChannelType
+    },
+    client::Context
+};
+
+pub fn build() -> CreateCommand {
+    CreateCommand::new("session")
+        .description("Schedule game sessions and see who's coming")
+        .set_options(vec![
+                CreateCommandOption::new(CommandOptionType::SubCommand, "schedule", "Announce a game session, players answer whether they'll attend")
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::String, "when", "Such as `friday 19:00`, `2026-10-24 7pm UTC+2` or `in 3 days`")
+                            .required(true)
+                            .max_length(100)
+                    )
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::String, "title", "What the session is called")
+                            .required(true)
+                            .max_length(100)
+                    )
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::Channel, "channel", "Where to announce it, defaults to this channel")
+                            .channel_types(vec![ ChannelType::Text ])
+                    ),
+                CreateCommandOption::new(CommandOptionType::SubCommand, "cancel", "Call off a session. Organiser only")
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::String, "session", "The session to cancel")
+                            .required(true)
+                            .set_autocomplete(true)
+                    ),
+                CreateCommandOption::new(CommandOptionType::SubCommand, "list", "List the sessions coming up in this server")
+        ])
+}
+
+
+pub async fn run( runtime_client: &RuntimeClient, ctx: Context, interaction_data: CommandInteraction ) {
+    let sub_command_name = &interaction_data
+        .data
+        .options[0] // Because this command has sub commands, we know that there exists a 0th
+                    // option, and that it contains the name of the selected sub command
+        .name;
+
+
+    // Based on the sub command's name, delegate to the correct execution unit
+    match sub_command_name.as_str() {
+        "schedule" => session_commands::schedule::run(runtime_client, ctx, interaction_data).await,
+        "cancel"   => session_commands::cancel::run(runtime_client, ctx, interaction_data).await,
+        "list"     => session_commands::list::run(runtime_client, ctx, interaction_data).await,
+        // In the case that an unknown sub command is recived, inform the terminal of this
+        // occurance
+        unimplemented_subcommand => println!( "{}", create_log_message(
+                format!(
+                    "{}session{}: Recieved unknown sub command: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    unimplemented_subcommand,
+                    ColourCode::Reset
+                ),
+                ColourCode::Warning
+        ))
+    }
+}
+
+
+/// Answers an autocomplete request for a session option with the server's upcoming sessions whose
+/// titles contain what's been typed so far. The choices carry the session's ID
+pub async fn autocomplete( runtime_client: &RuntimeClient, ctx: Context, interaction_data: CommandInteraction ) {
+    let typed_so_far = commands::typed_so_far(&interaction_data);
+    let sessions = match interaction_data.guild_id {
+        Some(guild_id) => sql_scripts::sessions::get_upcoming(&runtime_client.database_connection, guild_id.get())
+            .await
+            .unwrap_or_default(),
+        None => vec![]
+    };
+
+    let mut autocomplete_response = CreateAutocompleteResponse::new();
+    for session in sessions.into_iter().filter(|session| session.title.to_lowercase().contains(&typed_so_far)).take(25) {
+        // Choice names are plain text, so the start time can't be a Discord timestamp
+        let starts_at = chrono::DateTime::from_timestamp(session.starts_at, 0)
+            .map_or(String::new(), |starts_at| starts_at.format(" (%Y-%m-%d %H:%M UTC)").to_string());
+        autocomplete_response = autocomplete_response.add_string_choice(format!( "{}{starts_at}", session.title ), session.id.to_string());
+    }
+
+    let send_choices = interaction_data.create_response(&ctx.http, CreateInteractionResponse::Autocomplete(autocomplete_response));
+    if let Err(why) = send_choices.await {
+        println!( "{}", create_log_message(
+                format!(
+                    "Failed to send autocomplete choices: `{}{}{}`",
+                    ColourCode::Info,
+                    why,
+                    ColourCode::Reset
+                ),
+                ColourCode::Caution
+        ));
+    }
+}
+
+
+pub async fn handle_component_interaction( runtime_client: &RuntimeClient, interaction_data: ComponentInteraction, ctx: Context, split_custom_id: Vec<&str> ) {
+    match split_custom_id[1] {
+        "rsvp" => session_commands::rsvp::handle_component(runtime_client, interaction_data, ctx, split_custom_id).await,
+        unknown_component => println!( "{}", create_log_message(
+                format!(
+                    "{}session{}: Recieved unknown component: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    unknown_component,
+                    ColourCode::Reset
+                ),
+                ColourCode::Warning
+        ))
+    }
+}
+
+
+
+// --== ANNOUNCEMENT ==-- //
+
+    /// Builds the embed announcing a session, listing who answered what
+    pub fn session_embed( session: &Session, rsvps: &[SessionRsvp] ) -> CreateEmbed {
+        let mut session_embed = CreateEmbed::new()
+            .description(format!(
+                "<t:{0}:F>, <t:{0}:R>\nOrganised by <@{1}>",
+                session.starts_at,
+                session.organiser.get()
+            ))
+            .footer(CreateEmbedFooter::new(format!( "Session #{}", session.id )));
+
+        session_embed = if session.is_cancelled {
+            session_embed
+                .title(format!( "~~{}~~ (cancelled)", session.title ))
+                .colour(ColourCode::Caution.to_embed_colour())
+        } else {
+            session_embed
+                .title(&session.title)
+                .colour(ColourCode::Info.to_embed_colour())
+        };
+
+        for response in RsvpResponse::ALL {
+            let responders: Vec<String> = rsvps.iter()
+                .filter(|rsvp| rsvp.response == response)
+                .map(|rsvp| format!( "<@{}>", rsvp.user.get() ))
+                .collect();
+
+            // Embed field values are capped at 1024 characters, a mention takes at most 23
+            let mut field_value = responders.iter().take(40).cloned().collect::<Vec<_>>().join("\n");
+            if responders.len() > 40 {
+                field_value.push_str(&format!( "\nand {} more", responders.len() - 40 ));
+            }
+            if field_value.is_empty() {
+                field_value.push('-');
+            }
+
+            session_embed = session_embed.field(format!( "{} ({})", response.label(), responders.len() ), field_value, true);
+        }
+
+        session_embed
+    }
+
+    /// The buttons players answer a session with. They're disabled once the session is cancelled
+    pub fn session_buttons( session: &Session ) -> Vec<CreateActionRow> {
+        let buttons = RsvpResponse::ALL.into_iter()
+            .map(|response| {
+                let style = match response {
+                    RsvpResponse::Attending => ButtonStyle::Success,
+                    RsvpResponse::Maybe     => ButtonStyle::Secondary,
+                    RsvpResponse::Declining => ButtonStyle::Danger
+                };
+
+                CreateButton::new(format!( "session|rsvp|{}|{}", session.id, response.key() ))
+                    .style(style)
+                    .label(response.label())
+                    .disabled(session.is_cancelled)
+            })
+            .collect();
+
+        vec![ CreateActionRow::Buttons(buttons) ]
+    }
+
+    /// A link to the message announcing a session, if it was posted in a server
+    pub fn session_message_link( session: &Session ) -> Option<String> {
+        session.message_id.map(|message_id| format!(
+            "https://discord.com/channels/{}/{}/{}",
+            session.guild_id.get(),
+            session.channel_id.get(),
+            message_id.get()
+        ))
+    }
+// ==--
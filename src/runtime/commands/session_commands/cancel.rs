@@ -0,0 +1,101 @@
+use crate::{
+    runtime::{
+        commands::{
+            is_server_manager, query_error_embed, respond_with_embed, string_option, sub_command_options,
+            session::{session_buttons, session_embed}
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts
+    },
+    utils::misc::{colour_codes::ColourCode, logging::create_log_message},
+};
+
+use serenity::{
+    builder::{CreateEmbed, EditMessage},
+    client::Context,
+    model::{application::CommandInteraction, id::{ChannelId, MessageId}},
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let database_conn_pool = &runtime_client.database_connection;
+    let invoker_id = interaction_data.user.id.get();
+
+    let sub_options = sub_command_options(&interaction_data);
+    let session_option = string_option(&sub_options, "session").expect("`session` is a required option");
+
+    let response_embed = 'response_embed: {
+
+        let unknown_session_embed = CreateEmbed::new()
+            .title(format!( "There's no session `{session_option}` coming up" ))
+            .description("Pick one of the suggestions, `/session list` shows them all")
+            .colour(ColourCode::Info.to_embed_colour());
+
+        let Ok(session_id) = session_option.parse::<u64>() else {
+            break 'response_embed unknown_session_embed;
+        };
+        let session = match sql_scripts::sessions::get_session(database_conn_pool, session_id).await {
+            Ok(Some(session)) if interaction_data.guild_id.is_some_and(|guild_id| guild_id.get() == session.guild_id.get()) => session,
+            Ok(_) => break 'response_embed unknown_session_embed,
+            Err(query_err) => break 'response_embed query_error_embed(
+                "session::cancel::get_session", "find the session", &query_err
+            )
+        };
+
+        if session.organiser.get() != invoker_id && !is_server_manager(interaction_data.member.as_deref()) {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "Only <@{}> can cancel {}", session.organiser.get(), session.title ))
+                .description("Staff who manage the server may step in, too")
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        match sql_scripts::sessions::cancel_session(database_conn_pool, session.id).await {
+            Ok(true) => {},
+            Ok(false) => break 'response_embed CreateEmbed::new()
+                .title(format!( "{} was already cancelled", session.title ))
+                .colour(ColourCode::Info.to_embed_colour()),
+            Err(query_err) => break 'response_embed query_error_embed(
+                "session::cancel::cancel_session", "cancel the session", &query_err
+            )
+        }
+
+        // --== UPDATE ANNOUNCEMENT ==-- //
+
+            // The cancellation already stands, so a failure here only leaves the announcement stale
+            let cancelled_session = sql_scripts::sessions::get_session(database_conn_pool, session.id).await;
+            let rsvps = sql_scripts::sessions::get_rsvps(database_conn_pool, session.id).await;
+
+            if let (Ok(Some(cancelled_session)), Ok(rsvps), Some(message_id)) = (cancelled_session, rsvps, session.message_id) {
+                let edited_announcement = EditMessage::new()
+                    .embed(session_embed(&cancelled_session, &rsvps))
+                    .components(session_buttons(&cancelled_session));
+
+                let channel_id = ChannelId::new(session.channel_id.get());
+                if let Err(why) = channel_id.edit_message(&ctx.http, MessageId::new(message_id.get()), edited_announcement).await {
+                    println!( "{}", create_log_message(
+                            format!(
+                                "{}session::cancel{}: Failed to update announcement: `{}{}{}`",
+                                ColourCode::Location,
+                                ColourCode::Reset,
+                                ColourCode::Info,
+                                why,
+                                ColourCode::Reset
+                            ),
+                            ColourCode::Warning
+                    ));
+                }
+            }
+        // ==--
+
+        CreateEmbed::new()
+            .title(format!( "{} is cancelled", session.title ))
+            .description("No reminders will go out for it")
+            .colour(ColourCode::Success.to_embed_colour())
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, true, "session::cancel").await;
+}
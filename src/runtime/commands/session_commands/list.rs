@@ -0,0 +1,76 @@
+use crate::{
+    runtime::{
+        commands::{query_error_embed, respond_with_embed, session::session_message_link},
+        runtime_client::RuntimeClient,
+        sql_scripts::{self, models::RsvpResponse}
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let database_conn_pool = &runtime_client.database_connection;
+
+    let response_embed = 'response_embed: {
+
+        let Some(guild_id) = interaction_data.guild_id else {
+            break 'response_embed CreateEmbed::new()
+                .title("Sessions are only scheduled in servers")
+                .colour(ColourCode::Info.to_embed_colour());
+        };
+
+        let sessions = match sql_scripts::sessions::get_upcoming(database_conn_pool, guild_id.get()).await {
+            Ok(sessions) => sessions,
+            Err(query_err) => break 'response_embed query_error_embed(
+                "session::list::get_upcoming", "fetch the upcoming sessions", &query_err
+            )
+        };
+
+        if sessions.is_empty() {
+            break 'response_embed CreateEmbed::new()
+                .title("No sessions are coming up")
+                .description("`/session schedule` announces one")
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        let mut session_list = String::new();
+        // Embed descriptions are capped at 4096 characters, each line takes under 200
+        for session in sessions.iter().take(20) {
+            let attending = match sql_scripts::sessions::get_rsvps(database_conn_pool, session.id).await {
+                Ok(rsvps) => rsvps.iter().filter(|rsvp| rsvp.response == RsvpResponse::Attending).count(),
+                Err(query_err) => break 'response_embed query_error_embed(
+                    "session::list::get_rsvps", "count who's attending", &query_err
+                )
+            };
+
+            let title = match session_message_link(session) {
+                Some(message_link) => format!( "[{}]({message_link})", session.title ),
+                None => session.title.clone()
+            };
+            session_list.push_str(&format!(
+                "- **{title}** <t:{0}:F>, <t:{0}:R>. {attending} attending\n",
+                session.starts_at
+            ));
+        }
+        if sessions.len() > 20 {
+            session_list.push_str(&format!( "and {} more", sessions.len() - 20 ));
+        }
+
+        CreateEmbed::new()
+            .title("Upcoming sessions")
+            .description(session_list)
+            .colour(ColourCode::Info.to_embed_colour())
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, true, "session::list").await;
+}
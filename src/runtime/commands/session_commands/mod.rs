@@ -0,0 +1,4 @@
+pub mod schedule;
+pub mod cancel;
+pub mod list;
+pub mod rsvp;
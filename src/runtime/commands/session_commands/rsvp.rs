@@ -0,0 +1,105 @@
+use crate::{
+    runtime::{
+        commands::{query_error_embed, session::{session_buttons, session_embed}},
+        runtime_client::RuntimeClient,
+        sql_scripts::{self, models::RsvpResponse}
+    },
+    utils::misc::{colour_codes::ColourCode, logging::create_log_message},
+};
+
+use serenity::{
+    builder::{CreateEmbed, CreateInteractionResponse, CreateInteractionResponseMessage},
+    client::Context,
+    model::application::ComponentInteraction,
+};
+
+// RSVP buttons follow the format:
+//     session|rsvp|session_id|response
+// Anyone who can see the announcement may answer it
+
+pub async fn handle_component(
+    runtime_client: &RuntimeClient,
+    component_interaction: ComponentInteraction,
+    ctx: Context,
+    split_custom_id: Vec<&str>,
+) {
+    let database_conn_pool = &runtime_client.database_connection;
+    let invoker_id = component_interaction.user.id.get();
+
+    let session_id = split_custom_id.get(2).and_then(|session_id| session_id.parse::<u64>().ok());
+    let response = split_custom_id.get(3).and_then(|response| RsvpResponse::from_key(response));
+
+    // Answers refresh the announcement, refusals are only shown to whoever pressed the button
+    let response = 'response: {
+        let refusal = |refusal_embed: CreateEmbed| CreateInteractionResponse::Message(
+            CreateInteractionResponseMessage::new()
+                .embed(refusal_embed)
+                .ephemeral(true)
+        );
+
+        let (Some(session_id), Some(response)) = (session_id, response) else {
+            break 'response refusal(
+                CreateEmbed::new()
+                    .title("This session can't be answered anymore")
+                    .colour(ColourCode::Info.to_embed_colour())
+            );
+        };
+
+        let session = match sql_scripts::sessions::get_session(database_conn_pool, session_id).await {
+            Ok(Some(session)) => session,
+            Ok(None) => break 'response refusal(
+                CreateEmbed::new()
+                    .title("This session no longer exists")
+                    .colour(ColourCode::Info.to_embed_colour())
+            ),
+            Err(query_err) => break 'response refusal(query_error_embed(
+                "session::rsvp::get_session", "find the session", &query_err
+            ))
+        };
+
+        if session.is_cancelled {
+            break 'response refusal(
+                CreateEmbed::new()
+                    .title(format!( "{} was cancelled", session.title ))
+                    .colour(ColourCode::Info.to_embed_colour())
+            );
+        }
+        if session.starts_at <= chrono::Utc::now().timestamp() {
+            break 'response refusal(
+                CreateEmbed::new()
+                    .title(format!( "{} has already started", session.title ))
+                    .colour(ColourCode::Info.to_embed_colour())
+            );
+        }
+
+        if let Err(query_err) = sql_scripts::sessions::set_rsvp(database_conn_pool, session.id, invoker_id, response).await {
+            break 'response refusal(query_error_embed("session::rsvp::set_rsvp", "record your answer", &query_err));
+        }
+        let rsvps = match sql_scripts::sessions::get_rsvps(database_conn_pool, session.id).await {
+            Ok(rsvps) => rsvps,
+            Err(query_err) => break 'response refusal(query_error_embed(
+                "session::rsvp::get_rsvps", "fetch everyone's answers", &query_err
+            ))
+        };
+
+        CreateInteractionResponse::UpdateMessage(
+            CreateInteractionResponseMessage::new()
+                .embed(session_embed(&session, &rsvps))
+                .components(session_buttons(&session))
+        )
+    };
+
+    if let Err(response_send_err) = component_interaction.create_response(&ctx.http, response).await {
+        println!( "{}", create_log_message(
+                format!(
+                    "{}session::rsvp::handle_component{}: Failed to send response: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    response_send_err,
+                    ColourCode::Reset
+                ),
+                ColourCode::Error
+        ));
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{
+    runtime::{
+        commands::{
+            channel_option, query_error_embed, respond_with_embed, string_option, sub_command_options,
+            session::{session_buttons, session_embed}
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts
+    },
+    utils::misc::{
+        colour_codes::ColourCode, logging::create_log_message,
+        schedule_time::{format_minutes, parse_start_time}, utc_offset::format_utc_offset
+    },
+};
+
+use serenity::{
+    builder::{CreateEmbed, CreateMessage},
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let database_conn_pool = &runtime_client.database_connection;
+    let invoker_id = interaction_data.user.id.get();
+    let invoker_tag = interaction_data.user.tag();
+
+    let sub_options = sub_command_options(&interaction_data);
+    let when = string_option(&sub_options, "when").expect("`when` is a required option");
+    let title = string_option(&sub_options, "title")
+        .expect("`title` is a required option")
+        .trim()
+        .to_string();
+    // Sessions are announced where they're scheduled, unless told otherwise. Channel options only
+    // offer the channels of the server the command is used in
+    let announcement_channel = channel_option(&sub_options, "channel")
+        .unwrap_or(interaction_data.channel_id);
+
+    let response_embed = 'response_embed: {
+
+        let Some(guild_id) = interaction_data.guild_id else {
+            break 'response_embed CreateEmbed::new()
+                .title("Sessions can only be scheduled in servers")
+                .colour(ColourCode::Info.to_embed_colour());
+        };
+
+        // --== PARSE START TIME ==-- //
+
+            // Times without an offset are read in the invoker's own time zone
+            let utc_offset_minutes = match sql_scripts::discord_users::get_by_user_id(database_conn_pool, invoker_id).await {
+                Ok(discord_user) => discord_user.map_or(0, |discord_user| discord_user.preferences.utc_offset_minutes),
+                Err(query_err) => break 'response_embed query_error_embed(
+                    "session::schedule::get_by_user_id", "fetch your time zone", &query_err
+                )
+            };
+
+            let now = chrono::Utc::now();
+            let starts_at = match parse_start_time(when, utc_offset_minutes, now) {
+                Some(starts_at) if starts_at > now => starts_at.timestamp(),
+                Some(starts_at) => break 'response_embed CreateEmbed::new()
+                    .title("That time has already passed")
+                    .description(format!( "`{when}` was <t:{}:R>", starts_at.timestamp() ))
+                    .colour(ColourCode::Info.to_embed_colour()),
+                None => break 'response_embed CreateEmbed::new()
+                    .title(format!( "`{when}` isn't a time we understand" ))
+                    .description(format!(
+                        "Try something like `friday 19:00`, `tomorrow 7pm`, `2026-10-24 19:30`, `24/10 7pm` or `in 3 days`. \
+                        Times are read as {}, your `/profile settings` time zone, unless they end with one such as `UTC+2`",
+                        format_utc_offset(utc_offset_minutes)
+                    ))
+                    .colour(ColourCode::Info.to_embed_colour())
+            };
+        // ==--
+
+        // --== INSERT SESSION ==-- //
+
+            let add_session_query = sql_scripts::sessions::add_session(
+                database_conn_pool,
+                guild_id.get(),
+                announcement_channel.get(),
+                invoker_id,
+                &title,
+                starts_at,
+                &runtime_client.session_reminder_offsets
+            );
+            let session = match add_session_query.await {
+                Ok(session_id) => match sql_scripts::sessions::get_session(database_conn_pool, session_id).await {
+                    Ok(Some(session)) => session,
+                    Ok(None) => break 'response_embed CreateEmbed::new()
+                        .title("The session vanished as it was made")
+                        .colour(ColourCode::Error.to_embed_colour()),
+                    Err(query_err) => break 'response_embed query_error_embed(
+                        "session::schedule::get_session", "fetch the new session", &query_err
+                    )
+                },
+                Err(query_err) => break 'response_embed query_error_embed(
+                    "session::schedule::add_session", "schedule the session", &query_err
+                )
+            };
+        // ==--
+
+        // --== ANNOUNCE ==-- //
+
+            let announcement = CreateMessage::new()
+                .embed(session_embed(&session, &[]))
+                .components(session_buttons(&session));
+
+            let announcement_message = match announcement_channel.send_message(&ctx.http, announcement).await {
+                Ok(announcement_message) => announcement_message,
+                Err(why) => {
+                    // A session nobody can see is no use, so take it back out
+                    if let Err(query_err) = sql_scripts::sessions::remove_session(database_conn_pool, session.id).await {
+                        println!( "{}", create_log_message(
+                                format!(
+                                    "{}session::schedule::remove_session{}: Failed to remove unannounced session: `{}{}{}`",
+                                    ColourCode::Location,
+                                    ColourCode::Reset,
+                                    ColourCode::Info,
+                                    query_err,
+                                    ColourCode::Reset
+                                ),
+                                ColourCode::Error
+                        ));
+                    }
+
+                    break 'response_embed CreateEmbed::new()
+                        .title("We couldn't announce the session")
+                        .description(format!( "Posting in <#{}> failed: {why}", announcement_channel.get() ))
+                        .colour(ColourCode::Error.to_embed_colour());
+                }
+            };
+
+            if let Err(query_err) = sql_scripts::sessions::set_message(database_conn_pool, session.id, announcement_message.id.get()).await {
+                break 'response_embed query_error_embed("session::schedule::set_message", "remember the announcement", &query_err);
+            }
+        // ==--
+
+        println!( "{}", create_log_message(
+                format!(
+                    "`{}{}:#{}{}` scheduled session `{}{}{}`",
+                    ColourCode::Info,
+                    invoker_tag,
+                    invoker_id,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    title,
+                    ColourCode::Reset
+                ),
+                ColourCode::Success
+        ));
+
+        let reminders: Vec<String> = runtime_client.session_reminder_offsets.iter()
+            .filter(|offset_minutes| starts_at - **offset_minutes * 60 > now.timestamp())
+            .map(|offset_minutes| format_minutes(*offset_minutes))
+            .collect();
+        let reminder_line = if reminders.is_empty() {
+            String::from("It's too soon for any reminders")
+        } else {
+            format!( "Reminders go out {} before", reminders.join(", ") )
+        };
+
+        CreateEmbed::new()
+            .title(format!( "{title} is scheduled" ))
+            .description(format!(
+                "Starts <t:{starts_at}:F>, announced in {}\n{reminder_line}",
+                announcement_message.link()
+            ))
+            .colour(ColourCode::Success.to_embed_colour())
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, true, "session::schedule").await;
+}
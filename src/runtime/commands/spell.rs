@@ -0,0 +1,118 @@
+use crate::{
+    runtime::{
+        commands::{self, spell_commands, xp::autocomplete_lores},
+        runtime_client::RuntimeClient,
+        sql_scripts
+    },
+    utils::misc::{
+        colour_codes::ColourCode, logging::create_log_message
+    }
+};
+
+use serenity::{
+    builder::{
+        CreateAutocompleteResponse,
+        CreateCommand,
+        CreateCommandOption,
+        CreateInteractionResponse
+    },
+    model::application::{
+        CommandInteraction,
+        CommandOptionType
+    },
+    client::Context
+};
+
+pub fn build() -> CreateCommand {
+    CreateCommand::new("spell")
+        .description("Browse the spells of the lores of magic")
+        .set_options(vec![
+                CreateCommandOption::new(CommandOptionType::SubCommand, "list", "List the spells of a lore")
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::String, "lore", "The lore whose spells to list")
+                            .required(true)
+                            .set_autocomplete(true)
+                    ),
+                CreateCommandOption::new(CommandOptionType::SubCommand, "info", "Show what a spell does")
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::String, "spell", "The spell to look up")
+                            .required(true)
+                            .set_autocomplete(true)
+                    ),
+                CreateCommandOption::new(CommandOptionType::SubCommand, "known", "List the spells a character knows")
+                    .add_sub_option(
+                        CreateCommandOption::new(
+                            CommandOptionType::String,
+                            "character",
+                            "Defaults to your selected character, GMs may pick any character in their campaign"
+                        )
+                            .set_autocomplete(true)
+                    )
+        ])
+}
+
+
+pub async fn run( runtime_client: &RuntimeClient, ctx: Context, interaction_data: CommandInteraction ) {
+    let sub_command_name = &interaction_data
+        .data
+        .options[0] // Because this command has sub commands, we know that there exists a 0th
+                    // option, and that it contains the name of the selected sub command
+        .name;
+
+
+    // Based on the sub command's name, delegate to the correct execution unit
+    match sub_command_name.as_str() {
+        "list"  => spell_commands::list::run(runtime_client, ctx, interaction_data).await,
+        "info"  => spell_commands::info::run(runtime_client, ctx, interaction_data).await,
+        "known" => spell_commands::known::run(runtime_client, ctx, interaction_data).await,
+        // In the case that an unknown sub command is recived, inform the terminal of this
+        // occurance
+        unimplemented_subcommand => println!( "{}", create_log_message(
+                format!(
+                    "{}spell{}: Recieved unknown sub command: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    unimplemented_subcommand,
+                    ColourCode::Reset
+                ),
+                ColourCode::Warning
+        ))
+    }
+}
+
+
+pub async fn autocomplete( runtime_client: &RuntimeClient, ctx: Context, interaction_data: CommandInteraction ) {
+    match interaction_data.data.autocomplete().map(|focused_option| focused_option.name) {
+        Some("lore") => autocomplete_lores(runtime_client, &ctx, &interaction_data).await,
+        Some("spell") => autocomplete_spells(runtime_client, &ctx, &interaction_data).await,
+        _ => commands::autocomplete_campaign_characters(runtime_client, &ctx, &interaction_data).await
+    }
+}
+
+/// Answers an autocomplete request for a spell option with the spells whose names contain what's
+/// been typed so far. The choices carry the spell's ID
+async fn autocomplete_spells( runtime_client: &RuntimeClient, ctx: &Context, interaction_data: &CommandInteraction ) {
+    let typed_so_far = commands::typed_so_far(interaction_data);
+    let spells = sql_scripts::spells::get_spells(&runtime_client.database_connection)
+        .await
+        .unwrap_or_default();
+
+    let mut autocomplete_response = CreateAutocompleteResponse::new();
+    for spell in spells.into_iter().filter(|spell| spell.name.to_lowercase().contains(&typed_so_far)).take(25) {
+        autocomplete_response = autocomplete_response.add_string_choice(spell.name, spell.id.to_string());
+    }
+
+    let send_choices = interaction_data.create_response(&ctx.http, CreateInteractionResponse::Autocomplete(autocomplete_response));
+    if let Err(why) = send_choices.await {
+        println!( "{}", create_log_message(
+                format!(
+                    "Failed to send autocomplete choices: `{}{}{}`",
+                    ColourCode::Info,
+                    why,
+                    ColourCode::Reset
+                ),
+                ColourCode::Caution
+        ));
+    }
+}
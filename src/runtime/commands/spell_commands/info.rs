@@ -0,0 +1,63 @@
+use crate::{
+    runtime::{
+        commands::{query_error_embed, respond_with_embed, string_option, sub_command_options},
+        runtime_client::RuntimeClient,
+        sql_scripts
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let database_conn_pool = &runtime_client.database_connection;
+
+    let sub_options = sub_command_options(&interaction_data);
+    let spell_option = string_option(&sub_options, "spell")
+        .expect("`spell` is a required option");
+
+    let response_embed = 'response_embed: {
+
+        // The choices carry the spell's ID, but a typed in name works too
+        let spell = match sql_scripts::spells::get_spells(database_conn_pool).await {
+            Ok(spells) => spells.into_iter().find(|spell| {
+                spell_option.parse::<u64>().is_ok_and(|spell_id| spell_id == spell.id)
+                    || spell.name.eq_ignore_ascii_case(spell_option.trim())
+            }),
+            Err(query_err) => break 'response_embed query_error_embed(
+                "spell::info::get_spells", "fetch the spells", &query_err
+            )
+        };
+        let Some(spell) = spell else {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "There is no spell called `{spell_option}`" ))
+                .colour(ColourCode::Info.to_embed_colour());
+        };
+
+        let lore_name = match sql_scripts::lores::get_lore(database_conn_pool, spell.lore_id).await {
+            Ok(lore) => lore.map_or(String::from("A forgotten lore"), |lore| lore.name),
+            Err(query_err) => break 'response_embed query_error_embed(
+                "spell::info::get_lore", "fetch the spell's lore", &query_err
+            )
+        };
+
+        CreateEmbed::new()
+            .title(&spell.name)
+            .description(&spell.description)
+            .field("Lore", lore_name, true)
+            .field("Tier", spell.tier.to_string(), true)
+            .field("Cost", spell.cost.to_string(), true)
+            .colour(ColourCode::Info.to_embed_colour())
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, false, "spell::info").await;
+}
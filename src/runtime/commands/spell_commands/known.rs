@@ -0,0 +1,79 @@
+use crate::{
+    runtime::{
+        commands::{query_error_embed, resolve_character_target, respond_with_embed},
+        runtime_client::RuntimeClient,
+        sql_scripts
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let database_conn_pool = &runtime_client.database_connection;
+
+    let response_embed = 'response_embed: {
+
+        let target = match resolve_character_target(runtime_client, &ctx, &interaction_data, "character").await {
+            Ok(target) => target,
+            Err(refusal_embed) => break 'response_embed refusal_embed
+        };
+
+        let classes = match sql_scripts::character_classes::get_character_classes(database_conn_pool, target.character_id).await {
+            Ok(classes) => classes,
+            Err(query_err) => break 'response_embed query_error_embed(
+                "spell::known::get_character_classes", "check the character's class", &query_err
+            )
+        };
+        if !classes.is_empty() && classes.iter().all(|class| !class.can_cast()) {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "{} is no spellcaster", target.name ))
+                .description("Their class can't learn spells")
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        let (known_spells, lores) = match (
+            sql_scripts::spells::get_known_spells(database_conn_pool, target.character_id).await,
+            sql_scripts::lores::get_lores(database_conn_pool).await
+        ) {
+            (Ok(known_spells), Ok(lores)) => (known_spells, lores),
+            (Err(query_err), _) | (_, Err(query_err)) => break 'response_embed query_error_embed(
+                "spell::known::get_known_spells", "fetch the character's spells", &query_err
+            )
+        };
+
+        if known_spells.is_empty() {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "{} knows no spells yet", target.name ))
+                .description("Spells are learnt by spending lore points with `/xp study`")
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        // One field for each lore the character knows spells of
+        let mut known_embed = CreateEmbed::new()
+            .title(format!( "The spells {} knows", target.name ))
+            .colour(ColourCode::Info.to_embed_colour());
+        for lore in lores {
+            let spell_names: Vec<String> = known_spells.iter()
+                .filter(|spell| spell.lore_id == lore.id)
+                .map(|spell| format!( "{} (tier {})", spell.name, spell.tier ))
+                .collect();
+            if !spell_names.is_empty() {
+                known_embed = known_embed.field(lore.name, spell_names.join("\n"), true);
+            }
+        }
+
+        known_embed
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, false, "spell::known").await;
+}
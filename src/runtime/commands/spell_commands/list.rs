@@ -0,0 +1,65 @@
+use crate::{
+    runtime::{
+        commands::{query_error_embed, respond_with_embed, string_option, sub_command_options, xp::find_lore},
+        runtime_client::RuntimeClient,
+        sql_scripts
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::{CreateEmbed, CreateEmbedFooter},
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let database_conn_pool = &runtime_client.database_connection;
+
+    let sub_options = sub_command_options(&interaction_data);
+    let lore_option = string_option(&sub_options, "lore")
+        .expect("`lore` is a required option");
+
+    let response_embed = 'response_embed: {
+
+        let lore = match sql_scripts::lores::get_lores(database_conn_pool).await {
+            Ok(lores) => find_lore(lores, lore_option),
+            Err(query_err) => break 'response_embed query_error_embed(
+                "spell::list::get_lores", "fetch the lores of magic", &query_err
+            )
+        };
+        let Some(lore) = lore else {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "There is no lore called `{lore_option}`" ))
+                .colour(ColourCode::Info.to_embed_colour());
+        };
+
+        let spells = match sql_scripts::spells::get_spells_by_lore(database_conn_pool, lore.id).await {
+            Ok(spells) => spells,
+            Err(query_err) => break 'response_embed query_error_embed(
+                "spell::list::get_spells_by_lore", "fetch the lore's spells", &query_err
+            )
+        };
+
+        let mut spell_list = String::new();
+        for spell in &spells {
+            spell_list.push_str(&format!( "- **{}**, tier {}, costs {}\n", spell.name, spell.tier, spell.cost ));
+        }
+        if spells.is_empty() {
+            spell_list.push_str("No spells have been written down for this lore yet");
+        }
+
+        CreateEmbed::new()
+            .title(format!( "The spells of {}", lore.name ))
+            .description(spell_list)
+            .footer(CreateEmbedFooter::new("A spell is known once as many points as its tier are spent in its lore, with /xp study"))
+            .colour(ColourCode::Info.to_embed_colour())
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, false, "spell::list").await;
+}
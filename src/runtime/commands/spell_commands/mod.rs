@@ -0,0 +1,3 @@
+pub mod list;
+pub mod info;
+pub mod known;
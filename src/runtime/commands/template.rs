@@ -0,0 +1,152 @@
+use crate::{
+    runtime::{
+        commands::{self, template_commands},
+        runtime_client::RuntimeClient,
+        sql_scripts::{self, models::CharacterTemplate}
+    },
+    utils::misc::{
+        colour_codes::ColourCode, logging::create_log_message
+    }
+};
+
+use serenity::{
+    builder::{
+        CreateAutocompleteResponse,
+        CreateCommand,
+        CreateCommandOption,
+        CreateInteractionResponse
+    },
+    model::application::{
+        CommandInteraction,
+        CommandOptionType
+    },
+    client::Context
+};
+
+/// The template fields that are set through options, as (option name, description, max length)
+pub const TEMPLATE_FIELDS: [(&str, &str, u16); 9] = [
+    ("species", "Their species", 100),
+    ("alignment", "Their alignment", 100),
+    ("likes", "What they have a regard for", 1000),
+    ("dislikes", "What they abhor", 1000),
+    ("motivations", "What drives them", 1000),
+    ("companions", "Who they wander with", 1000),
+    ("backstory", "Their past", 1000),
+    ("appearance", "What they look like", 1000),
+    ("extras", "Anything else", 1000)
+];
+
+pub fn build() -> CreateCommand {
+    let mut create_sub_command = CreateCommandOption::new(
+        CommandOptionType::SubCommand,
+        "create",
+        "Offer an archetype players can start their characters from, server managers only"
+    )
+        .add_sub_option(
+            CreateCommandOption::new(CommandOptionType::String, "name", "What the archetype is called")
+                .required(true)
+                .max_length(100)
+        )
+        .add_sub_option(
+            CreateCommandOption::new(CommandOptionType::String, "class", "Their class")
+                .set_autocomplete(true)
+        );
+    for (field, description, max_length) in TEMPLATE_FIELDS {
+        create_sub_command = create_sub_command.add_sub_option(
+            CreateCommandOption::new(CommandOptionType::String, field, description)
+                .max_length(max_length)
+        );
+    }
+
+    CreateCommand::new("template")
+        .description("Archetypes to start building characters from")
+        .dm_permission(false)
+        .set_options(vec![
+                create_sub_command,
+                CreateCommandOption::new(CommandOptionType::SubCommand, "list", "List the archetypes on offer"),
+                CreateCommandOption::new(CommandOptionType::SubCommand, "delete", "Stop offering an archetype, server managers only")
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::String, "template", "The archetype to remove")
+                            .required(true)
+                            .set_autocomplete(true)
+                    )
+        ])
+}
+
+
+pub async fn run( runtime_client: &RuntimeClient, ctx: Context, interaction_data: CommandInteraction ) {
+    let sub_command_name = &interaction_data
+        .data
+        .options[0] // Because this command has sub commands, we know that there exists a 0th
+                    // option, and that it contains the name of the selected sub command
+        .name;
+
+
+    // Based on the sub command's name, delegate to the correct execution unit
+    match sub_command_name.as_str() {
+        "create" => template_commands::create::run(runtime_client, ctx, interaction_data).await,
+        "list"   => template_commands::list::run(runtime_client, ctx, interaction_data).await,
+        "delete" => template_commands::delete::run(runtime_client, ctx, interaction_data).await,
+        // In the case that an unknown sub command is recived, inform the terminal of this
+        // occurance
+        unimplemented_subcommand => println!( "{}", create_log_message(
+                format!(
+                    "{}template{}: Recieved unknown sub command: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    unimplemented_subcommand,
+                    ColourCode::Reset
+                ),
+                ColourCode::Warning
+        ))
+    }
+}
+
+
+pub async fn autocomplete( runtime_client: &RuntimeClient, ctx: Context, interaction_data: CommandInteraction ) {
+    match interaction_data.data.autocomplete().map(|focused_option| focused_option.name) {
+        Some("class") => commands::admin::autocomplete_classes(runtime_client, &ctx, &interaction_data).await,
+        _ => autocomplete_templates(runtime_client, &ctx, &interaction_data).await
+    }
+}
+
+/// Answers an autocomplete request for a template option with the templates whose names contain
+/// what's been typed so far. The choices carry the template's ID
+pub async fn autocomplete_templates( runtime_client: &RuntimeClient, ctx: &Context, interaction_data: &CommandInteraction ) {
+    let typed_so_far = commands::typed_so_far(interaction_data);
+    let templates = match interaction_data.guild_id {
+        Some(guild_id) => sql_scripts::templates::get_templates(&runtime_client.database_connection, guild_id.get())
+            .await
+            .unwrap_or_default(),
+        None => vec![]
+    };
+
+    let mut autocomplete_response = CreateAutocompleteResponse::new();
+    for template in templates.into_iter().filter(|template| template.name.to_lowercase().contains(&typed_so_far)).take(25) {
+        autocomplete_response = autocomplete_response.add_string_choice(template.name, template.id.to_string());
+    }
+
+    let send_choices = interaction_data.create_response(&ctx.http, CreateInteractionResponse::Autocomplete(autocomplete_response));
+    if let Err(why) = send_choices.await {
+        println!( "{}", create_log_message(
+                format!(
+                    "Failed to send autocomplete choices: `{}{}{}`",
+                    ColourCode::Info,
+                    why,
+                    ColourCode::Reset
+                ),
+                ColourCode::Caution
+        ));
+    }
+}
+
+/// Finds the template a template option refers to. The choices carry the template's ID, but a
+/// typed in name works too
+pub fn find_template( templates: Vec<CharacterTemplate>, template_option: &str ) -> Option<CharacterTemplate> {
+    templates.into_iter().find(|template| {
+        template_option.parse::<u64>().is_ok_and(|template_id| template_id == template.id)
+            || template.name.eq_ignore_ascii_case(template_option.trim())
+    })
+}
+
@@ -0,0 +1,108 @@
+use crate::{
+    runtime::{
+        commands::{
+            admin::find_class, is_server_manager, query_error_embed, respond_with_embed, string_option,
+            sub_command_options, template::TEMPLATE_FIELDS
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts::{self, models::TemplateDetails}
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let database_conn_pool = &runtime_client.database_connection;
+
+    let sub_options = sub_command_options(&interaction_data);
+    let name = string_option(&sub_options, "name")
+        .expect("`name` is a required option")
+        .trim();
+
+    let response_embed = 'response_embed: {
+
+        let Some(guild_id) = interaction_data.guild_id else {
+            break 'response_embed CreateEmbed::new()
+                .title("Templates are offered per server")
+                .colour(ColourCode::Info.to_embed_colour());
+        };
+
+        if !is_server_manager(interaction_data.member.as_deref()) {
+            break 'response_embed CreateEmbed::new()
+                .title("Only server managers can offer templates")
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        let class_id = match string_option(&sub_options, "class") {
+            None => None,
+            Some(class_option) => match sql_scripts::character_classes::get_classes(database_conn_pool).await {
+                Ok(classes) => match find_class(classes, class_option) {
+                    Some(class) => Some(class.id),
+                    None => break 'response_embed CreateEmbed::new()
+                        .title(format!( "There is no class called `{class_option}`" ))
+                        .description("Pick one from the suggestions")
+                        .colour(ColourCode::Info.to_embed_colour())
+                },
+                Err(query_err) => break 'response_embed query_error_embed(
+                    "template::create::get_classes", "fetch the classes", &query_err
+                )
+            }
+        };
+
+        // Blank options are left unset, so players fill them in themselves
+        let field = |field_name: &str| string_option(&sub_options, field_name)
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(String::from);
+
+        let details = TemplateDetails {
+            species:     field("species"),
+            alignment:   field("alignment"),
+            likes:       field("likes"),
+            dislikes:    field("dislikes"),
+            motivations: field("motivations"),
+            companions:  field("companions"),
+            backstory:   field("backstory"),
+            appearance:  field("appearance"),
+            extras:      field("extras"),
+            class_id
+        };
+
+        if details.is_empty() {
+            let field_names: Vec<&str> = TEMPLATE_FIELDS.iter().map(|(field_name, _, _)| *field_name).collect();
+            break 'response_embed CreateEmbed::new()
+                .title("A template has to fill in something")
+                .description(format!( "Set at least one of `class`, `{}`", field_names.join("`, `") ))
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        match sql_scripts::templates::add_template(database_conn_pool, guild_id.get(), interaction_data.user.id.get(), name, &details).await {
+            Ok(_) => {},
+            Err(query_err) if query_err.as_database_error().is_some_and(|db_err| db_err.is_unique_violation()) => {
+                break 'response_embed CreateEmbed::new()
+                    .title(format!( "There already is a template called `{name}`" ))
+                    .colour(ColourCode::Info.to_embed_colour());
+            },
+            Err(query_err) => break 'response_embed query_error_embed(
+                "template::create::add_template", "save the template", &query_err
+            )
+        }
+
+        CreateEmbed::new()
+            .title(format!( "Characters can now start out as {name}" ))
+            .description("Pick it with the `template` option of `/character create`")
+            .colour(ColourCode::Success.to_embed_colour())
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, true, "template::create").await;
+}
@@ -0,0 +1,70 @@
+use crate::{
+    runtime::{
+        commands::{
+            is_server_manager, query_error_embed, respond_with_embed, string_option, sub_command_options,
+            template::find_template
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let database_conn_pool = &runtime_client.database_connection;
+
+    let sub_options = sub_command_options(&interaction_data);
+    let template_option = string_option(&sub_options, "template")
+        .expect("`template` is a required option");
+
+    let response_embed = 'response_embed: {
+
+        let Some(guild_id) = interaction_data.guild_id else {
+            break 'response_embed CreateEmbed::new()
+                .title("Templates are offered per server")
+                .colour(ColourCode::Info.to_embed_colour());
+        };
+
+        if !is_server_manager(interaction_data.member.as_deref()) {
+            break 'response_embed CreateEmbed::new()
+                .title("Only server managers can remove templates")
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        let template = match sql_scripts::templates::get_templates(database_conn_pool, guild_id.get()).await {
+            Ok(templates) => find_template(templates, template_option),
+            Err(query_err) => break 'response_embed query_error_embed(
+                "template::delete::get_templates", "fetch the templates", &query_err
+            )
+        };
+        let Some(template) = template else {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "There is no template called `{template_option}`" ))
+                .colour(ColourCode::Info.to_embed_colour());
+        };
+
+        // Characters already built from it keep what it filled in
+        if let Err(query_err) = sql_scripts::templates::remove_template(database_conn_pool, template.id).await {
+            break 'response_embed query_error_embed(
+                "template::delete::remove_template", "remove the template", &query_err
+            );
+        }
+
+        CreateEmbed::new()
+            .title(format!( "{} is no longer offered", template.name ))
+            .colour(ColourCode::Success.to_embed_colour())
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, true, "template::delete").await;
+}
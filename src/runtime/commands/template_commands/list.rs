@@ -0,0 +1,81 @@
+use crate::{
+    runtime::{
+        commands::{query_error_embed, respond_with_embed},
+        runtime_client::RuntimeClient,
+        sql_scripts
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::{CreateEmbed, CreateEmbedFooter},
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+/// Kept under the 4096 character limit of embed descriptions
+const LIST_MAX_BYTES: usize = 4000;
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let database_conn_pool = &runtime_client.database_connection;
+
+    let response_embed = 'response_embed: {
+
+        let Some(guild_id) = interaction_data.guild_id else {
+            break 'response_embed CreateEmbed::new()
+                .title("Templates are offered per server")
+                .colour(ColourCode::Info.to_embed_colour());
+        };
+
+        let templates = match sql_scripts::templates::get_templates(database_conn_pool, guild_id.get()).await {
+            Ok(templates) => templates,
+            Err(query_err) => break 'response_embed query_error_embed(
+                "template::list::get_templates", "fetch the templates", &query_err
+            )
+        };
+
+        if templates.is_empty() {
+            break 'response_embed CreateEmbed::new()
+                .title("No templates are on offer yet")
+                .description("Server managers can add them with `/template create`")
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        let classes = match sql_scripts::character_classes::get_classes(database_conn_pool).await {
+            Ok(classes) => classes,
+            Err(query_err) => break 'response_embed query_error_embed(
+                "template::list::get_classes", "fetch the classes", &query_err
+            )
+        };
+
+        // A line for each template, naming the fields it fills in, while they fit in the embed
+        let mut template_list = String::new();
+        for template in &templates {
+            let filled_in: Vec<String> = template.details.wizard_fields()
+                .into_iter()
+                .map(|(field_name, _)| field_name.to_string())
+                .collect();
+            let class = template.details.class_id
+                .and_then(|class_id| classes.iter().find(|class| class.id == class_id))
+                .map_or(String::new(), |class| format!( ", {}", class.name ));
+            let template_line = format!( "- **{}**{class}: fills in {}\n", template.name, filled_in.join(", ") );
+            if template_list.len() + template_line.len() > LIST_MAX_BYTES {
+                break;
+            }
+            template_list.push_str(&template_line);
+        }
+
+        CreateEmbed::new()
+            .title("Character templates")
+            .description(template_list)
+            .footer(CreateEmbedFooter::new("Start from one with the template option of /character create"))
+            .colour(ColourCode::Info.to_embed_colour())
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, false, "template::list").await;
+}
@@ -0,0 +1,140 @@
+use crate::{
+    runtime::{
+        commands::{self, wallet_commands},
+        runtime_client::RuntimeClient
+    },
+    utils::misc::{
+        colour_codes::ColourCode, logging::create_log_message
+    }
+};
+
+use serenity::{
+    builder::{
+        CreateCommand,
+        CreateCommandOption,
+        CreateEmbed
+    },
+    model::application::{
+        CommandInteraction,
+        CommandOptionType,
+        ComponentInteraction
+    },
+    client::Context
+};
+
+pub fn build() -> CreateCommand {
+    let character_option = |option_name: &str| CreateCommandOption::new(
+        CommandOptionType::String,
+        option_name,
+        "Defaults to your selected character, GMs may pick any character in their campaign"
+    )
+        .set_autocomplete(true);
+    let amount_option = |description: &str| CreateCommandOption::new(CommandOptionType::String, "amount", description)
+        .required(true)
+        .max_length(100);
+    let note_option = || CreateCommandOption::new(CommandOptionType::String, "note", "What it was for, kept in the history")
+        .max_length(200);
+
+    CreateCommand::new("wallet")
+        .description("Manage your characters' coin")
+        .set_options(vec![
+                CreateCommandOption::new(CommandOptionType::SubCommand, "balance", "Show how much coin a character has")
+                    .add_sub_option(character_option("character")),
+                CreateCommandOption::new(CommandOptionType::SubCommand, "pay", "Pay another character from one of yours")
+                    .add_sub_option(amount_option("How much, such as `3 gp 5 sp`"))
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::User, "player", "Who the paid character belongs to")
+                            .required(true)
+                    )
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::String, "recipient", "The character being paid")
+                            .required(true)
+                            .set_autocomplete(true)
+                    )
+                    .add_sub_option(character_option("from"))
+                    .add_sub_option(note_option()),
+                CreateCommandOption::new(CommandOptionType::SubCommand, "grant", "Give or, with a leading `-`, take coin. GM only")
+                    .add_sub_option(amount_option("How much, such as `10 gp` or `-2 sp`"))
+                    .add_sub_option(character_option("character"))
+                    .add_sub_option(note_option()),
+                CreateCommandOption::new(CommandOptionType::SubCommand, "history", "List the changes to a character's wallet")
+                    .add_sub_option(character_option("character"))
+        ])
+}
+
+
+pub async fn run( runtime_client: &RuntimeClient, ctx: Context, interaction_data: CommandInteraction ) {
+    let sub_command_name = &interaction_data
+        .data
+        .options[0] // Because this command has sub commands, we know that there exists a 0th
+                    // option, and that it contains the name of the selected sub command
+        .name;
+
+
+    // Based on the sub command's name, delegate to the correct execution unit
+    match sub_command_name.as_str() {
+        "balance" => wallet_commands::balance::run(runtime_client, ctx, interaction_data).await,
+        "pay"     => wallet_commands::pay::run(runtime_client, ctx, interaction_data).await,
+        "grant"   => wallet_commands::grant::run(runtime_client, ctx, interaction_data).await,
+        "history" => wallet_commands::history::run(runtime_client, ctx, interaction_data).await,
+        // In the case that an unknown sub command is recived, inform the terminal of this
+        // occurance
+        unimplemented_subcommand => println!( "{}", create_log_message(
+                format!(
+                    "{}wallet{}: Recieved unknown sub command: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    unimplemented_subcommand,
+                    ColourCode::Reset
+                ),
+                ColourCode::Warning
+        ))
+    }
+}
+
+
+pub async fn autocomplete( runtime_client: &RuntimeClient, ctx: Context, interaction_data: CommandInteraction ) {
+    match interaction_data.data.autocomplete().map(|focused_option| focused_option.name) {
+        // The paid character belongs to whoever was picked as `player`
+        Some("recipient") => commands::autocomplete_player_characters(&ctx, &interaction_data, "player").await,
+        _ => commands::autocomplete_campaign_characters(runtime_client, &ctx, &interaction_data).await
+    }
+}
+
+
+pub async fn handle_component_interaction( runtime_client: &RuntimeClient, interaction_data: ComponentInteraction, ctx: Context, split_custom_id: Vec<&str> ) {
+    match split_custom_id[1] {
+        "history" => wallet_commands::history::handle_component(runtime_client, interaction_data, ctx, split_custom_id).await,
+        unknown_component => println!( "{}", create_log_message(
+                format!(
+                    "{}wallet{}: Recieved unknown component: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    unknown_component,
+                    ColourCode::Reset
+                ),
+                ColourCode::Warning
+        ))
+    }
+}
+
+
+/// Explains what an amount of coin looks like, for when the `amount` option couldn't be parsed
+pub fn unknown_amount_embed( runtime_client: &RuntimeClient, amount_option: &str ) -> CreateEmbed {
+    let currency = &runtime_client.currency;
+    let denominations = currency.denominations();
+    let coin_names: Vec<&str> = denominations
+        .iter()
+        .map(|denomination| denomination.name.as_str())
+        .collect();
+
+    // Three of the most valuable coin and five of the least, e.g. `3 gp 5 cp`
+    let example_amount = 3 * denominations[0].value + 5;
+
+    CreateEmbed::new()
+        .title(format!( "`{amount_option}` isn't an amount of coin" ))
+        .description(format!( "Amounts look like `{}`, using the coins: {}", currency.format(example_amount), coin_names.join(", ") ))
+        .colour(ColourCode::Info.to_embed_colour())
+}
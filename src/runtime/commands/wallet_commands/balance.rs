@@ -0,0 +1,39 @@
+use crate::{
+    runtime::{
+        commands::{query_error_embed, resolve_character_target, respond_with_embed},
+        runtime_client::RuntimeClient,
+        sql_scripts
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let response_embed = 'response_embed: {
+
+        let target = match resolve_character_target(runtime_client, &ctx, &interaction_data, "character").await {
+            Ok(target) => target,
+            Err(refusal_embed) => break 'response_embed refusal_embed
+        };
+
+        match sql_scripts::wallets::get_balance(&runtime_client.database_connection, target.character_id).await {
+            Ok(balance) => CreateEmbed::new()
+                .title(format!( "{}'s wallet", target.name ))
+                .description(format!( "**{}**", runtime_client.currency.format(balance) ))
+                .colour(ColourCode::Info.to_embed_colour()),
+            Err(query_err) => query_error_embed("wallet::balance::get_balance", "fetch the balance", &query_err)
+        }
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, true, "wallet::balance").await;
+}
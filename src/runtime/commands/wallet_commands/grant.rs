@@ -0,0 +1,94 @@
+use crate::{
+    runtime::{
+        commands::{
+            is_server_manager, query_error_embed, resolve_character_target, respond_with_embed,
+            string_option, sub_command_options, wallet::unknown_amount_embed
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts::{self, wallets::WalletOutcome}
+    },
+    utils::misc::{colour_codes::ColourCode, logging::create_log_message},
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let invoker_id = interaction_data.user.id.get();
+    let invoker_tag = interaction_data.user.tag();
+    let is_server_manager = is_server_manager(interaction_data.member.as_deref());
+    let currency = &runtime_client.currency;
+
+    let sub_options = sub_command_options(&interaction_data);
+    let amount_option = string_option(&sub_options, "amount")
+        .expect("`amount` is a required option");
+    let note = string_option(&sub_options, "note");
+
+    let response_embed = 'response_embed: {
+
+        let amount = match currency.parse(amount_option) {
+            Some(amount) => amount,
+            None => break 'response_embed unknown_amount_embed(runtime_client, amount_option)
+        };
+
+        let target = match resolve_character_target(runtime_client, &ctx, &interaction_data, "character").await {
+            Ok(target) => target,
+            Err(refusal_embed) => break 'response_embed refusal_embed
+        };
+
+        // Coin comes from the GM of the campaign the character plays in, or a server manager
+        if !target.is_gm && !is_server_manager {
+            break 'response_embed CreateEmbed::new()
+                .title("Only GMs can grant coin")
+                .description("The character has to take part in the campaign you run in this channel")
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        match sql_scripts::wallets::grant(&runtime_client.database_connection, target.character_id, invoker_id, amount, note).await {
+            Ok(WalletOutcome::Done(balance)) => {
+                println!( "{}", create_log_message(
+                        format!(
+                            "`{}{}:#{}{}` granted `{}{}{}` to character `{}{}{}`",
+                            ColourCode::Info,
+                            invoker_tag,
+                            invoker_id,
+                            ColourCode::Reset,
+                            ColourCode::Info,
+                            amount,
+                            ColourCode::Reset,
+                            ColourCode::Info,
+                            target.character_id,
+                            ColourCode::Reset
+                        ),
+                        ColourCode::Success
+                ));
+
+                let title = if amount < 0 {
+                    format!( "{} was relieved of {}", target.name, currency.format(-amount) )
+                } else {
+                    format!( "{} received {}", target.name, currency.format(amount) )
+                };
+
+                CreateEmbed::new()
+                    .title(title)
+                    .description(format!( "They now have {}\n<@{}>", currency.format(balance), target.owner_id ))
+                    .colour(ColourCode::Success.to_embed_colour())
+            },
+            Ok(WalletOutcome::InsufficientFunds(balance)) => CreateEmbed::new()
+                .title(format!( "{} doesn't have that much", target.name ))
+                .description(format!( "They only have {}", currency.format(balance) ))
+                .colour(ColourCode::Info.to_embed_colour()),
+            Err(query_err) => query_error_embed("wallet::grant::grant", "grant the coin", &query_err)
+        }
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, false, "wallet::grant").await;
+}
@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+
+use crate::{
+    runtime::{
+        commands::{query_error_embed, resolve_character_target},
+        runtime_client::RuntimeClient,
+        sql_scripts
+    },
+    utils::misc::{colour_codes::ColourCode, logging::create_log_message},
+};
+
+use serenity::{
+    builder::{
+        CreateActionRow, CreateButton, CreateEmbed, CreateEmbedFooter, CreateInteractionResponse,
+        CreateInteractionResponseMessage
+    },
+    client::Context,
+    model::application::{ButtonStyle, CommandInteraction, ComponentInteraction},
+};
+
+// Page buttons follow the format:
+//     wallet|history|character_id|page
+// The history is only ever shown ephemerally, so whoever can press them was already allowed to see it
+
+const PAGE_LENGTH: u32 = 10;
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let response_message = match resolve_character_target(runtime_client, &ctx, &interaction_data, "character").await {
+        Ok(target) => history_page(runtime_client, target.character_id, &target.name, 0).await,
+        Err(refusal_embed) => CreateInteractionResponseMessage::new().embed(refusal_embed)
+    };
+
+    let response = CreateInteractionResponse::Message(response_message.ephemeral(true));
+    if let Err(response_send_err) = interaction_data.create_response(&ctx.http, response).await {
+        println!( "{}", create_log_message(
+                format!(
+                    "{}wallet::history{}: Failed to send response: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    response_send_err,
+                    ColourCode::Reset
+                ),
+                ColourCode::Error
+        ));
+    }
+}
+
+pub async fn handle_component(
+    runtime_client: &RuntimeClient,
+    component_interaction: ComponentInteraction,
+    ctx: Context,
+    split_custom_id: Vec<&str>,
+) {
+    let character_id = split_custom_id.get(2).and_then(|character_id| character_id.parse::<u64>().ok());
+    let page = split_custom_id.get(3).and_then(|page| page.parse::<u32>().ok());
+
+    let response_message = 'response_message: {
+        let (Some(character_id), Some(page)) = (character_id, page) else {
+            break 'response_message CreateInteractionResponseMessage::new()
+                .embed(
+                    CreateEmbed::new()
+                        .title("This history can't be paged through anymore")
+                        .colour(ColourCode::Info.to_embed_colour())
+                )
+                .components(vec![]);
+        };
+
+        let character_name = match sql_scripts::characters::get_character(&runtime_client.database_connection, character_id).await {
+            Ok(Some(character)) => character.name,
+            Ok(None) => "A former character".to_string(),
+            Err(query_err) => break 'response_message CreateInteractionResponseMessage::new()
+                .embed(query_error_embed("wallet::history::get_character", "find the character", &query_err))
+                .components(vec![])
+        };
+
+        history_page(runtime_client, character_id, &character_name, page).await
+    };
+
+    let response = CreateInteractionResponse::UpdateMessage(response_message);
+    if let Err(response_send_err) = component_interaction.create_response(&ctx.http, response).await {
+        println!( "{}", create_log_message(
+                format!(
+                    "{}wallet::history::handle_component{}: Failed to send response: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    response_send_err,
+                    ColourCode::Reset
+                ),
+                ColourCode::Error
+        ));
+    }
+}
+
+
+
+// --== HISTORY PAGE ==-- //
+
+    /// Builds one page of a character's ledger, along with the buttons to its neighbours
+    async fn history_page( runtime_client: &RuntimeClient, character_id: u64, character_name: &str, page: u32 ) -> CreateInteractionResponseMessage {
+        let database_conn_pool = &runtime_client.database_connection;
+        let currency = &runtime_client.currency;
+
+        let page_embed = 'page_embed: {
+            let entry_count = match sql_scripts::wallets::count_ledger(database_conn_pool, character_id).await {
+                Ok(entry_count) => entry_count,
+                Err(query_err) => break 'page_embed Err(query_error_embed(
+                    "wallet::history::count_ledger", "count the wallet's history", &query_err
+                ))
+            };
+            let page_count = entry_count.div_ceil(PAGE_LENGTH).max(1);
+            let page = page.min(page_count - 1);
+
+            let ledger = match sql_scripts::wallets::get_ledger(database_conn_pool, character_id, PAGE_LENGTH, page * PAGE_LENGTH).await {
+                Ok(ledger) => ledger,
+                Err(query_err) => break 'page_embed Err(query_error_embed(
+                    "wallet::history::get_ledger", "fetch the wallet's history", &query_err
+                ))
+            };
+
+            // Payments name the other side, which may have been deleted since
+            let mut counterparty_names: HashMap<u64, String> = HashMap::new();
+            for counterparty_id in ledger.iter().filter_map(|entry| entry.counterparty_character_id) {
+                if counterparty_names.contains_key(&counterparty_id) {
+                    continue;
+                }
+                let counterparty_name = match sql_scripts::characters::get_character(database_conn_pool, counterparty_id).await {
+                    Ok(Some(counterparty)) => counterparty.name,
+                    Ok(None) => "a former character".to_string(),
+                    Err(query_err) => break 'page_embed Err(query_error_embed(
+                        "wallet::history::get_character", "find who was paid", &query_err
+                    ))
+                };
+                counterparty_names.insert(counterparty_id, counterparty_name);
+            }
+
+            let mut history_description = String::new();
+            for entry in &ledger {
+                let signed_amount = if entry.amount < 0 {
+                    format!( "-{}", currency.format(-entry.amount) )
+                } else {
+                    format!( "+{}", currency.format(entry.amount) )
+                };
+
+                let action = match (entry.counterparty_character_id, entry.amount < 0) {
+                    (Some(counterparty_id), true) => format!( "paid to {}", counterparty_names[&counterparty_id] ),
+                    (Some(counterparty_id), false) => format!( "paid by {}", counterparty_names[&counterparty_id] ),
+                    (None, _) => format!( "granted by <@{}>", entry.actor.get() )
+                };
+
+                history_description.push_str(&format!(
+                    "- <t:{}:R> **{signed_amount}** {action}, leaving {}",
+                    entry.created_at,
+                    currency.format(entry.balance_after)
+                ));
+                if let Some(note) = &entry.note {
+                    history_description.push_str(&format!( "\n  *{note}*" ));
+                }
+                history_description.push('\n');
+            }
+            if ledger.is_empty() {
+                history_description.push_str("No coin has changed hands yet");
+            }
+
+            Ok((
+                CreateEmbed::new()
+                    .title(format!( "{character_name}'s wallet history" ))
+                    .description(history_description)
+                    .footer(CreateEmbedFooter::new(format!( "Page {} of {page_count}", page + 1 )))
+                    .colour(ColourCode::Info.to_embed_colour()),
+                page,
+                page_count
+            ))
+        };
+
+        match page_embed {
+            Ok((embed, page, page_count)) => CreateInteractionResponseMessage::new()
+                .embed(embed)
+                .components(vec![
+                    CreateActionRow::Buttons(vec![
+                        CreateButton::new(format!( "wallet|history|{character_id}|{}", page.saturating_sub(1) ))
+                            .style(ButtonStyle::Secondary)
+                            .label("Newer")
+                            .disabled(page == 0),
+                        CreateButton::new(format!( "wallet|history|{character_id}|{}", page + 1 ))
+                            .style(ButtonStyle::Secondary)
+                            .label("Older")
+                            .disabled(page + 1 >= page_count),
+                    ])
+                ]),
+            Err(error_embed) => CreateInteractionResponseMessage::new()
+                .embed(error_embed)
+                .components(vec![])
+        }
+    }
+// ==--
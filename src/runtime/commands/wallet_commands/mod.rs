@@ -0,0 +1,4 @@
+pub mod balance;
+pub mod grant;
+pub mod history;
+pub mod pay;
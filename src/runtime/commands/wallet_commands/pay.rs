@@ -0,0 +1,87 @@
+use crate::{
+    runtime::{
+        commands::{
+            query_error_embed, resolve_character_target, resolve_user_character, respond_with_embed,
+            string_option, sub_command_options, user_option, wallet::unknown_amount_embed
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts::{self, wallets::WalletOutcome}
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let invoker_id = interaction_data.user.id.get();
+    let currency = &runtime_client.currency;
+
+    let sub_options = sub_command_options(&interaction_data);
+    let amount_option = string_option(&sub_options, "amount")
+        .expect("`amount` is a required option");
+    let player = user_option(&sub_options, "player")
+        .expect("`player` is a required option");
+    let recipient_option = string_option(&sub_options, "recipient")
+        .expect("`recipient` is a required option");
+    let note = string_option(&sub_options, "note");
+
+    let response_embed = 'response_embed: {
+
+        let amount = match currency.parse(amount_option) {
+            Some(amount) if amount > 0 => amount,
+            Some(_) => {
+                break 'response_embed CreateEmbed::new()
+                    .title("Payments have to be worth something")
+                    .colour(ColourCode::Info.to_embed_colour());
+            },
+            None => break 'response_embed unknown_amount_embed(runtime_client, amount_option)
+        };
+
+        let sender = match resolve_character_target(runtime_client, &ctx, &interaction_data, "from").await {
+            Ok(sender) => sender,
+            Err(refusal_embed) => break 'response_embed refusal_embed
+        };
+
+        // A GM may look into their players' wallets, but not spend from them
+        if sender.owner_id != invoker_id {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "Only {}'s owner can pay with their coin", sender.name ))
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        let Some((recipient_id, recipient_name)) = resolve_user_character(&ctx, player.id.get(), recipient_option).await else {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "{} doesn't have a character called `{recipient_option}`", player.name ))
+                .colour(ColourCode::Info.to_embed_colour());
+        };
+
+        if recipient_id == sender.character_id {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "{} can't pay themselves", sender.name ))
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        match sql_scripts::wallets::pay(&runtime_client.database_connection, sender.character_id, recipient_id, invoker_id, amount, note).await {
+            Ok(WalletOutcome::Done(sender_balance)) => CreateEmbed::new()
+                .title(format!( "{} paid {} to {recipient_name}", sender.name, currency.format(amount) ))
+                .description(format!( "{} has {} left", sender.name, currency.format(sender_balance) ))
+                .colour(ColourCode::Success.to_embed_colour()),
+            Ok(WalletOutcome::InsufficientFunds(balance)) => CreateEmbed::new()
+                .title(format!( "{} can't afford that", sender.name ))
+                .description(format!( "They only have {}", currency.format(balance) ))
+                .colour(ColourCode::Info.to_embed_colour()),
+            Err(query_err) => query_error_embed("wallet::pay::pay", "make the payment", &query_err)
+        }
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, false, "wallet::pay").await;
+}
@@ -0,0 +1,198 @@
+use crate::{
+    runtime::{
+        commands::{self, xp_commands},
+        runtime_client::RuntimeClient,
+        sql_scripts::{self, character_stats::Stat, experience::ExperienceAward, models::Lore}
+    },
+    utils::misc::{
+        colour_codes::ColourCode, levelling::Levelling, logging::create_log_message
+    }
+};
+
+use serenity::{
+    builder::{
+        CreateAutocompleteResponse,
+        CreateCommand,
+        CreateCommandOption,
+        CreateEmbed,
+        CreateInteractionResponse,
+        CreateInteractionResponseMessage
+    },
+    model::application::{
+        CommandInteraction,
+        CommandOptionType
+    },
+    client::Context
+};
+
+pub fn build() -> CreateCommand {
+    let character_option = || CreateCommandOption::new(
+        CommandOptionType::String,
+        "character",
+        "Defaults to your selected character, GMs may pick any character in their campaign"
+    )
+        .set_autocomplete(true);
+    let points_option = || CreateCommandOption::new(CommandOptionType::Integer, "points", "How many unspent points to put in")
+        .required(true)
+        .min_int_value(1);
+
+    let mut stat_option = CreateCommandOption::new(CommandOptionType::String, "stat", "The stat to raise")
+        .required(true);
+    for stat in Stat::ALL {
+        stat_option = stat_option.add_string_choice(stat.column(), stat.column());
+    }
+
+    CreateCommand::new("xp")
+        .description("Experience, levels and the points they grant")
+        .set_options(vec![
+                CreateCommandOption::new(CommandOptionType::SubCommand, "award", "Award experience to a character. GM only")
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::Integer, "amount", "How much experience")
+                            .required(true)
+                            .min_int_value(1)
+                    )
+                    .add_sub_option(character_option()),
+                CreateCommandOption::new(CommandOptionType::SubCommand, "party", "Award experience to every character in this channel's campaign. GM only")
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::Integer, "amount", "How much experience each")
+                            .required(true)
+                            .min_int_value(1)
+                    ),
+                CreateCommandOption::new(CommandOptionType::SubCommand, "status", "Show a character's level and unspent points")
+                    .add_sub_option(character_option()),
+                CreateCommandOption::new(CommandOptionType::SubCommand, "allocate", "Spend unspent stat points")
+                    .add_sub_option(stat_option)
+                    .add_sub_option(points_option())
+                    .add_sub_option(character_option()),
+                CreateCommandOption::new(CommandOptionType::SubCommand, "study", "Spend unspent lore points on a lore of magic")
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::String, "lore", "The lore to study")
+                            .required(true)
+                            .set_autocomplete(true)
+                    )
+                    .add_sub_option(points_option())
+                    .add_sub_option(character_option())
+        ])
+}
+
+
+pub async fn run( runtime_client: &RuntimeClient, ctx: Context, interaction_data: CommandInteraction ) {
+    let sub_command_name = &interaction_data
+        .data
+        .options[0] // Because this command has sub commands, we know that there exists a 0th
+                    // option, and that it contains the name of the selected sub command
+        .name;
+
+
+    // Based on the sub command's name, delegate to the correct execution unit
+    match sub_command_name.as_str() {
+        "award"    => xp_commands::award::run(runtime_client, ctx, interaction_data).await,
+        "party"    => xp_commands::party::run(runtime_client, ctx, interaction_data).await,
+        "status"   => xp_commands::status::run(runtime_client, ctx, interaction_data).await,
+        "allocate" => xp_commands::allocate::run(runtime_client, ctx, interaction_data).await,
+        "study"    => xp_commands::study::run(runtime_client, ctx, interaction_data).await,
+        // In the case that an unknown sub command is recived, inform the terminal of this
+        // occurance
+        unimplemented_subcommand => println!( "{}", create_log_message(
+                format!(
+                    "{}xp{}: Recieved unknown sub command: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    unimplemented_subcommand,
+                    ColourCode::Reset
+                ),
+                ColourCode::Warning
+        ))
+    }
+}
+
+
+pub async fn autocomplete( runtime_client: &RuntimeClient, ctx: Context, interaction_data: CommandInteraction ) {
+    match interaction_data.data.autocomplete().map(|focused_option| focused_option.name) {
+        Some("lore") => autocomplete_lores(runtime_client, &ctx, &interaction_data).await,
+        _ => commands::autocomplete_campaign_characters(runtime_client, &ctx, &interaction_data).await
+    }
+}
+
+/// Answers an autocomplete request for a lore option with the lores whose names contain what's
+/// been typed so far. The choices carry the lore's ID
+pub async fn autocomplete_lores( runtime_client: &RuntimeClient, ctx: &Context, interaction_data: &CommandInteraction ) {
+    let typed_so_far = commands::typed_so_far(interaction_data);
+    let lores = sql_scripts::lores::get_lores(&runtime_client.database_connection)
+        .await
+        .unwrap_or_default();
+
+    let mut autocomplete_response = CreateAutocompleteResponse::new();
+    for lore in lores.into_iter().filter(|lore| lore.name.to_lowercase().contains(&typed_so_far)).take(25) {
+        autocomplete_response = autocomplete_response.add_string_choice(lore.name, lore.id.to_string());
+    }
+
+    let send_choices = interaction_data.create_response(&ctx.http, CreateInteractionResponse::Autocomplete(autocomplete_response));
+    if let Err(why) = send_choices.await {
+        println!( "{}", create_log_message(
+                format!(
+                    "Failed to send autocomplete choices: `{}{}{}`",
+                    ColourCode::Info,
+                    why,
+                    ColourCode::Reset
+                ),
+                ColourCode::Caution
+        ));
+    }
+}
+
+/// Finds the lore a lore option refers to. The choices carry the lore's ID, but a typed in name
+/// works too
+pub fn find_lore( lores: Vec<Lore>, lore_option: &str ) -> Option<Lore> {
+    lores.into_iter().find(|lore| {
+        lore_option.parse::<u64>().is_ok_and(|lore_id| lore_id == lore.id)
+            || lore.name.eq_ignore_ascii_case(lore_option.trim())
+    })
+}
+
+
+/// The line announcing a character's level up, mentioning their owner
+pub fn level_up_announcement( character_name: &str, owner_id: u64, award: &ExperienceAward ) -> String {
+    let mut announcement = format!(
+        "**{character_name}** reached level {}! <@{owner_id}> has {} stat point(s) to `/xp allocate`",
+        award.level,
+        award.stat_points_gained
+    );
+    if award.lore_points_gained > 0 {
+        announcement.push_str(&format!( " and {} lore point(s) to `/xp study`", award.lore_points_gained ));
+    }
+
+    announcement
+}
+
+/// Responds publicly with the outcome of an award. Level ups go in the message content, so that
+/// the owners get pinged
+pub async fn respond_with_award( ctx: &Context, interaction_data: &CommandInteraction, embed: CreateEmbed, announcements: Vec<String>, location: &str ) {
+    let mut response_message = CreateInteractionResponseMessage::new().embed(embed);
+    if !announcements.is_empty() {
+        response_message = response_message.content(announcements.join("\n"));
+    }
+
+    if let Err(response_send_err) = interaction_data.create_response(&ctx.http, CreateInteractionResponse::Message(response_message)).await {
+        println!( "{}", create_log_message(
+                format!(
+                    "{}{location}{}: Failed to send response: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    response_send_err,
+                    ColourCode::Reset
+                ),
+                ColourCode::Error
+        ));
+    }
+}
+
+/// How far a character is along the curve, such as `1000 XP, 1700 to level 4`
+pub fn progress_line( levelling: &Levelling, experience: i64, level: i64 ) -> String {
+    match levelling.next_threshold(level) {
+        Some(next_threshold) => format!( "{experience} XP, {} to level {}", (next_threshold - experience).max(0), level + 1 ),
+        None => format!( "{experience} XP, the highest level there is" )
+    }
+}
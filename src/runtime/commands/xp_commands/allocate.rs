@@ -0,0 +1,62 @@
+use crate::{
+    runtime::{
+        commands::{
+            integer_option, query_error_embed, resolve_character_target, respond_with_embed,
+            string_option, sub_command_options
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts::{self, character_stats::Stat, experience::SpendOutcome}
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let invoker_id = interaction_data.user.id.get();
+
+    let sub_options = sub_command_options(&interaction_data);
+    let stat = string_option(&sub_options, "stat")
+        .and_then(Stat::from_column)
+        .expect("`stat` is a required option with fixed choices");
+    let points = integer_option(&sub_options, "points")
+        .expect("`points` is a required option");
+
+    let response_embed = 'response_embed: {
+
+        let target = match resolve_character_target(runtime_client, &ctx, &interaction_data, "character").await {
+            Ok(target) => target,
+            Err(refusal_embed) => break 'response_embed refusal_embed
+        };
+
+        if target.owner_id != invoker_id {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "Only {}'s owner can allocate their points", target.name ))
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        match sql_scripts::experience::spend_stat_points(&runtime_client.database_connection, target.character_id, stat, points).await {
+            Ok(SpendOutcome::Spent(new_value)) => CreateEmbed::new()
+                .title(format!( "{}'s {} rose to {new_value}", target.name, stat.column() ))
+                .colour(ColourCode::Success.to_embed_colour()),
+            Ok(SpendOutcome::NotEnough(unspent)) => CreateEmbed::new()
+                .title(format!( "{} only has {unspent} stat point(s) to spend", target.name ))
+                .colour(ColourCode::Info.to_embed_colour()),
+            Ok(SpendOutcome::Missing) => CreateEmbed::new()
+                .title(format!( "{} no longer exists", target.name ))
+                .colour(ColourCode::Info.to_embed_colour()),
+            Err(query_err) => query_error_embed("xp::allocate::spend_stat_points", "allocate the points", &query_err)
+        }
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, false, "xp::allocate").await;
+}
@@ -0,0 +1,67 @@
+use crate::{
+    runtime::{
+        commands::{
+            integer_option, is_server_manager, query_error_embed, resolve_character_target, sub_command_options,
+            xp::{level_up_announcement, progress_line, respond_with_award}
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let is_server_manager = is_server_manager(interaction_data.member.as_deref());
+
+    let sub_options = sub_command_options(&interaction_data);
+    let amount = integer_option(&sub_options, "amount")
+        .expect("`amount` is a required option");
+
+    let mut announcements = vec![];
+    let response_embed = 'response_embed: {
+
+        let target = match resolve_character_target(runtime_client, &ctx, &interaction_data, "character").await {
+            Ok(target) => target,
+            Err(refusal_embed) => break 'response_embed refusal_embed
+        };
+
+        if !target.is_gm && !is_server_manager {
+            break 'response_embed CreateEmbed::new()
+                .title("Only GMs can award experience")
+                .description("The character has to take part in the campaign you run in this channel")
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        let award = match sql_scripts::experience::award_experience(&runtime_client.database_connection, target.character_id, amount, &runtime_client.levelling).await {
+            Ok(Some(award)) => award,
+            Ok(None) => break 'response_embed CreateEmbed::new()
+                .title(format!( "{} no longer exists", target.name ))
+                .colour(ColourCode::Info.to_embed_colour()),
+            Err(query_err) => break 'response_embed query_error_embed(
+                "xp::award::award_experience", "award the experience", &query_err
+            )
+        };
+
+        if award.levelled_up() {
+            announcements.push(level_up_announcement(&target.name, target.owner_id, &award));
+        }
+
+        CreateEmbed::new()
+            .title(format!( "{} gained {amount} XP", target.name ))
+            .description(progress_line(&runtime_client.levelling, award.experience, award.level))
+            .colour(ColourCode::Success.to_embed_colour())
+
+    }; // let response_embed = {...}
+
+    respond_with_award(&ctx, &interaction_data, response_embed, announcements, "xp::award").await;
+}
@@ -0,0 +1,5 @@
+pub mod allocate;
+pub mod award;
+pub mod party;
+pub mod status;
+pub mod study;
@@ -0,0 +1,97 @@
+use crate::{
+    runtime::{
+        commands::{
+            campaign::interaction_campaign, integer_option, is_server_manager, query_error_embed, sub_command_options,
+            xp::{level_up_announcement, progress_line, respond_with_award}
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let database_conn_pool = &runtime_client.database_connection;
+    let invoker_id = interaction_data.user.id.get();
+    let is_server_manager = is_server_manager(interaction_data.member.as_deref());
+
+    let sub_options = sub_command_options(&interaction_data);
+    let amount = integer_option(&sub_options, "amount")
+        .expect("`amount` is a required option");
+
+    let mut announcements = vec![];
+    let response_embed = 'response_embed: {
+
+        let campaign = match interaction_campaign(runtime_client, &interaction_data).await {
+            Ok(Some(campaign)) => campaign,
+            Ok(None) => break 'response_embed CreateEmbed::new()
+                .title("There is no campaign running in this channel")
+                .colour(ColourCode::Info.to_embed_colour()),
+            Err(query_err) => break 'response_embed query_error_embed(
+                "xp::party::interaction_campaign", "find this channel's campaign", &query_err
+            )
+        };
+
+        if campaign.gm.get() != invoker_id && !is_server_manager {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "Only the GM of {} can award its party experience", campaign.name ))
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        let roster = match sql_scripts::campaigns::get_roster(database_conn_pool, campaign.id).await {
+            Ok(roster) => roster,
+            Err(query_err) => break 'response_embed query_error_embed(
+                "xp::party::get_roster", "fetch the campaign's roster", &query_err
+            )
+        };
+
+        if roster.is_empty() {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "Nobody has joined {} yet", campaign.name ))
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        let character_ids: Vec<u64> = roster.iter().map(|entry| entry.character_id).collect();
+        let awards = match sql_scripts::experience::award_experience_to_all(database_conn_pool, &character_ids, amount, &runtime_client.levelling).await {
+            Ok(awards) => awards,
+            Err(query_err) => break 'response_embed query_error_embed(
+                "xp::party::award_experience_to_all", "award the experience", &query_err
+            )
+        };
+
+        let mut party_description = String::new();
+        for award in &awards {
+            let Some(entry) = roster.iter().find(|entry| entry.character_id == award.character_id) else {
+                continue;
+            };
+
+            party_description.push_str(&format!(
+                "- **{}**, level {}: {}\n",
+                entry.name,
+                award.level,
+                progress_line(&runtime_client.levelling, award.experience, award.level)
+            ));
+            if award.levelled_up() {
+                announcements.push(level_up_announcement(&entry.name, entry.owner.get(), award));
+            }
+        }
+
+        CreateEmbed::new()
+            .title(format!( "The party of {} gained {amount} XP each", campaign.name ))
+            .description(party_description)
+            .colour(ColourCode::Success.to_embed_colour())
+
+    }; // let response_embed = {...}
+
+    respond_with_award(&ctx, &interaction_data, response_embed, announcements, "xp::party").await;
+}
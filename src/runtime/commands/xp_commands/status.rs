@@ -0,0 +1,48 @@
+use crate::{
+    runtime::{
+        commands::{query_error_embed, resolve_character_target, respond_with_embed, xp::progress_line},
+        runtime_client::RuntimeClient,
+        sql_scripts
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let response_embed = 'response_embed: {
+
+        let target = match resolve_character_target(runtime_client, &ctx, &interaction_data, "character").await {
+            Ok(target) => target,
+            Err(refusal_embed) => break 'response_embed refusal_embed
+        };
+
+        let progression = match sql_scripts::experience::get_progression(&runtime_client.database_connection, target.character_id).await {
+            Ok(Some(progression)) => progression,
+            Ok(None) => break 'response_embed CreateEmbed::new()
+                .title(format!( "{} no longer exists", target.name ))
+                .colour(ColourCode::Info.to_embed_colour()),
+            Err(query_err) => break 'response_embed query_error_embed(
+                "xp::status::get_progression", "fetch the character's progression", &query_err
+            )
+        };
+
+        CreateEmbed::new()
+            .title(format!( "{} is level {}", target.name, progression.level ))
+            .description(progress_line(&runtime_client.levelling, progression.experience, progression.level))
+            .field("Unspent stat points", progression.unspent_stat_points.to_string(), true)
+            .field("Unspent lore points", progression.unspent_lore_points.to_string(), true)
+            .colour(ColourCode::Info.to_embed_colour())
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, false, "xp::status").await;
+}
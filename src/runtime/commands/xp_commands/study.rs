@@ -0,0 +1,88 @@
+use crate::{
+    runtime::{
+        commands::{
+            integer_option, query_error_embed, resolve_character_target, respond_with_embed,
+            string_option, sub_command_options, xp::find_lore
+        },
+        runtime_client::RuntimeClient,
+        sql_scripts::{self, experience::SpendOutcome}
+    },
+    utils::misc::colour_codes::ColourCode,
+};
+
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    model::application::CommandInteraction,
+};
+
+pub async fn run(
+    runtime_client: &RuntimeClient,
+    ctx: Context,
+    interaction_data: CommandInteraction,
+) {
+    let database_conn_pool = &runtime_client.database_connection;
+    let invoker_id = interaction_data.user.id.get();
+
+    let sub_options = sub_command_options(&interaction_data);
+    let lore_option = string_option(&sub_options, "lore")
+        .expect("`lore` is a required option");
+    let points = integer_option(&sub_options, "points")
+        .expect("`points` is a required option");
+
+    let response_embed = 'response_embed: {
+
+        let target = match resolve_character_target(runtime_client, &ctx, &interaction_data, "character").await {
+            Ok(target) => target,
+            Err(refusal_embed) => break 'response_embed refusal_embed
+        };
+
+        if target.owner_id != invoker_id {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "Only {}'s owner can allocate their points", target.name ))
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        let lore = match sql_scripts::lores::get_lores(database_conn_pool).await {
+            Ok(lores) => find_lore(lores, lore_option),
+            Err(query_err) => break 'response_embed query_error_embed(
+                "xp::study::get_lores", "fetch the lores of magic", &query_err
+            )
+        };
+        let Some(lore) = lore else {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "There is no lore called `{lore_option}`" ))
+                .colour(ColourCode::Info.to_embed_colour());
+        };
+
+        let classes = match sql_scripts::character_classes::get_character_classes(database_conn_pool, target.character_id).await {
+            Ok(classes) => classes,
+            Err(query_err) => break 'response_embed query_error_embed(
+                "xp::study::get_character_classes", "check the character's class", &query_err
+            )
+        };
+        if !classes.is_empty() && classes.iter().all(|class| !class.can_cast()) {
+            break 'response_embed CreateEmbed::new()
+                .title(format!( "{} is no spellcaster", target.name ))
+                .description("Their class can't study the lores of magic")
+                .colour(ColourCode::Info.to_embed_colour());
+        }
+
+        match sql_scripts::experience::spend_lore_points(database_conn_pool, target.character_id, lore.id, points).await {
+            Ok(SpendOutcome::Spent(spent_points)) => CreateEmbed::new()
+                .title(format!( "{} studied {}", target.name, lore.name ))
+                .description(format!( "They have put {spent_points} point(s) into it so far" ))
+                .colour(ColourCode::Success.to_embed_colour()),
+            Ok(SpendOutcome::NotEnough(unspent)) => CreateEmbed::new()
+                .title(format!( "{} only has {unspent} lore point(s) to spend", target.name ))
+                .colour(ColourCode::Info.to_embed_colour()),
+            Ok(SpendOutcome::Missing) => CreateEmbed::new()
+                .title(format!( "{} no longer exists", target.name ))
+                .colour(ColourCode::Info.to_embed_colour()),
+            Err(query_err) => query_error_embed("xp::study::spend_lore_points", "allocate the points", &query_err)
+        }
+
+    }; // let response_embed = {...}
+
+    respond_with_embed(&ctx, &interaction_data, response_embed, false, "xp::study").await;
+}
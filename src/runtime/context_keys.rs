@@ -1,22 +1,15 @@
-use serenity::{
-    prelude::TypeMapKey,
-    model::channel::Message
-};
+use serenity::prelude::TypeMapKey;
 use std::collections::hash_map::HashMap;
 
 /// Containts a `HashMap` who's keys are the id's of users who are in the process of building a
-/// character. The value of this `HashMap` is a tuple of another `HashMap` where the key `String`
-/// is the name of a character's attribute, and the value is a `String` containing it's value when 
-/// it it known.
-/// The second element of the tuple is a `Message` struct that
-/// coresponds to the base message that the bot sends when `character create` gets called that in
-/// turn contains an editable embed and an editable ActionRow contianing Buttons
+/// character. The value of this `HashMap` is another `HashMap` where the key `String` is the name
+/// of a character's attribute, and the value is a `String` containing it's value when it it known.
+///
+/// The wizard's message itself is not stored, every step responds to an interaction coming from
+/// it and so updates it through that interaction
 pub struct CharacterBuildingDataKey;
 impl TypeMapKey for CharacterBuildingDataKey {
-    // In order to not have to bother with editing an arbitrary message using a message- and
-    // chanel_id, we will use a `Message` in order to utilise it's `edit` method for a more
-    // conveniant and sure-fire approach
-    type Value = HashMap<u64, (HashMap<String, String>, Message)>; 
+    type Value = HashMap<u64, HashMap<String, String>>;
 }
 
 /// HashMap<discord_user_id: u64, HashMap<character_id: u64, character_name: String>>
@@ -27,3 +20,21 @@ impl TypeMapKey for UserCharactersCache {
         HashMap<u64, String>
     >;
 }
+
+/// Records a character under its owner in the `UserCharactersCache` map, creating the owner's
+/// entry if this is their first character
+pub fn cache_user_character( user_characters: &mut HashMap<u64, HashMap<u64, String>>, owner_id: u64, character_id: u64, character_name: String ) {
+    user_characters
+        .entry(owner_id)
+        .or_default()
+        .insert(character_id, character_name);
+}
+
+/// Moves a character from one owner's entry in the `UserCharactersCache` map to another's, as when
+/// it is handed over
+pub fn move_user_character( user_characters: &mut HashMap<u64, HashMap<u64, String>>, from_owner: u64, to_owner: u64, character_id: u64, character_name: String ) {
+    if let Some(owned_characters) = user_characters.get_mut(&from_owner) {
+        owned_characters.remove(&character_id);
+    }
+    cache_user_character(user_characters, to_owner, character_id, character_name);
+}
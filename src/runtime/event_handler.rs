@@ -87,7 +87,17 @@ impl EventHandler for runtime_client::RuntimeClient {
         // --== REGISTER COMMANDS TO GATEWAY ==-- //
             let slash_commands = vec![
                 commands::profile::build(),
-                commands::character::build()
+                commands::character::build(),
+                commands::campaign::build(),
+                commands::combat::build(),
+                commands::hp::build(),
+                commands::inventory::build(),
+                commands::wallet::build(),
+                commands::xp::build(),
+                commands::session::build(),
+                commands::spell::build(),
+                commands::template::build(),
+                commands::admin::build()
             ];
 
             match Command::set_global_commands(&ctx.http, slash_commands).await {
@@ -112,24 +122,84 @@ impl EventHandler for runtime_client::RuntimeClient {
 
         match interaction_data {
 
-            Interaction::Command(command_interaction_data) => match command_interaction_data.data.name.as_str() {
-                "profile"   => commands::profile::run( self, ctx, command_interaction_data ).await,
-                "character" => commands::character::run( self, ctx, command_interaction_data ).await,
-                _ => {}
+            Interaction::Command(command_interaction_data) => {
+                commands::note_guild_member( self, &command_interaction_data ).await;
+
+                match command_interaction_data.data.name.as_str() {
+                    "profile"   => commands::profile::run( self, ctx, command_interaction_data ).await,
+                    "character" => commands::character::run( self, ctx, command_interaction_data ).await,
+                    "campaign"  => commands::campaign::run( self, ctx, command_interaction_data ).await,
+                    "combat"    => commands::combat::run( self, ctx, command_interaction_data ).await,
+                    "hp"        => commands::hp::run( self, ctx, command_interaction_data ).await,
+                    "inventory" => commands::inventory::run( self, ctx, command_interaction_data ).await,
+                    "wallet"    => commands::wallet::run( self, ctx, command_interaction_data ).await,
+                    "xp"        => commands::xp::run( self, ctx, command_interaction_data ).await,
+                    "session"   => commands::session::run( self, ctx, command_interaction_data ).await,
+                    "spell"     => commands::spell::run( self, ctx, command_interaction_data ).await,
+                    "template"  => commands::template::run( self, ctx, command_interaction_data ).await,
+                    "admin"     => commands::admin::run( self, ctx, command_interaction_data ).await,
+                    _ => {}
+                }
             },
             // match command
 
+            Interaction::Autocomplete(autocomplete_interaction_data) => match autocomplete_interaction_data.data.name.as_str() {
+                "character" => commands::character::autocomplete( self, ctx, autocomplete_interaction_data ).await,
+                "campaign"  => commands::campaign::autocomplete( ctx, autocomplete_interaction_data ).await,
+                "combat"    => commands::combat::autocomplete( ctx, autocomplete_interaction_data ).await,
+                "hp"        => commands::hp::autocomplete( self, ctx, autocomplete_interaction_data ).await,
+                "inventory" => commands::inventory::autocomplete( self, ctx, autocomplete_interaction_data ).await,
+                "wallet"    => commands::wallet::autocomplete( self, ctx, autocomplete_interaction_data ).await,
+                "xp"        => commands::xp::autocomplete( self, ctx, autocomplete_interaction_data ).await,
+                "session"   => commands::session::autocomplete( self, ctx, autocomplete_interaction_data ).await,
+                "spell"     => commands::spell::autocomplete( self, ctx, autocomplete_interaction_data ).await,
+                "template"  => commands::template::autocomplete( self, ctx, autocomplete_interaction_data ).await,
+                "admin"     => commands::admin::autocomplete( self, ctx, autocomplete_interaction_data ).await,
+                _ => {}
+            },
+            // match autocomplete
+
             Interaction::Component(component_interaction_data) => {
                 let custom_id_clone = component_interaction_data.data.custom_id.clone();
                 let split_custom_id: Vec<&str> = custom_id_clone.split("|").collect();
 
                 match split_custom_id[0] {
+                    "profile" => commands::profile::handle_component_interaction(
+                        self,
+                        component_interaction_data,
+                        ctx,
+                        split_custom_id
+                    ).await,
                     "character" => commands::character::handle_component_interaction(
                         self, 
                         component_interaction_data,
                         ctx,
                         split_custom_id
                     ).await,
+                    "combat" => commands::combat::handle_component_interaction(
+                        self,
+                        component_interaction_data,
+                        ctx,
+                        split_custom_id
+                    ).await,
+                    "inventory" => commands::inventory::handle_component_interaction(
+                        self,
+                        component_interaction_data,
+                        ctx,
+                        split_custom_id
+                    ).await,
+                    "wallet" => commands::wallet::handle_component_interaction(
+                        self,
+                        component_interaction_data,
+                        ctx,
+                        split_custom_id
+                    ).await,
+                    "session" => commands::session::handle_component_interaction(
+                        self,
+                        component_interaction_data,
+                        ctx,
+                        split_custom_id
+                    ).await,
                     unknown_component => panic!("Unknown Component: `{unknown_component}`")
                 }
             },
@@ -140,7 +210,7 @@ impl EventHandler for runtime_client::RuntimeClient {
                 let split_custom_id: Vec<&str> = custom_id_clone.split("|").collect();
 
                 match split_custom_id[0] {
-                    "character" => commands::character::handle_modal(modal_interaction_data, ctx, split_custom_id).await,
+                    "character" => commands::character::handle_modal(self, modal_interaction_data, ctx, split_custom_id).await,
                     unknown_modal => panic!("Unknown Modal: `{unknown_modal}`")
                 }
             },
@@ -3,4 +3,6 @@ pub mod event_handler;
 pub mod context_keys;
 pub mod sql_scripts;
 pub mod commands;
+pub mod preconditions;
+pub mod scheduler;
 
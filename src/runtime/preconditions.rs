@@ -0,0 +1,175 @@
+// --== MODULE IMPORTS ==-- //
+    use crate::{
+        runtime::{runtime_client::RuntimeClient, sql_scripts},
+        utils::misc::{colour_codes::ColourCode, logging::create_log_message}
+    };
+// ==--
+
+// --== CRATE IMPORTS ==-- //
+
+    // SERENITY
+        use serenity::{
+            builder::{
+                CreateActionRow, CreateButton, CreateEmbed, CreateInteractionResponse,
+                CreateInteractionResponseMessage
+            },
+            client::Context,
+            model::{application::{ButtonStyle, CommandInteraction}, user::User}
+        };
+// ==--
+
+
+
+/// Conditions that have to hold before a command may run. Commands declare theirs in a
+/// `PRECONDITIONS` constant and check them with `enforce` before doing anything else
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Precondition {
+    /// The invoker has a profile in `DiscordUsers`. Depending on `auto_register_profiles` in the
+    /// config the profile is either created on the fly, or the invoker is asked to register
+    RegisteredProfile
+}
+
+
+
+/// Checks every precondition in order.
+///
+/// On success returns any notices the command should show alongside its response, such as one
+/// telling the user they've been registered. On failure returns the message to refuse with
+pub async fn check( runtime_client: &RuntimeClient, user: &User, preconditions: &[Precondition] ) -> Result<Vec<CreateEmbed>, CreateInteractionResponseMessage> {
+    if preconditions.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let user_id = user.id.get();
+    let mut notices = vec![];
+
+    let discord_user = match sql_scripts::discord_users::get_by_user_id(&runtime_client.database_connection, user_id).await {
+        Ok(discord_user) => discord_user,
+        Err(query_err) => {
+            println!( "{}", create_log_message(
+                    format!(
+                        "{}preconditions::check{}: Failed to fetch profile: `{}{}{}`",
+                        ColourCode::Location,
+                        ColourCode::Reset,
+                        ColourCode::Info,
+                        query_err,
+                        ColourCode::Reset
+                    ),
+                    ColourCode::Error
+            ));
+
+            return Err(refusal(
+                CreateEmbed::new()
+                    .title("An unexpected error occured :(")
+                    .description(format!("We were unable to check your profile\n{query_err}"))
+                    .colour(ColourCode::Error.to_embed_colour()),
+                vec![]
+            ));
+        }
+    };
+
+    // --== REGISTERED PROFILE ==-- //
+
+        match discord_user {
+            Some(_) => {},
+
+            None if runtime_client.auto_register_profiles => {
+                if let Err(query_err) = sql_scripts::discord_users::add_user(&runtime_client.database_connection, user_id).await {
+                    println!( "{}", create_log_message(
+                            format!(
+                                "Failed to add user `{}{}:#{}{}` to the database: `{}{}{}`",
+                                ColourCode::Info,
+                                user.tag(),
+                                user_id,
+                                ColourCode::Reset,
+                                ColourCode::Info,
+                                query_err,
+                                ColourCode::Reset
+                            ),
+                            ColourCode::Error
+                    ));
+
+                    return Err(refusal(
+                        CreateEmbed::new()
+                            .title("Failed to add you to the database :(")
+                            .description(format!("`{query_err}`"))
+                            .colour(ColourCode::Error.to_embed_colour()),
+                        vec![]
+                    ));
+                }
+
+                println!( "{}", create_log_message(
+                        format!(
+                            "successfully added user `{}{}:#{}{}` to the database",
+                            ColourCode::Info,
+                            user.tag(),
+                            user_id,
+                            ColourCode::Reset
+                        ),
+                        ColourCode::Success
+                ));
+
+                notices.push(
+                    CreateEmbed::new()
+                        .title("We've added you to the database")
+                        .description("You didn't have a profile yet, so one was made for you. See `/profile view`")
+                        .colour(ColourCode::Success.to_embed_colour())
+                );
+            },
+
+            None => {
+                let register_button = CreateActionRow::Buttons(vec![
+                    CreateButton::new(format!( "profile|register|{user_id}" ))
+                        .style(ButtonStyle::Primary)
+                        .label("Register")
+                ]);
+
+                return Err(refusal(
+                    CreateEmbed::new()
+                        .title("You're not in the database")
+                        .description("You need a profile for this. Press `Register` to add yourself, then try again")
+                        .colour(ColourCode::Info.to_embed_colour()),
+                    vec![ register_button ]
+                ));
+            }
+        }
+    // ==--
+
+    Ok(notices)
+}
+
+
+
+/// Checks the preconditions of a slash command, responding to it with the refusal should any
+/// fail. Returns `None` when the command must not run
+pub async fn enforce( runtime_client: &RuntimeClient, ctx: &Context, interaction_data: &CommandInteraction, preconditions: &[Precondition] ) -> Option<Vec<CreateEmbed>> {
+    match check(runtime_client, &interaction_data.user, preconditions).await {
+        Ok(notices) => Some(notices),
+        Err(refusal_message) => {
+            let send_refusal = interaction_data.create_response(&ctx.http, CreateInteractionResponse::Message(refusal_message));
+            if let Err(why) = send_refusal.await {
+                println!( "{}", create_log_message(
+                        format!(
+                            "{}preconditions::enforce{}: Failed to send response: `{}{}{}`",
+                            ColourCode::Location,
+                            ColourCode::Reset,
+                            ColourCode::Info,
+                            why,
+                            ColourCode::Reset
+                        ),
+                        ColourCode::Error
+                ));
+            }
+            None
+        }
+    }
+}
+
+
+
+fn refusal( embed: CreateEmbed, components: Vec<CreateActionRow> ) -> CreateInteractionResponseMessage {
+    CreateInteractionResponseMessage::new()
+        .embed(embed)
+        .components(components)
+        .ephemeral(true)
+}
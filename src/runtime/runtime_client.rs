@@ -2,7 +2,27 @@
 
 use sqlx::sqlite::SqlitePool;
 
+use crate::utils::misc::{currency::Currency, levelling::Levelling, multiclassing::Multiclassing, wizard::Wizard};
+
 pub struct RuntimeClient {
     pub database_connection: SqlitePool,
-    pub wakeup_channel_id: u64
+    pub wakeup_channel_id: u64,
+    /// Whether commands needing a profile create one on the fly, rather than asking the user to
+    /// register
+    pub auto_register_profiles: bool,
+    /// Where new characters are posted for staff to approve. Without one, characters can be used
+    /// as soon as they are created
+    pub review_channel_id: Option<u64>,
+    /// Who may change what every server shares, such as the classes characters are created with
+    pub bot_admin_ids: Vec<u64>,
+    /// The coins wallets are counted in
+    pub currency: Currency,
+    /// The experience curve, and what each level grants
+    pub levelling: Levelling,
+    /// Which classes characters may combine
+    pub multiclassing: Multiclassing,
+    /// How many minutes before a session starts reminders go out, furthest first
+    pub session_reminder_offsets: Vec<i64>,
+    /// The stages `/character create` walks through
+    pub wizard: Wizard
 }
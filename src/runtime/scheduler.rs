@@ -0,0 +1,175 @@
+// --== MODULE IMPORTS ==-- //
+
+    use crate::{
+        runtime::{
+            commands::session::session_message_link,
+            sql_scripts::{self, models::{DueReminder, RsvpResponse}}
+        },
+        utils::misc::{
+            colour_codes::ColourCode, logging::create_log_message, schedule_time::format_minutes
+        }
+    };
+// ==--
+
+// --== CRATE IMPORTS ==-- //
+
+    // STD & CORE
+        use std::{sync::Arc, time::Duration};
+
+    // SERENITY
+        use serenity::{
+            builder::{CreateAllowedMentions, CreateEmbed, CreateMessage},
+            http::Http,
+            model::id::{ChannelId, UserId}
+        };
+
+    // SQLX
+        use sqlx::SqlitePool;
+// ==--
+
+
+
+/// How often the database is checked for reminders that came due
+const POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Starts sending session reminders in the background, and clearing out lapsed character transfers.
+///
+/// Reminders are read from the database on every tick rather than kept in memory, so those that
+/// came due while the bot was offline go out as soon as it is back
+pub fn start( database_conn_pool: SqlitePool, http: Arc<Http> ) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(POLL_INTERVAL);
+        loop {
+            interval.tick().await;
+            send_due_reminders(&database_conn_pool, &http).await;
+            remove_expired_transfers(&database_conn_pool).await;
+        }
+    });
+}
+
+async fn send_due_reminders( database_conn_pool: &SqlitePool, http: &Http ) {
+    let due_reminders = match sql_scripts::sessions::get_due_reminders(database_conn_pool).await {
+        Ok(due_reminders) => due_reminders,
+        Err(query_err) => {
+            println!( "{}", create_log_message(
+                    format!(
+                        "{}scheduler::get_due_reminders{}: Failed to fetch due reminders: `{}{}{}`",
+                        ColourCode::Location,
+                        ColourCode::Reset,
+                        ColourCode::Info,
+                        query_err,
+                        ColourCode::Reset
+                    ),
+                    ColourCode::Error
+            ));
+            return;
+        }
+    };
+
+    let now = chrono::Utc::now().timestamp();
+    for reminder in &due_reminders {
+        // After time offline several reminders of a session may be due at once, only the latest
+        // is worth sending. Those of sessions that have already started are dropped altogether
+        let is_superseded = due_reminders.iter().any(|other_reminder| {
+            other_reminder.session.id == reminder.session.id && other_reminder.offset_minutes < reminder.offset_minutes
+        });
+        let is_stale = is_superseded || reminder.session.starts_at <= now;
+
+        // Claiming the reminder first means it is never sent twice, at worst a failed send is lost
+        match sql_scripts::sessions::mark_reminder_sent(database_conn_pool, reminder.session.id, reminder.offset_minutes).await {
+            Ok(true) if !is_stale => send_reminder(database_conn_pool, http, reminder).await,
+            Ok(_) => {},
+            Err(query_err) => println!( "{}", create_log_message(
+                    format!(
+                        "{}scheduler::mark_reminder_sent{}: Failed to claim reminder: `{}{}{}`",
+                        ColourCode::Location,
+                        ColourCode::Reset,
+                        ColourCode::Info,
+                        query_err,
+                        ColourCode::Reset
+                    ),
+                    ColourCode::Error
+            ))
+        }
+    }
+}
+
+/// Lapsed offers are refused when accepted anyway, this only keeps them from piling up
+async fn remove_expired_transfers( database_conn_pool: &SqlitePool ) {
+    if let Err(query_err) = sql_scripts::character_transfers::remove_expired_transfers(database_conn_pool).await {
+        println!( "{}", create_log_message(
+                format!(
+                    "{}scheduler::remove_expired_transfers{}: Failed to remove lapsed transfers: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    query_err,
+                    ColourCode::Reset
+                ),
+                ColourCode::Error
+        ));
+    }
+}
+
+/// Posts a reminder in the session's channel, pinging everyone who is attending or might be
+async fn send_reminder( database_conn_pool: &SqlitePool, http: &Http, reminder: &DueReminder ) {
+    let session = &reminder.session;
+
+    let responders: Vec<UserId> = match sql_scripts::sessions::get_rsvps(database_conn_pool, session.id).await {
+        Ok(rsvps) => rsvps.into_iter()
+            .filter(|rsvp| rsvp.response != RsvpResponse::Declining)
+            .map(|rsvp| UserId::new(rsvp.user.get()))
+            .collect(),
+        Err(query_err) => {
+            println!( "{}", create_log_message(
+                    format!(
+                        "{}scheduler::get_rsvps{}: Failed to fetch answers: `{}{}{}`",
+                        ColourCode::Location,
+                        ColourCode::Reset,
+                        ColourCode::Info,
+                        query_err,
+                        ColourCode::Reset
+                    ),
+                    ColourCode::Error
+            ));
+            vec![]
+        }
+    };
+
+    let mut reminder_description = format!(
+        "Starts <t:{0}:F>, in about {1}",
+        session.starts_at,
+        format_minutes(reminder.offset_minutes)
+    );
+    if let Some(message_link) = session_message_link(session) {
+        reminder_description.push_str(&format!( "\n[Answer the announcement]({message_link}) if you haven't yet" ));
+    }
+
+    let mention_list: Vec<String> = responders.iter().map(|user_id| format!( "<@{user_id}>" )).collect();
+    let reminder_message = CreateMessage::new()
+        .content(mention_list.join(" "))
+        .embed(
+            CreateEmbed::new()
+                .title(format!( "Reminder: {}", session.title ))
+                .description(reminder_description)
+                .colour(ColourCode::Info.to_embed_colour())
+        )
+        .allowed_mentions(CreateAllowedMentions::new().users(responders));
+
+    if let Err(why) = ChannelId::new(session.channel_id.get()).send_message(http, reminder_message).await {
+        println!( "{}", create_log_message(
+                format!(
+                    "{}scheduler::send_reminder{}: Failed to remind of session `{}{}{}`: `{}{}{}`",
+                    ColourCode::Location,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    session.id,
+                    ColourCode::Reset,
+                    ColourCode::Info,
+                    why,
+                    ColourCode::Reset
+                ),
+                ColourCode::Error
+        ));
+    }
+}
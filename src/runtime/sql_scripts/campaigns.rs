@@ -0,0 +1,140 @@
+use sqlx::{
+    sqlite::{
+        SqlitePool, SqliteQueryResult
+    },
+    Error
+};
+
+use crate::runtime::sql_scripts::models::{
+    Campaign, CampaignRosterEntry, DiscordId
+};
+
+// --== CAMPAIGNS ==-- //
+
+    pub async fn add_campaign(database_conn_pool: &SqlitePool, guild_id: u64, channel_id: u64, gm_id: u64, name: &str) -> Result<u64, Error> {
+        sqlx::query_scalar(
+            "INSERT INTO Campaigns (guildID, channelID, gmDiscordID, name, createdAt) \
+            VALUES ($1, $2, $3, $4, unixepoch()) \
+            RETURNING pk_campaignID;"
+        )
+            .bind(DiscordId(guild_id))
+            .bind(DiscordId(channel_id))
+            .bind(DiscordId(gm_id))
+            .bind(name)
+            .fetch_one(database_conn_pool)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_campaign(database_conn_pool: &SqlitePool, campaign_id: u64) -> Result<Option<Campaign>, Error> {
+        sqlx::query_as("SELECT * FROM Campaigns WHERE pk_campaignID = $1;")
+            .bind(campaign_id as i64)
+            .fetch_optional(database_conn_pool)
+            .await
+    }
+
+    /// Returns the campaign currently running in a channel, archived campaigns are skipped
+    pub async fn get_active_by_channel(database_conn_pool: &SqlitePool, channel_id: u64) -> Result<Option<Campaign>, Error> {
+        sqlx::query_as("SELECT * FROM Campaigns WHERE channelID = $1 AND isArchived = FALSE;")
+            .bind(DiscordId(channel_id))
+            .fetch_optional(database_conn_pool)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_campaigns_by_guild(database_conn_pool: &SqlitePool, guild_id: u64) -> Result<Vec<Campaign>, Error> {
+        sqlx::query_as("SELECT * FROM Campaigns WHERE guildID = $1 ORDER BY pk_campaignID;")
+            .bind(DiscordId(guild_id))
+            .fetch_all(database_conn_pool)
+            .await
+    }
+
+    pub async fn archive_campaign(database_conn_pool: &SqlitePool, campaign_id: u64) -> Result<SqliteQueryResult, Error> {
+        sqlx::query("UPDATE Campaigns SET isArchived = TRUE WHERE pk_campaignID = $1;")
+            .bind(campaign_id as i64)
+            .execute(database_conn_pool)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn remove_campaign(database_conn_pool: &SqlitePool, campaign_id: u64) -> Result<SqliteQueryResult, Error> {
+        sqlx::query("DELETE FROM Campaigns WHERE pk_campaignID = $1;")
+            .bind(campaign_id as i64)
+            .execute(database_conn_pool)
+            .await
+    }
+// ==--
+
+// --== CAMPAIGN CHARACTERS ==-- //
+
+    pub async fn add_character(database_conn_pool: &SqlitePool, campaign_id: u64, character_id: u64) -> Result<SqliteQueryResult, Error> {
+        sqlx::query("INSERT INTO CampaignCharacters VALUES ($1, $2, unixepoch());")
+            .bind(campaign_id as i64)
+            .bind(character_id as i64)
+            .execute(database_conn_pool)
+            .await
+    }
+
+    pub async fn remove_character(database_conn_pool: &SqlitePool, campaign_id: u64, character_id: u64) -> Result<SqliteQueryResult, Error> {
+        sqlx::query("DELETE FROM CampaignCharacters WHERE pk_fk_campaignID = $1 AND pk_fk_characterID = $2;")
+            .bind(campaign_id as i64)
+            .bind(character_id as i64)
+            .execute(database_conn_pool)
+            .await
+    }
+
+    pub async fn get_roster(database_conn_pool: &SqlitePool, campaign_id: u64) -> Result<Vec<CampaignRosterEntry>, Error> {
+        sqlx::query_as(
+            "SELECT Characters.pk_characterID, Characters.ownerDiscordID, Characters.name, CampaignCharacters.joinedAt \
+            FROM CampaignCharacters \
+            JOIN Characters ON Characters.pk_characterID = CampaignCharacters.pk_fk_characterID \
+            WHERE CampaignCharacters.pk_fk_campaignID = $1 \
+            ORDER BY CampaignCharacters.joinedAt, Characters.pk_characterID;"
+        )
+            .bind(campaign_id as i64)
+            .fetch_all(database_conn_pool)
+            .await
+    }
+// ==--
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::sql_scripts::{discord_users, insert_test_character, open_test_database};
+
+    #[tokio::test]
+    async fn campaign_lifecycle() {
+        let database_conn_pool = open_test_database().await;
+        discord_users::add_user(&database_conn_pool, 1).await.expect("Insert should succeed");
+        let character_id = insert_test_character(&database_conn_pool, 2, "Hale").await;
+
+        let campaign_id = add_campaign(&database_conn_pool, 100, 200, 1, "The Long Road").await.expect("Insert should succeed");
+        assert_eq!(
+            get_active_by_channel(&database_conn_pool, 200).await.expect("Select should succeed").map(|campaign| campaign.id),
+            Some(campaign_id)
+        );
+
+        // Only one campaign may run in a channel at a time
+        assert!(add_campaign(&database_conn_pool, 100, 200, 1, "Another Road").await.is_err());
+
+        add_character(&database_conn_pool, campaign_id, character_id).await.expect("Insert should succeed");
+        let roster = get_roster(&database_conn_pool, campaign_id).await.expect("Select should succeed");
+        assert_eq!(roster.len(), 1);
+        assert_eq!((roster[0].owner, roster[0].name.as_str()), (DiscordId(2), "Hale"));
+
+        remove_character(&database_conn_pool, campaign_id, character_id).await.expect("Delete should succeed");
+        assert!(get_roster(&database_conn_pool, campaign_id).await.expect("Select should succeed").is_empty());
+
+        // Nor may a server run two campaigns of the same name
+        assert!(add_campaign(&database_conn_pool, 100, 201, 1, "The Long Road").await.is_err());
+
+        // Archiving frees up the channel and the name
+        archive_campaign(&database_conn_pool, campaign_id).await.expect("Update should succeed");
+        assert!(get_active_by_channel(&database_conn_pool, 200).await.expect("Select should succeed").is_none());
+        add_campaign(&database_conn_pool, 100, 200, 1, "The Long Road").await.expect("Insert should succeed");
+
+        assert_eq!(get_campaigns_by_guild(&database_conn_pool, 100).await.expect("Select should succeed").len(), 2);
+    }
+}
@@ -0,0 +1,373 @@
+use sqlx::{
+    sqlite::{
+        SqlitePool, SqliteQueryResult
+    },
+    Error
+};
+
+use crate::{
+    runtime::sql_scripts::models::{CharacterClass, SelectedClass},
+    utils::misc::multiclassing::Multiclassing
+};
+
+/// What a character's classes allow between them
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CombinedCaps {
+    /// The highest tier of spell any of the classes can know. `None` for characters without a
+    /// class, who count as casters without a cap
+    pub casting_cap: Option<i64>,
+    /// The largest stat budget among the classes, which is what the character has been granted
+    pub stat_budget: i64
+}
+
+impl CombinedCaps {
+    pub fn of(classes: &[SelectedClass]) -> CombinedCaps {
+        CombinedCaps {
+            casting_cap: classes.iter().map(|selected| selected.class.casting_cap).max(),
+            stat_budget: classes.iter().map(|selected| selected.class.stat_budget).max().unwrap_or(0)
+        }
+    }
+}
+
+/// How taking up another class went
+#[derive(Clone, Debug, PartialEq)]
+pub enum MulticlassOutcome {
+    /// The class was added at level 1, raising the character's stat budget by this many points
+    Added(i64),
+    /// The primary class is at level 1, so it has no level to give up for the new class
+    NoLevelToSpare,
+    /// The server's multiclassing rules don't allow the class, for the reason given
+    NotAllowed(String)
+}
+
+// --== CHARACTER CLASSES ==-- //
+
+    pub async fn get_classes(database_conn_pool: &SqlitePool) -> Result<Vec<CharacterClass>, Error> {
+        sqlx::query_as("SELECT * FROM CharacterClasses ORDER BY pk_classID;")
+            .fetch_all(database_conn_pool)
+            .await
+    }
+
+    pub async fn get_class(database_conn_pool: &SqlitePool, class_id: u64) -> Result<Option<CharacterClass>, Error> {
+        sqlx::query_as("SELECT * FROM CharacterClasses WHERE pk_classID = $1;")
+            .bind(class_id as i64)
+            .fetch_optional(database_conn_pool)
+            .await
+    }
+
+    /// Adds a class, names are unique regardless of case
+    pub async fn add_class(database_conn_pool: &SqlitePool, class_name: &str, description: &str, casting_cap: i64, stat_budget: i64, base_hit_points: i64) -> Result<u64, Error> {
+        sqlx::query_scalar(
+            "INSERT INTO CharacterClasses (className, description, castingCap, statBudget, baseHitPoints) VALUES ($1, $2, $3, $4, $5) \
+            RETURNING pk_classID;"
+        )
+            .bind(class_name)
+            .bind(description)
+            .bind(casting_cap)
+            .bind(stat_budget)
+            .bind(base_hit_points)
+            .fetch_one(database_conn_pool)
+            .await
+    }
+
+    /// Overwrites everything but the ID of the class with that ID
+    pub async fn update_class(database_conn_pool: &SqlitePool, class: &CharacterClass) -> Result<SqliteQueryResult, Error> {
+        sqlx::query(
+            "UPDATE CharacterClasses SET className = $2, description = $3, castingCap = $4, statBudget = $5, baseHitPoints = $6 \
+            WHERE pk_classID = $1;"
+        )
+            .bind(class.id as i64)
+            .bind(&class.name)
+            .bind(&class.description)
+            .bind(class.casting_cap)
+            .bind(class.stat_budget)
+            .bind(class.base_hit_points)
+            .execute(database_conn_pool)
+            .await
+    }
+
+    /// Whether any character has the class, which keeps it from being removed
+    pub async fn is_class_in_use(database_conn_pool: &SqlitePool, class_id: u64) -> Result<bool, Error> {
+        sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM SelectedCharacterClasses WHERE pk_fk_classID = $1);")
+            .bind(class_id as i64)
+            .fetch_one(database_conn_pool)
+            .await
+    }
+
+    /// Fails while any character still has the class, see `is_class_in_use`
+    pub async fn remove_class(database_conn_pool: &SqlitePool, class_id: u64) -> Result<SqliteQueryResult, Error> {
+        sqlx::query("DELETE FROM CharacterClasses WHERE pk_classID = $1;")
+            .bind(class_id as i64)
+            .execute(database_conn_pool)
+            .await
+    }
+// ==--
+
+// --== SELECTED CHARACTER CLASSES ==-- //
+
+    /// The classes of a character, their primary class first
+    pub async fn get_character_classes(database_conn_pool: &SqlitePool, character_id: u64) -> Result<Vec<CharacterClass>, Error> {
+        Ok(get_class_split(database_conn_pool, character_id)
+            .await?
+            .into_iter()
+            .map(|selected| selected.class)
+            .collect())
+    }
+
+    /// The classes of a character along with their levels in each, their primary class first
+    pub async fn get_class_split(database_conn_pool: &SqlitePool, character_id: u64) -> Result<Vec<SelectedClass>, Error> {
+        sqlx::query_as(
+            "SELECT CharacterClasses.*, SelectedCharacterClasses.isPrimary, SelectedCharacterClasses.classLevel \
+            FROM SelectedCharacterClasses \
+            JOIN CharacterClasses ON CharacterClasses.pk_classID = SelectedCharacterClasses.pk_fk_classID \
+            WHERE SelectedCharacterClasses.pk_fk_characterID = $1 \
+            ORDER BY SelectedCharacterClasses.isPrimary DESC, SelectedCharacterClasses.classLevel DESC, CharacterClasses.pk_classID;"
+        )
+            .bind(character_id as i64)
+            .fetch_all(database_conn_pool)
+            .await
+    }
+
+    /// Gives a character a class at level 1, making it their primary class if they have none
+    #[allow(dead_code)]
+    pub async fn add_character_class(database_conn_pool: &SqlitePool, character_id: u64, class_id: u64) -> Result<SqliteQueryResult, Error> {
+        sqlx::query(
+            "INSERT INTO SelectedCharacterClasses (pk_fk_characterID, pk_fk_classID, isPrimary) \
+            VALUES ( $1, $2, NOT EXISTS (SELECT 1 FROM SelectedCharacterClasses WHERE pk_fk_characterID = $1 AND isPrimary) );"
+        )
+            .bind(character_id as i64)
+            .bind(class_id as i64)
+            .execute(database_conn_pool)
+            .await
+    }
+
+    /// Has a character take up another class. Their level stays the same, so the new class's
+    /// first level is taken from their primary class. Should the new class have a larger stat
+    /// budget than their others, they get the difference to spend.
+    ///
+    /// The multiclassing rules are checked within the same transaction, so that two classes
+    /// taken up at once can't both pass them
+    pub async fn multiclass(database_conn_pool: &SqlitePool, multiclassing: &Multiclassing, character_id: u64, class_id: u64) -> Result<MulticlassOutcome, Error> {
+        let mut transaction = database_conn_pool.begin().await?;
+
+        // Taking the level first makes the transaction take the write lock straight away
+        let spared_level: Option<i64> = sqlx::query_scalar(
+            "UPDATE SelectedCharacterClasses SET classLevel = classLevel - 1 \
+            WHERE pk_fk_characterID = $1 AND isPrimary AND classLevel > 1 \
+            RETURNING classLevel;"
+        )
+            .bind(character_id as i64)
+            .fetch_optional(&mut *transaction)
+            .await?;
+        if spared_level.is_none() {
+            return Ok(MulticlassOutcome::NoLevelToSpare);
+        }
+
+        let level: i64 = sqlx::query_scalar("SELECT level FROM Characters WHERE pk_characterID = $1;")
+            .bind(character_id as i64)
+            .fetch_one(&mut *transaction)
+            .await?;
+        let class_names: Vec<String> = sqlx::query_scalar(
+            "SELECT CharacterClasses.className FROM SelectedCharacterClasses \
+            JOIN CharacterClasses ON CharacterClasses.pk_classID = SelectedCharacterClasses.pk_fk_classID \
+            WHERE SelectedCharacterClasses.pk_fk_characterID = $1;"
+        )
+            .bind(character_id as i64)
+            .fetch_all(&mut *transaction)
+            .await?;
+        let new_class_name: String = sqlx::query_scalar("SELECT className FROM CharacterClasses WHERE pk_classID = $1;")
+            .bind(class_id as i64)
+            .fetch_one(&mut *transaction)
+            .await?;
+
+        // Dropping the transaction rolls back the level taken above
+        let class_names: Vec<&str> = class_names.iter().map(String::as_str).collect();
+        if let Err(reason) = multiclassing.check(level, &class_names, &new_class_name) {
+            return Ok(MulticlassOutcome::NotAllowed(reason));
+        }
+
+        let budget_query = "SELECT COALESCE(MAX(CharacterClasses.statBudget), 0) \
+            FROM SelectedCharacterClasses \
+            JOIN CharacterClasses ON CharacterClasses.pk_classID = SelectedCharacterClasses.pk_fk_classID \
+            WHERE SelectedCharacterClasses.pk_fk_characterID = $1;";
+        let previous_budget: i64 = sqlx::query_scalar(budget_query)
+            .bind(character_id as i64)
+            .fetch_one(&mut *transaction)
+            .await?;
+
+        sqlx::query("INSERT INTO SelectedCharacterClasses (pk_fk_characterID, pk_fk_classID, isPrimary) VALUES ( $1, $2, FALSE );")
+            .bind(character_id as i64)
+            .bind(class_id as i64)
+            .execute(&mut *transaction)
+            .await?;
+
+        let budget: i64 = sqlx::query_scalar(budget_query)
+            .bind(character_id as i64)
+            .fetch_one(&mut *transaction)
+            .await?;
+
+        let stat_points_gained = budget - previous_budget;
+        sqlx::query("UPDATE Characters SET unspentStatPoints = unspentStatPoints + $2 WHERE pk_characterID = $1;")
+            .bind(character_id as i64)
+            .bind(stat_points_gained)
+            .execute(&mut *transaction)
+            .await?;
+
+        transaction.commit().await?;
+        Ok(MulticlassOutcome::Added(stat_points_gained))
+    }
+
+    /// Makes one of a character's classes their primary one. Returns `false`, changing nothing,
+    /// if the character doesn't have the class
+    pub async fn set_primary_class(database_conn_pool: &SqlitePool, character_id: u64, class_id: u64) -> Result<bool, Error> {
+        let mut transaction = database_conn_pool.begin().await?;
+
+        // Only one class may be primary at a time, and SQLite checks that row by row, so the old
+        // one is cleared first
+        sqlx::query("UPDATE SelectedCharacterClasses SET isPrimary = FALSE WHERE pk_fk_characterID = $1;")
+            .bind(character_id as i64)
+            .execute(&mut *transaction)
+            .await?;
+        let updated = sqlx::query("UPDATE SelectedCharacterClasses SET isPrimary = TRUE WHERE pk_fk_characterID = $1 AND pk_fk_classID = $2;")
+            .bind(character_id as i64)
+            .bind(class_id as i64)
+            .execute(&mut *transaction)
+            .await?;
+
+        if updated.rows_affected() == 0 {
+            return Ok(false);
+        }
+        transaction.commit().await?;
+        Ok(true)
+    }
+
+    #[allow(dead_code)]
+    pub async fn remove_character_class(database_conn_pool: &SqlitePool, character_id: u64, class_id: u64) -> Result<SqliteQueryResult, Error> {
+        sqlx::query("DELETE FROM SelectedCharacterClasses WHERE pk_fk_characterID = $1 AND pk_fk_classID = $2;")
+            .bind(character_id as i64)
+            .bind(class_id as i64)
+            .execute(database_conn_pool)
+            .await
+    }
+// ==--
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        runtime::sql_scripts::{
+            characters, discord_users, experience, insert_test_character, open_test_database, test_character
+        },
+        utils::misc::levelling::Levelling
+    };
+
+    #[tokio::test]
+    async fn classes_are_seeded_and_editable() {
+        let database_conn_pool = open_test_database().await;
+
+        let class_names: Vec<String> = get_classes(&database_conn_pool)
+            .await
+            .expect("Select should succeed")
+            .into_iter()
+            .map(|class| class.name)
+            .collect();
+        assert_eq!(class_names, vec![ "Martial", "Half-Caster", "Caster" ]);
+
+        let class_id = add_class(&database_conn_pool, "Warden", "Keeps watch", 1, 4, 10).await.expect("Insert should succeed");
+        assert!(add_class(&database_conn_pool, "warden", "", 0, 0, 8).await.is_err(), "Names are unique regardless of case");
+
+        let mut class = get_class(&database_conn_pool, class_id).await.expect("Select should succeed").expect("Class exists");
+        class.name = "Ranger".to_string();
+        class.casting_cap = 0;
+        update_class(&database_conn_pool, &class).await.expect("Update should succeed");
+        assert_eq!(
+            get_class(&database_conn_pool, class_id).await.expect("Select should succeed"),
+            Some(CharacterClass { id: class_id, name: "Ranger".to_string(), description: "Keeps watch".to_string(), casting_cap: 0, stat_budget: 4, base_hit_points: 10 })
+        );
+
+        // Characters start with their class's stat budget, and keep it from being removed
+        let mut new_character = test_character("Fenn");
+        new_character.class_id = class_id;
+        discord_users::add_user(&database_conn_pool, 1).await.expect("Insert should succeed");
+        let character_id = characters::insert_character(&database_conn_pool, 1, new_character).await.expect("Insert should succeed");
+        let progression = experience::get_progression(&database_conn_pool, character_id).await.expect("Select should succeed");
+        assert_eq!(progression.map(|progression| progression.unspent_stat_points), Some(4));
+        assert!(is_class_in_use(&database_conn_pool, class_id).await.expect("Select should succeed"));
+        assert!(remove_class(&database_conn_pool, class_id).await.is_err());
+
+        characters::remove_character(&database_conn_pool, character_id).await.expect("Delete should succeed");
+        assert!(!is_class_in_use(&database_conn_pool, class_id).await.expect("Select should succeed"));
+        remove_class(&database_conn_pool, class_id).await.expect("Delete should succeed");
+        assert!(get_class(&database_conn_pool, class_id).await.expect("Select should succeed").is_none());
+    }
+
+    #[tokio::test]
+    async fn selected_classes_crud() {
+        let database_conn_pool = open_test_database().await;
+        let character_id = insert_test_character(&database_conn_pool, 1, "Dorn").await;
+
+        add_character_class(&database_conn_pool, character_id, CharacterClass::MARTIAL_ID).await.expect("Insert should succeed");
+        let class_ids: Vec<u64> = get_character_classes(&database_conn_pool, character_id)
+            .await
+            .expect("Select should succeed")
+            .into_iter()
+            .map(|class| class.id)
+            .collect();
+        assert_eq!(class_ids, vec![ CharacterClass::CASTER_ID, CharacterClass::MARTIAL_ID ], "The primary class comes first");
+
+        remove_character_class(&database_conn_pool, character_id, CharacterClass::CASTER_ID).await.expect("Delete should succeed");
+        assert_eq!(get_character_classes(&database_conn_pool, character_id).await.expect("Select should succeed").len(), 1);
+    }
+
+    #[tokio::test]
+    async fn multiclassing_splits_levels() {
+        let database_conn_pool = open_test_database().await;
+        let character_id = insert_test_character(&database_conn_pool, 1, "Dorn").await;
+        let knight_id = add_class(&database_conn_pool, "Knight", "", 0, 3, 12).await.expect("Insert should succeed");
+
+        // Nothing to spare at level 1
+        assert_eq!(
+            multiclass(&database_conn_pool, &Multiclassing::default(), character_id, knight_id).await.expect("Update should succeed"),
+            MulticlassOutcome::NoLevelToSpare
+        );
+
+        experience::award_experience(&database_conn_pool, character_id, 900, &Levelling::default()).await.expect("Update should succeed");
+        let unspent_before = experience::get_progression(&database_conn_pool, character_id).await.expect("Select should succeed").expect("Character exists").unspent_stat_points;
+
+        // Rules that forbid it leave the primary class's levels untouched
+        let mut single_class = Multiclassing::default();
+        single_class.max_classes = 1;
+        assert!(matches!(
+            multiclass(&database_conn_pool, &single_class, character_id, knight_id).await.expect("Update should succeed"),
+            MulticlassOutcome::NotAllowed(_)
+        ));
+        let split = get_class_split(&database_conn_pool, character_id).await.expect("Select should succeed");
+        assert_eq!((split.len(), split[0].level), (1, 3));
+
+        assert_eq!(
+            multiclass(&database_conn_pool, &Multiclassing::default(), character_id, knight_id).await.expect("Update should succeed"),
+            MulticlassOutcome::Added(3)
+        );
+        let unspent_after = experience::get_progression(&database_conn_pool, character_id).await.expect("Select should succeed").expect("Character exists").unspent_stat_points;
+        assert_eq!(unspent_after - unspent_before, 3);
+
+        let split = get_class_split(&database_conn_pool, character_id).await.expect("Select should succeed");
+        let levels: Vec<(u64, bool, i64)> = split.iter().map(|selected| (selected.class.id, selected.is_primary, selected.level)).collect();
+        assert_eq!(levels, vec![ (CharacterClass::CASTER_ID, true, 2), (knight_id, false, 1) ]);
+        assert_eq!(CombinedCaps::of(&split), CombinedCaps { casting_cap: Some(3), stat_budget: 3 });
+
+        // Levels gained from then on go to the new primary class
+        assert!(!set_primary_class(&database_conn_pool, character_id, CharacterClass::MARTIAL_ID).await.expect("Update should succeed"));
+        assert!(set_primary_class(&database_conn_pool, character_id, knight_id).await.expect("Update should succeed"));
+        experience::award_experience(&database_conn_pool, character_id, 2700, &Levelling::default()).await.expect("Update should succeed");
+        let levels: Vec<(u64, bool, i64)> = get_class_split(&database_conn_pool, character_id)
+            .await
+            .expect("Select should succeed")
+            .iter()
+            .map(|selected| (selected.class.id, selected.is_primary, selected.level))
+            .collect();
+        assert_eq!(levels, vec![ (knight_id, true, 2), (CharacterClass::CASTER_ID, false, 2) ]);
+    }
+}
@@ -0,0 +1,274 @@
+use sqlx::{
+    sqlite::SqlitePool,
+    Error
+};
+
+use crate::runtime::sql_scripts::models::{
+    Character, CharacterSearchResult, DiscordId
+};
+
+/// Limits a query joining `Characters` and their owners' `DiscordUsers` row to the characters the
+/// viewer, bound as `$1`, may see: their own, and the approved characters of users who keep their
+/// characters public
+const VISIBLE_TO_VIEWER: &str =
+    "( Characters.ownerDiscordID = $1 OR (DiscordUsers.charactersPublic AND Characters.status = 'approved') )";
+
+/// Limits a query on `Characters` to those whose owners are members of the server bound as `$2`
+const IN_GUILD: &str =
+    "EXISTS ( \
+        SELECT 1 FROM GuildMembers \
+        WHERE GuildMembers.pk_guildID = $2 AND GuildMembers.pk_discordID = Characters.ownerDiscordID \
+    )";
+
+const VISIBLE_CHARACTERS: &str =
+    "FROM Characters \
+    INNER JOIN DiscordUsers ON DiscordUsers.pk_discordID = Characters.ownerDiscordID";
+
+/// What the directory can be narrowed down by, every filter left `None` is ignored
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DirectoryFilters {
+    pub class_id: Option<u64>,
+    /// Matched whole, ignoring case
+    pub species: Option<String>,
+    pub owner_id: Option<u64>
+}
+
+/// Turns what a user typed into an FTS5 query, so that quotes, operators and the like are searched
+/// for rather than interpreted. Every word has to appear, as the start of a word, in any column.
+/// Returns `None` when nothing searchable is left
+pub fn match_expression( search_text: &str ) -> Option<String> {
+    let terms: Vec<String> = search_text
+        .split(|character: char| !character.is_alphanumeric() && character != '\'')
+        .map(|term| term.trim_matches('\''))
+        .filter(|term| !term.is_empty())
+        .map(|term| format!( "\"{}\"*", term.replace('"', "\"\"") ))
+        .collect();
+
+    if terms.is_empty() {
+        None
+    } else {
+        Some(terms.join(" "))
+    }
+}
+
+// --== SEARCH ==-- //
+
+    fn visible_matches() -> String {
+        format!(
+            "{VISIBLE_CHARACTERS} \
+            INNER JOIN CharacterSearch ON CharacterSearch.rowid = Characters.pk_characterID \
+            WHERE CharacterSearch MATCH $2 AND {VISIBLE_TO_VIEWER}"
+        )
+    }
+
+    /// Returns a page of the characters matching an FTS5 query, best matches first. Names weigh
+    /// the most, then species, then the longer descriptive columns
+    pub async fn search_characters(database_conn_pool: &SqlitePool, match_expression: &str, searcher_id: u64, limit: u32, offset: u32) -> Result<Vec<CharacterSearchResult>, Error> {
+        sqlx::query_as(&format!(
+            "SELECT Characters.pk_characterID, Characters.ownerDiscordID, Characters.name, \
+                snippet(CharacterSearch, -1, '**', '**', '...', 16) AS snippet \
+            {} \
+            ORDER BY bm25(CharacterSearch, 10.0, 5.0, 2.0, 1.0, 1.0), Characters.pk_characterID \
+            LIMIT $3 OFFSET $4;",
+            visible_matches()
+        ))
+            .bind(DiscordId(searcher_id))
+            .bind(match_expression)
+            .bind(limit)
+            .bind(offset)
+            .fetch_all(database_conn_pool)
+            .await
+    }
+
+    pub async fn count_search_results(database_conn_pool: &SqlitePool, match_expression: &str, searcher_id: u64) -> Result<u32, Error> {
+        sqlx::query_scalar(&format!( "SELECT COUNT(*) {};", visible_matches() ))
+            .bind(DiscordId(searcher_id))
+            .bind(match_expression)
+            .fetch_one(database_conn_pool)
+            .await
+    }
+// ==--
+
+
+
+// --== DIRECTORY ==-- //
+
+    fn visible_filtered() -> String {
+        format!(
+            "{VISIBLE_CHARACTERS} \
+            WHERE {VISIBLE_TO_VIEWER} AND {IN_GUILD} \
+                AND ( $3 IS NULL OR EXISTS ( \
+                    SELECT 1 FROM SelectedCharacterClasses \
+                    WHERE pk_fk_characterID = Characters.pk_characterID AND pk_fk_classID = $3 \
+                ) ) \
+                AND ( $4 IS NULL OR Characters.species = $4 COLLATE NOCASE ) \
+                AND ( $5 IS NULL OR Characters.ownerDiscordID = $5 )"
+        )
+    }
+
+    /// Returns a page of the characters of a server's members the viewer may see that pass the
+    /// filters, by name
+    pub async fn get_directory_page(database_conn_pool: &SqlitePool, viewer_id: u64, guild_id: u64, filters: &DirectoryFilters, limit: u32, offset: u32) -> Result<Vec<Character>, Error> {
+        sqlx::query_as(&format!(
+            "SELECT Characters.* {} \
+            ORDER BY Characters.name COLLATE NOCASE, Characters.pk_characterID \
+            LIMIT $6 OFFSET $7;",
+            visible_filtered()
+        ))
+            .bind(DiscordId(viewer_id))
+            .bind(DiscordId(guild_id))
+            .bind(filters.class_id.map(|class_id| class_id as i64))
+            .bind(filters.species.as_deref())
+            .bind(filters.owner_id.map(DiscordId))
+            .bind(limit)
+            .bind(offset)
+            .fetch_all(database_conn_pool)
+            .await
+    }
+
+    pub async fn count_directory(database_conn_pool: &SqlitePool, viewer_id: u64, guild_id: u64, filters: &DirectoryFilters) -> Result<u32, Error> {
+        sqlx::query_scalar(&format!( "SELECT COUNT(*) {};", visible_filtered() ))
+            .bind(DiscordId(viewer_id))
+            .bind(DiscordId(guild_id))
+            .bind(filters.class_id.map(|class_id| class_id as i64))
+            .bind(filters.species.as_deref())
+            .bind(filters.owner_id.map(DiscordId))
+            .fetch_one(database_conn_pool)
+            .await
+    }
+
+    /// Returns the character if the viewer may see it, and its owner is a member of the server
+    pub async fn get_visible_character(database_conn_pool: &SqlitePool, viewer_id: u64, guild_id: u64, character_id: u64) -> Result<Option<Character>, Error> {
+        sqlx::query_as(&format!(
+            "SELECT Characters.* {VISIBLE_CHARACTERS} \
+            WHERE {VISIBLE_TO_VIEWER} AND {IN_GUILD} AND Characters.pk_characterID = $3;"
+        ))
+            .bind(DiscordId(viewer_id))
+            .bind(DiscordId(guild_id))
+            .bind(character_id as i64)
+            .fetch_optional(database_conn_pool)
+            .await
+    }
+
+    /// Returns the distinct species of the characters of a server's members the viewer may see
+    /// that start with `typed`, for autocompletion
+    pub async fn get_visible_species(database_conn_pool: &SqlitePool, viewer_id: u64, guild_id: u64, typed: &str, limit: u32) -> Result<Vec<String>, Error> {
+        sqlx::query_scalar(&format!(
+            "SELECT MIN(Characters.species) {VISIBLE_CHARACTERS} \
+            WHERE {VISIBLE_TO_VIEWER} AND {IN_GUILD} AND instr(lower(Characters.species), lower($3)) = 1 \
+            GROUP BY Characters.species COLLATE NOCASE \
+            ORDER BY Characters.species COLLATE NOCASE \
+            LIMIT $4;"
+        ))
+            .bind(DiscordId(viewer_id))
+            .bind(DiscordId(guild_id))
+            .bind(typed)
+            .bind(limit)
+            .fetch_all(database_conn_pool)
+            .await
+    }
+// ==--
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::sql_scripts::{
+        characters, discord_users, insert_test_character, open_test_database, test_character,
+        models::{CharacterClass, CharacterStatus}
+    };
+
+    #[test]
+    fn user_text_becomes_safe_terms() {
+        assert_eq!(match_expression("cursed sword").as_deref(), Some("\"cursed\"* \"sword\"*"));
+        assert_eq!(match_expression("\"elf\" OR NEAR(").as_deref(), Some("\"elf\"* \"OR\"* \"NEAR\"*"));
+        assert_eq!(match_expression("Kel'thas"), Some(String::from("\"Kel'thas\"*")));
+        assert_eq!(match_expression(" *-() "), None);
+    }
+
+    #[tokio::test]
+    async fn search_follows_edits_and_privacy() {
+        let database_conn_pool = open_test_database().await;
+        let hale = insert_test_character(&database_conn_pool, 1, "Hale").await;
+        let mira = insert_test_character(&database_conn_pool, 2, "Mira").await;
+
+        let mut details = test_character("Hale").details;
+        details.extras = String::from("Carries a cursed sword");
+        characters::update_character(&database_conn_pool, hale, details).await.expect("Update should succeed");
+
+        let expression = match_expression("curse sword").expect("Query has terms");
+        let results = search_characters(&database_conn_pool, &expression, 2, 10, 0).await.expect("Search should succeed");
+        assert_eq!(results.iter().map(|result| result.character_id).collect::<Vec<_>>(), vec![hale]);
+        assert!(results[0].snippet.contains("**cursed**"));
+
+        // Names outrank the other columns
+        let mut details = test_character("Mira Swordsworn").details;
+        details.extras = String::from("A sword for hire");
+        characters::update_character(&database_conn_pool, mira, details).await.expect("Update should succeed");
+        let expression = match_expression("sword").expect("Query has terms");
+        let results = search_characters(&database_conn_pool, &expression, 3, 10, 0).await.expect("Search should succeed");
+        assert_eq!(results.iter().map(|result| result.character_id).collect::<Vec<_>>(), vec![mira, hale]);
+
+        // Hidden and unapproved characters are only found by their owner
+        let mut preferences = discord_users::get_by_user_id(&database_conn_pool, 1)
+            .await
+            .expect("Select should succeed")
+            .expect("Profile exists")
+            .preferences;
+        preferences.characters_public = false;
+        discord_users::set_preferences(&database_conn_pool, 1, &preferences).await.expect("Update should succeed");
+        characters::transition_status(&database_conn_pool, mira, CharacterStatus::Approved, CharacterStatus::Pending, None).await.expect("Update should succeed");
+
+        assert_eq!(count_search_results(&database_conn_pool, &expression, 3).await.expect("Count should succeed"), 0);
+        assert_eq!(count_search_results(&database_conn_pool, &expression, 1).await.expect("Count should succeed"), 1);
+        assert_eq!(count_search_results(&database_conn_pool, &expression, 2).await.expect("Count should succeed"), 1);
+
+        characters::remove_character(&database_conn_pool, hale).await.expect("Delete should succeed");
+        assert_eq!(count_search_results(&database_conn_pool, &expression, 1).await.expect("Count should succeed"), 0);
+    }
+
+    #[tokio::test]
+    async fn directory_filters_visible_characters() {
+        let database_conn_pool = open_test_database().await;
+        let hale = insert_test_character(&database_conn_pool, 1, "Hale").await;
+        let mira = insert_test_character(&database_conn_pool, 2, "Mira").await;
+        let orin = insert_test_character(&database_conn_pool, 2, "Orin").await;
+        let vess = insert_test_character(&database_conn_pool, 4, "Vess").await;
+        for (guild_id, user_id) in [(100, 1), (100, 2), (100, 3), (200, 3), (200, 4)] {
+            discord_users::add_guild_member(&database_conn_pool, guild_id, user_id).await.expect("Insert should succeed");
+        }
+
+        let mut details = test_character("Orin").details;
+        details.species = String::from("Dwarf");
+        characters::update_character(&database_conn_pool, orin, details).await.expect("Update should succeed");
+        sqlx::query("UPDATE SelectedCharacterClasses SET pk_fk_classID = $2 WHERE pk_fk_characterID = $1;")
+            .bind(mira as i64)
+            .bind(CharacterClass::MARTIAL_ID as i64)
+            .execute(&database_conn_pool)
+            .await
+            .expect("Update should succeed");
+        characters::transition_status(&database_conn_pool, hale, CharacterStatus::Approved, CharacterStatus::Pending, None).await.expect("Update should succeed");
+
+        let page_ids = |page: Vec<Character>| page.into_iter().map(|character| character.id).collect::<Vec<_>>();
+
+        // Unapproved characters are only listed for their owner
+        let everyone = DirectoryFilters::default();
+        assert_eq!(page_ids(get_directory_page(&database_conn_pool, 3, 100, &everyone, 10, 0).await.expect("Select should succeed")), vec![mira, orin]);
+        assert_eq!(page_ids(get_directory_page(&database_conn_pool, 1, 100, &everyone, 1, 1).await.expect("Select should succeed")), vec![mira]);
+        assert_eq!(count_directory(&database_conn_pool, 1, 100, &everyone).await.expect("Count should succeed"), 3);
+
+        let martial = DirectoryFilters { class_id: Some(CharacterClass::MARTIAL_ID), ..Default::default() };
+        assert_eq!(page_ids(get_directory_page(&database_conn_pool, 3, 100, &martial, 10, 0).await.expect("Select should succeed")), vec![mira]);
+        let dwarves = DirectoryFilters { species: Some(String::from("dwarf")), owner_id: Some(2), ..Default::default() };
+        assert_eq!(page_ids(get_directory_page(&database_conn_pool, 3, 100, &dwarves, 10, 0).await.expect("Select should succeed")), vec![orin]);
+
+        assert_eq!(get_visible_species(&database_conn_pool, 3, 100, "", 10).await.expect("Select should succeed"), vec!["Dwarf", "Elf"]);
+        assert!(get_visible_character(&database_conn_pool, 3, 100, hale).await.expect("Select should succeed").is_none());
+        assert!(get_visible_character(&database_conn_pool, 1, 100, hale).await.expect("Select should succeed").is_some());
+
+        // Characters of another server's members stay within that server
+        assert!(get_visible_character(&database_conn_pool, 3, 100, vess).await.expect("Select should succeed").is_none());
+        assert_eq!(page_ids(get_directory_page(&database_conn_pool, 3, 200, &everyone, 10, 0).await.expect("Select should succeed")), vec![vess]);
+    }
+}
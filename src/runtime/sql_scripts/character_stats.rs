@@ -0,0 +1,143 @@
+use sqlx::{
+    sqlite::{
+        SqliteConnection, SqlitePool, SqliteQueryResult
+    },
+    Error
+};
+
+use crate::runtime::sql_scripts::models::CharacterStats;
+
+/// One of the columns of `CharacterStats`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Stat {
+    Strength,
+    Dexterity,
+    Perception,
+    Knowledge,
+    Constitution,
+    Casting
+}
+
+impl Stat {
+    pub const ALL: [Stat; 6] = [
+        Stat::Strength, Stat::Dexterity, Stat::Perception, Stat::Knowledge, Stat::Constitution, Stat::Casting
+    ];
+
+    /// The name of the stat's column, which is also how users refer to it
+    pub fn column(self) -> &'static str {
+        match self {
+            Stat::Strength     => "strength",
+            Stat::Dexterity    => "dexterity",
+            Stat::Perception   => "perception",
+            Stat::Knowledge    => "knowledge",
+            Stat::Constitution => "constitution",
+            Stat::Casting      => "casting"
+        }
+    }
+
+    pub fn from_column(column: &str) -> Option<Stat> {
+        Stat::ALL.into_iter().find(|stat| stat.column() == column)
+    }
+}
+
+#[allow(dead_code)]
+pub async fn get_stats(database_conn_pool: &SqlitePool, character_id: u64) -> Result<Option<CharacterStats>, Error> {
+    sqlx::query_as("SELECT * FROM CharacterStats WHERE pk_fk_characterID = $1;")
+        .bind(character_id as i64)
+        .fetch_optional(database_conn_pool)
+        .await
+}
+
+/// A character's stats with their species' modifiers added, which is what is shown, rolled with,
+/// and what hit points and carry limits go by. `None` if they haven't got any stats yet
+pub async fn fetch_effective_stats(database_conn: &mut SqliteConnection, character_id: u64) -> Result<Option<CharacterStats>, Error> {
+    sqlx::query_as(
+        "SELECT \
+            CharacterStats.pk_fk_characterID, \
+            CharacterStats.strength     + COALESCE(Species.strength, 0)     AS strength, \
+            CharacterStats.dexterity    + COALESCE(Species.dexterity, 0)    AS dexterity, \
+            CharacterStats.perception   + COALESCE(Species.perception, 0)   AS perception, \
+            CharacterStats.knowledge    + COALESCE(Species.knowledge, 0)    AS knowledge, \
+            CharacterStats.constitution + COALESCE(Species.constitution, 0) AS constitution, \
+            CharacterStats.casting      + COALESCE(Species.casting, 0)      AS casting \
+        FROM CharacterStats \
+        JOIN Characters ON Characters.pk_characterID = CharacterStats.pk_fk_characterID \
+        LEFT JOIN Species ON Species.pk_speciesID = Characters.fk_speciesID \
+        WHERE CharacterStats.pk_fk_characterID = $1;"
+    )
+        .bind(character_id as i64)
+        .fetch_optional(&mut *database_conn)
+        .await
+}
+
+pub async fn get_effective_stats(database_conn_pool: &SqlitePool, character_id: u64) -> Result<Option<CharacterStats>, Error> {
+    fetch_effective_stats(&mut *database_conn_pool.acquire().await?, character_id).await
+}
+
+/// Inserts the stats of a character, overwriting them should they already exist
+#[allow(dead_code)]
+pub async fn set_stats(database_conn_pool: &SqlitePool, stats: &CharacterStats) -> Result<SqliteQueryResult, Error> {
+    sqlx::query(
+        "INSERT INTO CharacterStats VALUES ( $1, $2, $3, $4, $5, $6, $7 ) \
+        ON CONFLICT (pk_fk_characterID) DO UPDATE SET \
+            strength = excluded.strength, \
+            dexterity = excluded.dexterity, \
+            perception = excluded.perception, \
+            knowledge = excluded.knowledge, \
+            constitution = excluded.constitution, \
+            casting = excluded.casting;"
+    )
+        .bind(stats.character_id as i64)
+        .bind(stats.strength)
+        .bind(stats.dexterity)
+        .bind(stats.perception)
+        .bind(stats.knowledge)
+        .bind(stats.constitution)
+        .bind(stats.casting)
+        .execute(database_conn_pool)
+        .await
+}
+
+#[allow(dead_code)]
+pub async fn remove_stats(database_conn_pool: &SqlitePool, character_id: u64) -> Result<SqliteQueryResult, Error> {
+    sqlx::query("DELETE FROM CharacterStats WHERE pk_fk_characterID = $1;")
+        .bind(character_id as i64)
+        .execute(database_conn_pool)
+        .await
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::sql_scripts::{insert_test_character, open_test_database};
+
+    #[tokio::test]
+    async fn character_stats_crud() {
+        let database_conn_pool = open_test_database().await;
+        let character_id = insert_test_character(&database_conn_pool, 1, "Brynn").await;
+
+        let mut stats = CharacterStats {
+            character_id,
+            strength: 3,
+            dexterity: 4,
+            perception: 2,
+            knowledge: 1,
+            constitution: 5,
+            casting: 0
+        };
+        set_stats(&database_conn_pool, &stats).await.expect("Insert should succeed");
+
+        stats.casting = 2;
+        set_stats(&database_conn_pool, &stats).await.expect("Upsert should succeed");
+
+        let read_back = get_stats(&database_conn_pool, character_id)
+            .await
+            .expect("Select should succeed");
+        assert_eq!(read_back, Some(stats));
+
+        remove_stats(&database_conn_pool, character_id).await.expect("Delete should succeed");
+        assert!(get_stats(&database_conn_pool, character_id).await.expect("Select should succeed").is_none());
+    }
+}
@@ -0,0 +1,179 @@
+use sqlx::{
+    sqlite::{
+        SqlitePool, SqliteQueryResult
+    },
+    Error
+};
+
+use crate::runtime::sql_scripts::models::{
+    CharacterTransfer, DiscordId
+};
+
+/// How accepting a character transfer went
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AcceptOutcome {
+    /// The character now belongs to the recipient. Holds the previous owner's ID
+    Transferred(u64),
+    /// The recipient already has a character of the same name, nothing was changed
+    NameTaken,
+    /// The offer lapsed before it was accepted, and was removed
+    Expired,
+    /// The offer was declined, cancelled or already accepted, or the character changed hands since
+    Missing
+}
+
+// --== TRANSFERS ==-- //
+
+    /// Offers a character to another user until `expires_at`. Lapsed offers of the same character
+    /// are cleared first, an offer that is still open makes this fail on its unique constraint
+    pub async fn add_transfer(database_conn_pool: &SqlitePool, character_id: u64, from_user: u64, to_user: u64, expires_at: i64) -> Result<u64, Error> {
+        let mut transaction = database_conn_pool.begin().await?;
+
+        sqlx::query("DELETE FROM CharacterTransfers WHERE fk_characterID = $1 AND expiresAt <= unixepoch();")
+            .bind(character_id as i64)
+            .execute(&mut *transaction)
+            .await?;
+
+        let transfer_id = sqlx::query_scalar(
+            "INSERT INTO CharacterTransfers (fk_characterID, fromDiscordID, toDiscordID, createdAt, expiresAt) \
+            VALUES ($1, $2, $3, unixepoch(), $4) \
+            RETURNING pk_transferID;"
+        )
+            .bind(character_id as i64)
+            .bind(DiscordId(from_user))
+            .bind(DiscordId(to_user))
+            .bind(expires_at)
+            .fetch_one(&mut *transaction)
+            .await?;
+
+        transaction.commit().await?;
+        Ok(transfer_id)
+    }
+
+    pub async fn get_transfer(database_conn_pool: &SqlitePool, transfer_id: u64) -> Result<Option<CharacterTransfer>, Error> {
+        sqlx::query_as("SELECT * FROM CharacterTransfers WHERE pk_transferID = $1;")
+            .bind(transfer_id as i64)
+            .fetch_optional(database_conn_pool)
+            .await
+    }
+
+    pub async fn remove_transfer(database_conn_pool: &SqlitePool, transfer_id: u64) -> Result<SqliteQueryResult, Error> {
+        sqlx::query("DELETE FROM CharacterTransfers WHERE pk_transferID = $1;")
+            .bind(transfer_id as i64)
+            .execute(database_conn_pool)
+            .await
+    }
+
+    /// Removes every offer that has lapsed, returning how many there were
+    pub async fn remove_expired_transfers(database_conn_pool: &SqlitePool) -> Result<u64, Error> {
+        let query_result = sqlx::query("DELETE FROM CharacterTransfers WHERE expiresAt <= unixepoch();")
+            .execute(database_conn_pool)
+            .await?;
+
+        Ok(query_result.rows_affected())
+    }
+
+    /// Hands the character over to the recipient, clearing it as the previous owner's selected
+    /// character. Both happen in one transaction along with closing the offer, so it can only be
+    /// accepted once
+    pub async fn accept_transfer(database_conn_pool: &SqlitePool, transfer_id: u64) -> Result<AcceptOutcome, Error> {
+        let mut transaction = database_conn_pool.begin().await?;
+
+        let transfer: Option<CharacterTransfer> = sqlx::query_as("DELETE FROM CharacterTransfers WHERE pk_transferID = $1 RETURNING *;")
+            .bind(transfer_id as i64)
+            .fetch_optional(&mut *transaction)
+            .await?;
+
+        let Some(transfer) = transfer else {
+            return Ok(AcceptOutcome::Missing);
+        };
+        if transfer.expires_at <= chrono::Utc::now().timestamp() {
+            transaction.commit().await?;
+            return Ok(AcceptOutcome::Expired);
+        }
+
+        // The offer was made by whoever owned the character then, should it have changed hands
+        // since the offer no longer stands
+        let ownership_change = sqlx::query("UPDATE Characters SET ownerDiscordID = $3 WHERE pk_characterID = $1 AND ownerDiscordID = $2;")
+            .bind(transfer.character_id as i64)
+            .bind(transfer.from_user)
+            .bind(transfer.to_user)
+            .execute(&mut *transaction)
+            .await;
+
+        match ownership_change {
+            Ok(query_result) if query_result.rows_affected() == 0 => {
+                transaction.commit().await?;
+                return Ok(AcceptOutcome::Missing);
+            },
+            Ok(_) => {},
+            // Leaves the offer open, the recipient may rename their own character and try again
+            Err(query_err) if query_err.as_database_error().is_some_and(|db_err| db_err.is_unique_violation()) => {
+                return Ok(AcceptOutcome::NameTaken);
+            },
+            Err(query_err) => return Err(query_err)
+        }
+
+        sqlx::query("UPDATE DiscordUsers SET fk_selectedCharacter = NULL WHERE pk_discordID = $1 AND fk_selectedCharacter = $2;")
+            .bind(transfer.from_user)
+            .bind(transfer.character_id as i64)
+            .execute(&mut *transaction)
+            .await?;
+
+        transaction.commit().await?;
+        Ok(AcceptOutcome::Transferred(transfer.from_user.get()))
+    }
+// ==--
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::sql_scripts::{characters, discord_users, insert_test_character, open_test_database};
+
+    #[tokio::test]
+    async fn accepted_transfers_change_owner() {
+        let database_conn_pool = open_test_database().await;
+        let hale = insert_test_character(&database_conn_pool, 1, "Hale").await;
+        insert_test_character(&database_conn_pool, 2, "Hale").await;
+        discord_users::set_selected_character(&database_conn_pool, 1, Some(hale)).await.expect("Update should succeed");
+        let in_a_day = chrono::Utc::now().timestamp() + 86_400;
+
+        let transfer_id = add_transfer(&database_conn_pool, hale, 1, 2, in_a_day).await.expect("Insert should succeed");
+        assert!(add_transfer(&database_conn_pool, hale, 1, 2, in_a_day).await.is_err(), "Only one offer may be open per character");
+
+        // The recipient already has a Hale of their own
+        assert_eq!(accept_transfer(&database_conn_pool, transfer_id).await.expect("Accept should succeed"), AcceptOutcome::NameTaken);
+        sqlx::query("UPDATE Characters SET name = 'Hale the Elder' WHERE ownerDiscordID = 2;")
+            .execute(&database_conn_pool)
+            .await
+            .expect("Update should succeed");
+
+        assert_eq!(accept_transfer(&database_conn_pool, transfer_id).await.expect("Accept should succeed"), AcceptOutcome::Transferred(1));
+        assert_eq!(accept_transfer(&database_conn_pool, transfer_id).await.expect("Accept should succeed"), AcceptOutcome::Missing);
+
+        let character = characters::get_character(&database_conn_pool, hale).await.expect("Select should succeed").expect("Character exists");
+        assert_eq!(character.owner, Some(DiscordId(2)));
+        let previous_owner = discord_users::get_by_user_id(&database_conn_pool, 1).await.expect("Select should succeed").expect("Profile exists");
+        assert_eq!(previous_owner.selected_character, None);
+    }
+
+    #[tokio::test]
+    async fn lapsed_transfers_expire() {
+        let database_conn_pool = open_test_database().await;
+        let hale = insert_test_character(&database_conn_pool, 1, "Hale").await;
+        insert_test_character(&database_conn_pool, 2, "Mira").await;
+        let an_hour_ago = chrono::Utc::now().timestamp() - 3_600;
+
+        let transfer_id = add_transfer(&database_conn_pool, hale, 1, 2, an_hour_ago).await.expect("Insert should succeed");
+        assert_eq!(accept_transfer(&database_conn_pool, transfer_id).await.expect("Accept should succeed"), AcceptOutcome::Expired);
+        assert!(get_transfer(&database_conn_pool, transfer_id).await.expect("Select should succeed").is_none());
+
+        // A lapsed offer doesn't stand in the way of a new one
+        add_transfer(&database_conn_pool, hale, 1, 2, an_hour_ago).await.expect("Insert should succeed");
+        add_transfer(&database_conn_pool, hale, 1, 2, an_hour_ago).await.expect("Insert should succeed");
+        assert_eq!(remove_expired_transfers(&database_conn_pool).await.expect("Delete should succeed"), 1);
+        assert_eq!(characters::get_character(&database_conn_pool, hale).await.expect("Select should succeed").expect("Character exists").owner, Some(DiscordId(1)));
+    }
+}
@@ -1,52 +1,46 @@
+
 use std::collections::HashMap;
 
 use sqlx::{
-    sqlite::{SqlitePool, SqliteRow}, Error, Row
+    sqlite::{SqlitePool, SqliteQueryResult}, Error
 };
 
-#[derive(Clone)]
-pub enum CharacterClass {
-    Martial,
-    HalfCaster,
-    Caster
-}
-impl Into<u8> for CharacterClass {
-    fn into(self) -> u8 {
-        match self {
-            Self::Martial    => 1,
-            Self::HalfCaster => 2,
-            Self::Caster     => 3
-        }
-    }
-}
-#[derive(Clone)]
-pub struct Character {
-    pub name: String,
-    species: String,
-    alignment: String,
-    likes: String,
-    dislike: String,
-    motivations: String,
-    companions: String,
-    backstory: String,
-    appearance: String,
-    extras: String,
-    class: CharacterClass
+use crate::runtime::sql_scripts::models::{
+    Character, CharacterDetails, CharacterIdentifier, CharacterStatus, DiscordId
+};
+
+/// The wizard answer ids that have a column of their own in `Characters`
+pub const SHEET_FIELDS: [&str; 10] = [
+    "name",
+    "species",
+    "alignment",
+    "likes",
+    "dislikes",
+    "motivations",
+    "companions",
+    "backstory",
+    "appearance",
+    "extras"
+];
+
+/// A character as collected by the creation wizard, before it is given an ID
+#[derive(Clone, Debug)]
+pub struct NewCharacter {
+    pub details: CharacterDetails,
+    pub class_id: u64,
+    /// `Pending` when staff have to approve the character before it can be used
+    pub status: CharacterStatus,
+    /// Answers kept in `CharacterCustomFields`, as (field key, value)
+    pub custom_fields: Vec<(String, String)>
 }
-impl Character {
-    pub fn from_hashmap_cache(data_in: &HashMap<String, String>) -> Result<Character, String> {
+impl NewCharacter {
+    /// Builds the character from the wizard's answers. Only the name and class have to be
+    /// answered, sheet fields the wizard doesn't ask are left blank. Answers to `custom_field_ids`
+    /// become custom fields
+    pub fn from_hashmap_cache(data_in: &HashMap<String, String>, custom_field_ids: &[&str]) -> Result<NewCharacter, String> {
 
         let fields = [
             "name",
-            "species",
-            "alignment",
-            "likes",
-            "dislikes",
-            "motivations",
-            "companions",
-            "backstory",
-            "appearance",
-            "extra",
             "class"
         ];
 
@@ -68,79 +62,151 @@ impl Character {
             return Err(error_message);
         }
 
-        Ok(Character {
-            name: data_in["name"].clone(),
-            species: data_in["species"].clone(),
-            alignment: data_in["alignment"].clone(),
-            likes: data_in["likes"].clone(),
-            dislike: data_in["dislikes"].clone(),
-            motivations: data_in["motivations"].clone(),
-            companions: data_in["companions"].clone(),
-            backstory: data_in["backstory"].clone(),
-            appearance: data_in["appearance"].clone(),
-            extras: data_in["extra"].clone(),
-            class: match data_in["class"].as_str() {
-                "martial" => CharacterClass::Martial,
-                "half-caster" => CharacterClass::HalfCaster,
-                "caster" => CharacterClass::Caster,
-                other_class => return Err(String::from( format!("Invalid Class Recived: `{other_class}`") ))
-            }
+        let sheet_field = |field: &str| data_in.get(field).cloned().unwrap_or_default();
+
+        Ok(NewCharacter {
+            details: CharacterDetails {
+                name: data_in["name"].clone(),
+                species: sheet_field("species"),
+                alignment: sheet_field("alignment"),
+                likes: sheet_field("likes"),
+                dislikes: sheet_field("dislikes"),
+                motivations: sheet_field("motivations"),
+                companions: sheet_field("companions"),
+                backstory: sheet_field("backstory"),
+                appearance: sheet_field("appearance"),
+                extras: sheet_field("extras")
+            },
+            class_id: match data_in["class"].parse::<u64>() {
+                Ok(class_id) => class_id,
+                Err(_) => return Err(format!("Invalid Class Recived: `{}`", data_in["class"]))
+            },
+            status: CharacterStatus::Approved,
+            // Optional questions left blank aren't worth keeping
+            custom_fields: custom_field_ids.iter()
+                .filter_map(|field_key| data_in.get(*field_key).map(|value| (field_key.to_string(), value.trim().to_string())))
+                .filter(|(_, value)| !value.is_empty())
+                .collect()
         })
     }
 }
 
-pub async fn insert_character(database_conn_pool: &SqlitePool, user_id: u64, character_in: Character) -> Result<(), Error> {
-    let mut transaction = database_conn_pool.begin().await?;
+/// Inserts a character along with its class and custom fields, returning the ID SQLite assigned to
+/// it.
+///
+/// The ID is read back with `RETURNING` inside the same transaction, so callers can update their
+/// caches with it without racing against other inserts
+pub async fn insert_character(database_conn_pool: &SqlitePool, user_id: u64, character_in: NewCharacter) -> Result<u64, Error> {
+    // Taking the write lock up front makes concurrent inserts wait their turn, rather than fail
+    // when another commits between this transaction's first read and its first write
+    let mut transaction = database_conn_pool.begin_with("BEGIN IMMEDIATE").await?;
+    let details = character_in.details;
 
 
-    sqlx::query(
-        "INSERT INTO Characters VALUES ( \
-            (
-                SELECT IFNULL(MAX(pk_characterID), 0) + 1 FROM Characters
-            ), \
-            $1, \
-            $2, $3, $4, $5, $6, $7, $8, $9, $10, $11 \
-        );"
+    let character_id: u64 = sqlx::query_scalar(
+        "INSERT INTO Characters ( \
+            ownerDiscordID, \
+            name, species, alignment, likes, dislikes, motivations, companions, backstory, appearance, extras, \
+            status, fk_speciesID \
+        ) \
+        VALUES ( $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, (SELECT pk_speciesID FROM Species WHERE name = $3) ) \
+        RETURNING pk_characterID;"
     )
-        .bind(user_id as i64)
-        .bind(character_in.name)
-        .bind(character_in.species)
-        .bind(character_in.alignment)
-        .bind(character_in.likes)
-        .bind(character_in.dislike)
-        .bind(character_in.motivations)
-        .bind(character_in.companions)
-        .bind(character_in.backstory)
-        .bind(character_in.appearance)
-        .bind(character_in.extras)
-        .execute(&mut *transaction)
+        .bind(DiscordId(user_id))
+        .bind(details.name)
+        .bind(details.species)
+        .bind(details.alignment)
+        .bind(details.likes)
+        .bind(details.dislikes)
+        .bind(details.motivations)
+        .bind(details.companions)
+        .bind(details.backstory)
+        .bind(details.appearance)
+        .bind(details.extras)
+        .bind(character_in.status)
+        .fetch_one(&mut *transaction)
         .await?;
 
 
-    let character_class_id: u8 = character_in.class.into();
+    sqlx::query(
+        "INSERT INTO SelectedCharacterClasses (pk_fk_characterID, pk_fk_classID, isPrimary) VALUES ( $1, $2, TRUE );"
+    )
+        .bind(character_id as i64)
+        .bind(character_in.class_id as i64)
+        .execute(&mut *transaction)
+        .await?;
 
-    // Because this is ran in a transaction, we know that the character with the largest ID, must
-    // be the one we just inserted
+    // Characters start out with their class's stat points to spend
     sqlx::query(
-        "INSERT INTO SelectedCharacterClasses VALUES ( \
-            (SELECT MAX(pk_characterID) FROM Characters),
-            $1
-        );"
+        "UPDATE Characters SET unspentStatPoints = (SELECT statBudget FROM CharacterClasses WHERE pk_classID = $2) \
+        WHERE pk_characterID = $1;"
     )
-        .bind(character_class_id)
+        .bind(character_id as i64)
+        .bind(character_in.class_id as i64)
         .execute(&mut *transaction)
         .await?;
 
-    transaction.commit().await
+    for (field_key, value) in character_in.custom_fields {
+        sqlx::query("INSERT INTO CharacterCustomFields (fk_characterID, fieldKey, value) VALUES ( $1, $2, $3 );")
+            .bind(character_id as i64)
+            .bind(field_key)
+            .bind(value)
+            .execute(&mut *transaction)
+            .await?;
+    }
+
+    transaction.commit().await?;
+
+    Ok(character_id)
 }
 
 
 
-pub async fn get_character_identifiers( database_conn_pool: &SqlitePool ) -> Result<Vec<SqliteRow>, Error> {
-    sqlx::query(
+pub async fn get_character( database_conn_pool: &SqlitePool, character_id: u64 ) -> Result<Option<Character>, Error> {
+    sqlx::query_as("SELECT * FROM Characters WHERE pk_characterID = $1;")
+        .bind(character_id as i64)
+        .fetch_optional(database_conn_pool)
+        .await
+}
+
+
+
+/// The character's custom fields, as (field key, value)
+pub async fn get_custom_fields( database_conn_pool: &SqlitePool, character_id: u64 ) -> Result<Vec<(String, String)>, Error> {
+    sqlx::query_as("SELECT fieldKey, value FROM CharacterCustomFields WHERE fk_characterID = $1;")
+        .bind(character_id as i64)
+        .fetch_all(database_conn_pool)
+        .await
+}
+
+
+
+pub async fn get_characters_by_owner( database_conn_pool: &SqlitePool, user_id: u64 ) -> Result<Vec<Character>, Error> {
+    sqlx::query_as("SELECT * FROM Characters WHERE ownerDiscordID = $1 ORDER BY pk_characterID;")
+        .bind(DiscordId(user_id))
+        .fetch_all(database_conn_pool)
+        .await
+}
+
+
+
+/// Whether the user already has a character by the name, regardless of case
+pub async fn is_name_taken( database_conn_pool: &SqlitePool, user_id: u64, name: &str ) -> Result<bool, Error> {
+    sqlx::query_scalar("SELECT EXISTS ( SELECT 1 FROM Characters WHERE ownerDiscordID = $1 AND name = $2 COLLATE NOCASE );")
+        .bind(DiscordId(user_id))
+        .bind(name)
+        .fetch_one(database_conn_pool)
+        .await
+}
+
+
+
+pub async fn get_character_identifiers( database_conn_pool: &SqlitePool ) -> Result<Vec<CharacterIdentifier>, Error> {
+    sqlx::query_as(
         "\
         SELECT ownerDiscordID, pk_characterID, name
-        FROM Characters;
+        FROM Characters
+        WHERE ownerDiscordID IS NOT NULL;
         "
     )
         .fetch_all(database_conn_pool)
@@ -148,15 +214,233 @@ pub async fn get_character_identifiers( database_conn_pool: &SqlitePool ) -> Res
 }
 
 
-pub async fn get_largest_character_id( database_conn_pool: &SqlitePool ) -> Result<u64, Error> {
-    let largest_id: u64 = sqlx::query("\
-        SELECT MAX(pk_characterID)
-        FROM Characters;
-    ")
-        .fetch_one(database_conn_pool)
-        .await?
-        .get(0);
 
-    Ok(largest_id)
+#[allow(dead_code)]
+pub async fn update_character( database_conn_pool: &SqlitePool, character_id: u64, details: CharacterDetails ) -> Result<SqliteQueryResult, Error> {
+    sqlx::query(
+        "UPDATE Characters SET \
+            name = $2, species = $3, alignment = $4, likes = $5, dislikes = $6, \
+            motivations = $7, companions = $8, backstory = $9, appearance = $10, extras = $11, \
+            fk_speciesID = (SELECT pk_speciesID FROM Species WHERE name = $3) \
+        WHERE pk_characterID = $1;"
+    )
+        .bind(character_id as i64)
+        .bind(details.name)
+        .bind(details.species)
+        .bind(details.alignment)
+        .bind(details.likes)
+        .bind(details.dislikes)
+        .bind(details.motivations)
+        .bind(details.companions)
+        .bind(details.backstory)
+        .bind(details.appearance)
+        .bind(details.extras)
+        .execute(database_conn_pool)
+        .await
+}
+
+
+
+/// Moves a character from one review status to another, recording the reviewer's note. Returns
+/// whether it was still in status `from`, so two reviewers can't both decide on the same character
+pub async fn transition_status( database_conn_pool: &SqlitePool, character_id: u64, from: CharacterStatus, to: CharacterStatus, review_note: Option<&str> ) -> Result<bool, Error> {
+    let transition_result = sqlx::query("UPDATE Characters SET status = $3, reviewNote = $4 WHERE pk_characterID = $1 AND status = $2;")
+        .bind(character_id as i64)
+        .bind(from)
+        .bind(to)
+        .bind(review_note)
+        .execute(database_conn_pool)
+        .await?;
+
+    Ok(transition_result.rows_affected() == 1)
 }
 
+
+
+/// Removes a character, every row that refers to it is removed or cleared along with it
+#[allow(dead_code)]
+pub async fn remove_character( database_conn_pool: &SqlitePool, character_id: u64 ) -> Result<SqliteQueryResult, Error> {
+    sqlx::query("DELETE FROM Characters WHERE pk_characterID = $1;")
+        .bind(character_id as i64)
+        .execute(database_conn_pool)
+        .await
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::{
+        context_keys,
+        sql_scripts::{
+            discord_users, insert_test_character, open_test_database, open_test_database_file,
+            remove_test_database_file, test_character
+        }
+    };
+    use std::sync::Arc;
+    use tokio::sync::RwLock;
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn parallel_inserts_keep_cache_and_database_in_agreement() {
+        // A database file with several connections, so the inserts truly run side by side
+        let (database_conn_pool, database_path) = open_test_database_file("parallel-inserts").await;
+        let user_characters = Arc::new(RwLock::new(HashMap::new()));
+        for owner_id in 1000..1004 {
+            discord_users::add_user(&database_conn_pool, owner_id).await.expect("Insert should succeed");
+        }
+
+        let mut insert_tasks = vec![];
+        for task_idx in 0..64u64 {
+            let database_conn_pool = database_conn_pool.clone();
+            let user_characters = Arc::clone(&user_characters);
+
+            insert_tasks.push(tokio::spawn(async move {
+                let owner_id = 1000 + task_idx % 4;
+                let character_name = format!("Character {task_idx}");
+
+                let character_id = insert_character(&database_conn_pool, owner_id, test_character(&character_name))
+                    .await
+                    .expect("Insert should succeed");
+
+                // Yield between the insert and the cache update to give other tasks the chance
+                // to interleave, as they would between two Discord interactions
+                tokio::task::yield_now().await;
+
+                context_keys::cache_user_character(
+                    &mut *user_characters.write().await,
+                    owner_id,
+                    character_id,
+                    character_name
+                );
+            }));
+        }
+        for task in insert_tasks {
+            task.await.expect("Insert task should not panic");
+        }
+
+        let mut database_characters: HashMap<u64, HashMap<u64, String>> = HashMap::new();
+        for identifier in get_character_identifiers(&database_conn_pool).await.expect("Select should succeed") {
+            context_keys::cache_user_character(&mut database_characters, identifier.owner.get(), identifier.id, identifier.name);
+        }
+
+        assert_eq!(database_characters.values().map(HashMap::len).sum::<usize>(), 64);
+        assert_eq!(*user_characters.read().await, database_characters);
+
+        let class_rows: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM SelectedCharacterClasses;")
+            .fetch_one(&database_conn_pool)
+            .await
+            .expect("Count should succeed");
+        assert_eq!(class_rows, 64);
+
+        database_conn_pool.close().await;
+        remove_test_database_file(&database_path);
+    }
+
+    #[tokio::test]
+    async fn character_crud() {
+        let database_conn_pool = open_test_database().await;
+
+        let character_id = insert_test_character(&database_conn_pool, 42, "Aldric").await;
+
+        let character = get_character(&database_conn_pool, character_id)
+            .await
+            .expect("Select should succeed")
+            .expect("Character was just inserted");
+        assert_eq!(character.owner, Some(DiscordId(42)));
+        assert_eq!(character.name, "Aldric");
+        assert_eq!(character.dislikes, "Rain");
+
+        let mut details = test_character("Aldric the Bold").details;
+        details.extras = "A cursed sword".to_string();
+        update_character(&database_conn_pool, character_id, details)
+            .await
+            .expect("Update should succeed");
+
+        let owned_characters = get_characters_by_owner(&database_conn_pool, 42)
+            .await
+            .expect("Select should succeed");
+        assert_eq!(owned_characters.len(), 1);
+        assert_eq!(owned_characters[0].name, "Aldric the Bold");
+        assert_eq!(owned_characters[0].extras, "A cursed sword");
+
+        assert!(is_name_taken(&database_conn_pool, 42, "aldric THE bold").await.expect("Select should succeed"));
+        assert!(!is_name_taken(&database_conn_pool, 42, "Aldric").await.expect("Select should succeed"));
+        assert!(!is_name_taken(&database_conn_pool, 43, "Aldric the Bold").await.expect("Select should succeed"));
+
+        remove_character(&database_conn_pool, character_id)
+            .await
+            .expect("Delete should succeed");
+        assert!(get_character(&database_conn_pool, character_id).await.expect("Select should succeed").is_none());
+
+        let class_rows: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM SelectedCharacterClasses;")
+            .fetch_one(&database_conn_pool)
+            .await
+            .expect("Count should succeed");
+        assert_eq!(class_rows, 0);
+    }
+
+    #[tokio::test]
+    async fn pending_characters_wait_for_review() {
+        let database_conn_pool = open_test_database().await;
+        discord_users::add_user(&database_conn_pool, 42).await.expect("Insert should succeed");
+
+        let mut new_character = test_character("Aldric");
+        new_character.status = CharacterStatus::Pending;
+        let character_id = insert_character(&database_conn_pool, 42, new_character).await.expect("Insert should succeed");
+
+        let select_result = discord_users::set_selected_character(&database_conn_pool, 42, Some(character_id))
+            .await
+            .expect("Update should not error");
+        assert_eq!(select_result.rows_affected(), 0);
+
+        assert!(
+            transition_status(&database_conn_pool, character_id, CharacterStatus::Pending, CharacterStatus::ChangesRequested, Some("More backstory"))
+                .await
+                .expect("Update should succeed")
+        );
+        // A second reviewer acting on the same review is turned away
+        assert!(
+            !transition_status(&database_conn_pool, character_id, CharacterStatus::Pending, CharacterStatus::Approved, None)
+                .await
+                .expect("Update should succeed")
+        );
+
+        let character = get_character(&database_conn_pool, character_id)
+            .await
+            .expect("Select should succeed")
+            .expect("Character was just inserted");
+        assert_eq!(character.status, CharacterStatus::ChangesRequested);
+        assert_eq!(character.review_note.as_deref(), Some("More backstory"));
+
+        transition_status(&database_conn_pool, character_id, CharacterStatus::ChangesRequested, CharacterStatus::Pending, None).await.expect("Update should succeed");
+        transition_status(&database_conn_pool, character_id, CharacterStatus::Pending, CharacterStatus::Approved, None).await.expect("Update should succeed");
+        let select_result = discord_users::set_selected_character(&database_conn_pool, 42, Some(character_id))
+            .await
+            .expect("Update should succeed");
+        assert_eq!(select_result.rows_affected(), 1);
+    }
+
+    #[tokio::test]
+    async fn wizard_answers_keep_custom_fields() {
+        let database_conn_pool = open_test_database().await;
+        discord_users::add_user(&database_conn_pool, 42).await.expect("Insert should succeed");
+
+        let answers: HashMap<String, String> = [("name", "Aldric"), ("class", "1"), ("deity", " Sol "), ("oath", "")]
+            .into_iter()
+            .map(|(field_key, answer)| (field_key.to_string(), answer.to_string()))
+            .collect();
+        let new_character = NewCharacter::from_hashmap_cache(&answers, &["deity", "oath"]).expect("Name and class are answered");
+        assert_eq!(new_character.details.species, "", "Sheet fields that weren't asked are left blank");
+
+        let character_id = insert_character(&database_conn_pool, 42, new_character).await.expect("Insert should succeed");
+        assert_eq!(
+            get_custom_fields(&database_conn_pool, character_id).await.expect("Select should succeed"),
+            vec![(String::from("deity"), String::from("Sol"))]
+        );
+
+        let mut answers = answers;
+        answers.remove("class");
+        assert!(NewCharacter::from_hashmap_cache(&answers, &[]).is_err());
+    }
+}
@@ -5,25 +5,118 @@ use sqlx::{
     Error
 };
 
-pub async fn get_by_user_id(database_conn_pool: &SqlitePool, user_id: u64) -> Result<Option<sqlx::sqlite::SqliteRow>, Error> {
-    sqlx::query("SELECT * FROM DiscordUsers WHERE pk_discordID = $1;")
-        .bind(user_id as i64)
+use crate::runtime::sql_scripts::models::{
+    DiscordId, DiscordUser, UserPreferences
+};
+
+pub async fn get_by_user_id(database_conn_pool: &SqlitePool, user_id: u64) -> Result<Option<DiscordUser>, Error> {
+    sqlx::query_as("SELECT * FROM DiscordUsers WHERE pk_discordID = $1;")
+        .bind(DiscordId(user_id))
         .fetch_optional(database_conn_pool)
         .await
 }
 
 pub async fn add_user(database_conn_pool: &SqlitePool, user_id: u64) -> Result<SqliteQueryResult, Error> {
-    sqlx::query("INSERT INTO DiscordUsers VALUES ($1, NULL);")
-        .bind(user_id as i64)   // SQLite does not implement unsigned integers, but we can just
-                                // safely cast to i64 here
+    sqlx::query("INSERT INTO DiscordUsers (pk_discordID, registeredAt) VALUES ($1, unixepoch());")
+        .bind(DiscordId(user_id))
+        .execute(database_conn_pool)
+        .await
+}
+
+/// Selects one of the user's characters, or clears the selection. Only approved characters the user
+/// owns can be selected, no rows are affected otherwise
+pub async fn set_selected_character(database_conn_pool: &SqlitePool, user_id: u64, character_id: Option<u64>) -> Result<SqliteQueryResult, Error> {
+    sqlx::query(
+        "UPDATE DiscordUsers SET fk_selectedCharacter = $2 \
+        WHERE pk_discordID = $1 \
+        AND ( $2 IS NULL OR EXISTS (SELECT 1 FROM Characters WHERE pk_characterID = $2 AND ownerDiscordID = $1 AND status = 'approved') );"
+    )
+        .bind(DiscordId(user_id))
+        .bind(character_id.map(|id| id as i64))
+        .execute(database_conn_pool)
+        .await
+}
+
+pub async fn set_preferences(database_conn_pool: &SqlitePool, user_id: u64, preferences: &UserPreferences) -> Result<SqliteQueryResult, Error> {
+    sqlx::query(
+        "UPDATE DiscordUsers SET \
+            utcOffsetMinutes = $2, \
+            charactersPublic = $3, \
+            ephemeralWizard = $4 \
+        WHERE pk_discordID = $1;"
+    )
+        .bind(DiscordId(user_id))
+        .bind(preferences.utc_offset_minutes)
+        .bind(preferences.characters_public)
+        .bind(preferences.ephemeral_wizard)
+        .execute(database_conn_pool)
+        .await
+}
+
+/// Notes that a user is a member of a server, for listings that are kept to a server's members
+pub async fn add_guild_member(database_conn_pool: &SqlitePool, guild_id: u64, user_id: u64) -> Result<SqliteQueryResult, Error> {
+    sqlx::query("INSERT OR IGNORE INTO GuildMembers (pk_guildID, pk_discordID) VALUES ($1, $2);")
+        .bind(DiscordId(guild_id))
+        .bind(DiscordId(user_id))
         .execute(database_conn_pool)
         .await
 }
 
 pub async fn remove_user(database_conn_pool: &SqlitePool, user_id: u64) -> Result<SqliteQueryResult, Error> {
     sqlx::query("DELETE FROM DiscordUsers WHERE pk_discordID = $1;")
-        .bind(user_id as i64)
+        .bind(DiscordId(user_id))
         .execute(database_conn_pool)
         .await
 }
 
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::sql_scripts::{characters, open_test_database, test_character};
+
+    #[tokio::test]
+    async fn discord_user_crud() {
+        let database_conn_pool = open_test_database().await;
+        let user_id = 987_654_321_987_654_321;
+
+        assert!(get_by_user_id(&database_conn_pool, user_id).await.expect("Select should succeed").is_none());
+
+        add_user(&database_conn_pool, user_id).await.expect("Insert should succeed");
+        let character_id = characters::insert_character(&database_conn_pool, user_id, test_character("Eira"))
+            .await
+            .expect("Insert should succeed");
+        set_selected_character(&database_conn_pool, user_id, Some(character_id)).await.expect("Update should succeed");
+
+        // Nobody else may select the character
+        add_user(&database_conn_pool, 1).await.expect("Insert should succeed");
+        let selection = set_selected_character(&database_conn_pool, 1, Some(character_id)).await.expect("Update should succeed");
+        assert_eq!(selection.rows_affected(), 0);
+
+        let preferences = UserPreferences {
+            utc_offset_minutes: -150,
+            characters_public: false,
+            ephemeral_wizard: true
+        };
+        set_preferences(&database_conn_pool, user_id, &preferences).await.expect("Update should succeed");
+
+        let discord_user = get_by_user_id(&database_conn_pool, user_id)
+            .await
+            .expect("Select should succeed")
+            .expect("User was just inserted");
+        assert_eq!(discord_user.id, DiscordId(user_id));
+        assert_eq!(discord_user.selected_character, Some(character_id));
+        assert!(discord_user.registered_at.is_some());
+        assert_eq!(discord_user.preferences, preferences);
+
+        characters::remove_character(&database_conn_pool, character_id).await.expect("Delete should succeed");
+        assert_eq!(
+            get_by_user_id(&database_conn_pool, user_id).await.expect("Select should succeed").and_then(|user| user.selected_character),
+            None
+        );
+
+        remove_user(&database_conn_pool, user_id).await.expect("Delete should succeed");
+        assert!(get_by_user_id(&database_conn_pool, user_id).await.expect("Select should succeed").is_none());
+    }
+}
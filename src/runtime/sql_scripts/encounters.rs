@@ -0,0 +1,148 @@
+use sqlx::{
+    sqlite::{
+        SqlitePool, SqliteQueryResult
+    },
+    Error
+};
+
+use crate::runtime::sql_scripts::models::{
+    DiscordId, Encounter, EncounterEntry
+};
+
+// --== ENCOUNTERS ==-- //
+
+    pub async fn add_encounter(database_conn_pool: &SqlitePool, channel_id: u64, gm_id: u64) -> Result<u64, Error> {
+        sqlx::query_scalar(
+            "INSERT INTO Encounters (channelID, gmDiscordID, startedAt) \
+            VALUES ($1, $2, unixepoch()) \
+            RETURNING pk_encounterID;"
+        )
+            .bind(DiscordId(channel_id))
+            .bind(DiscordId(gm_id))
+            .fetch_one(database_conn_pool)
+            .await
+    }
+
+    pub async fn get_encounter(database_conn_pool: &SqlitePool, encounter_id: u64) -> Result<Option<Encounter>, Error> {
+        sqlx::query_as("SELECT * FROM Encounters WHERE pk_encounterID = $1;")
+            .bind(encounter_id as i64)
+            .fetch_optional(database_conn_pool)
+            .await
+    }
+
+    pub async fn get_by_channel(database_conn_pool: &SqlitePool, channel_id: u64) -> Result<Option<Encounter>, Error> {
+        sqlx::query_as("SELECT * FROM Encounters WHERE channelID = $1;")
+            .bind(DiscordId(channel_id))
+            .fetch_optional(database_conn_pool)
+            .await
+    }
+
+    pub async fn set_tracker_message(database_conn_pool: &SqlitePool, encounter_id: u64, message_id: u64) -> Result<SqliteQueryResult, Error> {
+        sqlx::query("UPDATE Encounters SET trackerMessageID = $2 WHERE pk_encounterID = $1;")
+            .bind(encounter_id as i64)
+            .bind(DiscordId(message_id))
+            .execute(database_conn_pool)
+            .await
+    }
+
+    pub async fn set_turn(database_conn_pool: &SqlitePool, encounter_id: u64, round: i64, current_entry: Option<u64>) -> Result<SqliteQueryResult, Error> {
+        sqlx::query("UPDATE Encounters SET round = $2, fk_currentEntryID = $3 WHERE pk_encounterID = $1;")
+            .bind(encounter_id as i64)
+            .bind(round)
+            .bind(current_entry.map(|entry_id| entry_id as i64))
+            .execute(database_conn_pool)
+            .await
+    }
+
+    pub async fn remove_encounter(database_conn_pool: &SqlitePool, encounter_id: u64) -> Result<SqliteQueryResult, Error> {
+        sqlx::query("DELETE FROM Encounters WHERE pk_encounterID = $1;")
+            .bind(encounter_id as i64)
+            .execute(database_conn_pool)
+            .await
+    }
+// ==--
+
+// --== ENCOUNTER ENTRIES ==-- //
+
+    /// Adds a combatant to an encounter. NPCs are added without a `character_id`
+    pub async fn add_entry(database_conn_pool: &SqlitePool, encounter_id: u64, character_id: Option<u64>, name: &str, initiative: i64, dexterity: i64) -> Result<u64, Error> {
+        sqlx::query_scalar(
+            "INSERT INTO EncounterEntries (fk_encounterID, fk_characterID, name, initiative, dexterity) \
+            VALUES ($1, $2, $3, $4, $5) \
+            RETURNING pk_entryID;"
+        )
+            .bind(encounter_id as i64)
+            .bind(character_id.map(|character_id| character_id as i64))
+            .bind(name)
+            .bind(initiative)
+            .bind(dexterity)
+            .fetch_one(database_conn_pool)
+            .await
+    }
+
+    /// Returns the entries of an encounter in turn order: highest initiative first, ties broken by
+    /// dexterity and then by who joined first
+    pub async fn get_entries(database_conn_pool: &SqlitePool, encounter_id: u64) -> Result<Vec<EncounterEntry>, Error> {
+        sqlx::query_as(
+            "SELECT EncounterEntries.pk_entryID, EncounterEntries.fk_characterID, Characters.ownerDiscordID, \
+                EncounterEntries.name, EncounterEntries.initiative, EncounterEntries.dexterity \
+            FROM EncounterEntries \
+            LEFT JOIN Characters ON Characters.pk_characterID = EncounterEntries.fk_characterID \
+            WHERE EncounterEntries.fk_encounterID = $1 \
+            ORDER BY EncounterEntries.initiative DESC, EncounterEntries.dexterity DESC, EncounterEntries.pk_entryID;"
+        )
+            .bind(encounter_id as i64)
+            .fetch_all(database_conn_pool)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn remove_entry(database_conn_pool: &SqlitePool, entry_id: u64) -> Result<SqliteQueryResult, Error> {
+        sqlx::query("DELETE FROM EncounterEntries WHERE pk_entryID = $1;")
+            .bind(entry_id as i64)
+            .execute(database_conn_pool)
+            .await
+    }
+// ==--
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::sql_scripts::{discord_users, insert_test_character, open_test_database};
+
+    #[tokio::test]
+    async fn encounter_lifecycle() {
+        let database_conn_pool = open_test_database().await;
+        discord_users::add_user(&database_conn_pool, 1).await.expect("Insert should succeed");
+        let character_id = insert_test_character(&database_conn_pool, 2, "Hale").await;
+
+        let encounter_id = add_encounter(&database_conn_pool, 200, 1).await.expect("Insert should succeed");
+        assert!(add_encounter(&database_conn_pool, 200, 1).await.is_err(), "A channel runs one encounter at a time");
+
+        let goblin_id = add_entry(&database_conn_pool, encounter_id, None, "Goblin", 12, 3).await.expect("Insert should succeed");
+        let hale_id = add_entry(&database_conn_pool, encounter_id, Some(character_id), "Hale", 12, 5).await.expect("Insert should succeed");
+        assert!(add_entry(&database_conn_pool, encounter_id, Some(character_id), "Hale", 4, 5).await.is_err(), "A character joins once");
+        // NPCs have no character, so any number of them may join
+        let wolf_id = add_entry(&database_conn_pool, encounter_id, None, "Wolf", 15, 1).await.expect("Insert should succeed");
+
+        let entries = get_entries(&database_conn_pool, encounter_id).await.expect("Select should succeed");
+        assert_eq!(entries.iter().map(|entry| entry.id).collect::<Vec<_>>(), vec![wolf_id, hale_id, goblin_id]);
+        assert_eq!(entries[1].owner, Some(DiscordId(2)));
+        assert_eq!(entries[0].owner, None);
+
+        set_turn(&database_conn_pool, encounter_id, 2, Some(goblin_id)).await.expect("Update should succeed");
+        set_tracker_message(&database_conn_pool, encounter_id, 300).await.expect("Update should succeed");
+        let encounter = get_by_channel(&database_conn_pool, 200).await.expect("Select should succeed").expect("Encounter was inserted");
+        assert_eq!((encounter.round, encounter.current_entry, encounter.tracker_message_id), (2, Some(goblin_id), Some(DiscordId(300))));
+
+        // Removing the entry whose turn it is clears the turn rather than leaving a dangling ID
+        remove_entry(&database_conn_pool, goblin_id).await.expect("Delete should succeed");
+        let encounter = get_encounter(&database_conn_pool, encounter_id).await.expect("Select should succeed").expect("Encounter was inserted");
+        assert_eq!(encounter.current_entry, None);
+
+        remove_encounter(&database_conn_pool, encounter_id).await.expect("Delete should succeed");
+        assert!(get_entries(&database_conn_pool, encounter_id).await.expect("Select should succeed").is_empty());
+    }
+}
@@ -0,0 +1,294 @@
+use sqlx::{
+    sqlite::{
+        SqliteConnection, SqlitePool
+    },
+    Error
+};
+
+use crate::{
+    runtime::sql_scripts::{
+        character_stats::Stat,
+        models::CharacterProgression
+    },
+    utils::misc::levelling::Levelling
+};
+
+/// What an award of experience did to a character
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ExperienceAward {
+    pub character_id: u64,
+    pub experience: i64,
+    pub previous_level: i64,
+    pub level: i64,
+    pub stat_points_gained: i64,
+    pub lore_points_gained: i64
+}
+
+impl ExperienceAward {
+    pub fn levelled_up(&self) -> bool {
+        self.level > self.previous_level
+    }
+}
+
+/// How allocating unspent points went
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SpendOutcome {
+    /// The points were allocated, bringing the stat or lore to this value
+    Spent(i64),
+    /// The character only has this many points left to spend. Nothing was changed
+    NotEnough(i64),
+    /// The character does not exist
+    Missing
+}
+
+pub async fn get_progression(database_conn_pool: &SqlitePool, character_id: u64) -> Result<Option<CharacterProgression>, Error> {
+    sqlx::query_as(
+        "SELECT pk_characterID, experience, level, unspentStatPoints, unspentLorePoints \
+        FROM Characters WHERE pk_characterID = $1;"
+    )
+        .bind(character_id as i64)
+        .fetch_optional(database_conn_pool)
+        .await
+}
+
+// --== AWARDING EXPERIENCE ==-- //
+
+    async fn award_in(database_conn: &mut SqliteConnection, character_id: u64, amount: i64, levelling: &Levelling) -> Result<Option<ExperienceAward>, Error> {
+        // Adding the experience first makes the transaction take the write lock straight away
+        let progression: Option<(i64, i64)> = sqlx::query_as(
+            "UPDATE Characters SET experience = experience + $2 WHERE pk_characterID = $1 RETURNING experience, level;"
+        )
+            .bind(character_id as i64)
+            .bind(amount)
+            .fetch_optional(&mut *database_conn)
+            .await?;
+
+        let Some((experience, previous_level)) = progression else {
+            return Ok(None);
+        };
+
+        // Levels are never lost, even if the curve was made steeper since they were reached
+        let level = levelling.level_for(experience).max(previous_level);
+        let levels_gained = level - previous_level;
+
+        // Like with hit points, characters without a class count as casters. Those with several
+        // can cast should any of them allow it
+        let can_cast: bool = sqlx::query_scalar(
+            "SELECT COALESCE(MAX(CharacterClasses.castingCap > 0), TRUE) \
+            FROM SelectedCharacterClasses \
+            JOIN CharacterClasses ON CharacterClasses.pk_classID = SelectedCharacterClasses.pk_fk_classID \
+            WHERE SelectedCharacterClasses.pk_fk_characterID = $1;"
+        )
+            .bind(character_id as i64)
+            .fetch_one(&mut *database_conn)
+            .await?;
+
+        let stat_points_gained = levels_gained * levelling.stat_points_per_level;
+        let lore_points_gained = if can_cast { levels_gained * levelling.lore_points_per_level } else { 0 };
+
+        if levels_gained > 0 {
+            sqlx::query(
+                "UPDATE Characters SET \
+                    level = $2, \
+                    unspentStatPoints = unspentStatPoints + $3, \
+                    unspentLorePoints = unspentLorePoints + $4 \
+                WHERE pk_characterID = $1;"
+            )
+                .bind(character_id as i64)
+                .bind(level)
+                .bind(stat_points_gained)
+                .bind(lore_points_gained)
+                .execute(&mut *database_conn)
+                .await?;
+
+            // The levels go to the character's primary class
+            sqlx::query(
+                "UPDATE SelectedCharacterClasses SET classLevel = classLevel + $2 WHERE pk_fk_characterID = $1 AND isPrimary;"
+            )
+                .bind(character_id as i64)
+                .bind(levels_gained)
+                .execute(&mut *database_conn)
+                .await?;
+        }
+
+        Ok(Some(ExperienceAward {
+            character_id,
+            experience,
+            previous_level,
+            level,
+            stat_points_gained,
+            lore_points_gained
+        }))
+    }
+
+    /// Adds experience to a character, levelling them up as far as the curve allows. Returns
+    /// `None` when the character does not exist
+    pub async fn award_experience(database_conn_pool: &SqlitePool, character_id: u64, amount: i64, levelling: &Levelling) -> Result<Option<ExperienceAward>, Error> {
+        let mut transaction = database_conn_pool.begin().await?;
+        let award = award_in(&mut transaction, character_id, amount, levelling).await?;
+        transaction.commit().await?;
+
+        Ok(award)
+    }
+
+    /// Awards the same experience to several characters at once, either all of them get it or
+    /// none do. Characters that don't exist are skipped
+    pub async fn award_experience_to_all(database_conn_pool: &SqlitePool, character_ids: &[u64], amount: i64, levelling: &Levelling) -> Result<Vec<ExperienceAward>, Error> {
+        let mut transaction = database_conn_pool.begin().await?;
+
+        let mut awards = vec![];
+        for character_id in character_ids {
+            if let Some(award) = award_in(&mut transaction, *character_id, amount, levelling).await? {
+                awards.push(award);
+            }
+        }
+        transaction.commit().await?;
+
+        Ok(awards)
+    }
+// ==--
+
+// --== SPENDING POINTS ==-- //
+
+    /// Takes `points` from a character's unspent points in `column`, returning how many were
+    /// left beforehand if there weren't enough
+    async fn take_points(database_conn: &mut SqliteConnection, character_id: u64, column: &str, points: i64) -> Result<Option<SpendOutcome>, Error> {
+        let remaining: Option<i64> = sqlx::query_scalar(&format!(
+            "UPDATE Characters SET {column} = {column} - $2 WHERE pk_characterID = $1 AND {column} >= $2 RETURNING {column};"
+        ))
+            .bind(character_id as i64)
+            .bind(points)
+            .fetch_optional(&mut *database_conn)
+            .await?;
+
+        if remaining.is_some() {
+            return Ok(None);
+        }
+
+        let unspent: Option<i64> = sqlx::query_scalar(&format!( "SELECT {column} FROM Characters WHERE pk_characterID = $1;" ))
+            .bind(character_id as i64)
+            .fetch_optional(&mut *database_conn)
+            .await?;
+
+        Ok(Some(match unspent {
+            Some(unspent) => SpendOutcome::NotEnough(unspent),
+            None => SpendOutcome::Missing
+        }))
+    }
+
+    pub async fn spend_stat_points(database_conn_pool: &SqlitePool, character_id: u64, stat: Stat, points: i64) -> Result<SpendOutcome, Error> {
+        let mut transaction = database_conn_pool.begin().await?;
+
+        if let Some(refusal) = take_points(&mut transaction, character_id, "unspentStatPoints", points).await? {
+            return Ok(refusal);
+        }
+
+        // Characters from before stats were asked for start from nothing
+        sqlx::query("INSERT INTO CharacterStats VALUES ( $1, 0, 0, 0, 0, 0, 0 ) ON CONFLICT DO NOTHING;")
+            .bind(character_id as i64)
+            .execute(&mut *transaction)
+            .await?;
+
+        let column = stat.column();
+        let new_value: i64 = sqlx::query_scalar(&format!(
+            "UPDATE CharacterStats SET {column} = {column} + $2 WHERE pk_fk_characterID = $1 RETURNING {column};"
+        ))
+            .bind(character_id as i64)
+            .bind(points)
+            .fetch_one(&mut *transaction)
+            .await?;
+
+        transaction.commit().await?;
+        Ok(SpendOutcome::Spent(new_value))
+    }
+
+    pub async fn spend_lore_points(database_conn_pool: &SqlitePool, character_id: u64, lore_id: u64, points: i64) -> Result<SpendOutcome, Error> {
+        let mut transaction = database_conn_pool.begin().await?;
+
+        if let Some(refusal) = take_points(&mut transaction, character_id, "unspentLorePoints", points).await? {
+            return Ok(refusal);
+        }
+
+        let spent_points: i64 = sqlx::query_scalar(
+            "INSERT INTO CharacterUsedLores VALUES ( $1, $2, $3 ) \
+            ON CONFLICT (pk_fk_loreID, pk_fk_characterID) DO UPDATE SET spentPoints = spentPoints + excluded.spentPoints \
+            RETURNING spentPoints;"
+        )
+            .bind(lore_id as i64)
+            .bind(character_id as i64)
+            .bind(points)
+            .fetch_one(&mut *transaction)
+            .await?;
+
+        transaction.commit().await?;
+        Ok(SpendOutcome::Spent(spent_points))
+    }
+// ==--
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::sql_scripts::{character_stats, insert_test_character, lores, models::CharacterClass, open_test_database};
+
+    #[tokio::test]
+    async fn levelling_grants_points_to_spend() {
+        let database_conn_pool = open_test_database().await;
+        let character_id = insert_test_character(&database_conn_pool, 1, "Brynn").await;
+        let levelling = Levelling::default();
+
+        let award = award_experience(&database_conn_pool, character_id, 200, &levelling)
+            .await
+            .expect("Award should succeed")
+            .expect("Character exists");
+        assert!(!award.levelled_up());
+
+        // Skipping straight past level 2 grants the points of both levels
+        let award = award_experience(&database_conn_pool, character_id, 800, &levelling)
+            .await
+            .expect("Award should succeed")
+            .expect("Character exists");
+        assert_eq!((award.previous_level, award.level, award.experience), (1, 3, 1000));
+        assert_eq!((award.stat_points_gained, award.lore_points_gained), (4, 2));
+
+        assert_eq!(
+            spend_stat_points(&database_conn_pool, character_id, Stat::Casting, 5).await.expect("Spend should not error"),
+            SpendOutcome::NotEnough(4)
+        );
+        assert_eq!(
+            spend_stat_points(&database_conn_pool, character_id, Stat::Casting, 3).await.expect("Spend should succeed"),
+            SpendOutcome::Spent(3)
+        );
+        assert_eq!(
+            spend_lore_points(&database_conn_pool, character_id, 1, 2).await.expect("Spend should succeed"),
+            SpendOutcome::Spent(2)
+        );
+
+        let stats = character_stats::get_stats(&database_conn_pool, character_id).await.expect("Select should succeed");
+        assert_eq!(stats.map(|stats| stats.casting), Some(3));
+        let used_lores = lores::get_used_lores(&database_conn_pool, character_id).await.expect("Select should succeed");
+        assert_eq!(used_lores[0].spent_points, 2);
+
+        let progression = get_progression(&database_conn_pool, character_id).await.expect("Select should succeed");
+        assert_eq!(progression.map(|progression| (progression.unspent_stat_points, progression.unspent_lore_points)), Some((1, 0)));
+    }
+
+    #[tokio::test]
+    async fn martial_characters_gain_no_lore_points() {
+        let database_conn_pool = open_test_database().await;
+        let character_id = insert_test_character(&database_conn_pool, 1, "Brynn").await;
+        sqlx::query("UPDATE SelectedCharacterClasses SET pk_fk_classID = $2 WHERE pk_fk_characterID = $1;")
+            .bind(character_id as i64)
+            .bind(CharacterClass::MARTIAL_ID as i64)
+            .execute(&database_conn_pool)
+            .await
+            .expect("Update should succeed");
+
+        let awards = award_experience_to_all(&database_conn_pool, &[character_id, 999], 300, &Levelling::default())
+            .await
+            .expect("Award should succeed");
+        assert_eq!(awards.len(), 1);
+        assert_eq!((awards[0].stat_points_gained, awards[0].lore_points_gained), (2, 0));
+    }
+}
@@ -0,0 +1,312 @@
+use sqlx::{
+    sqlite::{
+        SqliteConnection, SqlitePool
+    },
+    Error
+};
+
+use crate::runtime::sql_scripts::{
+    character_stats,
+    models::{CharacterHitPoints, DiscordId, HitPointChange}
+};
+
+// --== MAXIMUM HIT POINTS ==-- //
+
+    /// Hit points of characters without a class, before their constitution is added
+    const CLASSLESS_BASE_HIT_POINTS: i64 = 8;
+
+    /// Every point of constitution adds two to the class's base hit points, but nobody drops
+    /// below one
+    pub fn max_hit_points( base_hit_points: i64, constitution: i64 ) -> i64 {
+        (base_hit_points + 2 * constitution).max(1)
+    }
+
+    async fn fetch_max_hit_points(database_conn: &mut SqliteConnection, character_id: u64) -> Result<i64, Error> {
+        // Those with several classes go by their primary class. Characters without a class or
+        // stats get the classless base and no constitution
+        let base_hit_points: Option<i64> = sqlx::query_scalar(
+            "SELECT CharacterClasses.baseHitPoints FROM SelectedCharacterClasses \
+            JOIN CharacterClasses ON CharacterClasses.pk_classID = SelectedCharacterClasses.pk_fk_classID \
+            WHERE SelectedCharacterClasses.pk_fk_characterID = $1 \
+            ORDER BY SelectedCharacterClasses.isPrimary DESC, SelectedCharacterClasses.pk_fk_classID LIMIT 1;"
+        )
+            .bind(character_id as i64)
+            .fetch_optional(&mut *database_conn)
+            .await?;
+
+        let stats = character_stats::fetch_effective_stats(database_conn, character_id).await?;
+
+        Ok(max_hit_points(
+            base_hit_points.unwrap_or(CLASSLESS_BASE_HIT_POINTS),
+            stats.map_or(0, |stats| stats.constitution)
+        ))
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_max_hit_points(database_conn_pool: &SqlitePool, character_id: u64) -> Result<i64, Error> {
+        fetch_max_hit_points(&mut *database_conn_pool.acquire().await?, character_id).await
+    }
+// ==--
+
+// --== ADJUSTMENTS ==-- //
+
+    /// A change to a character's hit points, as requested through `/hp`
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub enum HitPointAdjustment {
+        /// Temporary hit points soak up damage first, and hit points stop at zero
+        Damage(i64),
+        /// Healing never takes a character above their maximum, nor restores temporary hit points
+        Heal(i64),
+        /// Overwrites whichever of the values are given
+        Set { current: Option<i64>, temporary: Option<i64> }
+    }
+    impl HitPointAdjustment {
+        /// The `kind` this adjustment is recorded as in `HitPointHistory`
+        pub fn kind(&self) -> &'static str {
+            match self {
+                HitPointAdjustment::Damage(_) => "damage",
+                HitPointAdjustment::Heal(_) => "heal",
+                HitPointAdjustment::Set { .. } => "set"
+            }
+        }
+
+        pub fn amount(&self) -> Option<i64> {
+            match self {
+                HitPointAdjustment::Damage(amount) | HitPointAdjustment::Heal(amount) => Some(*amount),
+                HitPointAdjustment::Set { .. } => None
+            }
+        }
+
+        pub fn apply(&self, hit_points: CharacterHitPoints, max_hp: i64) -> CharacterHitPoints {
+            match *self {
+                HitPointAdjustment::Damage(amount) => {
+                    let absorbed = amount.min(hit_points.temporary);
+                    CharacterHitPoints {
+                        current: (hit_points.current - (amount - absorbed)).max(0),
+                        temporary: hit_points.temporary - absorbed,
+                        ..hit_points
+                    }
+                },
+                HitPointAdjustment::Heal(amount) => CharacterHitPoints {
+                    // Should the maximum have dropped since, healing must not lower hit points
+                    current: (hit_points.current + amount).min(max_hp).max(hit_points.current),
+                    ..hit_points
+                },
+                HitPointAdjustment::Set { current, temporary } => CharacterHitPoints {
+                    current: current.map_or(hit_points.current, |current| current.clamp(0, max_hp)),
+                    temporary: temporary.map_or(hit_points.temporary, |temporary| temporary.max(0)),
+                    ..hit_points
+                }
+            }
+        }
+    }
+// ==--
+
+// --== HIT POINTS ==-- //
+
+    async fn fetch_hit_points(database_conn: &mut SqliteConnection, character_id: u64, max_hp: i64) -> Result<CharacterHitPoints, Error> {
+        let hit_points = sqlx::query_as("SELECT * FROM CharacterHitPoints WHERE pk_fk_characterID = $1;")
+            .bind(character_id as i64)
+            .fetch_optional(&mut *database_conn)
+            .await?;
+
+        Ok(hit_points.unwrap_or(CharacterHitPoints { character_id, current: max_hp, temporary: 0 }))
+    }
+
+    async fn store_hit_points(database_conn: &mut SqliteConnection, hit_points: &CharacterHitPoints) -> Result<(), Error> {
+        sqlx::query(
+            "INSERT INTO CharacterHitPoints VALUES ( $1, $2, $3 ) \
+            ON CONFLICT (pk_fk_characterID) DO UPDATE SET \
+                currentHP = excluded.currentHP, \
+                temporaryHP = excluded.temporaryHP;"
+        )
+            .bind(hit_points.character_id as i64)
+            .bind(hit_points.current)
+            .bind(hit_points.temporary)
+            .execute(&mut *database_conn)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn record_change(database_conn: &mut SqliteConnection, changed_by: u64, kind: &str, amount: Option<i64>, previous: &CharacterHitPoints, new: &CharacterHitPoints) -> Result<(), Error> {
+        sqlx::query(
+            "INSERT INTO HitPointHistory ( \
+                fk_characterID, changedByDiscordID, kind, amount, \
+                previousHP, previousTemporaryHP, newHP, newTemporaryHP, changedAt \
+            ) \
+            VALUES ( $1, $2, $3, $4, $5, $6, $7, $8, unixepoch() );"
+        )
+            .bind(new.character_id as i64)
+            .bind(DiscordId(changed_by))
+            .bind(kind)
+            .bind(amount)
+            .bind(previous.current)
+            .bind(previous.temporary)
+            .bind(new.current)
+            .bind(new.temporary)
+            .execute(&mut *database_conn)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Returns a character's hit points along with their maximum
+    pub async fn get_hit_points(database_conn_pool: &SqlitePool, character_id: u64) -> Result<(CharacterHitPoints, i64), Error> {
+        let mut database_conn = database_conn_pool.acquire().await?;
+
+        let max_hp = fetch_max_hit_points(&mut database_conn, character_id).await?;
+        let hit_points = fetch_hit_points(&mut database_conn, character_id, max_hp).await?;
+
+        Ok((hit_points, max_hp))
+    }
+
+    /// Applies an adjustment and records it in `HitPointHistory`, both in one transaction.
+    ///
+    /// Returns the hit points from before and after the change, along with the maximum
+    pub async fn adjust_hit_points(database_conn_pool: &SqlitePool, character_id: u64, changed_by: u64, adjustment: HitPointAdjustment) -> Result<(CharacterHitPoints, CharacterHitPoints, i64), Error> {
+        let mut transaction = database_conn_pool.begin().await?;
+
+        let max_hp = fetch_max_hit_points(&mut transaction, character_id).await?;
+        let previous = fetch_hit_points(&mut transaction, character_id, max_hp).await?;
+        let adjusted = adjustment.apply(previous, max_hp);
+
+        store_hit_points(&mut transaction, &adjusted).await?;
+        record_change(&mut transaction, changed_by, adjustment.kind(), adjustment.amount(), &previous, &adjusted).await?;
+
+        transaction.commit().await?;
+
+        Ok((previous, adjusted, max_hp))
+    }
+// ==--
+
+// --== HISTORY ==-- //
+
+    /// Returns a character's most recent changes, newest first
+    pub async fn get_history(database_conn_pool: &SqlitePool, character_id: u64, limit: u32) -> Result<Vec<HitPointChange>, Error> {
+        sqlx::query_as(
+            "SELECT * FROM HitPointHistory \
+            WHERE fk_characterID = $1 \
+            ORDER BY pk_changeID DESC \
+            LIMIT $2;"
+        )
+            .bind(character_id as i64)
+            .bind(limit)
+            .fetch_all(database_conn_pool)
+            .await
+    }
+
+    /// Reverts the newest change that has not been undone yet, restoring the values from before
+    /// it. The undo is recorded in the history as well, but undos and the changes they undid are
+    /// skipped over, so repeated calls walk further back through the history.
+    ///
+    /// Returns the change that was undone, or `None` when there is nothing left to undo
+    pub async fn undo_last_change(database_conn_pool: &SqlitePool, character_id: u64, changed_by: u64) -> Result<Option<HitPointChange>, Error> {
+        let mut transaction = database_conn_pool.begin().await?;
+
+        let last_change: Option<HitPointChange> = sqlx::query_as(
+            "SELECT * FROM HitPointHistory \
+            WHERE fk_characterID = $1 AND isUndone = FALSE AND kind != 'undo' \
+            ORDER BY pk_changeID DESC \
+            LIMIT 1;"
+        )
+            .bind(character_id as i64)
+            .fetch_optional(&mut *transaction)
+            .await?;
+
+        let Some(last_change) = last_change else {
+            return Ok(None);
+        };
+
+        let max_hp = fetch_max_hit_points(&mut transaction, character_id).await?;
+        let previous = fetch_hit_points(&mut transaction, character_id, max_hp).await?;
+        let restored = CharacterHitPoints {
+            character_id,
+            current: last_change.previous_hp,
+            temporary: last_change.previous_temporary_hp
+        };
+
+        store_hit_points(&mut transaction, &restored).await?;
+        record_change(&mut transaction, changed_by, "undo", None, &previous, &restored).await?;
+
+        sqlx::query("UPDATE HitPointHistory SET isUndone = TRUE WHERE pk_changeID = $1;")
+            .bind(last_change.id as i64)
+            .execute(&mut *transaction)
+            .await?;
+
+        transaction.commit().await?;
+
+        Ok(Some(last_change))
+    }
+// ==--
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::sql_scripts::{character_classes, character_stats, insert_test_character, models::CharacterStats, open_test_database};
+
+    #[test]
+    fn adjustments_respect_limits() {
+        let hit_points = CharacterHitPoints { character_id: 1, current: 10, temporary: 4 };
+
+        let damaged = HitPointAdjustment::Damage(6).apply(hit_points, 20);
+        assert_eq!((damaged.current, damaged.temporary), (8, 0));
+        assert_eq!(HitPointAdjustment::Damage(50).apply(hit_points, 20).current, 0);
+
+        assert_eq!(HitPointAdjustment::Heal(50).apply(hit_points, 20).current, 20);
+        assert_eq!(HitPointAdjustment::Heal(5).apply(hit_points, 8).current, 10, "Healing never lowers hit points");
+
+        let set = HitPointAdjustment::Set { current: Some(99), temporary: None }.apply(hit_points, 20);
+        assert_eq!((set.current, set.temporary), (20, 4));
+    }
+
+    #[tokio::test]
+    async fn changes_are_recorded_and_undone() {
+        let database_conn_pool = open_test_database().await;
+        let character_id = insert_test_character(&database_conn_pool, 1, "Hale").await;
+        character_stats::set_stats(&database_conn_pool, &CharacterStats {
+            character_id, strength: 1, dexterity: 1, perception: 1, knowledge: 1, constitution: 3, casting: 1
+        }).await.expect("Insert should succeed");
+
+        // A caster with 3 constitution, untouched so far
+        let (hit_points, max_hp) = get_hit_points(&database_conn_pool, character_id).await.expect("Select should succeed");
+        assert_eq!((hit_points.current, max_hp), (14, 14));
+
+        adjust_hit_points(&database_conn_pool, character_id, 1, HitPointAdjustment::Damage(5)).await.expect("Update should succeed");
+        adjust_hit_points(&database_conn_pool, character_id, 2, HitPointAdjustment::Damage(4)).await.expect("Update should succeed");
+        assert_eq!(get_hit_points(&database_conn_pool, character_id).await.expect("Select should succeed").0.current, 5);
+
+        let history = get_history(&database_conn_pool, character_id, 10).await.expect("Select should succeed");
+        assert_eq!(history.iter().map(|change| (change.previous_hp, change.new_hp)).collect::<Vec<_>>(), vec![(9, 5), (14, 9)]);
+        assert_eq!(history[0].changed_by, DiscordId(2));
+
+        let undone = undo_last_change(&database_conn_pool, character_id, 3).await.expect("Undo should succeed");
+        assert_eq!(undone.map(|change| change.changed_by), Some(DiscordId(2)));
+        assert_eq!(get_hit_points(&database_conn_pool, character_id).await.expect("Select should succeed").0.current, 9);
+
+        // The undo is on record too, and the next one skips past it to the first change
+        let history = get_history(&database_conn_pool, character_id, 10).await.expect("Select should succeed");
+        assert_eq!(
+            history.iter().map(|change| (change.kind.as_str(), change.changed_by, change.previous_hp, change.new_hp, change.is_undone)).collect::<Vec<_>>(),
+            vec![ ("undo", DiscordId(3), 5, 9, false), ("damage", DiscordId(2), 9, 5, true), ("damage", DiscordId(1), 14, 9, false) ]
+        );
+
+        undo_last_change(&database_conn_pool, character_id, 3).await.expect("Undo should succeed");
+        assert_eq!(get_hit_points(&database_conn_pool, character_id).await.expect("Select should succeed").0.current, 14);
+        assert!(undo_last_change(&database_conn_pool, character_id, 3).await.expect("Undo should succeed").is_none());
+    }
+
+    #[tokio::test]
+    async fn classes_set_base_hit_points() {
+        let database_conn_pool = open_test_database().await;
+        let character_id = insert_test_character(&database_conn_pool, 1, "Hale").await;
+        assert_eq!(get_max_hit_points(&database_conn_pool, character_id).await.expect("Select should succeed"), 8);
+
+        let giant_id = character_classes::add_class(&database_conn_pool, "Giant", "", 0, 0, 30).await.expect("Insert should succeed");
+        character_classes::add_character_class(&database_conn_pool, character_id, giant_id).await.expect("Insert should succeed");
+        character_classes::set_primary_class(&database_conn_pool, character_id, giant_id).await.expect("Update should succeed");
+        assert_eq!(get_max_hit_points(&database_conn_pool, character_id).await.expect("Select should succeed"), 30);
+    }
+}
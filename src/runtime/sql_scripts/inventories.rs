@@ -0,0 +1,327 @@
+use sqlx::{
+    sqlite::{
+        SqliteConnection, SqlitePool, SqliteQueryResult
+    },
+    Error
+};
+
+use crate::runtime::sql_scripts::{
+    character_stats,
+    models::{InventoryEntry, ItemTransfer}
+};
+
+// --== ENCUMBRANCE ==-- //
+
+    /// How much weight a character can carry, every point of strength adds ten
+    pub fn carry_limit( strength: i64 ) -> f64 {
+        (50.0 + 10.0 * strength as f64).max(10.0)
+    }
+
+    async fn fetch_carry_limit(database_conn: &mut SqliteConnection, character_id: u64) -> Result<f64, Error> {
+        let stats = character_stats::fetch_effective_stats(database_conn, character_id).await?;
+
+        Ok(carry_limit(stats.map_or(0, |stats| stats.strength)))
+    }
+
+    async fn fetch_carried_weight(database_conn: &mut SqliteConnection, character_id: u64) -> Result<f64, Error> {
+        sqlx::query_scalar(
+            "SELECT COALESCE(SUM(Items.weight * CharacterInventories.quantity), 0.0) \
+            FROM CharacterInventories \
+            JOIN Items ON Items.pk_itemID = CharacterInventories.pk_fk_itemID \
+            WHERE CharacterInventories.pk_fk_characterID = $1;"
+        )
+            .bind(character_id as i64)
+            .fetch_one(&mut *database_conn)
+            .await
+    }
+
+    async fn fetch_item_weight(database_conn: &mut SqliteConnection, item_id: u64) -> Result<f64, Error> {
+        sqlx::query_scalar("SELECT weight FROM Items WHERE pk_itemID = $1;")
+            .bind(item_id as i64)
+            .fetch_one(&mut *database_conn)
+            .await
+    }
+
+    /// Returns the weight a character carries along with how much they may carry
+    pub async fn get_encumbrance(database_conn_pool: &SqlitePool, character_id: u64) -> Result<(f64, f64), Error> {
+        let mut database_conn = database_conn_pool.acquire().await?;
+
+        Ok((
+            fetch_carried_weight(&mut database_conn, character_id).await?,
+            fetch_carry_limit(&mut database_conn, character_id).await?
+        ))
+    }
+// ==--
+
+// --== INVENTORIES ==-- //
+
+    /// How adding items to an inventory went
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub enum AddOutcome {
+        /// The items were added, leaving this many in the stack
+        Added(i64),
+        /// The items would take the character past their carry limit, nothing was added
+        TooHeavy { carried: f64, limit: f64 }
+    }
+
+    /// How removing items from an inventory went
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub enum RemoveOutcome {
+        /// The items were removed, leaving this many in the stack
+        Removed(i64),
+        /// The character holds fewer items than were asked for, nothing was removed
+        NotEnough(i64)
+    }
+
+    /// Returns a character's inventory ordered by item name
+    pub async fn get_inventory(database_conn_pool: &SqlitePool, character_id: u64) -> Result<Vec<InventoryEntry>, Error> {
+        sqlx::query_as(
+            "SELECT Items.pk_itemID, Items.name, Items.weight, CharacterInventories.quantity \
+            FROM CharacterInventories \
+            JOIN Items ON Items.pk_itemID = CharacterInventories.pk_fk_itemID \
+            WHERE CharacterInventories.pk_fk_characterID = $1 \
+            ORDER BY Items.name;"
+        )
+            .bind(character_id as i64)
+            .fetch_all(database_conn_pool)
+            .await
+    }
+
+    async fn fetch_quantity(database_conn: &mut SqliteConnection, character_id: u64, item_id: u64) -> Result<i64, Error> {
+        let quantity: Option<i64> = sqlx::query_scalar(
+            "SELECT quantity FROM CharacterInventories WHERE pk_fk_characterID = $1 AND pk_fk_itemID = $2;"
+        )
+            .bind(character_id as i64)
+            .bind(item_id as i64)
+            .fetch_optional(&mut *database_conn)
+            .await?;
+
+        Ok(quantity.unwrap_or(0))
+    }
+
+    async fn store_quantity(database_conn: &mut SqliteConnection, character_id: u64, item_id: u64, quantity: i64) -> Result<(), Error> {
+        if quantity <= 0 {
+            sqlx::query("DELETE FROM CharacterInventories WHERE pk_fk_characterID = $1 AND pk_fk_itemID = $2;")
+                .bind(character_id as i64)
+                .bind(item_id as i64)
+                .execute(&mut *database_conn)
+                .await?;
+        } else {
+            sqlx::query(
+                "INSERT INTO CharacterInventories VALUES ( $1, $2, $3 ) \
+                ON CONFLICT (pk_fk_characterID, pk_fk_itemID) DO UPDATE SET quantity = excluded.quantity;"
+            )
+                .bind(character_id as i64)
+                .bind(item_id as i64)
+                .bind(quantity)
+                .execute(&mut *database_conn)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Checks the carry limit, then adds the items onto the character's stack
+    async fn add_to_inventory(database_conn: &mut SqliteConnection, character_id: u64, item_id: u64, quantity: i64) -> Result<AddOutcome, Error> {
+        let carried = fetch_carried_weight(database_conn, character_id).await?;
+        let limit = fetch_carry_limit(database_conn, character_id).await?;
+        let added_weight = fetch_item_weight(database_conn, item_id).await? * quantity as f64;
+
+        // Weightless items may always be picked up, even by those already over their limit
+        if added_weight > 0.0 && carried + added_weight > limit {
+            return Ok(AddOutcome::TooHeavy { carried, limit });
+        }
+
+        let stacked_quantity = fetch_quantity(database_conn, character_id, item_id).await? + quantity;
+        store_quantity(database_conn, character_id, item_id, stacked_quantity).await?;
+
+        Ok(AddOutcome::Added(stacked_quantity))
+    }
+
+    pub async fn add_items(database_conn_pool: &SqlitePool, character_id: u64, item_id: u64, quantity: i64) -> Result<AddOutcome, Error> {
+        let mut transaction = database_conn_pool.begin().await?;
+        let outcome = add_to_inventory(&mut transaction, character_id, item_id, quantity).await?;
+        transaction.commit().await?;
+
+        Ok(outcome)
+    }
+
+    pub async fn remove_items(database_conn_pool: &SqlitePool, character_id: u64, item_id: u64, quantity: i64) -> Result<RemoveOutcome, Error> {
+        let mut transaction = database_conn_pool.begin().await?;
+
+        let held_quantity = fetch_quantity(&mut transaction, character_id, item_id).await?;
+        if held_quantity < quantity {
+            return Ok(RemoveOutcome::NotEnough(held_quantity));
+        }
+
+        store_quantity(&mut transaction, character_id, item_id, held_quantity - quantity).await?;
+        transaction.commit().await?;
+
+        Ok(RemoveOutcome::Removed(held_quantity - quantity))
+    }
+// ==--
+
+// --== TRANSFERS ==-- //
+
+    /// How completing a transfer went
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub enum TransferOutcome {
+        /// The items changed hands, and the transfer was removed
+        Completed,
+        /// One of the owners has yet to confirm
+        AwaitingConfirmation,
+        /// The sender no longer holds enough of the item, the transfer was removed
+        NotEnough(i64),
+        /// The recipient can't carry that much, the transfer was removed
+        TooHeavy { carried: f64, limit: f64 },
+        /// The transfer was cancelled, or already completed
+        Missing
+    }
+
+    pub async fn add_transfer(database_conn_pool: &SqlitePool, from_character: u64, to_character: u64, item_id: u64, quantity: i64) -> Result<u64, Error> {
+        sqlx::query_scalar(
+            "INSERT INTO ItemTransfers (fk_fromCharacterID, fk_toCharacterID, fk_itemID, quantity, createdAt) \
+            VALUES ($1, $2, $3, $4, unixepoch()) \
+            RETURNING pk_transferID;"
+        )
+            .bind(from_character as i64)
+            .bind(to_character as i64)
+            .bind(item_id as i64)
+            .bind(quantity)
+            .fetch_one(database_conn_pool)
+            .await
+    }
+
+    pub async fn get_transfer(database_conn_pool: &SqlitePool, transfer_id: u64) -> Result<Option<ItemTransfer>, Error> {
+        sqlx::query_as("SELECT * FROM ItemTransfers WHERE pk_transferID = $1;")
+            .bind(transfer_id as i64)
+            .fetch_optional(database_conn_pool)
+            .await
+    }
+
+    pub async fn remove_transfer(database_conn_pool: &SqlitePool, transfer_id: u64) -> Result<SqliteQueryResult, Error> {
+        sqlx::query("DELETE FROM ItemTransfers WHERE pk_transferID = $1;")
+            .bind(transfer_id as i64)
+            .execute(database_conn_pool)
+            .await
+    }
+
+    /// Records the confirmation of the sending and/or receiving owner, and once both have
+    /// confirmed moves the items, all in one transaction
+    pub async fn confirm_transfer(database_conn_pool: &SqlitePool, transfer_id: u64, as_sender: bool, as_recipient: bool) -> Result<TransferOutcome, Error> {
+        let mut transaction = database_conn_pool.begin().await?;
+
+        let transfer: Option<ItemTransfer> = sqlx::query_as(
+            "UPDATE ItemTransfers SET \
+                senderConfirmed = senderConfirmed OR $2, \
+                recipientConfirmed = recipientConfirmed OR $3 \
+            WHERE pk_transferID = $1 \
+            RETURNING *;"
+        )
+            .bind(transfer_id as i64)
+            .bind(as_sender)
+            .bind(as_recipient)
+            .fetch_optional(&mut *transaction)
+            .await?;
+
+        let Some(transfer) = transfer else {
+            return Ok(TransferOutcome::Missing);
+        };
+
+        if !(transfer.sender_confirmed && transfer.recipient_confirmed) {
+            transaction.commit().await?;
+            return Ok(TransferOutcome::AwaitingConfirmation);
+        }
+
+        // Both have agreed, so the transfer is settled one way or another
+        sqlx::query("DELETE FROM ItemTransfers WHERE pk_transferID = $1;")
+            .bind(transfer_id as i64)
+            .execute(&mut *transaction)
+            .await?;
+
+        let held_quantity = fetch_quantity(&mut transaction, transfer.from_character, transfer.item_id).await?;
+        let outcome = if held_quantity < transfer.quantity {
+            TransferOutcome::NotEnough(held_quantity)
+        } else {
+            store_quantity(&mut transaction, transfer.from_character, transfer.item_id, held_quantity - transfer.quantity).await?;
+
+            match add_to_inventory(&mut transaction, transfer.to_character, transfer.item_id, transfer.quantity).await? {
+                AddOutcome::Added(_) => TransferOutcome::Completed,
+                AddOutcome::TooHeavy { carried, limit } => {
+                    // Put the sender's items back where they were
+                    store_quantity(&mut transaction, transfer.from_character, transfer.item_id, held_quantity).await?;
+                    TransferOutcome::TooHeavy { carried, limit }
+                }
+            }
+        };
+
+        transaction.commit().await?;
+        Ok(outcome)
+    }
+// ==--
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::sql_scripts::{insert_test_character, items, open_test_database};
+
+    #[tokio::test]
+    async fn items_stack_and_respect_carry_limit() {
+        let database_conn_pool = open_test_database().await;
+        let character_id = insert_test_character(&database_conn_pool, 1, "Hale").await;
+
+        let rope = items::add_item(&database_conn_pool, "Rope", 10.0).await.expect("Insert should succeed");
+        let feather = items::add_item(&database_conn_pool, "Feather", 0.0).await.expect("Insert should succeed");
+
+        assert_eq!(add_items(&database_conn_pool, character_id, rope, 2).await.expect("Insert should succeed"), AddOutcome::Added(2));
+        assert_eq!(add_items(&database_conn_pool, character_id, rope, 3).await.expect("Insert should succeed"), AddOutcome::Added(5));
+
+        // No stats means no strength, so the limit is 50
+        assert_eq!(
+            add_items(&database_conn_pool, character_id, rope, 1).await.expect("Insert should succeed"),
+            AddOutcome::TooHeavy { carried: 50.0, limit: 50.0 }
+        );
+        assert_eq!(add_items(&database_conn_pool, character_id, feather, 100).await.expect("Insert should succeed"), AddOutcome::Added(100));
+
+        assert_eq!(remove_items(&database_conn_pool, character_id, rope, 9).await.expect("Update should succeed"), RemoveOutcome::NotEnough(5));
+        assert_eq!(remove_items(&database_conn_pool, character_id, rope, 5).await.expect("Update should succeed"), RemoveOutcome::Removed(0));
+
+        let inventory = get_inventory(&database_conn_pool, character_id).await.expect("Select should succeed");
+        assert_eq!(inventory.iter().map(|entry| (entry.name.as_str(), entry.quantity)).collect::<Vec<_>>(), vec![("Feather", 100)]);
+        assert_eq!(get_encumbrance(&database_conn_pool, character_id).await.expect("Select should succeed"), (0.0, 50.0));
+    }
+
+    #[tokio::test]
+    async fn transfers_need_both_confirmations() {
+        let database_conn_pool = open_test_database().await;
+        let sender = insert_test_character(&database_conn_pool, 1, "Hale").await;
+        let recipient = insert_test_character(&database_conn_pool, 2, "Mira").await;
+
+        let rope = items::add_item(&database_conn_pool, "Rope", 1.0).await.expect("Insert should succeed");
+        add_items(&database_conn_pool, sender, rope, 3).await.expect("Insert should succeed");
+
+        let transfer_id = add_transfer(&database_conn_pool, sender, recipient, rope, 2).await.expect("Insert should succeed");
+        assert_eq!(confirm_transfer(&database_conn_pool, transfer_id, true, false).await.expect("Update should succeed"), TransferOutcome::AwaitingConfirmation);
+        assert_eq!(confirm_transfer(&database_conn_pool, transfer_id, true, false).await.expect("Update should succeed"), TransferOutcome::AwaitingConfirmation);
+        assert_eq!(confirm_transfer(&database_conn_pool, transfer_id, false, true).await.expect("Update should succeed"), TransferOutcome::Completed);
+        assert_eq!(confirm_transfer(&database_conn_pool, transfer_id, false, true).await.expect("Update should succeed"), TransferOutcome::Missing);
+
+        let quantity_of = |character_id| {
+            let database_conn_pool = database_conn_pool.clone();
+            async move {
+                get_inventory(&database_conn_pool, character_id).await.expect("Select should succeed")
+                    .first()
+                    .map_or(0, |entry| entry.quantity)
+            }
+        };
+        assert_eq!((quantity_of(sender).await, quantity_of(recipient).await), (1, 2));
+
+        // The sender gave away what they promised in the meantime
+        let transfer_id = add_transfer(&database_conn_pool, sender, recipient, rope, 1).await.expect("Insert should succeed");
+        remove_items(&database_conn_pool, sender, rope, 1).await.expect("Update should succeed");
+        assert_eq!(confirm_transfer(&database_conn_pool, transfer_id, true, true).await.expect("Update should succeed"), TransferOutcome::NotEnough(0));
+        assert!(get_transfer(&database_conn_pool, transfer_id).await.expect("Select should succeed").is_none());
+    }
+}
@@ -0,0 +1,97 @@
+use sqlx::{
+    sqlite::{
+        SqlitePool, SqliteQueryResult
+    },
+    Error
+};
+
+use crate::runtime::sql_scripts::models::Item;
+
+pub async fn get_item(database_conn_pool: &SqlitePool, item_id: u64) -> Result<Option<Item>, Error> {
+    sqlx::query_as("SELECT * FROM Items WHERE pk_itemID = $1;")
+        .bind(item_id as i64)
+        .fetch_optional(database_conn_pool)
+        .await
+}
+
+/// Looks an item up by name, ignoring case
+pub async fn get_item_by_name(database_conn_pool: &SqlitePool, item_name: &str) -> Result<Option<Item>, Error> {
+    sqlx::query_as("SELECT * FROM Items WHERE name = $1;")
+        .bind(item_name)
+        .fetch_optional(database_conn_pool)
+        .await
+}
+
+/// Returns the items whose names contain `search`, ordered by name
+pub async fn search_items(database_conn_pool: &SqlitePool, search: &str, limit: u32) -> Result<Vec<Item>, Error> {
+    sqlx::query_as("SELECT * FROM Items WHERE instr(lower(name), lower($1)) > 0 ORDER BY name LIMIT $2;")
+        .bind(search)
+        .bind(limit)
+        .fetch_all(database_conn_pool)
+        .await
+}
+
+#[allow(dead_code)]
+pub async fn add_item(database_conn_pool: &SqlitePool, item_name: &str, weight: f64) -> Result<u64, Error> {
+    sqlx::query_scalar("INSERT INTO Items (name, weight) VALUES ($1, $2) RETURNING pk_itemID;")
+        .bind(item_name)
+        .bind(weight)
+        .fetch_one(database_conn_pool)
+        .await
+}
+
+/// Returns the item with the given name, adding it to the catalogue with `weight` should it not
+/// exist yet. The weight of an item that already exists is left alone
+pub async fn get_or_add_item(database_conn_pool: &SqlitePool, item_name: &str, weight: f64) -> Result<Item, Error> {
+    sqlx::query("INSERT INTO Items (name, weight) VALUES ($1, $2) ON CONFLICT (name) DO NOTHING;")
+        .bind(item_name)
+        .bind(weight)
+        .execute(database_conn_pool)
+        .await?;
+
+    sqlx::query_as("SELECT * FROM Items WHERE name = $1;")
+        .bind(item_name)
+        .fetch_one(database_conn_pool)
+        .await
+}
+
+pub async fn set_weight(database_conn_pool: &SqlitePool, item_id: u64, weight: f64) -> Result<SqliteQueryResult, Error> {
+    sqlx::query("UPDATE Items SET weight = $2 WHERE pk_itemID = $1;")
+        .bind(item_id as i64)
+        .bind(weight)
+        .execute(database_conn_pool)
+        .await
+}
+
+/// Removes an item from the catalogue, which fails while anyone still carries it
+#[allow(dead_code)]
+pub async fn remove_item(database_conn_pool: &SqlitePool, item_id: u64) -> Result<SqliteQueryResult, Error> {
+    sqlx::query("DELETE FROM Items WHERE pk_itemID = $1;")
+        .bind(item_id as i64)
+        .execute(database_conn_pool)
+        .await
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::sql_scripts::open_test_database;
+
+    #[tokio::test]
+    async fn item_names_ignore_case() {
+        let database_conn_pool = open_test_database().await;
+
+        let rope = get_or_add_item(&database_conn_pool, "Rope", 2.5).await.expect("Insert should succeed");
+        let same_rope = get_or_add_item(&database_conn_pool, "rope", 9.0).await.expect("Insert should succeed");
+        assert_eq!(rope, same_rope, "The existing item is returned untouched");
+
+        assert!(add_item(&database_conn_pool, "ROPE", 1.0).await.is_err());
+        add_item(&database_conn_pool, "Lantern", 1.0).await.expect("Insert should succeed");
+
+        let found = search_items(&database_conn_pool, "OP", 25).await.expect("Select should succeed");
+        assert_eq!(found.iter().map(|item| item.name.as_str()).collect::<Vec<_>>(), vec!["Rope"]);
+        assert_eq!(get_item_by_name(&database_conn_pool, "lantern").await.expect("Select should succeed").map(|item| item.weight), Some(1.0));
+    }
+}
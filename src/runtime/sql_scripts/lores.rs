@@ -0,0 +1,130 @@
+use sqlx::{
+    sqlite::{
+        SqlitePool, SqliteQueryResult
+    },
+    Error
+};
+
+use crate::runtime::sql_scripts::models::{
+    CharacterUsedLore, Lore
+};
+
+// --== LORES OF MAGIC ==-- //
+
+    pub async fn get_lores(database_conn_pool: &SqlitePool) -> Result<Vec<Lore>, Error> {
+        sqlx::query_as("SELECT * FROM LoresOfMagic ORDER BY pk_loreID;")
+            .fetch_all(database_conn_pool)
+            .await
+    }
+
+    pub async fn get_lore(database_conn_pool: &SqlitePool, lore_id: u64) -> Result<Option<Lore>, Error> {
+        sqlx::query_as("SELECT * FROM LoresOfMagic WHERE pk_loreID = $1;")
+            .bind(lore_id as i64)
+            .fetch_optional(database_conn_pool)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn add_lore(database_conn_pool: &SqlitePool, lore_name: &str) -> Result<u64, Error> {
+        sqlx::query_scalar("INSERT INTO LoresOfMagic (loreName) VALUES ($1) RETURNING pk_loreID;")
+            .bind(lore_name)
+            .fetch_one(database_conn_pool)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn rename_lore(database_conn_pool: &SqlitePool, lore_id: u64, lore_name: &str) -> Result<SqliteQueryResult, Error> {
+        sqlx::query("UPDATE LoresOfMagic SET loreName = $2 WHERE pk_loreID = $1;")
+            .bind(lore_id as i64)
+            .bind(lore_name)
+            .execute(database_conn_pool)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn remove_lore(database_conn_pool: &SqlitePool, lore_id: u64) -> Result<SqliteQueryResult, Error> {
+        sqlx::query("DELETE FROM LoresOfMagic WHERE pk_loreID = $1;")
+            .bind(lore_id as i64)
+            .execute(database_conn_pool)
+            .await
+    }
+// ==--
+
+// --== CHARACTER USED LORES ==-- //
+
+    pub async fn get_used_lores(database_conn_pool: &SqlitePool, character_id: u64) -> Result<Vec<CharacterUsedLore>, Error> {
+        sqlx::query_as("SELECT * FROM CharacterUsedLores WHERE pk_fk_characterID = $1 ORDER BY pk_fk_loreID;")
+            .bind(character_id as i64)
+            .fetch_all(database_conn_pool)
+            .await
+    }
+
+    /// Sets how many points a character has spent in a lore, overwriting any previous value
+    #[allow(dead_code)]
+    pub async fn set_spent_points(database_conn_pool: &SqlitePool, used_lore: &CharacterUsedLore) -> Result<SqliteQueryResult, Error> {
+        sqlx::query(
+            "INSERT INTO CharacterUsedLores VALUES ( $1, $2, $3 ) \
+            ON CONFLICT (pk_fk_loreID, pk_fk_characterID) DO UPDATE SET spentPoints = excluded.spentPoints;"
+        )
+            .bind(used_lore.lore_id as i64)
+            .bind(used_lore.character_id as i64)
+            .bind(used_lore.spent_points)
+            .execute(database_conn_pool)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn remove_used_lore(database_conn_pool: &SqlitePool, character_id: u64, lore_id: u64) -> Result<SqliteQueryResult, Error> {
+        sqlx::query("DELETE FROM CharacterUsedLores WHERE pk_fk_characterID = $1 AND pk_fk_loreID = $2;")
+            .bind(character_id as i64)
+            .bind(lore_id as i64)
+            .execute(database_conn_pool)
+            .await
+    }
+// ==--
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::sql_scripts::{insert_test_character, open_test_database};
+
+    #[tokio::test]
+    async fn lores_are_seeded_and_editable() {
+        let database_conn_pool = open_test_database().await;
+
+        let lores = get_lores(&database_conn_pool).await.expect("Select should succeed");
+        assert_eq!(lores.len(), 11);
+        assert_eq!(lores[0], Lore { id: 1, name: "The lore of Flame".to_string() });
+
+        let lore_id = add_lore(&database_conn_pool, "The Lore of Ice").await.expect("Insert should succeed");
+        rename_lore(&database_conn_pool, lore_id, "The Lore of Frost").await.expect("Update should succeed");
+        assert_eq!(
+            get_lore(&database_conn_pool, lore_id).await.expect("Select should succeed").map(|lore| lore.name),
+            Some("The Lore of Frost".to_string())
+        );
+
+        remove_lore(&database_conn_pool, lore_id).await.expect("Delete should succeed");
+        assert!(get_lore(&database_conn_pool, lore_id).await.expect("Select should succeed").is_none());
+    }
+
+    #[tokio::test]
+    async fn used_lores_crud() {
+        let database_conn_pool = open_test_database().await;
+        let character_id = insert_test_character(&database_conn_pool, 1, "Cyra").await;
+
+        let mut used_lore = CharacterUsedLore { lore_id: 2, character_id, spent_points: 1 };
+        set_spent_points(&database_conn_pool, &used_lore).await.expect("Insert should succeed");
+        used_lore.spent_points = 3;
+        set_spent_points(&database_conn_pool, &used_lore).await.expect("Upsert should succeed");
+
+        assert_eq!(
+            get_used_lores(&database_conn_pool, character_id).await.expect("Select should succeed"),
+            vec![ used_lore ]
+        );
+
+        remove_used_lore(&database_conn_pool, character_id, 2).await.expect("Delete should succeed");
+        assert!(get_used_lores(&database_conn_pool, character_id).await.expect("Select should succeed").is_empty());
+    }
+}
@@ -0,0 +1,136 @@
+use sqlx::{
+    migrate::MigrateError,
+    sqlite::{SqliteConnection, SqlitePool},
+    Error, FromRow
+};
+
+/// A row whose foreign key points at a row that does not exist, as reported by
+/// `PRAGMA foreign_key_check`
+#[derive(Clone, Debug, PartialEq, FromRow)]
+pub struct OrphanRow {
+    pub table: String,
+    pub rowid: Option<i64>,
+    pub parent: String
+}
+
+/// Applies every pending migration on the given connection.
+///
+/// Foreign keys are switched off for the duration, as SQLite cannot do so inside the transaction
+/// each migration runs in, and migrations that rebuild tables would otherwise trip over the rows
+/// they move. Anything they leave dangling is reported by `get_orphan_rows`
+pub async fn run_migrations(database_connection: &mut SqliteConnection) -> Result<(), MigrateError> {
+    sqlx::query("PRAGMA foreign_keys = OFF;")
+        .execute(&mut *database_connection)
+        .await?;
+
+    let migration_result = sqlx::migrate!("./src/migrations")
+        .run(&mut *database_connection)
+        .await;
+
+    sqlx::query("PRAGMA foreign_keys = ON;")
+        .execute(&mut *database_connection)
+        .await?;
+
+    migration_result
+}
+
+pub async fn get_orphan_rows(database_conn_pool: &SqlitePool) -> Result<Vec<OrphanRow>, Error> {
+    sqlx::query_as("PRAGMA foreign_key_check;")
+        .fetch_all(database_conn_pool)
+        .await
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::sql_scripts::{characters, discord_users, open_test_database};
+    use std::borrow::Cow;
+
+    #[tokio::test]
+    async fn constraints_migration_keeps_existing_data() {
+        let database_conn_pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("In-memory database should always open");
+
+        // Bring the database to the state it was in before the constraints migration, and fill
+        // it with rows the old schema allowed
+        let mut init_migrator = sqlx::migrate!("./src/migrations");
+        init_migrator.migrations = Cow::Owned(init_migrator.migrations[..1].to_vec());
+        init_migrator.run(&database_conn_pool).await.expect("Init script should apply");
+
+        sqlx::query(
+            "INSERT INTO Characters VALUES \
+                (1, 10, 'Fenn', 'Elf', 'N', 'a', 'b', 'c', 'd', 'e', 'f', 'g'), \
+                (2, 10, 'Fenn', 'Elf', 'N', 'a', 'b', 'c', 'd', 'e', 'f', 'g'), \
+                (3, 20, 'Gale', 'Orc', 'N', 'a', 'b', 'c', 'd', 'e', 'f', 'g'), \
+                (4, 10, 'Fenn (2)', 'Elf', 'N', 'a', 'b', 'c', 'd', 'e', 'f', 'g'), \
+                (5, NULL, 'Ash', 'Elf', 'N', 'a', 'b', 'c', 'd', 'e', 'f', 'g'), \
+                (6, 20, 'Gale', 'Orc', 'N', 'a', 'b', 'c', 'd', 'e', 'f', 'g'); \
+            INSERT INTO DiscordUsers VALUES (20, 3); \
+            INSERT INTO CharacterStats VALUES (3, 1, 2, 3, 4, 5, 6); \
+            INSERT INTO SelectedCharacterClasses VALUES (1, 1), (2, 2), (3, 3);"
+        )
+            .execute(&database_conn_pool)
+            .await
+            .expect("Old schema should accept these rows");
+
+        run_migrations(&mut database_conn_pool.acquire().await.expect("Connection should be available"))
+            .await
+            .expect("Remaining migrations should apply");
+
+        // Character 10 had no profile, and their second `Fenn` clashed with the first. Suffixing
+        // it with its ID alone would clash with `Fenn (2)`, so a counter is added
+        let fenns: Vec<String> = characters::get_characters_by_owner(&database_conn_pool, 10)
+            .await
+            .expect("Select should succeed")
+            .into_iter()
+            .map(|character| character.name)
+            .collect();
+        assert_eq!(fenns, vec![ "Fenn", "Fenn (2-2)", "Fenn (2)" ]);
+        let gales: Vec<String> = characters::get_characters_by_owner(&database_conn_pool, 20)
+            .await
+            .expect("Select should succeed")
+            .into_iter()
+            .map(|character| character.name)
+            .collect();
+        assert_eq!(gales, vec![ "Gale", "Gale (6)" ]);
+        assert!(discord_users::get_by_user_id(&database_conn_pool, 10).await.expect("Select should succeed").is_some());
+        assert!(get_orphan_rows(&database_conn_pool).await.expect("Check should succeed").is_empty());
+
+        // Characters without an owner are kept as they are, without making up a profile for them
+        let unowned = characters::get_character(&database_conn_pool, 5)
+            .await
+            .expect("Select should succeed")
+            .expect("Character was kept");
+        assert_eq!((unowned.owner, unowned.name.as_str()), (None, "Ash"));
+        assert!(discord_users::get_by_user_id(&database_conn_pool, 0).await.expect("Select should succeed").is_none());
+
+        // And the rebuilt tables now cascade
+        discord_users::remove_user(&database_conn_pool, 20).await.expect("Delete should succeed");
+        let stats_rows: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM CharacterStats;")
+            .fetch_one(&database_conn_pool)
+            .await
+            .expect("Count should succeed");
+        assert_eq!(stats_rows, 0);
+    }
+
+    #[tokio::test]
+    async fn orphan_rows_are_reported() {
+        let database_conn_pool = open_test_database().await;
+
+        sqlx::query("PRAGMA foreign_keys = OFF;").execute(&database_conn_pool).await.expect("Pragma should succeed");
+        sqlx::query("INSERT INTO CharacterStats VALUES (99, 1, 1, 1, 1, 1, 1);")
+            .execute(&database_conn_pool)
+            .await
+            .expect("Insert should succeed with foreign keys off");
+
+        assert_eq!(
+            get_orphan_rows(&database_conn_pool).await.expect("Check should succeed"),
+            vec![ OrphanRow { table: "CharacterStats".to_string(), rowid: Some(99), parent: "Characters".to_string() } ]
+        );
+    }
+}
@@ -1,3 +1,112 @@
+
+pub mod models;
 pub mod discord_users;
 pub mod characters;
+pub mod character_search;
+pub mod character_transfers;
+pub mod character_stats;
+pub mod character_classes;
+pub mod species;
+pub mod lores;
+pub mod spells;
+pub mod campaigns;
+pub mod encounters;
+pub mod hit_points;
+pub mod items;
+pub mod inventories;
+pub mod wallets;
+pub mod experience;
+pub mod relationships;
+pub mod sessions;
+pub mod templates;
+pub mod maintenance;
+
+
+/// Opens a fresh in-memory database with every migration applied. A single connection is used
+/// as each SQLite `:memory:` connection would otherwise get its own, empty, database
+#[cfg(test)]
+pub async fn open_test_database() -> sqlx::SqlitePool {
+    let database_conn_pool = sqlx::sqlite::SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .expect("In-memory database should always open");
+
+    maintenance::run_migrations(&mut database_conn_pool.acquire().await.expect("Connection should be available"))
+        .await
+        .expect("Migrations should apply to a fresh database");
+
+    database_conn_pool
+}
+
+/// Opens a fresh database file in the temporary directory with every migration applied. Unlike
+/// `open_test_database` the pool holds several connections, as the bot's own does, so that tests
+/// can run queries side by side. The file is named after `name` and the process, and is up to the
+/// test to remove with `remove_test_database_file`
+#[cfg(test)]
+pub async fn open_test_database_file(name: &str) -> (sqlx::SqlitePool, std::path::PathBuf) {
+    let database_path = std::env::temp_dir().join(format!( "magician-{name}-{}.db", std::process::id() ));
+    remove_test_database_file(&database_path);
+
+    let sqlite_connection_options = sqlx::sqlite::SqliteConnectOptions::new()
+        .filename(&database_path)
+        .create_if_missing(true)
+        .foreign_keys(true);
+    let database_conn_pool = sqlx::sqlite::SqlitePoolOptions::new()
+        .max_connections(8)
+        .connect_with(sqlite_connection_options)
+        .await
+        .expect("Temporary database should open");
+
+    maintenance::run_migrations(&mut database_conn_pool.acquire().await.expect("Connection should be available"))
+        .await
+        .expect("Migrations should apply to a fresh database");
+
+    (database_conn_pool, database_path)
+}
+
+/// Removes a database file made by `open_test_database_file`, along with its journal files
+#[cfg(test)]
+pub fn remove_test_database_file(database_path: &std::path::Path) {
+    for suffix in ["", "-wal", "-shm", "-journal"] {
+        let mut file_path = database_path.as_os_str().to_owned();
+        file_path.push(suffix);
+        let _ = std::fs::remove_file(file_path);
+    }
+}
+
+/// A fully filled in character for tests to insert
+#[cfg(test)]
+pub fn test_character(name: &str) -> characters::NewCharacter {
+    characters::NewCharacter {
+        details: models::CharacterDetails {
+            name: name.to_string(),
+            species: "Elf".to_string(),
+            alignment: "Neutral".to_string(),
+            likes: "Tea".to_string(),
+            dislikes: "Rain".to_string(),
+            motivations: "Curiosity".to_string(),
+            companions: "N/A".to_string(),
+            backstory: "Born somewhere".to_string(),
+            appearance: "Tall".to_string(),
+            extras: "N/A".to_string()
+        },
+        class_id: models::CharacterClass::CASTER_ID,
+        status: models::CharacterStatus::Approved,
+        custom_fields: vec![]
+    }
+}
+
+/// Inserts a `test_character` for the given owner, giving them a profile first if need be
+#[cfg(test)]
+pub async fn insert_test_character(database_conn_pool: &sqlx::SqlitePool, owner_id: u64, name: &str) -> u64 {
+    sqlx::query("INSERT OR IGNORE INTO DiscordUsers (pk_discordID) VALUES ($1);")
+        .bind(models::DiscordId(owner_id))
+        .execute(database_conn_pool)
+        .await
+        .expect("Insert should succeed");
 
+    characters::insert_character(database_conn_pool, owner_id, test_character(name))
+        .await
+        .expect("Insert should succeed")
+}
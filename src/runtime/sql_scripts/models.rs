@@ -0,0 +1,766 @@
+// --== CRATE IMPORTS ==-- //
+
+    // SERENITY
+        use serenity::model::id::UserId;
+
+    // SQLX
+        use sqlx::{
+            encode::IsNull,
+            error::BoxDynError,
+            sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef},
+            Decode, Encode, FromRow, Type
+        };
+// ==--
+
+
+
+// --== DISCORD ID ==-- //
+
+    /// A Discord snowflake as it is stored in the database.
+    ///
+    /// SQLite does not implement unsigned integers, so snowflakes are stored in `INTEGER` columns
+    /// as `i64`. Snowflakes never use their highest bit, so the cast is lossless in both
+    /// directions, and this type is the only place where it happens
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    pub struct DiscordId(pub u64);
+
+    impl DiscordId {
+        pub fn get(self) -> u64 {
+            self.0
+        }
+    }
+
+    impl From<u64> for DiscordId {
+        fn from(id: u64) -> DiscordId {
+            DiscordId(id)
+        }
+    }
+
+    impl From<UserId> for DiscordId {
+        fn from(id: UserId) -> DiscordId {
+            DiscordId(id.get())
+        }
+    }
+
+    impl Type<Sqlite> for DiscordId {
+        fn type_info() -> SqliteTypeInfo {
+            <i64 as Type<Sqlite>>::type_info()
+        }
+
+        fn compatible(ty: &SqliteTypeInfo) -> bool {
+            <i64 as Type<Sqlite>>::compatible(ty)
+        }
+    }
+
+    impl<'q> Encode<'q, Sqlite> for DiscordId {
+        fn encode_by_ref(&self, args: &mut Vec<SqliteArgumentValue<'q>>) -> Result<IsNull, BoxDynError> {
+            args.push(SqliteArgumentValue::Int64(self.0 as i64));
+            Ok(IsNull::No)
+        }
+    }
+
+    impl<'r> Decode<'r, Sqlite> for DiscordId {
+        fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+            Ok(DiscordId( <i64 as Decode<Sqlite>>::decode(value)? as u64 ))
+        }
+    }
+// ==--
+
+
+
+// --== TABLE MODELS ==-- //
+
+    /// A row of `DiscordUsers`
+    #[derive(Clone, Debug, PartialEq, FromRow)]
+    pub struct DiscordUser {
+        #[sqlx(rename = "pk_discordID")]
+        pub id: DiscordId,
+        #[sqlx(rename = "fk_selectedCharacter")]
+        pub selected_character: Option<u64>,
+        /// Unix timestamp, unknown for profiles registered before it was recorded
+        #[sqlx(rename = "registeredAt")]
+        pub registered_at: Option<i64>,
+
+        #[sqlx(flatten)]
+        pub preferences: UserPreferences
+    }
+
+    /// The settings a user can change through `/profile settings`
+    #[derive(Clone, Debug, PartialEq, FromRow)]
+    pub struct UserPreferences {
+        #[sqlx(rename = "utcOffsetMinutes")]
+        pub utc_offset_minutes: i32,
+        /// Whether other users may look at this user's characters
+        #[sqlx(rename = "charactersPublic")]
+        pub characters_public: bool,
+        /// Whether the character creation wizard should only be visible to this user
+        #[sqlx(rename = "ephemeralWizard")]
+        pub ephemeral_wizard: bool
+    }
+    impl Default for UserPreferences {
+        fn default() -> UserPreferences {
+            UserPreferences {
+                utc_offset_minutes: 0,
+                characters_public: true,
+                ephemeral_wizard: false
+            }
+        }
+    }
+
+    /// A row of `Characters`
+    #[derive(Clone, Debug, PartialEq, FromRow)]
+    pub struct Character {
+        #[sqlx(rename = "pk_characterID")]
+        pub id: u64,
+        /// `None` for the characters that were already without an owner before owners were
+        /// enforced
+        #[sqlx(rename = "ownerDiscordID")]
+        pub owner: Option<DiscordId>,
+
+        pub name: String,
+        pub species: String,
+        pub alignment: String,
+        pub likes: String,
+        pub dislikes: String,
+        pub motivations: String,
+        pub companions: String,
+        pub backstory: String,
+        pub appearance: String,
+        pub extras: String,
+
+        pub status: CharacterStatus,
+        /// Why staff rejected the character or asked for changes
+        #[sqlx(rename = "reviewNote")]
+        pub review_note: Option<String>
+    }
+    impl Character {
+        /// Mentions the character's owner, for embeds
+        pub fn owner_mention(&self) -> String {
+            self.owner.map_or(String::from("Nobody"), |owner| format!( "<@{}>", owner.get() ))
+        }
+    }
+
+    /// Where a character stands in staff review, stored in `Characters.status`
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Type)]
+    #[sqlx(rename_all = "snake_case")]
+    pub enum CharacterStatus {
+        Pending,
+        Approved,
+        Rejected,
+        ChangesRequested
+    }
+    impl CharacterStatus {
+        pub fn describe(self) -> &'static str {
+            match self {
+                CharacterStatus::Pending          => "awaiting approval",
+                CharacterStatus::Approved         => "approved",
+                CharacterStatus::Rejected         => "rejected",
+                CharacterStatus::ChangesRequested => "waiting on changes"
+            }
+        }
+    }
+
+    /// The `Characters` columns that are written when a character is created or edited, the ID and
+    /// owner are handled by the query
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct CharacterDetails {
+        pub name: String,
+        pub species: String,
+        pub alignment: String,
+        pub likes: String,
+        pub dislikes: String,
+        pub motivations: String,
+        pub companions: String,
+        pub backstory: String,
+        pub appearance: String,
+        pub extras: String
+    }
+
+    /// The columns needed to build the `UserCharactersCache`
+    #[derive(Clone, Debug, PartialEq, FromRow)]
+    pub struct CharacterIdentifier {
+        #[sqlx(rename = "ownerDiscordID")]
+        pub owner: DiscordId,
+        #[sqlx(rename = "pk_characterID")]
+        pub id: u64,
+        pub name: String
+    }
+
+    /// A row of `CharacterStats`
+    #[derive(Clone, Debug, PartialEq, FromRow)]
+    pub struct CharacterStats {
+        #[sqlx(rename = "pk_fk_characterID")]
+        pub character_id: u64,
+
+        pub strength: i64,
+        pub dexterity: i64,
+        pub perception: i64,
+        pub knowledge: i64,
+        pub constitution: i64,
+        pub casting: i64
+    }
+
+    /// A row of `LoresOfMagic`
+    #[derive(Clone, Debug, PartialEq, FromRow)]
+    pub struct Lore {
+        #[sqlx(rename = "pk_loreID")]
+        pub id: u64,
+        #[sqlx(rename = "loreName")]
+        pub name: String
+    }
+
+    /// A row of `CharacterUsedLores`
+    #[derive(Clone, Debug, PartialEq, FromRow)]
+    pub struct CharacterUsedLore {
+        #[sqlx(rename = "pk_fk_loreID")]
+        pub lore_id: u64,
+        #[sqlx(rename = "pk_fk_characterID")]
+        pub character_id: u64,
+        #[sqlx(rename = "spentPoints")]
+        pub spent_points: i64
+    }
+
+    /// A row of `Spells`
+    #[derive(Clone, Debug, PartialEq, FromRow)]
+    pub struct Spell {
+        #[sqlx(rename = "pk_spellID")]
+        pub id: u64,
+        #[sqlx(rename = "fk_loreID")]
+        pub lore_id: u64,
+
+        pub name: String,
+        /// Points a character has to have spent in the lore to know the spell
+        pub tier: i64,
+        pub cost: i64,
+        pub description: String
+    }
+
+    /// A row of `CharacterClasses`
+    #[derive(Clone, Debug, PartialEq, FromRow)]
+    pub struct CharacterClass {
+        #[sqlx(rename = "pk_classID")]
+        pub id: u64,
+        #[sqlx(rename = "className")]
+        pub name: String,
+        pub description: String,
+        /// The highest tier of spell characters of the class can know, `0` for none at all
+        #[sqlx(rename = "castingCap")]
+        pub casting_cap: i64,
+        /// The stat points characters of the class start out with
+        #[sqlx(rename = "statBudget")]
+        pub stat_budget: i64,
+        /// The hit points characters of the class have before their constitution is added
+        #[sqlx(rename = "baseHitPoints")]
+        pub base_hit_points: i64
+    }
+    impl CharacterClass {
+        // IDs of the classes seeded by `001_init_script.sql`. Staff may change or remove them
+        // since, so only tests rely on these
+        #[cfg(test)]
+        pub const MARTIAL_ID: u64     = 1;
+        #[cfg(test)]
+        pub const HALF_CASTER_ID: u64 = 2;
+        #[cfg(test)]
+        pub const CASTER_ID: u64      = 3;
+
+        pub fn can_cast(&self) -> bool {
+            self.casting_cap > 0
+        }
+    }
+
+    /// A class a character has, from `SelectedCharacterClasses`
+    #[derive(Clone, Debug, PartialEq, FromRow)]
+    pub struct SelectedClass {
+        #[sqlx(flatten)]
+        pub class: CharacterClass,
+        /// Where the levels the character gains go, and what sets their hit points
+        #[sqlx(rename = "isPrimary")]
+        pub is_primary: bool,
+        #[sqlx(rename = "classLevel")]
+        pub level: i64
+    }
+
+    /// A row of `Species`
+    #[derive(Clone, Debug, Default, PartialEq, FromRow)]
+    pub struct Species {
+        #[sqlx(rename = "pk_speciesID")]
+        pub id: u64,
+        pub name: String,
+        pub description: String,
+        /// Shown on the sheets of characters of the species
+        pub traits: String,
+
+        // Added to the stats of characters of the species
+        pub strength: i64,
+        pub dexterity: i64,
+        pub perception: i64,
+        pub knowledge: i64,
+        pub constitution: i64,
+        pub casting: i64
+    }
+    impl Species {
+        /// The modifiers that aren't 0, such as `+1 dexterity, -1 casting`
+        pub fn describe_modifiers(&self) -> String {
+            [
+                ("strength", self.strength),
+                ("dexterity", self.dexterity),
+                ("perception", self.perception),
+                ("knowledge", self.knowledge),
+                ("constitution", self.constitution),
+                ("casting", self.casting)
+            ]
+                .into_iter()
+                .filter(|(_, modifier)| *modifier != 0)
+                .map(|(stat, modifier)| format!( "{modifier:+} {stat}" ))
+                .collect::<Vec<String>>()
+                .join(", ")
+        }
+    }
+
+    /// A row of `CharacterTemplates`
+    #[derive(Clone, Debug, PartialEq, FromRow)]
+    pub struct CharacterTemplate {
+        #[sqlx(rename = "pk_templateID")]
+        pub id: u64,
+        /// The server the template is offered in, `None` for those made before templates were
+        /// kept per server
+        #[sqlx(rename = "guildID")]
+        pub guild_id: Option<DiscordId>,
+        pub name: String,
+        #[sqlx(rename = "createdBy")]
+        pub created_by: DiscordId,
+        #[sqlx(rename = "createdAt")]
+        pub created_at: i64,
+
+        #[sqlx(flatten)]
+        pub details: TemplateDetails
+    }
+
+    /// The parts of a character a template fills in, each left `None` is up to the player
+    #[derive(Clone, Debug, Default, PartialEq, FromRow)]
+    pub struct TemplateDetails {
+        pub species: Option<String>,
+        pub alignment: Option<String>,
+        pub likes: Option<String>,
+        pub dislikes: Option<String>,
+        pub motivations: Option<String>,
+        pub companions: Option<String>,
+        pub backstory: Option<String>,
+        pub appearance: Option<String>,
+        pub extras: Option<String>,
+        #[sqlx(rename = "fk_classID")]
+        pub class_id: Option<u64>
+    }
+    impl TemplateDetails {
+        /// The filled in details, keyed as the creation wizard keys them in its cache. The class
+        /// is kept by its ID
+        pub fn wizard_fields(&self) -> Vec<(&'static str, String)> {
+            let text_fields = [
+                ("species", &self.species),
+                ("alignment", &self.alignment),
+                ("likes", &self.likes),
+                ("dislikes", &self.dislikes),
+                ("motivations", &self.motivations),
+                ("companions", &self.companions),
+                ("backstory", &self.backstory),
+                ("appearance", &self.appearance),
+                ("extras", &self.extras)
+            ];
+
+            let mut wizard_fields: Vec<(&'static str, String)> = text_fields.into_iter()
+                .filter_map(|(key, value)| value.clone().map(|value| (key, value)))
+                .collect();
+            if let Some(class_id) = self.class_id {
+                wizard_fields.push(("class", class_id.to_string()));
+            }
+            wizard_fields
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.wizard_fields().is_empty()
+        }
+    }
+
+    /// A row of `Campaigns`
+    #[derive(Clone, Debug, PartialEq, FromRow)]
+    pub struct Campaign {
+        #[sqlx(rename = "pk_campaignID")]
+        pub id: u64,
+        #[sqlx(rename = "guildID")]
+        pub guild_id: DiscordId,
+        #[sqlx(rename = "channelID")]
+        pub channel_id: DiscordId,
+        #[sqlx(rename = "gmDiscordID")]
+        pub gm: DiscordId,
+
+        pub name: String,
+        #[sqlx(rename = "isArchived")]
+        pub is_archived: bool,
+        #[sqlx(rename = "createdAt")]
+        pub created_at: i64
+    }
+
+    /// A character enrolled in a campaign, along with what is needed to show it in a roster
+    #[derive(Clone, Debug, PartialEq, FromRow)]
+    pub struct CampaignRosterEntry {
+        #[sqlx(rename = "pk_characterID")]
+        pub character_id: u64,
+        #[sqlx(rename = "ownerDiscordID")]
+        pub owner: DiscordId,
+        pub name: String,
+        #[sqlx(rename = "joinedAt")]
+        pub joined_at: i64
+    }
+
+    /// A row of `Encounters`
+    #[derive(Clone, Debug, PartialEq, FromRow)]
+    pub struct Encounter {
+        #[sqlx(rename = "pk_encounterID")]
+        pub id: u64,
+        #[sqlx(rename = "channelID")]
+        pub channel_id: DiscordId,
+        #[sqlx(rename = "gmDiscordID")]
+        pub gm: DiscordId,
+        #[sqlx(rename = "trackerMessageID")]
+        pub tracker_message_id: Option<DiscordId>,
+
+        pub round: i64,
+        #[sqlx(rename = "fk_currentEntryID")]
+        pub current_entry: Option<u64>,
+        #[sqlx(rename = "startedAt")]
+        pub started_at: i64
+    }
+
+    /// A row of `EncounterEntries`, along with the owner of the entry's character. NPCs have
+    /// neither a character nor an owner
+    #[derive(Clone, Debug, PartialEq, FromRow)]
+    pub struct EncounterEntry {
+        #[sqlx(rename = "pk_entryID")]
+        pub id: u64,
+        #[sqlx(rename = "fk_characterID")]
+        pub character_id: Option<u64>,
+        #[sqlx(rename = "ownerDiscordID")]
+        pub owner: Option<DiscordId>,
+
+        pub name: String,
+        pub initiative: i64,
+        pub dexterity: i64
+    }
+
+    /// A row of `CharacterHitPoints`
+    #[derive(Clone, Copy, Debug, PartialEq, FromRow)]
+    pub struct CharacterHitPoints {
+        #[sqlx(rename = "pk_fk_characterID")]
+        pub character_id: u64,
+        #[sqlx(rename = "currentHP")]
+        pub current: i64,
+        #[sqlx(rename = "temporaryHP")]
+        pub temporary: i64
+    }
+
+    /// A row of `HitPointHistory`
+    #[derive(Clone, Debug, PartialEq, FromRow)]
+    pub struct HitPointChange {
+        #[sqlx(rename = "pk_changeID")]
+        pub id: u64,
+        #[sqlx(rename = "fk_characterID")]
+        pub character_id: u64,
+        #[sqlx(rename = "changedByDiscordID")]
+        pub changed_by: DiscordId,
+
+        /// One of `damage`, `heal`, `set` or `undo`
+        pub kind: String,
+        pub amount: Option<i64>,
+        #[sqlx(rename = "previousHP")]
+        pub previous_hp: i64,
+        #[sqlx(rename = "previousTemporaryHP")]
+        pub previous_temporary_hp: i64,
+        #[sqlx(rename = "newHP")]
+        pub new_hp: i64,
+        #[sqlx(rename = "newTemporaryHP")]
+        pub new_temporary_hp: i64,
+        #[sqlx(rename = "changedAt")]
+        pub changed_at: i64,
+        #[sqlx(rename = "isUndone")]
+        pub is_undone: bool
+    }
+
+    /// A row of `Items`
+    #[derive(Clone, Debug, PartialEq, FromRow)]
+    pub struct Item {
+        #[sqlx(rename = "pk_itemID")]
+        pub id: u64,
+        pub name: String,
+        pub weight: f64
+    }
+
+    /// A stack of items in a character's inventory, along with what is needed to list it
+    #[derive(Clone, Debug, PartialEq, FromRow)]
+    pub struct InventoryEntry {
+        #[sqlx(rename = "pk_itemID")]
+        pub item_id: u64,
+        pub name: String,
+        /// Weight of a single item
+        pub weight: f64,
+        pub quantity: i64
+    }
+
+    /// A row of `ItemTransfers`
+    #[derive(Clone, Debug, PartialEq, FromRow)]
+    pub struct ItemTransfer {
+        #[sqlx(rename = "pk_transferID")]
+        pub id: u64,
+        #[sqlx(rename = "fk_fromCharacterID")]
+        pub from_character: u64,
+        #[sqlx(rename = "fk_toCharacterID")]
+        pub to_character: u64,
+        #[sqlx(rename = "fk_itemID")]
+        pub item_id: u64,
+        pub quantity: i64,
+
+        #[sqlx(rename = "senderConfirmed")]
+        pub sender_confirmed: bool,
+        #[sqlx(rename = "recipientConfirmed")]
+        pub recipient_confirmed: bool,
+        #[sqlx(rename = "createdAt")]
+        pub created_at: i64
+    }
+
+    /// A row of `CharacterTransfers`
+    #[derive(Clone, Debug, PartialEq, FromRow)]
+    pub struct CharacterTransfer {
+        #[sqlx(rename = "pk_transferID")]
+        pub id: u64,
+        #[sqlx(rename = "fk_characterID")]
+        pub character_id: u64,
+        #[sqlx(rename = "fromDiscordID")]
+        pub from_user: DiscordId,
+        #[sqlx(rename = "toDiscordID")]
+        pub to_user: DiscordId,
+
+        #[sqlx(rename = "createdAt")]
+        pub created_at: i64,
+        /// Unix timestamp after which the offer can no longer be accepted
+        #[sqlx(rename = "expiresAt")]
+        pub expires_at: i64
+    }
+
+    /// A row of `CharacterRelationships`, along with the names of both characters
+    #[derive(Clone, Debug, PartialEq, FromRow)]
+    pub struct Relationship {
+        #[sqlx(rename = "pk_relationshipID")]
+        pub id: u64,
+        #[sqlx(rename = "fk_characterID")]
+        pub character_id: u64,
+        #[sqlx(rename = "characterName")]
+        pub character_name: String,
+        #[sqlx(rename = "fk_otherCharacterID")]
+        pub other_character_id: u64,
+        #[sqlx(rename = "otherName")]
+        pub other_name: String,
+
+        pub kind: RelationshipKind,
+        pub note: Option<String>,
+        #[sqlx(rename = "characterConfirmed")]
+        pub character_confirmed: bool,
+        #[sqlx(rename = "otherConfirmed")]
+        pub other_confirmed: bool,
+        #[sqlx(rename = "createdAt")]
+        pub created_at: i64
+    }
+    impl Relationship {
+        pub fn is_confirmed(&self) -> bool {
+            self.character_confirmed && self.other_confirmed
+        }
+    }
+
+    /// How two characters are related, stored as the lowercase variant name
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Type)]
+    #[sqlx(rename_all = "snake_case")]
+    pub enum RelationshipKind {
+        Ally,
+        Rival,
+        Family,
+        /// The first character mentors the second
+        Mentor
+    }
+    impl RelationshipKind {
+        pub const ALL: [RelationshipKind; 4] = [ RelationshipKind::Ally, RelationshipKind::Rival, RelationshipKind::Family, RelationshipKind::Mentor ];
+
+        /// The name used in options and exports
+        pub fn key(self) -> &'static str {
+            match self {
+                RelationshipKind::Ally   => "ally",
+                RelationshipKind::Rival  => "rival",
+                RelationshipKind::Family => "family",
+                RelationshipKind::Mentor => "mentor"
+            }
+        }
+        pub fn from_key(key: &str) -> Option<RelationshipKind> {
+            RelationshipKind::ALL.into_iter().find(|kind| kind.key() == key)
+        }
+
+        /// How the first character relates to the second, such as `is an ally of`
+        pub fn describe(self) -> &'static str {
+            match self {
+                RelationshipKind::Ally   => "is an ally of",
+                RelationshipKind::Rival  => "is a rival of",
+                RelationshipKind::Family => "is family to",
+                RelationshipKind::Mentor => "mentors"
+            }
+        }
+        /// How the second character relates to the first
+        pub fn describe_reversed(self) -> &'static str {
+            match self {
+                RelationshipKind::Mentor => "is mentored by",
+                kind => kind.describe()
+            }
+        }
+    }
+
+    /// A character matched by a full-text search, with the best matching passage of its text
+    #[derive(Clone, Debug, PartialEq, FromRow)]
+    pub struct CharacterSearchResult {
+        #[sqlx(rename = "pk_characterID")]
+        pub character_id: u64,
+        #[sqlx(rename = "ownerDiscordID")]
+        pub owner: DiscordId,
+        pub name: String,
+        /// The matching passage, with the matched terms wrapped in `**`
+        pub snippet: String
+    }
+
+    /// A row of `WalletLedger`
+    #[derive(Clone, Debug, PartialEq, FromRow)]
+    pub struct LedgerEntry {
+        #[sqlx(rename = "pk_entryID")]
+        pub id: u64,
+        #[sqlx(rename = "characterID")]
+        pub character_id: u64,
+        #[sqlx(rename = "counterpartyCharacterID")]
+        pub counterparty_character_id: Option<u64>,
+        #[sqlx(rename = "actorDiscordID")]
+        pub actor: DiscordId,
+
+        /// Either `grant` or `payment`
+        pub kind: String,
+        pub amount: i64,
+        #[sqlx(rename = "balanceAfter")]
+        pub balance_after: i64,
+        pub note: Option<String>,
+        #[sqlx(rename = "createdAt")]
+        pub created_at: i64
+    }
+
+    /// The progression columns of `Characters`
+    #[derive(Clone, Copy, Debug, PartialEq, FromRow)]
+    pub struct CharacterProgression {
+        #[sqlx(rename = "pk_characterID")]
+        pub character_id: u64,
+
+        pub experience: i64,
+        pub level: i64,
+        #[sqlx(rename = "unspentStatPoints")]
+        pub unspent_stat_points: i64,
+        #[sqlx(rename = "unspentLorePoints")]
+        pub unspent_lore_points: i64
+    }
+
+    /// A row of `Sessions`
+    #[derive(Clone, Debug, PartialEq, FromRow)]
+    pub struct Session {
+        #[sqlx(rename = "pk_sessionID")]
+        pub id: u64,
+        #[sqlx(rename = "guildID")]
+        pub guild_id: DiscordId,
+        #[sqlx(rename = "channelID")]
+        pub channel_id: DiscordId,
+        #[sqlx(rename = "messageID")]
+        pub message_id: Option<DiscordId>,
+        #[sqlx(rename = "organiserDiscordID")]
+        pub organiser: DiscordId,
+
+        pub title: String,
+        #[sqlx(rename = "startsAt")]
+        pub starts_at: i64,
+        #[sqlx(rename = "isCancelled")]
+        pub is_cancelled: bool,
+        #[sqlx(rename = "createdAt")]
+        pub created_at: i64
+    }
+
+    /// A row of `SessionRsvps`
+    #[derive(Clone, Copy, Debug, PartialEq, FromRow)]
+    pub struct SessionRsvp {
+        #[sqlx(rename = "pk_discordID")]
+        pub user: DiscordId,
+        pub response: RsvpResponse,
+        #[sqlx(rename = "respondedAt")]
+        pub responded_at: i64
+    }
+
+    /// What a user answered to a session, stored as the lowercase variant name
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Type)]
+    #[sqlx(rename_all = "snake_case")]
+    pub enum RsvpResponse {
+        Attending,
+        Maybe,
+        Declining
+    }
+    impl RsvpResponse {
+        pub const ALL: [RsvpResponse; 3] = [ RsvpResponse::Attending, RsvpResponse::Maybe, RsvpResponse::Declining ];
+
+        /// The name used in button custom IDs
+        pub fn key(self) -> &'static str {
+            match self {
+                RsvpResponse::Attending => "attending",
+                RsvpResponse::Maybe     => "maybe",
+                RsvpResponse::Declining => "declining"
+            }
+        }
+        pub fn from_key(key: &str) -> Option<RsvpResponse> {
+            RsvpResponse::ALL.into_iter().find(|response| response.key() == key)
+        }
+
+        pub fn label(self) -> &'static str {
+            match self {
+                RsvpResponse::Attending => "Attending",
+                RsvpResponse::Maybe     => "Maybe",
+                RsvpResponse::Declining => "Declining"
+            }
+        }
+    }
+
+    /// A reminder that is due, along with the session it is for
+    #[derive(Clone, Debug, PartialEq, FromRow)]
+    pub struct DueReminder {
+        #[sqlx(rename = "pk_offsetMinutes")]
+        pub offset_minutes: i64,
+        #[sqlx(flatten)]
+        pub session: Session
+    }
+// ==--
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::sql_scripts::open_test_database;
+
+    #[tokio::test]
+    async fn discord_id_survives_a_round_trip() {
+        let database_conn_pool = open_test_database().await;
+
+        // Largest value a snowflake can take, and one that exercises the upper 32 bits
+        for id in [ DiscordId(u64::MAX >> 1), DiscordId(1_234_567_890_123_456_789) ] {
+            let read_back: DiscordId = sqlx::query_scalar("SELECT $1;")
+                .bind(id)
+                .fetch_one(&database_conn_pool)
+                .await
+                .expect("Select should succeed");
+
+            assert_eq!(read_back, id);
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use sqlx::{
+    sqlite::{
+        SqlitePool, SqliteQueryResult
+    },
+    Error
+};
+
+use crate::runtime::sql_scripts::models::{
+    Relationship, RelationshipKind
+};
+
+/// Every relationship query selects the row along with both characters' names
+const SELECT_RELATIONSHIPS: &str =
+    "SELECT CharacterRelationships.*, Characters.name AS characterName, OtherCharacters.name AS otherName \
+    FROM CharacterRelationships \
+    INNER JOIN Characters ON Characters.pk_characterID = CharacterRelationships.fk_characterID \
+    INNER JOIN Characters AS OtherCharacters ON OtherCharacters.pk_characterID = CharacterRelationships.fk_otherCharacterID";
+
+/// How confirming a relationship went
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConfirmOutcome {
+    /// The relationship still waits on the other owner
+    AwaitingConfirmation,
+    /// Both owners have now confirmed
+    Confirmed,
+    /// The relationship no longer exists
+    Missing
+}
+
+// --== RELATIONSHIPS ==-- //
+
+    /// Proposes a relationship. The sides whose owner proposed it start out confirmed
+    pub async fn add_relationship(database_conn_pool: &SqlitePool, character_id: u64, other_character_id: u64, kind: RelationshipKind, note: Option<&str>, character_confirmed: bool, other_confirmed: bool) -> Result<u64, Error> {
+        sqlx::query_scalar(
+            "INSERT INTO CharacterRelationships ( \
+                fk_characterID, fk_otherCharacterID, kind, note, characterConfirmed, otherConfirmed, createdAt \
+            ) \
+            VALUES ( $1, $2, $3, $4, $5, $6, unixepoch() ) \
+            RETURNING pk_relationshipID;"
+        )
+            .bind(character_id as i64)
+            .bind(other_character_id as i64)
+            .bind(kind)
+            .bind(note)
+            .bind(character_confirmed)
+            .bind(other_confirmed)
+            .fetch_one(database_conn_pool)
+            .await
+    }
+
+    pub async fn get_relationship(database_conn_pool: &SqlitePool, relationship_id: u64) -> Result<Option<Relationship>, Error> {
+        sqlx::query_as(&format!( "{SELECT_RELATIONSHIPS} WHERE pk_relationshipID = $1;" ))
+            .bind(relationship_id as i64)
+            .fetch_optional(database_conn_pool)
+            .await
+    }
+
+    /// Returns every relationship a character is part of, confirmed or not, oldest first
+    pub async fn get_character_relationships(database_conn_pool: &SqlitePool, character_id: u64) -> Result<Vec<Relationship>, Error> {
+        sqlx::query_as(&format!(
+            "{SELECT_RELATIONSHIPS} \
+            WHERE fk_characterID = $1 OR fk_otherCharacterID = $1 \
+            ORDER BY pk_relationshipID;"
+        ))
+            .bind(character_id as i64)
+            .fetch_all(database_conn_pool)
+            .await
+    }
+
+    /// Returns the confirmed relationships between characters who both take part in a campaign
+    pub async fn get_campaign_relationships(database_conn_pool: &SqlitePool, campaign_id: u64) -> Result<Vec<Relationship>, Error> {
+        sqlx::query_as(&format!(
+            "{SELECT_RELATIONSHIPS} \
+            WHERE characterConfirmed = TRUE AND otherConfirmed = TRUE \
+                AND fk_characterID IN (SELECT pk_fk_characterID FROM CampaignCharacters WHERE pk_fk_campaignID = $1) \
+                AND fk_otherCharacterID IN (SELECT pk_fk_characterID FROM CampaignCharacters WHERE pk_fk_campaignID = $1) \
+            ORDER BY pk_relationshipID;"
+        ))
+            .bind(campaign_id as i64)
+            .fetch_all(database_conn_pool)
+            .await
+    }
+
+    /// Confirms a relationship on behalf of either or both sides
+    pub async fn confirm_relationship(database_conn_pool: &SqlitePool, relationship_id: u64, as_character: bool, as_other: bool) -> Result<ConfirmOutcome, Error> {
+        let confirmations: Option<(bool, bool)> = sqlx::query_as(
+            "UPDATE CharacterRelationships SET \
+                characterConfirmed = characterConfirmed OR $2, \
+                otherConfirmed = otherConfirmed OR $3 \
+            WHERE pk_relationshipID = $1 \
+            RETURNING characterConfirmed, otherConfirmed;"
+        )
+            .bind(relationship_id as i64)
+            .bind(as_character)
+            .bind(as_other)
+            .fetch_optional(database_conn_pool)
+            .await?;
+
+        Ok(match confirmations {
+            Some((true, true)) => ConfirmOutcome::Confirmed,
+            Some(_) => ConfirmOutcome::AwaitingConfirmation,
+            None => ConfirmOutcome::Missing
+        })
+    }
+
+    pub async fn remove_relationship(database_conn_pool: &SqlitePool, relationship_id: u64) -> Result<SqliteQueryResult, Error> {
+        sqlx::query("DELETE FROM CharacterRelationships WHERE pk_relationshipID = $1;")
+            .bind(relationship_id as i64)
+            .execute(database_conn_pool)
+            .await
+    }
+
+    /// Removes the relationships between two characters, whichever way round they were made. Only
+    /// those of `kind` go when one is given
+    pub async fn remove_between(database_conn_pool: &SqlitePool, character_id: u64, other_character_id: u64, kind: Option<RelationshipKind>) -> Result<u64, Error> {
+        let query_result = sqlx::query(
+            "DELETE FROM CharacterRelationships \
+            WHERE MIN(fk_characterID, fk_otherCharacterID) = MIN($1, $2) \
+                AND MAX(fk_characterID, fk_otherCharacterID) = MAX($1, $2) \
+                AND ($3 IS NULL OR kind = $3);"
+        )
+            .bind(character_id as i64)
+            .bind(other_character_id as i64)
+            .bind(kind)
+            .execute(database_conn_pool)
+            .await?;
+
+        Ok(query_result.rows_affected())
+    }
+// ==--
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::sql_scripts::{campaigns, insert_test_character, open_test_database};
+
+    #[tokio::test]
+    async fn relationships_need_both_owners() {
+        let database_conn_pool = open_test_database().await;
+        let hale = insert_test_character(&database_conn_pool, 1, "Hale").await;
+        let mira = insert_test_character(&database_conn_pool, 2, "Mira").await;
+
+        let relationship_id = add_relationship(&database_conn_pool, hale, mira, RelationshipKind::Mentor, Some("Taught her the blade"), true, false)
+            .await
+            .expect("Insert should succeed");
+
+        // The same pair can't be linked the same way twice, not even the other way round
+        let duplicate = add_relationship(&database_conn_pool, mira, hale, RelationshipKind::Mentor, None, true, false).await;
+        assert!(duplicate.is_err_and(|query_err| query_err.as_database_error().is_some_and(|db_err| db_err.is_unique_violation())));
+        add_relationship(&database_conn_pool, mira, hale, RelationshipKind::Rival, None, true, false).await.expect("Insert should succeed");
+
+        let relationship = get_relationship(&database_conn_pool, relationship_id)
+            .await
+            .expect("Select should succeed")
+            .expect("Relationship should exist");
+        assert_eq!((relationship.character_name.as_str(), relationship.other_name.as_str()), ("Hale", "Mira"));
+        assert!(!relationship.is_confirmed());
+
+        assert_eq!(confirm_relationship(&database_conn_pool, relationship_id, true, false).await.expect("Update should succeed"), ConfirmOutcome::AwaitingConfirmation);
+        assert_eq!(confirm_relationship(&database_conn_pool, relationship_id, false, true).await.expect("Update should succeed"), ConfirmOutcome::Confirmed);
+        assert_eq!(get_character_relationships(&database_conn_pool, mira).await.expect("Select should succeed").len(), 2);
+
+        // Only confirmed relationships within the campaign make up its graph
+        let campaign_id = campaigns::add_campaign(&database_conn_pool, 10, 20, 1, "The Long Road").await.expect("Insert should succeed");
+        campaigns::add_character(&database_conn_pool, campaign_id, hale).await.expect("Insert should succeed");
+        assert!(get_campaign_relationships(&database_conn_pool, campaign_id).await.expect("Select should succeed").is_empty());
+        campaigns::add_character(&database_conn_pool, campaign_id, mira).await.expect("Insert should succeed");
+        let campaign_relationships = get_campaign_relationships(&database_conn_pool, campaign_id).await.expect("Select should succeed");
+        assert_eq!(campaign_relationships.iter().map(|relationship| relationship.kind).collect::<Vec<_>>(), vec![RelationshipKind::Mentor]);
+
+        assert_eq!(remove_between(&database_conn_pool, mira, hale, None).await.expect("Delete should succeed"), 2);
+        assert_eq!(confirm_relationship(&database_conn_pool, relationship_id, true, true).await.expect("Update should succeed"), ConfirmOutcome::Missing);
+    }
+}
@@ -0,0 +1,220 @@
+use sqlx::{
+    sqlite::{
+        SqlitePool, SqliteQueryResult
+    },
+    Error
+};
+
+use crate::runtime::sql_scripts::models::{
+    DiscordId, DueReminder, RsvpResponse, Session, SessionRsvp
+};
+
+// --== SESSIONS ==-- //
+
+    /// Adds a session, along with a reminder `offset` minutes before it starts for each of
+    /// `reminder_offsets`. Reminders that would already be due are left out
+    pub async fn add_session(database_conn_pool: &SqlitePool, guild_id: u64, channel_id: u64, organiser_id: u64, title: &str, starts_at: i64, reminder_offsets: &[i64]) -> Result<u64, Error> {
+        let mut transaction = database_conn_pool.begin().await?;
+
+        let session_id: u64 = sqlx::query_scalar(
+            "INSERT INTO Sessions (guildID, channelID, organiserDiscordID, title, startsAt, createdAt) \
+            VALUES ($1, $2, $3, $4, $5, unixepoch()) \
+            RETURNING pk_sessionID;"
+        )
+            .bind(DiscordId(guild_id))
+            .bind(DiscordId(channel_id))
+            .bind(DiscordId(organiser_id))
+            .bind(title)
+            .bind(starts_at)
+            .fetch_one(&mut *transaction)
+            .await?;
+
+        for offset_minutes in reminder_offsets {
+            sqlx::query(
+                "INSERT INTO SessionReminders (pk_fk_sessionID, pk_offsetMinutes, remindAt) \
+                SELECT $1, $2, $3 \
+                WHERE $3 > unixepoch() \
+                ON CONFLICT DO NOTHING;"
+            )
+                .bind(session_id as i64)
+                .bind(offset_minutes)
+                .bind(starts_at - offset_minutes * 60)
+                .execute(&mut *transaction)
+                .await?;
+        }
+
+        transaction.commit().await?;
+        Ok(session_id)
+    }
+
+    pub async fn set_message(database_conn_pool: &SqlitePool, session_id: u64, message_id: u64) -> Result<SqliteQueryResult, Error> {
+        sqlx::query("UPDATE Sessions SET messageID = $2 WHERE pk_sessionID = $1;")
+            .bind(session_id as i64)
+            .bind(DiscordId(message_id))
+            .execute(database_conn_pool)
+            .await
+    }
+
+    pub async fn get_session(database_conn_pool: &SqlitePool, session_id: u64) -> Result<Option<Session>, Error> {
+        sqlx::query_as("SELECT * FROM Sessions WHERE pk_sessionID = $1;")
+            .bind(session_id as i64)
+            .fetch_optional(database_conn_pool)
+            .await
+    }
+
+    /// Returns the sessions of a guild that have yet to start and aren't cancelled, soonest first
+    pub async fn get_upcoming(database_conn_pool: &SqlitePool, guild_id: u64) -> Result<Vec<Session>, Error> {
+        sqlx::query_as(
+            "SELECT * FROM Sessions \
+            WHERE guildID = $1 AND isCancelled = FALSE AND startsAt > unixepoch() \
+            ORDER BY startsAt;"
+        )
+            .bind(DiscordId(guild_id))
+            .fetch_all(database_conn_pool)
+            .await
+    }
+
+    /// Cancels a session, returning whether it was still on
+    pub async fn cancel_session(database_conn_pool: &SqlitePool, session_id: u64) -> Result<bool, Error> {
+        let query_result = sqlx::query("UPDATE Sessions SET isCancelled = TRUE WHERE pk_sessionID = $1 AND isCancelled = FALSE;")
+            .bind(session_id as i64)
+            .execute(database_conn_pool)
+            .await?;
+
+        Ok(query_result.rows_affected() == 1)
+    }
+
+    pub async fn remove_session(database_conn_pool: &SqlitePool, session_id: u64) -> Result<SqliteQueryResult, Error> {
+        sqlx::query("DELETE FROM Sessions WHERE pk_sessionID = $1;")
+            .bind(session_id as i64)
+            .execute(database_conn_pool)
+            .await
+    }
+// ==--
+
+// --== RSVPS ==-- //
+
+    /// Records a user's answer to a session, replacing any earlier one
+    pub async fn set_rsvp(database_conn_pool: &SqlitePool, session_id: u64, user_id: u64, response: RsvpResponse) -> Result<SqliteQueryResult, Error> {
+        sqlx::query(
+            "INSERT INTO SessionRsvps VALUES ($1, $2, $3, unixepoch()) \
+            ON CONFLICT DO UPDATE SET response = excluded.response, respondedAt = excluded.respondedAt;"
+        )
+            .bind(session_id as i64)
+            .bind(DiscordId(user_id))
+            .bind(response)
+            .execute(database_conn_pool)
+            .await
+    }
+
+    /// Returns every answer to a session, oldest first
+    pub async fn get_rsvps(database_conn_pool: &SqlitePool, session_id: u64) -> Result<Vec<SessionRsvp>, Error> {
+        sqlx::query_as("SELECT * FROM SessionRsvps WHERE pk_fk_sessionID = $1 ORDER BY respondedAt, pk_discordID;")
+            .bind(session_id as i64)
+            .fetch_all(database_conn_pool)
+            .await
+    }
+// ==--
+
+// --== REMINDERS ==-- //
+
+    /// Returns the reminders that are due and yet to be sent, skipping those of cancelled sessions
+    pub async fn get_due_reminders(database_conn_pool: &SqlitePool) -> Result<Vec<DueReminder>, Error> {
+        sqlx::query_as(
+            "SELECT SessionReminders.pk_offsetMinutes, Sessions.* FROM SessionReminders \
+            INNER JOIN Sessions ON Sessions.pk_sessionID = SessionReminders.pk_fk_sessionID \
+            WHERE SessionReminders.isSent = FALSE \
+                AND SessionReminders.remindAt <= unixepoch() \
+                AND Sessions.isCancelled = FALSE \
+            ORDER BY SessionReminders.remindAt;"
+        )
+            .fetch_all(database_conn_pool)
+            .await
+    }
+
+    /// Marks a reminder as sent, returning whether it hadn't been already. Whoever gets `true`
+    /// back is the one to send it
+    pub async fn mark_reminder_sent(database_conn_pool: &SqlitePool, session_id: u64, offset_minutes: i64) -> Result<bool, Error> {
+        let query_result = sqlx::query(
+            "UPDATE SessionReminders SET isSent = TRUE \
+            WHERE pk_fk_sessionID = $1 AND pk_offsetMinutes = $2 AND isSent = FALSE;"
+        )
+            .bind(session_id as i64)
+            .bind(offset_minutes)
+            .execute(database_conn_pool)
+            .await?;
+
+        Ok(query_result.rows_affected() == 1)
+    }
+// ==--
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::sql_scripts::open_test_database;
+
+    async fn now(database_conn_pool: &SqlitePool) -> i64 {
+        sqlx::query_scalar("SELECT unixepoch();")
+            .fetch_one(database_conn_pool)
+            .await
+            .expect("Select should succeed")
+    }
+
+    #[tokio::test]
+    async fn rsvps_replace_earlier_answers() {
+        let database_conn_pool = open_test_database().await;
+        let starts_at = now(&database_conn_pool).await + 3600;
+        let session_id = add_session(&database_conn_pool, 1, 2, 3, "Into the Barrow", starts_at, &[]).await.expect("Insert should succeed");
+
+        set_rsvp(&database_conn_pool, session_id, 10, RsvpResponse::Maybe).await.expect("Upsert should succeed");
+        set_rsvp(&database_conn_pool, session_id, 11, RsvpResponse::Declining).await.expect("Upsert should succeed");
+        set_rsvp(&database_conn_pool, session_id, 10, RsvpResponse::Attending).await.expect("Upsert should succeed");
+
+        let responses: Vec<(DiscordId, RsvpResponse)> = get_rsvps(&database_conn_pool, session_id)
+            .await
+            .expect("Select should succeed")
+            .into_iter()
+            .map(|rsvp| (rsvp.user, rsvp.response))
+            .collect();
+        assert_eq!(responses.len(), 2);
+        assert!(responses.contains(&(DiscordId(10), RsvpResponse::Attending)));
+        assert!(responses.contains(&(DiscordId(11), RsvpResponse::Declining)));
+
+        assert_eq!(get_upcoming(&database_conn_pool, 1).await.expect("Select should succeed").len(), 1);
+        assert!(cancel_session(&database_conn_pool, session_id).await.expect("Update should succeed"));
+        assert!(!cancel_session(&database_conn_pool, session_id).await.expect("Update should succeed"));
+        assert!(get_upcoming(&database_conn_pool, 1).await.expect("Select should succeed").is_empty());
+    }
+
+    #[tokio::test]
+    async fn reminders_are_sent_once() {
+        let database_conn_pool = open_test_database().await;
+        // An hour and a half out, so the hour reminder is still to come and the others already passed
+        let starts_at = now(&database_conn_pool).await + 5400;
+        let session_id = add_session(&database_conn_pool, 1, 2, 3, "Into the Barrow", starts_at, &[1440, 60, 120]).await.expect("Insert should succeed");
+        assert!(get_due_reminders(&database_conn_pool).await.expect("Select should succeed").is_empty());
+
+        let reminder_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM SessionReminders;")
+            .fetch_one(&database_conn_pool)
+            .await
+            .expect("Count should succeed");
+        assert_eq!(reminder_count, 1);
+
+        // Bring the hour reminder forward, as if the bot had been offline when it came due
+        sqlx::query("UPDATE SessionReminders SET remindAt = 0;").execute(&database_conn_pool).await.expect("Update should succeed");
+        let due_reminders = get_due_reminders(&database_conn_pool).await.expect("Select should succeed");
+        assert_eq!(due_reminders.iter().map(|reminder| reminder.offset_minutes).collect::<Vec<_>>(), vec![60]);
+        assert_eq!(due_reminders[0].session.id, session_id);
+
+        assert!(mark_reminder_sent(&database_conn_pool, session_id, 60).await.expect("Update should succeed"));
+        assert!(!mark_reminder_sent(&database_conn_pool, session_id, 60).await.expect("Update should succeed"));
+        assert!(get_due_reminders(&database_conn_pool).await.expect("Select should succeed").is_empty());
+
+        // Reminders of cancelled sessions never come due
+        sqlx::query("UPDATE SessionReminders SET isSent = FALSE;").execute(&database_conn_pool).await.expect("Update should succeed");
+        cancel_session(&database_conn_pool, session_id).await.expect("Update should succeed");
+        assert!(get_due_reminders(&database_conn_pool).await.expect("Select should succeed").is_empty());
+    }
+}
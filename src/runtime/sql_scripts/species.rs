@@ -0,0 +1,157 @@
+use sqlx::{
+    sqlite::{
+        SqlitePool, SqliteQueryResult
+    },
+    Error
+};
+
+use crate::runtime::sql_scripts::models::Species;
+
+// --== SPECIES ==-- //
+
+    /// Returns every registered species, by name
+    pub async fn get_species_list(database_conn_pool: &SqlitePool) -> Result<Vec<Species>, Error> {
+        sqlx::query_as("SELECT * FROM Species ORDER BY name;")
+            .fetch_all(database_conn_pool)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_species(database_conn_pool: &SqlitePool, species_id: u64) -> Result<Option<Species>, Error> {
+        sqlx::query_as("SELECT * FROM Species WHERE pk_speciesID = $1;")
+            .bind(species_id as i64)
+            .fetch_optional(database_conn_pool)
+            .await
+    }
+
+    /// The registered species of a character, if theirs isn't one of their own making
+    pub async fn get_character_species(database_conn_pool: &SqlitePool, character_id: u64) -> Result<Option<Species>, Error> {
+        sqlx::query_as(
+            "SELECT Species.* FROM Characters \
+            JOIN Species ON Species.pk_speciesID = Characters.fk_speciesID \
+            WHERE Characters.pk_characterID = $1;"
+        )
+            .bind(character_id as i64)
+            .fetch_optional(database_conn_pool)
+            .await
+    }
+
+    /// Adds a species, ignoring the ID it's given. Names are unique regardless of case, and
+    /// existing characters whose species goes by the name are linked to it
+    pub async fn add_species(database_conn_pool: &SqlitePool, species: &Species) -> Result<u64, Error> {
+        let mut transaction = database_conn_pool.begin().await?;
+
+        let species_id: u64 = sqlx::query_scalar(
+            "INSERT INTO Species ( \
+                name, description, traits, strength, dexterity, perception, knowledge, constitution, casting \
+            ) \
+            VALUES ( $1, $2, $3, $4, $5, $6, $7, $8, $9 ) \
+            RETURNING pk_speciesID;"
+        )
+            .bind(&species.name)
+            .bind(&species.description)
+            .bind(&species.traits)
+            .bind(species.strength)
+            .bind(species.dexterity)
+            .bind(species.perception)
+            .bind(species.knowledge)
+            .bind(species.constitution)
+            .bind(species.casting)
+            .fetch_one(&mut *transaction)
+            .await?;
+
+        sqlx::query("UPDATE Characters SET fk_speciesID = $1 WHERE fk_speciesID IS NULL AND species = $2 COLLATE NOCASE;")
+            .bind(species_id as i64)
+            .bind(&species.name)
+            .execute(&mut *transaction)
+            .await?;
+
+        transaction.commit().await?;
+        Ok(species_id)
+    }
+
+    /// Overwrites everything but the ID of the species with that ID. The characters of the species
+    /// take on a new name along with it
+    pub async fn update_species(database_conn_pool: &SqlitePool, species: &Species) -> Result<SqliteQueryResult, Error> {
+        let mut transaction = database_conn_pool.begin().await?;
+
+        let updated = sqlx::query(
+            "UPDATE Species SET \
+                name = $2, description = $3, traits = $4, \
+                strength = $5, dexterity = $6, perception = $7, knowledge = $8, constitution = $9, casting = $10 \
+            WHERE pk_speciesID = $1;"
+        )
+            .bind(species.id as i64)
+            .bind(&species.name)
+            .bind(&species.description)
+            .bind(&species.traits)
+            .bind(species.strength)
+            .bind(species.dexterity)
+            .bind(species.perception)
+            .bind(species.knowledge)
+            .bind(species.constitution)
+            .bind(species.casting)
+            .execute(&mut *transaction)
+            .await?;
+
+        sqlx::query("UPDATE Characters SET species = $2 WHERE fk_speciesID = $1;")
+            .bind(species.id as i64)
+            .bind(&species.name)
+            .execute(&mut *transaction)
+            .await?;
+
+        transaction.commit().await?;
+        Ok(updated)
+    }
+
+    /// Characters of the species keep its name as one of their own making
+    pub async fn remove_species(database_conn_pool: &SqlitePool, species_id: u64) -> Result<SqliteQueryResult, Error> {
+        sqlx::query("DELETE FROM Species WHERE pk_speciesID = $1;")
+            .bind(species_id as i64)
+            .execute(database_conn_pool)
+            .await
+    }
+// ==--
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::sql_scripts::{
+        character_stats, characters, hit_points, insert_test_character, inventories, models::CharacterStats, open_test_database
+    };
+
+    #[tokio::test]
+    async fn species_modify_effective_stats() {
+        let database_conn_pool = open_test_database().await;
+
+        // Characters whose species already goes by the name are linked once it's registered
+        let character_id = insert_test_character(&database_conn_pool, 1, "Lirael").await;
+        let stats = CharacterStats { character_id, strength: 1, dexterity: 1, perception: 1, knowledge: 1, constitution: 1, casting: 1 };
+        character_stats::set_stats(&database_conn_pool, &stats).await.expect("Insert should succeed");
+
+        let mut elf = Species { name: "elf".to_string(), strength: 1, dexterity: 2, constitution: -1, ..Default::default() };
+        elf.id = add_species(&database_conn_pool, &elf).await.expect("Insert should succeed");
+        assert!(add_species(&database_conn_pool, &Species { name: "ELF".to_string(), ..Default::default() }).await.is_err());
+        assert_eq!(elf.describe_modifiers(), "+1 strength, +2 dexterity, -1 constitution");
+        assert_eq!(get_character_species(&database_conn_pool, character_id).await.expect("Select should succeed"), Some(elf.clone()));
+
+        let effective_stats = character_stats::get_effective_stats(&database_conn_pool, character_id).await.expect("Select should succeed");
+        assert_eq!(effective_stats, Some(CharacterStats { strength: 2, dexterity: 3, constitution: 0, ..stats.clone() }));
+        // Which is what hit points and carry limits go by
+        assert_eq!(hit_points::get_max_hit_points(&database_conn_pool, character_id).await.expect("Select should succeed"), 8);
+        assert_eq!(inventories::get_encumbrance(&database_conn_pool, character_id).await.expect("Select should succeed").1, 70.0);
+
+        // Renaming the species renames it on sheets, removing it leaves the raw stats
+        elf.name = "High Elf".to_string();
+        update_species(&database_conn_pool, &elf).await.expect("Update should succeed");
+        let character = characters::get_character(&database_conn_pool, character_id).await.expect("Select should succeed").expect("Character exists");
+        assert_eq!(character.species, "High Elf");
+
+        remove_species(&database_conn_pool, elf.id).await.expect("Delete should succeed");
+        let effective_stats = character_stats::get_effective_stats(&database_conn_pool, character_id).await.expect("Select should succeed");
+        assert_eq!(effective_stats, Some(stats));
+        assert_eq!(characters::get_character(&database_conn_pool, character_id).await.expect("Select should succeed").map(|character| character.species), Some("High Elf".to_string()));
+    }
+}
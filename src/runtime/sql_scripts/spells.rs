@@ -0,0 +1,209 @@
+use sqlx::{
+    sqlite::{
+        SqlitePool, SqliteQueryResult
+    },
+    Error
+};
+use toml::{Table, Value};
+
+use crate::runtime::sql_scripts::models::{
+    Spell
+};
+
+/// The spell catalogue shipped with the bot, seeded into `Spells` at startup
+pub const SPELL_DATA: &str = include_str!("../../data/spells.toml");
+
+/// The `Spells` columns read from the spell catalogue, the ID is handled by the query
+#[derive(Clone, Debug, PartialEq)]
+pub struct NewSpell {
+    pub lore_id: u64,
+    pub name: String,
+    pub tier: i64,
+    pub cost: i64,
+    pub description: String
+}
+
+/// Reads the `[[spell]]` entries of a spell catalogue, see `src/data/spells.toml` for their fields
+pub fn parse_spell_data( spell_data: &str ) -> Result<Vec<NewSpell>, String> {
+    let spell_table = spell_data.parse::<Table>().map_err(|why| why.to_string())?;
+
+    let spell_entries = match spell_table.get("spell") {
+        Some(Value::Array(spell_entries)) => spell_entries,
+        None => return Ok(vec![]),
+        Some(_) => return Err(String::from("`spell` has to be a list of `[[spell]]` entries"))
+    };
+
+    spell_entries.iter()
+        .enumerate()
+        .map(|(idx, spell_entry)| {
+            let integer_field = |field: &str| match spell_entry.get(field) {
+                Some(Value::Integer(value)) => Ok(*value),
+                _ => Err(format!( "Spell #{} is missing `{field}`, or it isn't a whole number", idx + 1 ))
+            };
+            let string_field = |field: &str| match spell_entry.get(field) {
+                Some(Value::String(value)) if !value.trim().is_empty() => Ok(value.trim().to_string()),
+                _ => Err(format!( "Spell #{} is missing `{field}`, or it is empty", idx + 1 ))
+            };
+
+            let new_spell = NewSpell {
+                lore_id: integer_field("lore")? as u64,
+                name: string_field("name")?,
+                tier: integer_field("tier")?,
+                cost: integer_field("cost")?,
+                description: string_field("description")?
+            };
+
+            if new_spell.tier < 1 || new_spell.cost < 0 {
+                return Err(format!( "`{}` needs a tier of at least 1, and a cost no lower than 0", new_spell.name ));
+            }
+            Ok(new_spell)
+        })
+        .collect()
+}
+
+// --== SPELLS ==-- //
+
+    /// Adds every spell, updating those already known by their lore and name. Returns how many
+    /// were written
+    pub async fn seed_spells(database_conn_pool: &SqlitePool, new_spells: &[NewSpell]) -> Result<u64, Error> {
+        let mut transaction = database_conn_pool.begin().await?;
+
+        let mut written_spells = 0;
+        for new_spell in new_spells {
+            written_spells += sqlx::query(
+                "INSERT INTO Spells (fk_loreID, name, tier, cost, description) \
+                VALUES ($1, $2, $3, $4, $5) \
+                ON CONFLICT (fk_loreID, name) DO UPDATE SET \
+                    tier = excluded.tier, cost = excluded.cost, description = excluded.description;"
+            )
+                .bind(new_spell.lore_id as i64)
+                .bind(&new_spell.name)
+                .bind(new_spell.tier)
+                .bind(new_spell.cost)
+                .bind(&new_spell.description)
+                .execute(&mut *transaction)
+                .await?
+                .rows_affected();
+        }
+
+        transaction.commit().await?;
+        Ok(written_spells)
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_spell(database_conn_pool: &SqlitePool, spell_id: u64) -> Result<Option<Spell>, Error> {
+        sqlx::query_as("SELECT * FROM Spells WHERE pk_spellID = $1;")
+            .bind(spell_id as i64)
+            .fetch_optional(database_conn_pool)
+            .await
+    }
+
+    pub async fn get_spells(database_conn_pool: &SqlitePool) -> Result<Vec<Spell>, Error> {
+        sqlx::query_as("SELECT * FROM Spells ORDER BY fk_loreID, tier, name;")
+            .fetch_all(database_conn_pool)
+            .await
+    }
+
+    pub async fn get_spells_by_lore(database_conn_pool: &SqlitePool, lore_id: u64) -> Result<Vec<Spell>, Error> {
+        sqlx::query_as("SELECT * FROM Spells WHERE fk_loreID = $1 ORDER BY tier, name;")
+            .bind(lore_id as i64)
+            .fetch_all(database_conn_pool)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn remove_spell(database_conn_pool: &SqlitePool, spell_id: u64) -> Result<SqliteQueryResult, Error> {
+        sqlx::query("DELETE FROM Spells WHERE pk_spellID = $1;")
+            .bind(spell_id as i64)
+            .execute(database_conn_pool)
+            .await
+    }
+// ==--
+
+// --== KNOWN SPELLS ==-- //
+
+    /// Returns the spells a character knows: those whose tier is within the points they've spent
+    /// in the spell's lore, up to the casting cap of their class. Classes with a cap of 0 know
+    /// none, whatever they've spent
+    pub async fn get_known_spells(database_conn_pool: &SqlitePool, character_id: u64) -> Result<Vec<Spell>, Error> {
+        // Like with lore points, characters without a class count as casters, and those with
+        // several go by the highest cap among them
+        sqlx::query_as(
+            "SELECT Spells.* FROM Spells \
+            INNER JOIN CharacterUsedLores \
+                ON CharacterUsedLores.pk_fk_loreID = Spells.fk_loreID \
+                AND CharacterUsedLores.pk_fk_characterID = $1 \
+            WHERE Spells.tier <= CharacterUsedLores.spentPoints \
+                AND Spells.tier <= ( \
+                    SELECT COALESCE(MAX(CharacterClasses.castingCap), Spells.tier) FROM SelectedCharacterClasses \
+                    JOIN CharacterClasses ON CharacterClasses.pk_classID = SelectedCharacterClasses.pk_fk_classID \
+                    WHERE SelectedCharacterClasses.pk_fk_characterID = $1 \
+                ) \
+            ORDER BY Spells.fk_loreID, Spells.tier, Spells.name;"
+        )
+            .bind(character_id as i64)
+            .fetch_all(database_conn_pool)
+            .await
+    }
+// ==--
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::sql_scripts::{
+        character_classes, insert_test_character, lores, models::{CharacterClass, CharacterUsedLore}, open_test_database
+    };
+
+    #[tokio::test]
+    async fn shipped_catalogue_seeds() {
+        let database_conn_pool = open_test_database().await;
+        let new_spells = parse_spell_data(SPELL_DATA).expect("Shipped catalogue should parse");
+
+        // Every lore has spells, and seeding twice updates rather than duplicates
+        seed_spells(&database_conn_pool, &new_spells).await.expect("Seeding should succeed");
+        seed_spells(&database_conn_pool, &new_spells).await.expect("Reseeding should succeed");
+        let spells = get_spells(&database_conn_pool).await.expect("Select should succeed");
+        assert_eq!(spells.len(), new_spells.len());
+        for lore in lores::get_lores(&database_conn_pool).await.expect("Select should succeed") {
+            assert!(spells.iter().any(|spell| spell.lore_id == lore.id), "{} has no spells", lore.name);
+        }
+
+        assert!(parse_spell_data("[[spell]]\nlore = 1\nname = \"Nothing\"\ntier = 0\ncost = 1\ndescription = \"-\"").is_err());
+        assert!(parse_spell_data("[[spell]]\nlore = 1\nname = \"Nothing\"").is_err());
+    }
+
+    #[tokio::test]
+    async fn known_spells_follow_spent_points() {
+        let database_conn_pool = open_test_database().await;
+        seed_spells(&database_conn_pool, &parse_spell_data(SPELL_DATA).expect("Shipped catalogue should parse"))
+            .await
+            .expect("Seeding should succeed");
+        let character_id = insert_test_character(&database_conn_pool, 1, "Cyra").await;
+
+        assert!(get_known_spells(&database_conn_pool, character_id).await.expect("Select should succeed").is_empty());
+
+        lores::set_spent_points(&database_conn_pool, &CharacterUsedLore { lore_id: 1, character_id, spent_points: 2 })
+            .await
+            .expect("Insert should succeed");
+        let known_tiers: Vec<i64> = get_known_spells(&database_conn_pool, character_id)
+            .await
+            .expect("Select should succeed")
+            .into_iter()
+            .map(|spell| spell.tier)
+            .collect();
+        assert_eq!(known_tiers, vec![1, 2]);
+
+        // Only martial classes leaves the character without spells
+        sqlx::query("DELETE FROM SelectedCharacterClasses WHERE pk_fk_characterID = $1;")
+            .bind(character_id as i64)
+            .execute(&database_conn_pool)
+            .await
+            .expect("Delete should succeed");
+        character_classes::add_character_class(&database_conn_pool, character_id, CharacterClass::MARTIAL_ID)
+            .await
+            .expect("Insert should succeed");
+        assert!(get_known_spells(&database_conn_pool, character_id).await.expect("Select should succeed").is_empty());
+    }
+}
@@ -0,0 +1,109 @@
+use sqlx::{
+    sqlite::{
+        SqlitePool, SqliteQueryResult
+    },
+    Error
+};
+
+use crate::runtime::sql_scripts::models::{
+    CharacterTemplate, DiscordId, TemplateDetails
+};
+
+// --== TEMPLATES ==-- //
+
+    /// Adds a template to a server, names are unique within it regardless of case
+    pub async fn add_template(database_conn_pool: &SqlitePool, guild_id: u64, created_by: u64, name: &str, details: &TemplateDetails) -> Result<u64, Error> {
+        sqlx::query_scalar(
+            "INSERT INTO CharacterTemplates ( \
+                guildID, name, createdBy, createdAt, \
+                species, alignment, likes, dislikes, motivations, companions, backstory, appearance, extras, fk_classID \
+            ) \
+            VALUES ( $1, $2, $3, unixepoch(), $4, $5, $6, $7, $8, $9, $10, $11, $12, $13 ) \
+            RETURNING pk_templateID;"
+        )
+            .bind(DiscordId(guild_id))
+            .bind(name)
+            .bind(DiscordId(created_by))
+            .bind(details.species.as_deref())
+            .bind(details.alignment.as_deref())
+            .bind(details.likes.as_deref())
+            .bind(details.dislikes.as_deref())
+            .bind(details.motivations.as_deref())
+            .bind(details.companions.as_deref())
+            .bind(details.backstory.as_deref())
+            .bind(details.appearance.as_deref())
+            .bind(details.extras.as_deref())
+            .bind(details.class_id.map(|class_id| class_id as i64))
+            .fetch_one(database_conn_pool)
+            .await
+    }
+
+    /// Returns the template should it be offered in the server
+    pub async fn get_template(database_conn_pool: &SqlitePool, guild_id: u64, template_id: u64) -> Result<Option<CharacterTemplate>, Error> {
+        sqlx::query_as("SELECT * FROM CharacterTemplates WHERE pk_templateID = $1 AND guildID = $2;")
+            .bind(template_id as i64)
+            .bind(DiscordId(guild_id))
+            .fetch_optional(database_conn_pool)
+            .await
+    }
+
+    /// Returns every template offered in the server, by name
+    pub async fn get_templates(database_conn_pool: &SqlitePool, guild_id: u64) -> Result<Vec<CharacterTemplate>, Error> {
+        sqlx::query_as("SELECT * FROM CharacterTemplates WHERE guildID = $1 ORDER BY name;")
+            .bind(DiscordId(guild_id))
+            .fetch_all(database_conn_pool)
+            .await
+    }
+
+    pub async fn remove_template(database_conn_pool: &SqlitePool, template_id: u64) -> Result<SqliteQueryResult, Error> {
+        sqlx::query("DELETE FROM CharacterTemplates WHERE pk_templateID = $1;")
+            .bind(template_id as i64)
+            .execute(database_conn_pool)
+            .await
+    }
+// ==--
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::sql_scripts::{models::CharacterClass, open_test_database};
+
+    #[tokio::test]
+    async fn templates_fill_in_the_wizard() {
+        let database_conn_pool = open_test_database().await;
+
+        let details = TemplateDetails {
+            species: Some(String::from("Human")),
+            likes: Some(String::from("Herbs")),
+            class_id: Some(CharacterClass::HALF_CASTER_ID),
+            ..Default::default()
+        };
+        let template_id = add_template(&database_conn_pool, 100, 1, "Village Healer", &details).await.expect("Insert should succeed");
+        assert!(add_template(&database_conn_pool, 100, 1, "village healer", &TemplateDetails::default()).await.is_err(), "Names are unique regardless of case");
+        add_template(&database_conn_pool, 100, 1, "Sellsword", &TemplateDetails::default()).await.expect("Insert should succeed");
+
+        // Other servers have templates of their own
+        let other_template_id = add_template(&database_conn_pool, 200, 1, "Village Healer", &TemplateDetails::default())
+            .await
+            .expect("Names are only unique within a server");
+        assert!(get_template(&database_conn_pool, 100, other_template_id).await.expect("Select should succeed").is_none());
+
+        let template = get_template(&database_conn_pool, 100, template_id)
+            .await
+            .expect("Select should succeed")
+            .expect("Template exists");
+        assert_eq!(template.details, details);
+        assert_eq!(
+            template.details.wizard_fields(),
+            vec![("species", String::from("Human")), ("likes", String::from("Herbs")), ("class", String::from("2"))]
+        );
+
+        let names: Vec<String> = get_templates(&database_conn_pool, 100).await.expect("Select should succeed").into_iter().map(|template| template.name).collect();
+        assert_eq!(names, vec!["Sellsword", "Village Healer"]);
+
+        remove_template(&database_conn_pool, template_id).await.expect("Delete should succeed");
+        assert!(get_template(&database_conn_pool, 100, template_id).await.expect("Select should succeed").is_none());
+    }
+}
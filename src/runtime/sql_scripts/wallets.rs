@@ -0,0 +1,196 @@
+use sqlx::{
+    sqlite::{
+        SqliteConnection, SqlitePool
+    },
+    Error
+};
+
+use crate::runtime::sql_scripts::models::{
+    DiscordId, LedgerEntry
+};
+
+/// How a change to a wallet went
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WalletOutcome {
+    /// The change was made, leaving the wallet with this balance
+    Done(i64),
+    /// The wallet holds this much, which is not enough. Nothing was changed
+    InsufficientFunds(i64)
+}
+
+pub async fn get_balance(database_conn_pool: &SqlitePool, character_id: u64) -> Result<i64, Error> {
+    let balance: Option<i64> = sqlx::query_scalar("SELECT balance FROM CharacterWallets WHERE pk_fk_characterID = $1;")
+        .bind(character_id as i64)
+        .fetch_optional(database_conn_pool)
+        .await?;
+
+    Ok(balance.unwrap_or(0))
+}
+
+/// Adds `amount` to a wallet, unless that would take it below zero, returning the new balance.
+///
+/// The check happens within the `UPDATE` itself, so two changes racing each other can't both
+/// spend the same coins
+async fn change_balance(database_conn: &mut SqliteConnection, character_id: u64, amount: i64) -> Result<WalletOutcome, Error> {
+    // Writing first makes the transaction take the write lock straight away
+    sqlx::query("INSERT INTO CharacterWallets (pk_fk_characterID) VALUES ($1) ON CONFLICT DO NOTHING;")
+        .bind(character_id as i64)
+        .execute(&mut *database_conn)
+        .await?;
+
+    let new_balance: Option<i64> = sqlx::query_scalar(
+        "UPDATE CharacterWallets SET balance = balance + $2 \
+        WHERE pk_fk_characterID = $1 AND balance + $2 >= 0 \
+        RETURNING balance;"
+    )
+        .bind(character_id as i64)
+        .bind(amount)
+        .fetch_optional(&mut *database_conn)
+        .await?;
+
+    match new_balance {
+        Some(new_balance) => Ok(WalletOutcome::Done(new_balance)),
+        None => {
+            let balance = sqlx::query_scalar("SELECT balance FROM CharacterWallets WHERE pk_fk_characterID = $1;")
+                .bind(character_id as i64)
+                .fetch_one(&mut *database_conn)
+                .await?;
+            Ok(WalletOutcome::InsufficientFunds(balance))
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn append_ledger(database_conn: &mut SqliteConnection, character_id: u64, counterparty_id: Option<u64>, actor_id: u64, kind: &str, amount: i64, balance_after: i64, note: Option<&str>) -> Result<(), Error> {
+    sqlx::query(
+        "INSERT INTO WalletLedger ( \
+            characterID, counterpartyCharacterID, actorDiscordID, kind, amount, balanceAfter, note, createdAt \
+        ) \
+        VALUES ( $1, $2, $3, $4, $5, $6, $7, unixepoch() );"
+    )
+        .bind(character_id as i64)
+        .bind(counterparty_id.map(|counterparty_id| counterparty_id as i64))
+        .bind(DiscordId(actor_id))
+        .bind(kind)
+        .bind(amount)
+        .bind(balance_after)
+        .bind(note)
+        .execute(&mut *database_conn)
+        .await?;
+
+    Ok(())
+}
+
+/// Adds coins to, or with a negative `amount` takes them from, a wallet on a GM's behalf
+pub async fn grant(database_conn_pool: &SqlitePool, character_id: u64, actor_id: u64, amount: i64, note: Option<&str>) -> Result<WalletOutcome, Error> {
+    let mut transaction = database_conn_pool.begin().await?;
+
+    let outcome = change_balance(&mut transaction, character_id, amount).await?;
+    if let WalletOutcome::Done(new_balance) = outcome {
+        append_ledger(&mut transaction, character_id, None, actor_id, "grant", amount, new_balance, note).await?;
+        transaction.commit().await?;
+    }
+
+    Ok(outcome)
+}
+
+/// Moves coins from one wallet to another. The outcome describes the paying wallet
+pub async fn pay(database_conn_pool: &SqlitePool, from_character: u64, to_character: u64, actor_id: u64, amount: i64, note: Option<&str>) -> Result<WalletOutcome, Error> {
+    let mut transaction = database_conn_pool.begin().await?;
+
+    let outcome = change_balance(&mut transaction, from_character, -amount).await?;
+    let WalletOutcome::Done(sender_balance) = outcome else {
+        return Ok(outcome);
+    };
+    append_ledger(&mut transaction, from_character, Some(to_character), actor_id, "payment", -amount, sender_balance, note).await?;
+
+    // Receiving never takes a balance below zero
+    if let WalletOutcome::Done(recipient_balance) = change_balance(&mut transaction, to_character, amount).await? {
+        append_ledger(&mut transaction, to_character, Some(from_character), actor_id, "payment", amount, recipient_balance, note).await?;
+    }
+
+    transaction.commit().await?;
+    Ok(outcome)
+}
+
+/// Returns a page of a character's ledger, newest first
+pub async fn get_ledger(database_conn_pool: &SqlitePool, character_id: u64, limit: u32, offset: u32) -> Result<Vec<LedgerEntry>, Error> {
+    sqlx::query_as(
+        "SELECT * FROM WalletLedger \
+        WHERE characterID = $1 \
+        ORDER BY pk_entryID DESC \
+        LIMIT $2 OFFSET $3;"
+    )
+        .bind(character_id as i64)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(database_conn_pool)
+        .await
+}
+
+pub async fn count_ledger(database_conn_pool: &SqlitePool, character_id: u64) -> Result<u32, Error> {
+    sqlx::query_scalar("SELECT COUNT(*) FROM WalletLedger WHERE characterID = $1;")
+        .bind(character_id as i64)
+        .fetch_one(database_conn_pool)
+        .await
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::sql_scripts::{insert_test_character, open_test_database};
+
+    #[tokio::test]
+    async fn balances_never_go_negative() {
+        let database_conn_pool = open_test_database().await;
+        let hale = insert_test_character(&database_conn_pool, 1, "Hale").await;
+        let mira = insert_test_character(&database_conn_pool, 2, "Mira").await;
+
+        assert_eq!(grant(&database_conn_pool, hale, 9, 100, Some("Quest reward")).await.expect("Grant should succeed"), WalletOutcome::Done(100));
+        assert_eq!(grant(&database_conn_pool, hale, 9, -150, None).await.expect("Grant should succeed"), WalletOutcome::InsufficientFunds(100));
+
+        // Twenty payments of 10 race for 100 coins, only ten of them can go through
+        let mut payments = vec![];
+        for _ in 0..20 {
+            let database_conn_pool = database_conn_pool.clone();
+            payments.push(tokio::spawn(async move {
+                pay(&database_conn_pool, hale, mira, 1, 10, None).await.expect("Payment should not error")
+            }));
+        }
+        let mut completed_payments = 0;
+        for payment in payments {
+            if let WalletOutcome::Done(_) = payment.await.expect("Task should not panic") {
+                completed_payments += 1;
+            }
+        }
+        assert_eq!(completed_payments, 10);
+        assert_eq!(get_balance(&database_conn_pool, hale).await.expect("Select should succeed"), 0);
+        assert_eq!(get_balance(&database_conn_pool, mira).await.expect("Select should succeed"), 100);
+
+        // The failed grant and payments left no trace
+        assert_eq!(count_ledger(&database_conn_pool, hale).await.expect("Select should succeed"), 11);
+        let newest_entries = get_ledger(&database_conn_pool, mira, 2, 0).await.expect("Select should succeed");
+        assert_eq!(newest_entries.iter().map(|entry| entry.balance_after).collect::<Vec<_>>(), vec![100, 90]);
+        assert_eq!(newest_entries[0].counterparty_character_id, Some(hale));
+    }
+
+    #[tokio::test]
+    async fn ledger_is_append_only() {
+        let database_conn_pool = open_test_database().await;
+        let hale = insert_test_character(&database_conn_pool, 1, "Hale").await;
+        grant(&database_conn_pool, hale, 9, 100, None).await.expect("Grant should succeed");
+
+        assert!(sqlx::query("UPDATE WalletLedger SET amount = 1000000;").execute(&database_conn_pool).await.is_err());
+        assert!(sqlx::query("DELETE FROM WalletLedger;").execute(&database_conn_pool).await.is_err());
+
+        // Deleting the character leaves its history behind
+        sqlx::query("DELETE FROM Characters WHERE pk_characterID = $1;")
+            .bind(hale as i64)
+            .execute(&database_conn_pool)
+            .await
+            .expect("Delete should succeed");
+        assert_eq!(count_ledger(&database_conn_pool, hale).await.expect("Select should succeed"), 1);
+    }
+}
@@ -0,0 +1,170 @@
+use toml::Value;
+
+/// A coin wallets can be counted in, such as `gp`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Denomination {
+    pub name: String,
+    /// How many of the least valuable coin this one is worth
+    pub value: i64
+}
+
+/// The coins of the realm, read from `currency_denominations` in the config.
+///
+/// Balances are stored as a count of the least valuable coin, so the denominations only matter
+/// when amounts are read from or shown to users
+#[derive(Clone, Debug, PartialEq)]
+pub struct Currency {
+    /// Ordered from most to least valuable, the last one is always worth `1`
+    denominations: Vec<Denomination>
+}
+
+impl Default for Currency {
+    fn default() -> Currency {
+        Currency {
+            denominations: vec![
+                Denomination { name: String::from("gp"), value: 100 },
+                Denomination { name: String::from("sp"), value: 10 },
+                Denomination { name: String::from("cp"), value: 1 }
+            ]
+        }
+    }
+}
+
+impl Currency {
+    /// Reads the denominations from the config's `currency_denominations` table, such as
+    /// `{ gp = 100, sp = 10, cp = 1 }`, falling back to gp/sp/cp when it's absent
+    pub fn from_config( config_value: Option<&Value> ) -> Result<Currency, String> {
+        let Some(config_value) = config_value else {
+            return Ok(Currency::default());
+        };
+        let Value::Table(denomination_table) = config_value else {
+            return Err(String::from("`currency_denominations` has to be a table of coin names and values"));
+        };
+
+        let mut denominations = vec![];
+        for (name, value) in denomination_table {
+            match value {
+                Value::Integer(value) if *value > 0 && !name.trim().is_empty() => denominations.push(
+                    Denomination { name: name.trim().to_lowercase(), value: *value }
+                ),
+                _ => return Err(format!( "Coin `{name}` needs a name and a value above zero" ))
+            }
+        }
+        denominations.sort_by_key(|denomination| std::cmp::Reverse(denomination.value));
+
+        // Without a coin worth one, some amounts could not be paid out
+        if denominations.last().is_none_or(|denomination| denomination.value != 1) {
+            return Err(String::from("`currency_denominations` needs a coin with a value of 1"));
+        }
+
+        Ok(Currency { denominations })
+    }
+
+    pub fn denominations(&self) -> &[Denomination] {
+        &self.denominations
+    }
+
+    /// Formats an amount of the least valuable coin using as few coins as possible, such as
+    /// `3 gp 2 sp`
+    pub fn format(&self, amount: i64) -> String {
+        let mut remaining = amount.abs();
+        let mut coins = vec![];
+
+        for denomination in &self.denominations {
+            let count = remaining / denomination.value;
+            remaining %= denomination.value;
+
+            if count > 0 {
+                coins.push(format!( "{count} {}", denomination.name ));
+            }
+        }
+
+        let least_valuable = &self.denominations[self.denominations.len() - 1].name;
+        match (coins.is_empty(), amount < 0) {
+            (true, _) => format!( "0 {least_valuable}" ),
+            (false, true) => format!( "-{}", coins.join(" ") ),
+            (false, false) => coins.join(" ")
+        }
+    }
+
+    /// Parses an amount such as `3gp 2 sp` or `-5 gp` into the least valuable coin. A number
+    /// without a coin counts as the least valuable one
+    pub fn parse(&self, input: &str) -> Option<i64> {
+        let input = input.trim().to_lowercase();
+        let (is_negative, input) = match input.strip_prefix('-') {
+            Some(input) => (true, input.trim_start().to_string()),
+            None => (false, input)
+        };
+
+        let mut amount: i64 = 0;
+        let mut chars = input.chars().peekable();
+        let mut has_coins = false;
+
+        while chars.peek().is_some() {
+            // Coins are separated by any amount of whitespace or commas
+            while chars.next_if(|char| char.is_whitespace() || *char == ',').is_some() {}
+            if chars.peek().is_none() {
+                break;
+            }
+
+            let count: String = std::iter::from_fn(|| chars.next_if(char::is_ascii_digit)).collect();
+            while chars.next_if(|char| char.is_whitespace()).is_some() {}
+            let coin_name: String = std::iter::from_fn(|| chars.next_if(|char| char.is_alphabetic())).collect();
+
+            let count: i64 = count.parse().ok()?;
+            let value = match coin_name.as_str() {
+                "" => 1,
+                coin_name => self.denominations.iter().find(|denomination| denomination.name == coin_name)?.value
+            };
+
+            amount = amount.checked_add(count.checked_mul(value)?)?;
+            has_coins = true;
+        }
+
+        match (has_coins, is_negative) {
+            (false, _) => None,
+            (true, true) => Some(-amount),
+            (true, false) => Some(amount)
+        }
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn amounts_parse_and_format() {
+        let currency = Currency::default();
+
+        assert_eq!(currency.parse("3gp 2 sp"), Some(320));
+        assert_eq!(currency.parse("1 GP, 5cp"), Some(105));
+        assert_eq!(currency.parse("-5 gp"), Some(-500));
+        assert_eq!(currency.parse("42"), Some(42));
+        assert_eq!(currency.parse("3 pp"), None);
+        assert_eq!(currency.parse("gp"), None);
+        assert_eq!(currency.parse(""), None);
+
+        assert_eq!(currency.format(320), "3 gp 2 sp");
+        assert_eq!(currency.format(0), "0 cp");
+        assert_eq!(currency.format(-105), "-1 gp 5 cp");
+    }
+
+    #[test]
+    fn denominations_come_from_config() {
+        let config_data = toml::from_str::<toml::Table>("currency_denominations = { crowns = 20, pence = 1, shillings = 5 }").expect("Valid TOML");
+        let currency = Currency::from_config(config_data.get("currency_denominations")).expect("Denominations are valid");
+
+        assert_eq!(
+            currency.denominations().iter().map(|denomination| denomination.name.as_str()).collect::<Vec<_>>(),
+            vec!["crowns", "shillings", "pence"]
+        );
+        assert_eq!(currency.format(27), "1 crowns 1 shillings 2 pence");
+
+        let config_data = toml::from_str::<toml::Table>("currency_denominations = { gp = 100, sp = 10 }").expect("Valid TOML");
+        assert!(Currency::from_config(config_data.get("currency_denominations")).is_err());
+        assert_eq!(Currency::from_config(None), Ok(Currency::default()));
+    }
+}
@@ -0,0 +1,6 @@
+use rand::Rng;
+
+/// Rolls a single die with the given number of sides, returning a value in `1..=sides`
+pub fn roll_die( sides: i64 ) -> i64 {
+    rand::thread_rng().gen_range(1..=sides)
+}
@@ -0,0 +1,103 @@
+use toml::Value;
+
+/// How characters progress, read from the `levelling` table in the config
+#[derive(Clone, Debug, PartialEq)]
+pub struct Levelling {
+    /// The total experience needed to reach level 2, 3 and so on, in ascending order. Characters
+    /// stop levelling after the last one
+    thresholds: Vec<i64>,
+    pub stat_points_per_level: i64,
+    /// Only granted to characters who can cast
+    pub lore_points_per_level: i64
+}
+
+impl Default for Levelling {
+    fn default() -> Levelling {
+        Levelling {
+            thresholds: vec![300, 900, 2700, 6500, 14000, 23000, 34000, 48000, 64000, 85000],
+            stat_points_per_level: 2,
+            lore_points_per_level: 1
+        }
+    }
+}
+
+impl Levelling {
+    /// Reads the `levelling` table, such as `{ thresholds = [300, 900], stat_points_per_level = 2 }`.
+    /// Whatever is left out keeps its default
+    pub fn from_config( config_value: Option<&Value> ) -> Result<Levelling, String> {
+        let mut levelling = Levelling::default();
+
+        let Some(config_value) = config_value else {
+            return Ok(levelling);
+        };
+        let Value::Table(levelling_table) = config_value else {
+            return Err(String::from("`levelling` has to be a table"));
+        };
+
+        for (key, value) in levelling_table {
+            match (key.as_str(), value) {
+                ("thresholds", Value::Array(thresholds)) => {
+                    levelling.thresholds = thresholds.iter()
+                        .map(|threshold| match threshold {
+                            Value::Integer(threshold) if *threshold > 0 => Ok(*threshold),
+                            _ => Err(String::from("Every threshold has to be an amount of experience above zero"))
+                        })
+                        .collect::<Result<Vec<i64>, String>>()?;
+
+                    if levelling.thresholds.windows(2).any(|pair| pair[0] >= pair[1]) {
+                        return Err(String::from("Thresholds have to go up with every level"));
+                    }
+                },
+                ("stat_points_per_level", Value::Integer(points)) if *points >= 0 => levelling.stat_points_per_level = *points,
+                ("lore_points_per_level", Value::Integer(points)) if *points >= 0 => levelling.lore_points_per_level = *points,
+                (key, _) => return Err(format!( "`levelling.{key}` is unknown or has the wrong type" ))
+            }
+        }
+
+        Ok(levelling)
+    }
+
+    /// The level a character with this much experience has earned
+    pub fn level_for(&self, experience: i64) -> i64 {
+        1 + self.thresholds.iter().take_while(|threshold| **threshold <= experience).count() as i64
+    }
+
+    /// The total experience needed to reach the level after `level`, if there is one
+    pub fn next_threshold(&self, level: i64) -> Option<i64> {
+        usize::try_from(level - 1).ok().and_then(|idx| self.thresholds.get(idx).copied())
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levels_follow_the_thresholds() {
+        let levelling = Levelling::default();
+
+        assert_eq!(levelling.level_for(0), 1);
+        assert_eq!(levelling.level_for(299), 1);
+        assert_eq!(levelling.level_for(300), 2);
+        assert_eq!(levelling.level_for(1000), 3);
+        assert_eq!(levelling.level_for(1_000_000), 11);
+
+        assert_eq!(levelling.next_threshold(1), Some(300));
+        assert_eq!(levelling.next_threshold(11), None);
+    }
+
+    #[test]
+    fn curve_comes_from_config() {
+        let config_data = toml::from_str::<toml::Table>("levelling = { thresholds = [10, 20], lore_points_per_level = 3 }").expect("Valid TOML");
+        let levelling = Levelling::from_config(config_data.get("levelling")).expect("Levelling is valid");
+
+        assert_eq!(levelling.level_for(25), 3);
+        assert_eq!(levelling.stat_points_per_level, 2);
+        assert_eq!(levelling.lore_points_per_level, 3);
+
+        let config_data = toml::from_str::<toml::Table>("levelling = { thresholds = [20, 10] }").expect("Valid TOML");
+        assert!(Levelling::from_config(config_data.get("levelling")).is_err());
+    }
+}
@@ -2,4 +2,11 @@ pub mod colour_codes;
 pub mod template_config;
 pub mod help_menu;
 pub mod logging;
+pub mod utc_offset;
 
+pub mod dice;
+pub mod currency;
+pub mod levelling;
+pub mod multiclassing;
+pub mod schedule_time;
+pub mod wizard;
@@ -0,0 +1,110 @@
+use toml::Value;
+
+/// Which classes characters may combine, read from the `multiclassing` table in the config
+#[derive(Clone, Debug, PartialEq)]
+pub struct Multiclassing {
+    /// How many classes a character may have at once, 1 turns multiclassing off
+    pub max_classes: usize,
+    /// The level a character needs before taking up another class. At least 2, as the new class's
+    /// first level is taken from their primary class
+    pub min_level: i64,
+    /// Pairs of class names that can't be combined, compared regardless of case
+    forbidden: Vec<(String, String)>
+}
+
+impl Default for Multiclassing {
+    fn default() -> Multiclassing {
+        Multiclassing {
+            max_classes: 2,
+            min_level: 3,
+            forbidden: vec![]
+        }
+    }
+}
+
+impl Multiclassing {
+    /// Reads the `multiclassing` table, such as `{ max_classes = 2, forbidden = [["Martial", "Caster"]] }`.
+    /// Whatever is left out keeps its default
+    pub fn from_config( config_value: Option<&Value> ) -> Result<Multiclassing, String> {
+        let mut multiclassing = Multiclassing::default();
+
+        let Some(config_value) = config_value else {
+            return Ok(multiclassing);
+        };
+        let Value::Table(multiclassing_table) = config_value else {
+            return Err(String::from("`multiclassing` has to be a table"));
+        };
+
+        for (key, value) in multiclassing_table {
+            match (key.as_str(), value) {
+                ("max_classes", Value::Integer(max_classes)) if (1..=25).contains(max_classes) => multiclassing.max_classes = *max_classes as usize,
+                ("min_level", Value::Integer(min_level)) if *min_level >= 2 => multiclassing.min_level = *min_level,
+                ("forbidden", Value::Array(pairs)) => {
+                    multiclassing.forbidden = pairs.iter()
+                        .map(|pair| match pair.as_array().map(Vec::as_slice) {
+                            Some([Value::String(first), Value::String(second)]) => Ok((first.clone(), second.clone())),
+                            _ => Err(String::from("Every forbidden combination has to be a pair of class names"))
+                        })
+                        .collect::<Result<Vec<(String, String)>, String>>()?;
+                },
+                (key, _) => return Err(format!( "`multiclassing.{key}` is unknown or has the wrong type" ))
+            }
+        }
+
+        Ok(multiclassing)
+    }
+
+    /// Whether a character of `level` with the classes `class_names` may take up `new_class`.
+    /// The reason is given when they may not
+    pub fn check( &self, level: i64, class_names: &[&str], new_class: &str ) -> Result<(), String> {
+        if class_names.iter().any(|class_name| class_name.eq_ignore_ascii_case(new_class)) {
+            return Err(format!( "They already are a {new_class}" ));
+        }
+        if class_names.len() >= self.max_classes {
+            return Err(match self.max_classes {
+                1 => String::from("Characters can't have more than one class here"),
+                max_classes => format!( "Characters can have at most {max_classes} classes" )
+            });
+        }
+        if level < self.min_level {
+            return Err(format!( "Characters need to reach level {} before taking up another class", self.min_level ));
+        }
+
+        let forbidden_with = class_names.iter().find(|class_name| {
+            self.forbidden.iter().any(|(first, second)| {
+                (first.eq_ignore_ascii_case(class_name) && second.eq_ignore_ascii_case(new_class))
+                    || (second.eq_ignore_ascii_case(class_name) && first.eq_ignore_ascii_case(new_class))
+            })
+        });
+        if let Some(class_name) = forbidden_with {
+            return Err(format!( "{class_name}s can't also be {new_class}s" ));
+        }
+
+        Ok(())
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn combinations_follow_the_config() {
+        let config_data = toml::from_str::<toml::Table>("multiclassing = { max_classes = 3, forbidden = [[\"Martial\", \"Caster\"]] }").expect("Valid TOML");
+        let multiclassing = Multiclassing::from_config(config_data.get("multiclassing")).expect("Multiclassing is valid");
+
+        assert_eq!(multiclassing.min_level, 3);
+        assert!(multiclassing.check(3, &["Half-Caster"], "Martial").is_ok());
+        assert!(multiclassing.check(2, &["Half-Caster"], "Martial").is_err(), "Below the minimum level");
+        assert!(multiclassing.check(3, &["Half-Caster"], "half-caster").is_err(), "Already has the class");
+        assert!(multiclassing.check(3, &["Half-Caster", "caster"], "Martial").is_err(), "Forbidden either way around");
+        assert!(multiclassing.check(3, &["Half-Caster", "Martial", "Warden"], "Ranger").is_err(), "Too many classes");
+
+        let config_data = toml::from_str::<toml::Table>("multiclassing = { min_level = 1 }").expect("Valid TOML");
+        assert!(Multiclassing::from_config(config_data.get("multiclassing")).is_err());
+        let config_data = toml::from_str::<toml::Table>("multiclassing = { forbidden = [[\"Martial\"]] }").expect("Valid TOML");
+        assert!(Multiclassing::from_config(config_data.get("multiclassing")).is_err());
+    }
+}
@@ -0,0 +1,223 @@
+use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveDate, NaiveTime, Utc, Weekday};
+use toml::Value;
+
+use crate::utils::misc::utc_offset::parse_utc_offset;
+
+/// Parses when something is due, relative to `now`. Understood are:
+/// - Discord timestamps, such as `<t:1767225600:F>`
+/// - Durations from now, such as `in 2h 30m` or `in 3 days`
+/// - A time on a given day, such as `2026-10-24 19:00`, `24/10 7pm`, `tomorrow 19:30`,
+///   `friday 18:00`, or a lone `19:00` for its next occurrence
+///
+/// Times are read in the zone given by a trailing offset like `UTC+2`, or `default_offset_minutes`
+/// without one
+pub fn parse_start_time( input: &str, default_offset_minutes: i32, now: DateTime<Utc> ) -> Option<DateTime<Utc>> {
+    let input = input.trim().to_lowercase();
+
+    if let Some(discord_timestamp) = input.strip_prefix("<t:").and_then(|rest| rest.strip_suffix('>')) {
+        let seconds = discord_timestamp.split(':').next()?.parse::<i64>().ok()?;
+        return DateTime::from_timestamp(seconds, 0);
+    }
+
+    if let Some(relative) = input.strip_prefix("in ") {
+        return parse_duration(relative).and_then(|duration| now.checked_add_signed(duration));
+    }
+
+    // --== ZONE ==-- //
+
+        let mut tokens: Vec<&str> = input.split_whitespace().collect();
+        let offset_minutes = match tokens.last().and_then(|last_token| parse_utc_offset(last_token)) {
+            Some(offset_minutes) if tokens.len() > 1 => {
+                tokens.pop();
+                offset_minutes
+            },
+            _ => default_offset_minutes
+        };
+        let zone = FixedOffset::east_opt(offset_minutes * 60)?;
+        let local_now = now.with_timezone(&zone);
+    // ==--
+
+    // --== DAY AND TIME ==-- //
+
+        let (day_token, time_token) = match tokens.as_slice() {
+            [time_token] => (None, *time_token),
+            [day_token, time_token] => (Some(*day_token), *time_token),
+            _ => return None
+        };
+        let time = parse_time_of_day(time_token)?;
+
+        let today = local_now.date_naive();
+        let is_ahead = |date: NaiveDate| date.and_time(time) > local_now.naive_local();
+
+        let date = match day_token {
+            // A lone time means its next occurrence
+            None => if is_ahead(today) { today } else { today.succ_opt()? },
+            Some("today") => today,
+            Some("tomorrow") => today.succ_opt()?,
+            Some(day_token) => match day_token.parse::<Weekday>() {
+                Ok(weekday) => {
+                    let days_until = (7 + weekday.num_days_from_monday() as i64 - today.weekday().num_days_from_monday() as i64) % 7;
+                    let date = today + Duration::days(days_until);
+                    if is_ahead(date) { date } else { date + Duration::days(7) }
+                },
+                Err(_) => parse_date(day_token, today, is_ahead)?
+            }
+        };
+    // ==--
+
+    date.and_time(time)
+        .and_local_timezone(zone)
+        .single()
+        .map(|start_time| start_time.with_timezone(&Utc))
+}
+
+/// Parses times such as `19:00`, `7pm` or `7:30pm`
+fn parse_time_of_day( input: &str ) -> Option<NaiveTime> {
+    let (clock, is_pm) = match (input.strip_suffix("am"), input.strip_suffix("pm")) {
+        (Some(clock), _) => (clock, Some(false)),
+        (_, Some(clock)) => (clock, Some(true)),
+        _ => (input, None)
+    };
+
+    let (hours, minutes): (u32, u32) = match clock.split_once(':') {
+        Some((hours, minutes)) => (hours.parse().ok()?, minutes.parse().ok()?),
+        None if is_pm.is_some() => (clock.parse().ok()?, 0),
+        None => return None
+    };
+
+    let hours = match is_pm {
+        Some(_) if !(1..=12).contains(&hours) => return None,
+        Some(is_pm) => hours % 12 + if is_pm { 12 } else { 0 },
+        None => hours
+    };
+
+    NaiveTime::from_hms_opt(hours, minutes, 0)
+}
+
+/// Parses dates such as `2026-10-24`, `24/10/2026` or `24/10`. Without a year the date's next
+/// occurrence is meant
+fn parse_date( input: &str, today: NaiveDate, is_ahead: impl Fn(NaiveDate) -> bool ) -> Option<NaiveDate> {
+    if let Ok(date) = NaiveDate::parse_from_str(input, "%Y-%m-%d") {
+        return Some(date);
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(input, "%d/%m/%Y") {
+        return Some(date);
+    }
+
+    let (day, month) = input.split_once('/')?;
+    let (day, month): (u32, u32) = (day.parse().ok()?, month.parse().ok()?);
+
+    let this_year = NaiveDate::from_ymd_opt(today.year(), month, day);
+    match this_year {
+        Some(date) if is_ahead(date) => Some(date),
+        _ => NaiveDate::from_ymd_opt(today.year() + 1, month, day)
+    }
+}
+
+/// Parses durations such as `2h 30m`, `90 minutes` or `3 days`
+fn parse_duration( input: &str ) -> Option<Duration> {
+    let mut total = Duration::zero();
+    let mut remaining = input.trim();
+
+    while !remaining.is_empty() {
+        let digits_end = remaining.find(|character: char| !character.is_ascii_digit()).unwrap_or(remaining.len());
+        let amount: i64 = remaining[..digits_end].parse().ok()?;
+        remaining = remaining[digits_end..].trim_start();
+
+        let unit_end = remaining.find(|character: char| !character.is_alphabetic()).unwrap_or(remaining.len());
+        // Amounts too large for a duration are turned away rather than overflowing
+        let duration = match &remaining[..unit_end] {
+            "m" | "min" | "mins" | "minute" | "minutes" => Duration::try_minutes(amount),
+            "h" | "hr" | "hrs" | "hour" | "hours" => Duration::try_hours(amount),
+            "d" | "day" | "days" => Duration::try_days(amount),
+            "w" | "week" | "weeks" => Duration::try_weeks(amount),
+            _ => return None
+        }?;
+        total = total.checked_add(&duration)?;
+        remaining = remaining[unit_end..].trim_start_matches([' ', ',']);
+    }
+
+    (total > Duration::zero()).then_some(total)
+}
+
+/// Formats a number of minutes the way a reminder would mention it, such as `1 day` or `90 minutes`
+pub fn format_minutes( minutes: i64 ) -> String {
+    let (amount, unit) = match minutes {
+        minutes if minutes % 1440 == 0 => (minutes / 1440, "day"),
+        minutes if minutes % 60 == 0 => (minutes / 60, "hour"),
+        minutes => (minutes, "minute")
+    };
+
+    if amount == 1 {
+        format!( "1 {unit}" )
+    } else {
+        format!( "{amount} {unit}s" )
+    }
+}
+
+/// Reads `session_reminders` from the config, how many minutes before a session starts reminders
+/// go out. Defaults to a day and an hour before
+pub fn reminder_offsets_from_config( config_value: Option<&Value> ) -> Result<Vec<i64>, String> {
+    let Some(config_value) = config_value else {
+        return Ok(vec![1440, 60]);
+    };
+    let Value::Array(offsets) = config_value else {
+        return Err(String::from("`session_reminders` has to be a list of minutes"));
+    };
+
+    let mut offsets = offsets.iter()
+        .map(|offset| match offset {
+            Value::Integer(offset) if *offset > 0 => Ok(*offset),
+            _ => Err(String::from("Every reminder has to be a number of minutes above zero"))
+        })
+        .collect::<Result<Vec<i64>, String>>()?;
+    offsets.sort_unstable_by(|offset_a, offset_b| offset_b.cmp(offset_a));
+    offsets.dedup();
+
+    Ok(offsets)
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn start_times_parse_in_the_right_zone() {
+        // Monday 2026-10-19, 12:00 UTC
+        let now = DateTime::from_timestamp(1_792_411_200, 0).expect("Valid timestamp");
+        let at = |input: &str, offset_minutes: i32| parse_start_time(input, offset_minutes, now).map(|start_time| start_time.to_rfc3339());
+
+        assert_eq!(at("2026-10-24 19:00", 0), Some(String::from("2026-10-24T19:00:00+00:00")));
+        assert_eq!(at("2026-10-24 19:00", 120), Some(String::from("2026-10-24T17:00:00+00:00")));
+        assert_eq!(at("2026-10-24 19:00 UTC-5", 120), Some(String::from("2026-10-25T00:00:00+00:00")));
+        assert_eq!(at("24/10 7pm", 0), Some(String::from("2026-10-24T19:00:00+00:00")));
+        assert_eq!(at("tomorrow 7:30pm", 0), Some(String::from("2026-10-20T19:30:00+00:00")));
+        assert_eq!(at("friday 18:00", 0), Some(String::from("2026-10-23T18:00:00+00:00")));
+        assert_eq!(at("monday 11:00", 0), Some(String::from("2026-10-26T11:00:00+00:00")));
+        assert_eq!(at("11:00", 0), Some(String::from("2026-10-20T11:00:00+00:00")));
+        assert_eq!(at("in 2h 30m", 0), Some(String::from("2026-10-19T14:30:00+00:00")));
+        assert_eq!(at("<t:1792440000:F>", 0), Some(String::from("2026-10-19T20:00:00+00:00")));
+
+        assert_eq!(at("someday", 0), None);
+        assert_eq!(at("friday 25:00", 0), None);
+        assert_eq!(at("in forever", 0), None);
+        assert_eq!(at("in 99999999 weeks", 0), None);
+        assert_eq!(at("in 9223372036854775807 minutes", 0), None);
+        assert_eq!(at("in 10000000 weeks 10000000 weeks", 0), None);
+        assert_eq!(at("19:00 środa", 0), None);
+        assert_eq!(at("19:00 ż", 0), None);
+    }
+
+    #[test]
+    fn reminders_come_from_config() {
+        let config_data = toml::from_str::<toml::Table>("session_reminders = [15, 120, 15]").expect("Valid TOML");
+        assert_eq!(reminder_offsets_from_config(config_data.get("session_reminders")), Ok(vec![120, 15]));
+        assert_eq!(reminder_offsets_from_config(None), Ok(vec![1440, 60]));
+
+        assert_eq!(format_minutes(1440), "1 day");
+        assert_eq!(format_minutes(120), "2 hours");
+        assert_eq!(format_minutes(90), "90 minutes");
+    }
+}
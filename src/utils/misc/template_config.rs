@@ -2,5 +2,37 @@ pub const TEMPLATE: &str = "\
 bot_token = <INSERT DISCORD APPLICATION TOKEN>
 wakeup_channel_id = <INSERT WAKEUP DISCORD CHANNEL ID>
 
+# Optional. Whether commands needing a profile should create one on the fly, instead of asking
+# the user to run `/profile register` first
+auto_register_profiles = false
+
+# Optional. New characters are posted to this channel for staff to approve, and can't be selected
+# or played until they are. Leave it out to skip the review
+# review_channel_id = <INSERT REVIEW DISCORD CHANNEL ID>
+
+# Optional. Those who may change what every server the bot is in shares, such as the classes
+# characters are created with. Leave it out to keep those as they are
+# bot_admins = [<INSERT DISCORD USER ID>]
+
+# Optional. The coins wallets are counted in, and how many of the least valuable coin each is
+# worth. One coin has to be worth 1
+currency_denominations = { gp = 100, sp = 10, cp = 1 }
+
+# Optional. The total experience needed for each level from 2 onwards, and the points every level
+# grants. Lore points only go to characters who can cast
+levelling = { thresholds = [300, 900, 2700, 6500, 14000, 23000, 34000, 48000, 64000, 85000], stat_points_per_level = 2, lore_points_per_level = 1 }
+
+# Optional. How many classes a character may have, the level they need before taking up another,
+# and pairs of classes that can't be combined. A `max_classes` of 1 turns multiclassing off
+multiclassing = { max_classes = 2, min_level = 3, forbidden = [] }
+
+# Optional. How many minutes before a scheduled session reminders go out to everyone attending.
+# Sessions are announced in the channel they're scheduled in unless another is picked
+session_reminders = [1440, 60]
+
+# Optional. A file laying out the questions `/character create` asks, relative to where the bot is
+# started. Leave it out to ask the default ones, which double as an example of the layout
+# wizard_file = \"wizard.toml\"
+
 ";
 
@@ -0,0 +1,67 @@
+// Time zones are stored as a fixed offset from UTC, in minutes. Named zones, and with them
+// daylight saving, are not supported
+
+/// Parses offsets such as `UTC`, `UTC+2`, `GMT-03:30` or `+05:45` into minutes east of UTC
+pub fn parse_utc_offset( input: &str ) -> Option<i32> {
+    let uppercase_input = input.trim().to_ascii_uppercase();
+    let offset = uppercase_input
+        .strip_prefix("UTC")
+        .or_else(|| uppercase_input.strip_prefix("GMT"))
+        .unwrap_or(&uppercase_input)
+        .trim();
+
+    if offset.is_empty() {
+        return if uppercase_input.is_empty() { None } else { Some(0) };
+    }
+
+    let (sign, hours_and_minutes) = if let Some(rest) = offset.strip_prefix('+') {
+        (1, rest)
+    } else if let Some(rest) = offset.strip_prefix('-') {
+        (-1, rest)
+    } else {
+        return None;
+    };
+
+    let (hours, minutes): (i32, i32) = match hours_and_minutes.split_once(':') {
+        Some((hours, minutes)) => (hours.parse().ok()?, minutes.parse().ok()?),
+        None => (hours_and_minutes.parse().ok()?, 0)
+    };
+
+    // The furthest offsets in use are UTC-12:00 and UTC+14:00
+    if !(0..=14).contains(&hours) || !(0..60).contains(&minutes) {
+        return None;
+    }
+
+    Some( sign * (hours * 60 + minutes) )
+}
+
+/// Formats minutes east of UTC as `UTC+HH:MM`
+pub fn format_utc_offset( offset_minutes: i32 ) -> String {
+    let sign = if offset_minutes < 0 { '-' } else { '+' };
+    let offset_minutes = offset_minutes.abs();
+
+    format!( "UTC{sign}{:02}:{:02}", offset_minutes / 60, offset_minutes % 60 )
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offsets_parse_and_format() {
+        assert_eq!(parse_utc_offset("UTC"), Some(0));
+        assert_eq!(parse_utc_offset("utc+2"), Some(120));
+        assert_eq!(parse_utc_offset("GMT-03:30"), Some(-210));
+        assert_eq!(parse_utc_offset(" +05:45 "), Some(345));
+        assert_eq!(parse_utc_offset("Europe/Warsaw"), None);
+        assert_eq!(parse_utc_offset("+15"), None);
+        assert_eq!(parse_utc_offset(""), None);
+        assert_eq!(parse_utc_offset("ż"), None);
+        assert_eq!(parse_utc_offset("UTC é"), None);
+
+        assert_eq!(format_utc_offset(-210), "UTC-03:30");
+        assert_eq!(format_utc_offset(0), "UTC+00:00");
+    }
+}
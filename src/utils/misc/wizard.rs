@@ -0,0 +1,396 @@
+use std::collections::HashMap;
+
+use toml::{Table, Value};
+
+use crate::runtime::sql_scripts::characters::SHEET_FIELDS;
+
+/// The questions asked when no `wizard_file` is configured
+pub const DEFAULT_WIZARD: &str = include_str!("../../data/wizard.toml");
+
+// Discord's limits on modals and buttons
+const MAX_FORM_FIELDS: usize = 5;
+const MAX_CHOICE_OPTIONS: usize = 20;
+const MAX_INPUT_LABEL: usize = 45;
+const MAX_BUTTON_LABEL: usize = 80;
+const MAX_INPUT_LENGTH: u16 = 4000;
+const MAX_TITLE: usize = 256;
+const MAX_DESCRIPTION: usize = 4096;
+
+// Our own, keeping the stage numbers and answer ids of custom IDs short
+const MAX_STAGES: usize = 20;
+const MAX_ID_LENGTH: usize = 40;
+const MAX_VALUE_LENGTH: usize = 100;
+const MAX_FILTERED_WORDS: usize = 500;
+
+/// Longest a character's name may be, so it fits in embed titles and select menu labels
+pub const NAME_MAX_LENGTH: u16 = 100;
+/// How long other answers may be when the wizard doesn't say
+const DEFAULT_MAX_LENGTH: u16 = 1000;
+/// Kept out of names, as they would be taken for markdown, mentions, or the separator of custom IDs
+const FORBIDDEN_NAME_CHARACTERS: [char; 10] = ['*', '_', '~', '`', '|', '<', '>', '@', '#', '\\'];
+
+/// An input of a form stage
+#[derive(Clone, Debug, PartialEq)]
+pub struct WizardField {
+    pub label: String,
+    pub id: String,
+    pub is_paragraph: bool,
+    pub required: bool,
+    pub min_length: Option<u16>,
+    pub max_length: Option<u16>
+}
+
+impl WizardField {
+    /// The most characters an answer may have, set on the input as well
+    pub fn length_limit(&self) -> u16 {
+        match (self.max_length, self.id.as_str()) {
+            (Some(max_length), _) => max_length,
+            (None, "name") => NAME_MAX_LENGTH,
+            (None, _) => DEFAULT_MAX_LENGTH
+        }
+    }
+
+    /// What's wrong with an answer to the field, if anything. Answers are expected to be trimmed
+    pub fn check_answer(&self, answer: &str, word_filter: &[String]) -> Option<String> {
+        let length = answer.chars().count();
+
+        if answer.is_empty() {
+            return self.required.then(|| String::from("can't be left blank"));
+        }
+        if let Some(min_length) = self.min_length && length < usize::from(min_length) {
+            return Some(format!( "needs at least {min_length} characters, it has {length}" ));
+        }
+        if length > usize::from(self.length_limit()) {
+            return Some(format!( "can have at most {} characters, it has {length}", self.length_limit() ));
+        }
+        if self.id == "name" {
+            if answer.chars().any(char::is_control) {
+                return Some(String::from("has to fit on a single line"));
+            }
+            if let Some(forbidden) = answer.chars().find(|char| FORBIDDEN_NAME_CHARACTERS.contains(char)) {
+                return Some(format!( "can't have `{forbidden}` in it" ));
+            }
+        }
+
+        // Whole words only, so words merely containing a filtered one are left alone
+        let has_filtered_word = answer.split(|char: char| !char.is_alphanumeric())
+            .any(|word| word_filter.iter().any(|filtered| filtered.eq_ignore_ascii_case(word)));
+        if has_filtered_word {
+            return Some(String::from("uses a word that isn't allowed here"));
+        }
+
+        None
+    }
+}
+
+/// A button of a choice stage
+#[derive(Clone, Debug, PartialEq)]
+pub struct WizardOption {
+    pub label: String,
+    pub value: String
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum StageKind {
+    /// Opens a modal with these inputs
+    Form(Vec<WizardField>),
+    /// Stores the value of whichever option is pressed under `id`
+    Choice { id: String, options: Vec<WizardOption> },
+    /// Offers the classes in `CharacterClasses`, storing the ID of the one picked under `class`
+    Class
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct WizardStage {
+    pub title: String,
+    pub description: String,
+    pub kind: StageKind
+}
+
+/// The stages of the character creation wizard, read from `src/data/wizard.toml` or the file the
+/// config's `wizard_file` points to
+#[derive(Clone, Debug, PartialEq)]
+pub struct Wizard {
+    pub stages: Vec<WizardStage>,
+    /// Words no answer may use, compared regardless of case
+    pub word_filter: Vec<String>
+}
+
+impl Wizard {
+    /// Reads and validates a wizard definition, see `src/data/wizard.toml` for its layout
+    pub fn parse( wizard_data: &str ) -> Result<Wizard, String> {
+        let wizard_table = wizard_data.parse::<Table>().map_err(|why| why.to_string())?;
+
+        let stage_entries = match wizard_table.get("stage") {
+            Some(Value::Array(stage_entries)) if !stage_entries.is_empty() => stage_entries,
+            _ => return Err(String::from("The wizard needs a list of `[[stage]]` entries"))
+        };
+        if stage_entries.len() > MAX_STAGES {
+            return Err(format!( "The wizard can have at most {MAX_STAGES} stages" ));
+        }
+
+        let stages = stage_entries.iter()
+            .enumerate()
+            .map(|(idx, stage_entry)| parse_stage(idx + 1, stage_entry))
+            .collect::<Result<Vec<WizardStage>, String>>()?;
+
+        let word_filter = match wizard_table.get("word_filter") {
+            None => vec![],
+            Some(Value::Array(words)) if words.len() <= MAX_FILTERED_WORDS => words.iter()
+                .map(|word| match word {
+                    Value::String(word) if !word.trim().is_empty() && word.trim().chars().all(char::is_alphanumeric) => Ok(word.trim().to_string()),
+                    _ => Err(String::from("Every word of the `word_filter` has to be a single word of letters and digits"))
+                })
+                .collect::<Result<Vec<String>, String>>()?,
+            Some(_) => return Err(format!( "The `word_filter` has to be a list of up to {MAX_FILTERED_WORDS} words" ))
+        };
+        let wizard = Wizard { stages, word_filter };
+
+        // Every answer needs somewhere of its own to be kept
+        let answer_ids: Vec<&str> = wizard.stages.iter().flat_map(|stage| stage.answer_ids()).collect();
+        if let Some(duplicate_id) = answer_ids.iter().enumerate().find_map(|(idx, id)| answer_ids[..idx].contains(id).then_some(id)) {
+            return Err(format!( "`{duplicate_id}` is asked more than once" ));
+        }
+
+        let name_field = wizard.stages.iter().find_map(|stage| match &stage.kind {
+            StageKind::Form(fields) => fields.iter().find(|field| field.id == "name"),
+            StageKind::Choice { .. } | StageKind::Class => None
+        });
+        match name_field {
+            Some(name_field) if !name_field.required => return Err(String::from("The `name` has to be required")),
+            Some(name_field) if name_field.length_limit() > NAME_MAX_LENGTH => {
+                return Err(format!( "The `name` can have a `max_length` of at most {NAME_MAX_LENGTH}" ));
+            },
+            Some(_) => {},
+            None => return Err(String::from("The wizard has to ask for a `name` in a form"))
+        }
+        if !wizard.stages.iter().any(|stage| stage.kind == StageKind::Class) {
+            return Err(String::from("The wizard has to ask for a `class` through a choice stage"));
+        }
+
+        Ok(wizard)
+    }
+
+    /// The stage shown for a stage number, those start from 1 as 0 is the welcome message
+    pub fn stage(&self, stage_number: u8) -> Option<&WizardStage> {
+        usize::from(stage_number).checked_sub(1).and_then(|idx| self.stages.get(idx))
+    }
+
+    /// What's wrong with the answers to a stage's form, as lines naming the input for each problem.
+    /// Answers are expected to be trimmed, and those not given count as blank
+    pub fn check_stage(&self, stage_number: u8, answers: &HashMap<String, String>) -> Vec<String> {
+        let Some(StageKind::Form(fields)) = self.stage(stage_number).map(|stage| &stage.kind) else {
+            return vec![];
+        };
+
+        fields.iter()
+            .filter_map(|field| {
+                let answer = answers.get(&field.id).map_or("", String::as_str);
+                field.check_answer(answer, &self.word_filter).map(|problem| format!( "**{}** {problem}", field.label ))
+            })
+            .collect()
+    }
+
+    /// The stage asking for an answer, for sending players back to it
+    pub fn stage_of(&self, answer_id: &str) -> Option<u8> {
+        self.stages.iter()
+            .position(|stage| stage.answer_ids().contains(&answer_id))
+            .map(|idx| idx as u8 + 1)
+    }
+
+    /// What an answer is called, for the character sheet
+    pub fn label_of(&self, answer_id: &str) -> Option<&str> {
+        self.stages.iter().find_map(|stage| match &stage.kind {
+            StageKind::Form(fields) => fields.iter().find(|field| field.id == answer_id).map(|field| field.label.as_str()),
+            StageKind::Choice { .. } | StageKind::Class => None
+        })
+    }
+
+    /// The answer ids that are neither sheet fields nor the class, in the order they're asked
+    pub fn custom_field_ids(&self) -> Vec<&str> {
+        self.stages.iter()
+            .flat_map(|stage| stage.answer_ids())
+            .filter(|id| *id != "class" && !SHEET_FIELDS.contains(id))
+            .collect()
+    }
+}
+
+impl WizardStage {
+    fn answer_ids(&self) -> Vec<&str> {
+        match &self.kind {
+            StageKind::Form(fields) => fields.iter().map(|field| field.id.as_str()).collect(),
+            StageKind::Choice { id, .. } => vec![id.as_str()],
+            StageKind::Class => vec!["class"]
+        }
+    }
+}
+
+
+
+// --== PARSING ==-- //
+
+    fn string_entry( entry: &Value, key: &str, location: &str, max_length: usize ) -> Result<String, String> {
+        match entry.get(key) {
+            Some(Value::String(value)) if !value.trim().is_empty() && value.trim().chars().count() <= max_length => Ok(value.trim().to_string()),
+            _ => Err(format!( "{location} needs a `{key}` of 1 to {max_length} characters" ))
+        }
+    }
+
+    /// Ids end up in custom IDs and the database, so they are kept plain
+    fn answer_id( entry: &Value, key: &str, location: &str ) -> Result<String, String> {
+        let id = string_entry(entry, key, location, MAX_ID_LENGTH)?;
+        if !id.chars().all(|char| char.is_ascii_lowercase() || char.is_ascii_digit() || char == '-' || char == '_') {
+            return Err(format!( "{location} has `{key}` `{id}`, which may only use lowercase letters, digits, `-` and `_`" ));
+        }
+        Ok(id)
+    }
+
+    fn input_length( entry: &Value, key: &str, location: &str ) -> Result<Option<u16>, String> {
+        match entry.get(key) {
+            None => Ok(None),
+            Some(Value::Integer(length)) if (0..=i64::from(MAX_INPUT_LENGTH)).contains(length) => Ok(Some(*length as u16)),
+            Some(_) => Err(format!( "{location} has a `{key}` that isn't a whole number from 0 to {MAX_INPUT_LENGTH}" ))
+        }
+    }
+
+    fn parse_stage( stage_number: usize, stage_entry: &Value ) -> Result<WizardStage, String> {
+        let location = format!( "Stage #{stage_number}" );
+
+        let title = string_entry(stage_entry, "title", &location, MAX_TITLE)?;
+        let description = match stage_entry.get("description") {
+            None => String::new(),
+            Some(Value::String(description)) if description.chars().count() <= MAX_DESCRIPTION => description.trim().to_string(),
+            Some(_) => return Err(format!( "{location} has a `description` that isn't text of up to {MAX_DESCRIPTION} characters" ))
+        };
+
+        let kind = match (stage_entry.get("field"), stage_entry.get("choice")) {
+            (Some(Value::Array(field_entries)), None) => {
+                if field_entries.is_empty() || field_entries.len() > MAX_FORM_FIELDS {
+                    return Err(format!( "{location} needs 1 to {MAX_FORM_FIELDS} fields" ));
+                }
+
+                let fields = field_entries.iter()
+                    .enumerate()
+                    .map(|(idx, field_entry)| parse_field(&format!( "{location}, field #{}", idx + 1 ), field_entry))
+                    .collect::<Result<Vec<WizardField>, String>>()?;
+                StageKind::Form(fields)
+            },
+            (None, Some(_)) => {
+                let id = answer_id(stage_entry, "choice", &location)?;
+
+                // The classes come from the database, where staff manage them
+                if id == "class" {
+                    if stage_entry.get("option").is_some() {
+                        return Err(format!( "{location} asks for the `class`, whose options are the classes staff have set up" ));
+                    }
+                    return Ok(WizardStage { title, description, kind: StageKind::Class });
+                }
+
+                let option_entries = match stage_entry.get("option") {
+                    Some(Value::Array(option_entries)) if !option_entries.is_empty() && option_entries.len() <= MAX_CHOICE_OPTIONS => option_entries,
+                    _ => return Err(format!( "{location} needs 1 to {MAX_CHOICE_OPTIONS} `[[stage.option]]` entries" ))
+                };
+                let options = option_entries.iter()
+                    .enumerate()
+                    .map(|(idx, option_entry)| {
+                        let option_location = format!( "{location}, option #{}", idx + 1 );
+                        Ok(WizardOption {
+                            label: string_entry(option_entry, "label", &option_location, MAX_BUTTON_LABEL)?,
+                            value: string_entry(option_entry, "value", &option_location, MAX_VALUE_LENGTH)?
+                        })
+                    })
+                    .collect::<Result<Vec<WizardOption>, String>>()?;
+                StageKind::Choice { id, options }
+            },
+            _ => return Err(format!( "{location} needs either `[[stage.field]]` entries or a `choice`, but not both" ))
+        };
+
+        Ok(WizardStage { title, description, kind })
+    }
+
+    fn parse_field( location: &str, field_entry: &Value ) -> Result<WizardField, String> {
+        let field = WizardField {
+            label: string_entry(field_entry, "label", location, MAX_INPUT_LABEL)?,
+            id: answer_id(field_entry, "id", location)?,
+            is_paragraph: match field_entry.get("style") {
+                None => false,
+                Some(Value::String(style)) if style == "short" => false,
+                Some(Value::String(style)) if style == "paragraph" => true,
+                Some(_) => return Err(format!( "{location} has a `style` that isn't \"short\" or \"paragraph\"" ))
+            },
+            required: match field_entry.get("required") {
+                None => true,
+                Some(Value::Boolean(required)) => *required,
+                Some(_) => return Err(format!( "{location} has a `required` that isn't true or false" ))
+            },
+            min_length: input_length(field_entry, "min_length", location)?,
+            max_length: input_length(field_entry, "max_length", location)?
+        };
+
+        if let (Some(min_length), Some(max_length)) = (field.min_length, field.max_length) && min_length > max_length {
+            return Err(format!( "{location} has a `min_length` above its `max_length`" ));
+        }
+        if field.max_length == Some(0) {
+            return Err(format!( "{location} has a `max_length` of 0" ));
+        }
+
+        Ok(field)
+    }
+// ==--
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shipped_wizard_is_valid() {
+        let wizard = Wizard::parse(DEFAULT_WIZARD).expect("Shipped wizard should parse");
+
+        assert_eq!(wizard.stages.len(), 5);
+        assert!(wizard.stage(0).is_none());
+        assert_eq!(wizard.stage(5).expect("Stage exists").kind, StageKind::Class);
+        assert_eq!(wizard.label_of("extras"), Some("Extra"));
+        assert!(wizard.custom_field_ids().is_empty());
+    }
+
+    #[test]
+    fn wizards_are_validated() {
+        let choice = "[[stage]]\ntitle = \"Class\"\nchoice = \"class\"\n";
+        let name = "[[stage]]\ntitle = \"Name\"\n[[stage.field]]\nlabel = \"Name\"\nid = \"name\"\n";
+
+        let wizard = Wizard::parse(&format!( "{name}[[stage.field]]\nlabel = \"Deity\"\nid = \"deity\"\nrequired = false\n{choice}" ))
+            .expect("Wizard is valid");
+        assert_eq!(wizard.custom_field_ids(), vec!["deity"]);
+
+        // Missing the class, asking twice, options for the class, and an unsafe id
+        assert!(Wizard::parse(name).is_err());
+        assert!(Wizard::parse(&format!( "{name}{name}{choice}" )).is_err());
+        assert!(Wizard::parse(&format!( "{name}{choice}[[stage.option]]\nlabel = \"Bard\"\nvalue = \"bard\"\n" )).is_err());
+        assert!(Wizard::parse(&format!( "{}{choice}", name.replace("id = \"name\"", "id = \"name\"\n[[stage.field]]\nlabel = \"Home\"\nid = \"home|town\"") )).is_err());
+        assert!(Wizard::parse(&format!( "{}{choice}", name.replace("id = \"name\"", "id = \"name\"\nmin_length = 10\nmax_length = 5") )).is_err());
+        assert!(Wizard::parse(&format!( "{}{choice}", name.replace("id = \"name\"", "id = \"name\"\nmax_length = 500") )).is_err());
+        assert!(Wizard::parse(&format!( "word_filter = [\"two words\"]\n{name}{choice}" )).is_err());
+    }
+
+    #[test]
+    fn answers_are_checked() {
+        let wizard_data = format!( "word_filter = [\"Grog\"]\n{DEFAULT_WIZARD}" );
+        let wizard = Wizard::parse(&wizard_data).expect("Wizard is valid");
+        let answers = |pairs: &[(&str, &str)]| -> HashMap<String, String> {
+            pairs.iter().map(|(id, answer)| (id.to_string(), answer.to_string())).collect()
+        };
+
+        assert!(wizard.check_stage(1, &answers(&[("name", "Lirael"), ("species", "Elf"), ("appearance", "Tall")])).is_empty());
+        assert!(wizard.check_stage(1, &answers(&[("name", "Grogan"), ("species", "Elf"), ("appearance", "Tall")])).is_empty(), "Only whole words are filtered");
+
+        let problems = wizard.check_stage(1, &answers(&[("name", "**Lirael**"), ("species", "Elf")]));
+        assert_eq!(problems, vec!["**Name** can't have `*` in it", "**Appearance** can't be left blank"]);
+        let problems = wizard.check_stage(1, &answers(&[("name", &"a".repeat(101)), ("species", "Elf"), ("appearance", "Smells of grog")]));
+        assert_eq!(problems, vec!["**Name** can have at most 100 characters, it has 101", "**Appearance** uses a word that isn't allowed here"]);
+
+        assert_eq!(wizard.stage_of("likes"), Some(2));
+        assert_eq!(wizard.stage_of("class"), Some(5));
+    }
+}